@@ -5,7 +5,9 @@
 #include <wasmi/config.h>
 #include <wasmi/engine.h>
 #include <wasmi/error.h>
+#include <wasmi/module.h>
 #include <wasmi/store.h>
+#include <wasmi/trap.h>
 
 /**
  * \brief Wasmi version string.
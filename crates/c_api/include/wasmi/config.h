@@ -9,6 +9,8 @@
 
 #include <wasm.h>
 
+#define own
+
 #ifdef __cplusplus
 extern "C" {
 #endif
@@ -125,8 +127,85 @@ WASMI_CONFIG_PROP(void, compilation_mode, enum wasmi_compilation_mode_enum)
 
 #undef WASMI_CONFIG_PROP
 
+/**
+ * \brief A set of Wasm proposals to apply to a #wasm_config_t.
+ *
+ * All proposals are disabled upon construction via #wasm_features_new.
+ */
+WASM_DECLARE_OWN(features)
+
+/**
+ * \brief Creates a new #wasm_features_t with all Wasm proposals disabled.
+ *
+ * The returned #wasm_features_t must be deleted using #wasm_features_delete.
+ */
+WASM_API_EXTERN own wasm_features_t *wasm_features_new(void);
+
+#define WASM_FEATURES_PROP(name)                                               \
+  WASM_API_EXTERN void wasm_features_set_##name(wasm_features_t *, bool);
+
+/**
+ * \brief Whether or not the Wasm mutable-globals proposal is enabled.
+ */
+WASM_FEATURES_PROP(mutable_global)
+
+/**
+ * \brief Whether or not the Wasm sign-extension proposal is enabled.
+ */
+WASM_FEATURES_PROP(sign_extension)
+
+/**
+ * \brief Whether or not the Wasm non-trapping-float-to-int-conversions
+ * proposal is enabled.
+ */
+WASM_FEATURES_PROP(saturating_float_to_int)
+
+/**
+ * \brief Whether or not the Wasm multi-value proposal is enabled.
+ */
+WASM_FEATURES_PROP(multi_value)
+
+/**
+ * \brief Whether or not the Wasm multi-memory proposal is enabled.
+ */
+WASM_FEATURES_PROP(multi_memory)
+
+/**
+ * \brief Whether or not the Wasm bulk-memory-ops proposal is enabled.
+ */
+WASM_FEATURES_PROP(bulk_memory)
+
+/**
+ * \brief Whether or not the Wasm reference-types proposal is enabled.
+ */
+WASM_FEATURES_PROP(reference_types)
+
+/**
+ * \brief Whether or not the Wasm tail-call proposal is enabled.
+ */
+WASM_FEATURES_PROP(tail_call)
+
+/**
+ * \brief Whether or not the Wasm extended-const proposal is enabled.
+ */
+WASM_FEATURES_PROP(extended_const)
+
+#undef WASM_FEATURES_PROP
+
+/**
+ * \brief Applies all Wasm proposals of the #wasm_features_t to the
+ * #wasm_config_t at once.
+ *
+ * Proposals that are disabled in the #wasm_features_t are disabled for the
+ * #wasm_config_t. The #wasm_features_t remains owned by the caller.
+ */
+WASM_API_EXTERN void wasm_config_set_features(wasm_config_t *,
+                                              const wasm_features_t *);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif
 
+#undef own
+
 #endif // WASMI_CONFIG_H
@@ -0,0 +1,70 @@
+/**
+ * \file wasmi/module.h
+ *
+ * \brief Wasmi-specific extensions to #wasm_module_t
+ *
+ * \section serialization Serialization
+ *
+ * Wasmi does not support precompiled modules. The binary produced by
+ * #wasm_module_serialize is the raw Wasm binary from which the module was
+ * created, and #wasm_module_deserialize as well as #wasmi_module_deserialize
+ * parse, validate and translate it again. Deserialization is therefore not
+ * cheaper than #wasm_module_new.
+ *
+ * \section memory Memory Usage
+ *
+ * In order to support serialization every #wasm_module_t keeps a copy of the
+ * Wasm binary from which it was created in memory for as long as the module,
+ * or any #wasm_shared_module_t obtained from it, is alive.
+ */
+
+#ifndef WASMI_MODULE_H
+#define WASMI_MODULE_H
+
+#include <wasm.h>
+
+#define own
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * \brief Deserializes a module from the binary produced by #wasm_module_serialize.
+ *
+ * \param store the store used to create the module.
+ * \param binary the serialized module binary.
+ * \param trap stores a newly created trap describing the error if
+ *        deserialization failed. May be `NULL`.
+ *
+ * Unlike #wasm_module_deserialize this reports why deserialization failed.
+ *
+ * Returns `NULL` if deserialization failed. In this case the trap stored in
+ * `trap` is owned by the caller and must be deleted with #wasm_trap_delete.
+ *
+ * Wasmi does not support precompiled modules and thus this parses,
+ * validates and translates the serialized Wasm binary again.
+ */
+WASM_API_EXTERN own wasm_module_t *
+wasmi_module_deserialize(wasm_store_t *store, const wasm_byte_vec_t *binary,
+                         own wasm_trap_t **trap);
+
+/**
+ * \brief Stores the Wasm binary from which the module was created in `out`.
+ *
+ * This is the same binary that #wasm_module_serialize produces.
+ *
+ * The `out` argument should be uninitialized before this function is called
+ * and the caller is responsible for deallocating it with
+ * #wasm_byte_vec_delete afterwards.
+ */
+WASM_API_EXTERN void wasmi_module_binary(const wasm_module_t *module,
+                                         own wasm_byte_vec_t *out);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#undef own
+
+#endif // WASMI_MODULE_H
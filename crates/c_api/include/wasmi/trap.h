@@ -0,0 +1,43 @@
+/**
+ * \file wasmi/trap.h
+ *
+ * \brief Wasmi-specific extensions to #wasm_trap_t
+ */
+
+#ifndef WASMI_TRAP_H
+#define WASMI_TRAP_H
+
+#include <wasm.h>
+
+#define own
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * \brief Creates a new trap with the provided message.
+ *
+ * \param msg the message of the trap, which does not need to be null-terminated.
+ * \param msg_len the length of `msg` in bytes.
+ *
+ * Unlike #wasm_trap_new this does not require a #wasm_store_t and the message
+ * is given as a pointer and length pair. Invalid UTF-8 sequences within `msg`
+ * are replaced.
+ *
+ * The returned trap can be returned from host function callbacks in order to
+ * abort the execution of the calling Wasm function with the given message.
+ *
+ * The returned trap is owned by the caller and must be deleted with
+ * #wasm_trap_delete unless ownership is transferred by returning it from a
+ * host function callback.
+ */
+WASM_API_EXTERN own wasm_trap_t *wasmi_trap_new(const char *msg, size_t msg_len);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#undef own
+
+#endif // WASMI_TRAP_H
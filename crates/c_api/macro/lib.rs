@@ -94,6 +94,30 @@ pub fn declare_ref(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         This is not yet supported and aborts the process upon use."
     );
 
+    // Note: `wasm_func_t` implements its conversions to `wasm_ref_t` manually.
+    let as_ref_impls = match name.as_str() {
+        "wasm_func_t" => quote! {},
+        _ => quote! {
+            #[doc = #as_ref_docs]
+            #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+            #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+            pub extern "C" fn #as_ref(a: &#ty) -> ::alloc::boxed::Box<crate::wasm_ref_t> {
+                #[cfg(feature = "std")]
+                ::std::eprintln!("`{}` is not implemented", ::core::stringify!(#as_ref));
+                ::core::unimplemented!(::core::stringify!(#as_ref));
+            }
+
+            #[doc = #as_ref_const_docs]
+            #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+            #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+            pub extern "C" fn #as_ref_const(a: &#ty) -> ::alloc::boxed::Box<crate::wasm_ref_t> {
+                #[cfg(feature = "std")]
+                ::std::eprintln!("`{}` is not implemented", ::core::stringify!(#as_ref_const));
+                ::core::unimplemented!(::core::stringify!(#as_ref_const));
+            }
+        },
+    };
+
     (quote! {
         ::wasmi_c_api_macros::declare_ty!(#ty);
 
@@ -135,23 +159,7 @@ pub fn declare_ref(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
             ::core::unimplemented!(::core::stringify!(#set_host_info_final));
         }
 
-        #[doc = #as_ref_docs]
-        #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
-        #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
-        pub extern "C" fn #as_ref(a: &#ty) -> ::alloc::boxed::Box<crate::wasm_ref_t> {
-            #[cfg(feature = "std")]
-            ::std::eprintln!("`{}` is not implemented", ::core::stringify!(#as_ref));
-            ::core::unimplemented!(::core::stringify!(#as_ref));
-        }
-
-        #[doc = #as_ref_const_docs]
-        #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
-        #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
-        pub extern "C" fn #as_ref_const(a: &#ty) -> ::alloc::boxed::Box<crate::wasm_ref_t> {
-            #[cfg(feature = "std")]
-            ::std::eprintln!("`{}` is not implemented", ::core::stringify!(#as_ref_const));
-            ::core::unimplemented!(::core::stringify!(#as_ref_const));
-        }
+        #as_ref_impls
 
         // TODO: implement `wasm_ref_as_#name#`
         // TODO: implement `wasm_ref_as_#name#_const`
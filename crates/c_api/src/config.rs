@@ -164,3 +164,136 @@ pub extern "C" fn wasmi_config_ignore_custom_sections_set(
 ) {
     config.inner.ignore_custom_sections(enable);
 }
+
+/// A set of Wasm proposals to enable or disable for a [`wasm_config_t`].
+///
+/// All proposals are disabled by default and must be enabled explicitly.
+/// Apply the set to a [`wasm_config_t`] using [`wasm_config_set_features`].
+#[repr(C)]
+#[derive(Clone, Default)]
+pub struct wasm_features_t {
+    mutable_global: bool,
+    sign_extension: bool,
+    saturating_float_to_int: bool,
+    multi_value: bool,
+    multi_memory: bool,
+    bulk_memory: bool,
+    reference_types: bool,
+    tail_call: bool,
+    extended_const: bool,
+}
+
+wasmi_c_api_macros::declare_own!(wasm_features_t);
+
+/// Creates a new [`wasm_features_t`] with all Wasm proposals disabled.
+///
+/// The returned [`wasm_features_t`] must be freed using [`wasm_features_delete`].
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_features_new() -> Box<wasm_features_t> {
+    Box::new(wasm_features_t::default())
+}
+
+/// Enables or disables the Wasm [`mutable-global`] proposal for the [`wasm_features_t`].
+///
+/// [`mutable-global`]: <https://github.com/WebAssembly/mutable-global>
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_features_set_mutable_global(features: &mut wasm_features_t, enable: bool) {
+    features.mutable_global = enable;
+}
+
+/// Enables or disables the Wasm [`sign-extension-ops`] proposal for the [`wasm_features_t`].
+///
+/// [`sign-extension-ops`]: <https://github.com/WebAssembly/sign-extension-ops>
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_features_set_sign_extension(features: &mut wasm_features_t, enable: bool) {
+    features.sign_extension = enable;
+}
+
+/// Enables or disables the Wasm [`nontrapping-float-to-int-conversions`] proposal for the [`wasm_features_t`].
+///
+/// [`nontrapping-float-to-int-conversions`]: <https://github.com/WebAssembly/nontrapping-float-to-int-conversions>
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_features_set_saturating_float_to_int(
+    features: &mut wasm_features_t,
+    enable: bool,
+) {
+    features.saturating_float_to_int = enable;
+}
+
+/// Enables or disables the Wasm [`multi-value`] proposal for the [`wasm_features_t`].
+///
+/// [`multi-value`]: <https://github.com/WebAssembly/multi-value>
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_features_set_multi_value(features: &mut wasm_features_t, enable: bool) {
+    features.multi_value = enable;
+}
+
+/// Enables or disables the Wasm [`multi-memory`] proposal for the [`wasm_features_t`].
+///
+/// [`multi-memory`]: <https://github.com/WebAssembly/multi-memory>
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_features_set_multi_memory(features: &mut wasm_features_t, enable: bool) {
+    features.multi_memory = enable;
+}
+
+/// Enables or disables the Wasm [`bulk-memory-operations`] proposal for the [`wasm_features_t`].
+///
+/// [`bulk-memory-operations`]: <https://github.com/WebAssembly/bulk-memory-operations>
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_features_set_bulk_memory(features: &mut wasm_features_t, enable: bool) {
+    features.bulk_memory = enable;
+}
+
+/// Enables or disables the Wasm [`reference-types`] proposal for the [`wasm_features_t`].
+///
+/// [`reference-types`]: <https://github.com/WebAssembly/reference-types>
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_features_set_reference_types(features: &mut wasm_features_t, enable: bool) {
+    features.reference_types = enable;
+}
+
+/// Enables or disables the Wasm [`tail-call`] proposal for the [`wasm_features_t`].
+///
+/// [`tail-call`]: <https://github.com/WebAssembly/tail-call>
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_features_set_tail_call(features: &mut wasm_features_t, enable: bool) {
+    features.tail_call = enable;
+}
+
+/// Enables or disables the Wasm [`extended-const`] proposal for the [`wasm_features_t`].
+///
+/// [`extended-const`]: <https://github.com/WebAssembly/extended-const>
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_features_set_extended_const(features: &mut wasm_features_t, enable: bool) {
+    features.extended_const = enable;
+}
+
+/// Applies all Wasm proposals of `features` to the `config` at once.
+///
+/// Proposals disabled in `features` are disabled for the `config`.
+/// The `features` remain owned by the caller.
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_config_set_features(config: &mut wasm_config_t, features: &wasm_features_t) {
+    config
+        .inner
+        .wasm_mutable_global(features.mutable_global)
+        .wasm_sign_extension(features.sign_extension)
+        .wasm_saturating_float_to_int(features.saturating_float_to_int)
+        .wasm_multi_value(features.multi_value)
+        .wasm_multi_memory(features.multi_memory)
+        .wasm_bulk_memory(features.bulk_memory)
+        .wasm_reference_types(features.reference_types)
+        .wasm_tail_call(features.tail_call)
+        .wasm_extended_const(features.extended_const);
+}
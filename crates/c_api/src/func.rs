@@ -1,10 +1,13 @@
 use crate::{
     wasm_extern_t,
     wasm_functype_t,
+    wasm_ref_t,
     wasm_store_t,
     wasm_trap_t,
     wasm_val_t,
     wasm_val_vec_t,
+    WasmRef,
+    WasmStoreRef,
 };
 use alloc::{boxed::Box, string::String, vec, vec::Vec};
 use core::{any::Any, ffi::c_void, hint, iter, panic::AssertUnwindSafe, ptr, str};
@@ -49,6 +52,16 @@ impl wasm_func_t {
         }
     }
 
+    /// Creates a new [`wasm_func_t`] for the `func` owned by `store`.
+    pub(crate) fn new(store: WasmStoreRef, func: Func) -> Self {
+        Self {
+            inner: wasm_extern_t {
+                store,
+                which: func.into(),
+            },
+        }
+    }
+
     /// Returns the underlying [`Func`] of the [`wasm_func_t`].
     pub(crate) fn func(&self) -> Func {
         match self.inner.which {
@@ -78,14 +91,19 @@ unsafe fn create_function(
         + 'static,
 ) -> Box<wasm_func_t> {
     let ty = ty.ty().ty.clone();
+    // The host function is owned by the store and thus must not keep it alive.
+    let weak_store = store.inner.downgrade();
     let func = Func::new(
         store.inner.context_mut(),
         ty,
         move |_caller, params, results| {
+            let store = weak_store
+                .upgrade()
+                .expect("the store outlives calls to its host functions");
             let params: wasm_val_vec_t = params
                 .iter()
                 .cloned()
-                .map(wasm_val_t::from)
+                .map(|param| wasm_val_t::from_val(param, &store))
                 .collect::<Box<[_]>>()
                 .into();
             let mut out_results: wasm_val_vec_t = vec![wasm_val_t::default(); results.len()].into();
@@ -101,12 +119,7 @@ unsafe fn create_function(
             Ok(())
         },
     );
-    Box::new(wasm_func_t {
-        inner: wasm_extern_t {
-            store: store.inner.clone(),
-            which: func.into(),
-        },
-    })
+    Box::new(wasm_func_t::new(store.inner.clone(), func))
 }
 
 /// Creates a new [`wasm_func_t`] of type [`wasm_functype_t`] for the [`wasm_store_t`].
@@ -218,7 +231,7 @@ pub unsafe extern "C" fn wasm_func_call(
     match result {
         Ok(Ok(())) => {
             for (slot, val) in results.iter_mut().zip(wt_results.iter().cloned()) {
-                crate::initialize(slot, wasm_val_t::from(val));
+                crate::initialize(slot, wasm_val_t::from_val(val, &func.inner.store));
             }
             ptr::null_mut()
         }
@@ -301,3 +314,34 @@ pub extern "C" fn wasm_func_as_extern(f: &mut wasm_func_t) -> &mut wasm_extern_t
 pub extern "C" fn wasm_func_as_extern_const(f: &wasm_func_t) -> &wasm_extern_t {
     &f.inner
 }
+
+/// Returns the [`wasm_func_t`] as new [`wasm_ref_t`] function reference.
+///
+/// The returned [`wasm_ref_t`] can be stored into tables via [`wasm_table_set`]
+/// and converted back via [`wasm_ref_as_func`].
+///
+/// The caller is responsible for deleting the returned [`wasm_ref_t`].
+///
+/// [`wasm_table_set`]: crate::wasm_table_set
+/// [`wasm_ref_as_func`]: crate::wasm_ref_as_func
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_func_as_ref(f: &mut wasm_func_t) -> Box<wasm_ref_t> {
+    wasm_func_as_ref_const(f)
+}
+
+/// Returns the [`wasm_func_t`] as new [`wasm_ref_t`] function reference.
+///
+/// The returned [`wasm_ref_t`] can be stored into tables via [`wasm_table_set`]
+/// and converted back via [`wasm_ref_as_func_const`].
+///
+/// The caller is responsible for deleting the returned [`wasm_ref_t`].
+///
+/// [`wasm_table_set`]: crate::wasm_table_set
+/// [`wasm_ref_as_func_const`]: crate::wasm_ref_as_func_const
+#[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
+#[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
+pub extern "C" fn wasm_func_as_ref_const(f: &wasm_func_t) -> Box<wasm_ref_t> {
+    wasm_ref_t::new(WasmRef::Func(FuncRef::new(f.func())), &f.inner.store)
+        .expect("non-null function references always yield a `wasm_ref_t`")
+}
@@ -110,10 +110,8 @@ pub unsafe extern "C" fn wasm_global_type(g: &wasm_global_t) -> Box<wasm_globalt
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
 pub unsafe extern "C" fn wasm_global_get(g: &mut wasm_global_t, out: &mut MaybeUninit<wasm_val_t>) {
     let global = g.global();
-    crate::initialize(
-        out,
-        wasm_val_t::from(global.get(g.inner.store.context_mut())),
-    );
+    let value = global.get(g.inner.store.context_mut());
+    crate::initialize(out, wasm_val_t::from_val(value, &g.inner.store));
 }
 
 /// Sets the current value of the [`wasm_global_t`].
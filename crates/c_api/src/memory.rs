@@ -1,7 +1,7 @@
 use crate::{wasm_extern_t, wasm_memorytype_t, wasm_store_t};
 use alloc::boxed::Box;
 use core::hint;
-use wasmi::{Extern, Memory};
+use wasmi::{Extern, Memory, MemoryType};
 
 /// A Wasm linear memory.
 ///
@@ -82,6 +82,10 @@ pub extern "C" fn wasm_memory_as_extern_const(m: &wasm_memory_t) -> &wasm_extern
 ///
 /// Wraps [`Memory::ty`].
 ///
+/// The minimum limit of the returned [`wasm_memorytype_t`] reflects the
+/// current number of pages of the [`wasm_memory_t`] which might have grown
+/// since its creation.
+///
 /// # Safety
 ///
 /// It is the caller's responsibility not to alias the [`wasm_memory_t`]
@@ -89,7 +93,11 @@ pub extern "C" fn wasm_memory_as_extern_const(m: &wasm_memory_t) -> &wasm_extern
 #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
 pub unsafe extern "C" fn wasm_memory_type(m: &wasm_memory_t) -> Box<wasm_memorytype_t> {
-    let ty = m.memory().ty(m.inner.store.context());
+    let memory = m.memory();
+    let ty = memory.ty(m.inner.store.context());
+    let pages = memory.size(m.inner.store.context());
+    let ty = MemoryType::new(pages, ty.maximum_pages().map(Into::into))
+        .unwrap_or_else(|_| panic!("the current size of a memory must be valid for its type"));
     Box::new(wasm_memorytype_t::new(ty))
 }
 
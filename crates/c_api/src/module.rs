@@ -5,10 +5,11 @@ use crate::{
     wasm_importtype_t,
     wasm_importtype_vec_t,
     wasm_store_t,
+    wasm_trap_t,
     CExternType,
 };
-use alloc::{boxed::Box, string::String};
-use wasmi::{Engine, Module};
+use alloc::{boxed::Box, string::String, sync::Arc};
+use wasmi::{Engine, Error, Module};
 
 /// A Wasm module.
 ///
@@ -16,16 +17,34 @@ use wasmi::{Engine, Module};
 #[derive(Clone)]
 pub struct wasm_module_t {
     pub(crate) inner: Module,
+    /// The Wasm binary from which the [`Module`] was created.
+    binary: Arc<[u8]>,
 }
 
 wasmi_c_api_macros::declare_ref!(wasm_module_t);
 
 impl wasm_module_t {
-    pub(crate) fn new(module: Module) -> wasm_module_t {
-        wasm_module_t { inner: module }
+    pub(crate) fn new(module: Module, binary: Arc<[u8]>) -> wasm_module_t {
+        wasm_module_t {
+            inner: module,
+            binary,
+        }
     }
 }
 
+/// Creates a new [`wasm_module_t`] for `store` from the given Wasm `binary`.
+///
+/// Keeps the Wasm `binary` for [`wasm_module_serialize`] and [`wasmi_module_binary`].
+///
+/// # Safety
+///
+/// It is the caller's responsibility not to alias the [`wasm_module_t`]
+/// with its underlying, internal [`WasmStoreRef`](crate::WasmStoreRef).
+unsafe fn new_module(store: &mut wasm_store_t, binary: &[u8]) -> Result<Box<wasm_module_t>, Error> {
+    let module = Module::new(store.inner.context().engine(), binary)?;
+    Ok(Box::new(wasm_module_t::new(module, binary.into())))
+}
+
 /// A shared Wasm module.
 ///
 /// This is mostly used to satisfy the Wasm C-API for Wasm module copying.
@@ -35,6 +54,8 @@ impl wasm_module_t {
 #[derive(Clone)]
 pub struct wasm_shared_module_t {
     inner: Module,
+    /// The Wasm binary from which the [`Module`] was created.
+    binary: Arc<[u8]>,
 }
 
 wasmi_c_api_macros::declare_own!(wasm_shared_module_t);
@@ -55,10 +76,7 @@ pub unsafe extern "C" fn wasm_module_new(
     store: &mut wasm_store_t,
     binary: &wasm_byte_vec_t,
 ) -> Option<Box<wasm_module_t>> {
-    match Module::new(store.inner.context().engine(), binary.as_slice()) {
-        Ok(module) => Some(Box::new(wasm_module_t::new(module))),
-        Err(_) => None,
-    }
+    new_module(store, binary.as_slice()).ok()
 }
 
 /// Returns `true` if the Wasm `binary` successfully validates.
@@ -140,6 +158,7 @@ pub extern "C" fn wasm_module_imports(module: &wasm_module_t, out: &mut wasm_imp
 pub extern "C" fn wasm_module_share(module: &wasm_module_t) -> Box<wasm_shared_module_t> {
     Box::new(wasm_shared_module_t {
         inner: module.inner.clone(),
+        binary: module.binary.clone(),
     })
 }
 
@@ -161,7 +180,8 @@ pub unsafe extern "C" fn wasm_module_obtain(
 ) -> Option<Box<wasm_module_t>> {
     let module = shared_module.inner.clone();
     if Engine::same(store.inner.context().engine(), module.engine()) {
-        Some(Box::new(wasm_module_t::new(module)))
+        let binary = shared_module.binary.clone();
+        Some(Box::new(wasm_module_t::new(module, binary)))
     } else {
         None
     }
@@ -173,11 +193,12 @@ pub unsafe extern "C" fn wasm_module_obtain(
 ///
 /// # Note
 ///
-/// This API is unsupported and will panic upon use.
+/// Wasmi does not support precompiled modules and thus the serialized binary
+/// is the Wasm binary from which the [`wasm_module_t`] was created.
 #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
-pub extern "C" fn wasm_module_serialize(_module: &wasm_module_t, _ret: &mut wasm_byte_vec_t) {
-    unimplemented!("wasm_module_serialize")
+pub extern "C" fn wasm_module_serialize(module: &wasm_module_t, ret: &mut wasm_byte_vec_t) {
+    ret.set_buffer(module.binary[..].into());
 }
 
 /// Deserializes the binary as a [`wasm_module_t`].
@@ -188,7 +209,8 @@ pub extern "C" fn wasm_module_serialize(_module: &wasm_module_t, _ret: &mut wasm
 ///
 /// # Note
 ///
-/// This API is unsupported and will panic upon use.
+/// Wasmi does not support precompiled modules and thus this parses,
+/// validates and translates the serialized Wasm binary again.
 ///
 /// # Safety
 ///
@@ -197,8 +219,43 @@ pub extern "C" fn wasm_module_serialize(_module: &wasm_module_t, _ret: &mut wasm
 #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
 pub unsafe extern "C" fn wasm_module_deserialize(
-    _store: &mut wasm_store_t,
-    _binary: &wasm_byte_vec_t,
+    store: &mut wasm_store_t,
+    binary: &wasm_byte_vec_t,
+) -> Option<Box<wasm_module_t>> {
+    new_module(store, binary.as_slice()).ok()
+}
+
+/// Deserializes the binary as a [`wasm_module_t`].
+///
+/// - Unlike [`wasm_module_deserialize`] this stores a [`wasm_trap_t`] describing
+///   the error in `trap` in case deserialization failed.
+/// - Returns `None` if deserialization failed.
+///
+/// # Safety
+///
+/// It is the caller's responsibility not to alias the [`wasm_module_t`]
+/// with its underlying, internal [`WasmStoreRef`](crate::WasmStoreRef).
+#[no_mangle]
+pub unsafe extern "C" fn wasmi_module_deserialize(
+    store: &mut wasm_store_t,
+    binary: &wasm_byte_vec_t,
+    trap: Option<&mut *mut wasm_trap_t>,
 ) -> Option<Box<wasm_module_t>> {
-    unimplemented!("wasm_module_deserialize")
+    match new_module(store, binary.as_slice()) {
+        Ok(module) => Some(module),
+        Err(error) => {
+            if let Some(ptr) = trap {
+                *ptr = Box::into_raw(Box::new(wasm_trap_t::new(error)));
+            }
+            None
+        }
+    }
+}
+
+/// Stores the Wasm binary from which the [`wasm_module_t`] was created in `out`.
+///
+/// The caller is responsible to delete `out` via `wasm_byte_vec_delete`.
+#[no_mangle]
+pub extern "C" fn wasmi_module_binary(module: &wasm_module_t, out: &mut wasm_byte_vec_t) {
+    out.set_buffer(module.binary[..].into());
 }
@@ -8,10 +8,11 @@ use crate::{
     wasm_module_t,
     wasm_table_t,
     wasm_trap_t,
+    WasmStoreRef,
 };
 use alloc::boxed::Box;
 use core::{ffi::c_void, ptr, unimplemented};
-use wasmi::{ExternRef, FuncRef, Val};
+use wasmi::{core::UntypedVal, ExternRef, FuncRef, Val};
 
 /// `*mut wasm_ref_t` is a reference type (`externref` or `funcref`) for the C API.
 ///
@@ -25,9 +26,34 @@ use wasmi::{ExternRef, FuncRef, Val};
 /// Note: this is not `#[repr(C)]` because it is an opaque type in the header,
 /// and only ever referenced as `*mut wasm_ref_t`. This also lets us use a
 /// regular, non-`repr(C)` `enum` to define `WasmRef`.
-#[derive(Clone)]
 pub struct wasm_ref_t {
     pub(crate) inner: WasmRef,
+    /// The store owning the referenced entity.
+    ///
+    /// The store holds the [`HostInfo`] of the referenced entity if any.
+    pub(crate) store: WasmStoreRef,
+    /// The [`wasm_func_t`] referenced by the [`wasm_ref_t`] if it is a function reference.
+    pub(crate) func: Option<wasm_func_t>,
+}
+
+/// Host information attached to an entity referenced by [`wasm_ref_t`].
+///
+/// The `finalizer` is run with `info` when the [`HostInfo`] is dropped,
+/// e.g. when the last [`wasm_ref_t`] referencing its entity is deleted
+/// or when its host information is replaced.
+pub(crate) struct HostInfo {
+    /// The user provided host information.
+    info: *mut c_void,
+    /// The optional finalizer of `info`.
+    finalizer: Option<extern "C" fn(*mut c_void)>,
+}
+
+impl Drop for HostInfo {
+    fn drop(&mut self) {
+        if let Some(finalizer) = self.finalizer {
+            finalizer(self.info);
+        }
+    }
 }
 
 wasmi_c_api_macros::declare_own!(wasm_ref_t);
@@ -48,11 +74,6 @@ impl From<WasmRef> for Val {
 }
 
 impl WasmRef {
-    /// Returns `true` if `self` is a Wasm function reference.
-    pub fn is_func(&self) -> bool {
-        matches!(self, Self::Func(_))
-    }
-
     /// Returns `true` if `self` is a `null` reference.
     pub fn is_null(&self) -> bool {
         match self {
@@ -60,19 +81,69 @@ impl WasmRef {
             WasmRef::Extern(r) => r.is_null(),
         }
     }
+
+    /// Returns the [`RefKey`] of the entity referenced by `self`.
+    fn key(&self) -> RefKey {
+        match self {
+            WasmRef::Func(r) => RefKey::Func(u64::from(UntypedVal::from(*r))),
+            WasmRef::Extern(r) => RefKey::Extern(u64::from(UntypedVal::from(*r))),
+        }
+    }
+}
+
+/// Identifies an entity referenced by [`wasm_ref_t`] within its store.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum RefKey {
+    /// A function referenced by a `funcref`.
+    Func(u64),
+    /// An external object referenced by an `externref`.
+    Extern(u64),
 }
 
 impl wasm_ref_t {
-    /// Creates a new boxed [`wasm_ref_t`] from the given [`WasmRef`].
-    pub(crate) fn new(r: WasmRef) -> Option<Box<wasm_ref_t>> {
-        if r.is_null() || !r.is_func() {
-            None
-        } else {
-            Some(Box::new(wasm_ref_t { inner: r }))
+    /// Creates a new boxed [`wasm_ref_t`] from the given [`WasmRef`] owned by `store`.
+    ///
+    /// Returns `None` if `r` is `null`.
+    pub(crate) fn new(r: WasmRef, store: &WasmStoreRef) -> Option<Box<wasm_ref_t>> {
+        if r.is_null() {
+            return None;
+        }
+        let func = match &r {
+            WasmRef::Func(funcref) => funcref
+                .func()
+                .map(|func| wasm_func_t::new(store.clone(), *func)),
+            WasmRef::Extern(_) => None,
+        };
+        let mut store = store.clone();
+        store.retain_ref(r.key());
+        Some(Box::new(wasm_ref_t {
+            inner: r,
+            store,
+            func,
+        }))
+    }
+}
+
+impl Clone for wasm_ref_t {
+    fn clone(&self) -> Self {
+        let mut store = self.store.clone();
+        store.retain_ref(self.inner.key());
+        Self {
+            inner: self.inner.clone(),
+            store,
+            func: self.func.clone(),
         }
     }
 }
 
+impl Drop for wasm_ref_t {
+    fn drop(&mut self) {
+        // Note: the host information is dropped after releasing the store
+        //       so that its finalizer does not run while the store is borrowed.
+        let _host_info = self.store.release_ref(self.inner.key());
+    }
+}
+
 /// Converts the [`wasm_ref_t`] into a Wasmi [`Val`].
 ///
 /// # Note
@@ -107,43 +178,73 @@ pub extern "C" fn wasm_ref_same(_a: Option<&wasm_ref_t>, _b: Option<&wasm_ref_t>
     unimplemented!("wasm_ref_same")
 }
 
-/// Returns the host information of the [`wasm_ref_t`].
+/// Returns the host information of the entity referenced by [`wasm_ref_t`].
 ///
-/// # Note
+/// Returns a `null` pointer if `r` is `null` or has no host information attached.
+///
+/// # Safety
 ///
-/// This API is unsupported and always returns a `null` pointer.
+/// It is the caller's responsibility not to alias the [`wasm_ref_t`]
+/// with its underlying, internal [`WasmStoreRef`].
 #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
-pub extern "C" fn wasm_ref_get_host_info(_ref: Option<&wasm_ref_t>) -> *mut c_void {
-    ptr::null_mut()
+pub unsafe extern "C" fn wasm_ref_get_host_info(r: Option<&wasm_ref_t>) -> *mut c_void {
+    let Some(r) = r else {
+        return ptr::null_mut();
+    };
+    r.store
+        .context()
+        .data()
+        .host_infos
+        .get(&r.inner.key())
+        .map_or(ptr::null_mut(), |host_info| host_info.info)
 }
 
-/// Sets the host information of the [`wasm_ref_t`] to `info`.
+/// Sets the host information of the entity referenced by [`wasm_ref_t`] to `info`.
 ///
-/// # Note
+/// Runs the finalizer of the replaced host information if any.
+/// Does nothing if `r` is `null`.
 ///
-/// This API is unsupported and will panic upon use.
+/// # Safety
+///
+/// It is the caller's responsibility not to alias the [`wasm_ref_t`]
+/// with its underlying, internal [`WasmStoreRef`].
 #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
-pub extern "C" fn wasm_ref_set_host_info(_ref: Option<&wasm_ref_t>, _info: *mut c_void) {
-    unimplemented!("wasm_ref_set_host_info")
+pub unsafe extern "C" fn wasm_ref_set_host_info(r: Option<&mut wasm_ref_t>, info: *mut c_void) {
+    wasm_ref_set_host_info_with_finalizer(r, info, None)
 }
 
-/// Sets the host information of the [`wasm_ref_t`] to `info` with the associated `finalizer`.
+/// Sets the host information of the entity referenced by [`wasm_ref_t`] to `info`
+/// with the associated `finalizer`.
 ///
-/// The finalizer is run when deleting the [`wasm_ref_t`].
+/// The host information is shared by all [`wasm_ref_t`] referencing the same entity,
+/// including copies made via [`wasm_ref_copy`]. The `finalizer` is run with `info`
+/// when the last of those [`wasm_ref_t`] is deleted or when its host information is replaced.
 ///
-/// # Note
+/// Runs the finalizer of the replaced host information if any.
+/// Does nothing if `r` is `null`.
 ///
-/// This API is unsupported and will panic upon use.
+/// # Safety
+///
+/// It is the caller's responsibility not to alias the [`wasm_ref_t`]
+/// with its underlying, internal [`WasmStoreRef`].
 #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
-pub extern "C" fn wasm_ref_set_host_info_with_finalizer(
-    _ref: Option<&wasm_ref_t>,
-    _info: *mut c_void,
-    _finalizer: Option<extern "C" fn(*mut c_void)>,
+pub unsafe extern "C" fn wasm_ref_set_host_info_with_finalizer(
+    r: Option<&mut wasm_ref_t>,
+    info: *mut c_void,
+    finalizer: Option<extern "C" fn(*mut c_void)>,
 ) {
-    unimplemented!("wasm_ref_set_host_info_with_finalizer")
+    let Some(r) = r else {
+        return;
+    };
+    let key = r.inner.key();
+    r.store
+        .context_mut()
+        .data_mut()
+        .host_infos
+        .insert(key, HostInfo { info, finalizer });
 }
 
 /// Returns the [`wasm_ref_t`] as shared [`wasm_extern_t`] if possible or otherwise returns `None`.
@@ -194,26 +295,26 @@ pub extern "C" fn wasm_ref_as_foreign_const(
     unimplemented!("wasm_ref_as_foreign_const")
 }
 
-/// Returns the [`wasm_ref_t`] as shared [`wasm_func_t`] if possible or otherwise returns `None`.
+/// Returns the [`wasm_ref_t`] as mutable [`wasm_func_t`] if possible or otherwise returns `None`.
 ///
 /// # Note
 ///
-/// This API is unsupported and will panic upon use.
+/// Returns `None` if `r` is an `externref`.
 #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
-pub extern "C" fn wasm_ref_as_func(_ref: Option<&mut wasm_ref_t>) -> Option<&mut wasm_func_t> {
-    unimplemented!("wasm_ref_as_func")
+pub extern "C" fn wasm_ref_as_func(r: Option<&mut wasm_ref_t>) -> Option<&mut wasm_func_t> {
+    r?.func.as_mut()
 }
 
-/// Returns the [`wasm_ref_t`] as mutable [`wasm_func_t`] if possible or otherwise returns `None`.
+/// Returns the [`wasm_ref_t`] as shared [`wasm_func_t`] if possible or otherwise returns `None`.
 ///
 /// # Note
 ///
-/// This API is unsupported and will panic upon use.
+/// Returns `None` if `r` is an `externref`.
 #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
-pub extern "C" fn wasm_ref_as_func_const(_ref: Option<&wasm_ref_t>) -> Option<&wasm_func_t> {
-    unimplemented!("wasm_ref_as_func_const")
+pub extern "C" fn wasm_ref_as_func_const(r: Option<&wasm_ref_t>) -> Option<&wasm_func_t> {
+    r?.func.as_ref()
 }
 
 /// Returns the [`wasm_ref_t`] as shared [`wasm_global_t`] if possible or otherwise returns `None`.
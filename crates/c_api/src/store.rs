@@ -1,5 +1,9 @@
-use crate::{wasm_engine_t, wasmi_error_t, ForeignData};
-use alloc::{boxed::Box, sync::Arc};
+use crate::{wasm_engine_t, wasmi_error_t, ForeignData, HostInfo, RefKey};
+use alloc::{
+    boxed::Box,
+    collections::{btree_map::Entry, BTreeMap},
+    sync::{Arc, Weak},
+};
 use core::{cell::UnsafeCell, ffi};
 use wasmi::{AsContext, AsContextMut, Store, StoreContext, StoreContextMut};
 
@@ -16,7 +20,19 @@ use wasmi::{AsContext, AsContextMut, Store, StoreContext, StoreContextMut};
 /// least Wasmi's implementation).
 #[derive(Clone)]
 pub struct WasmStoreRef {
-    inner: Arc<UnsafeCell<Store<()>>>,
+    inner: Arc<UnsafeCell<Store<WasmStoreData>>>,
+}
+
+/// The data of the [`Store`] of a [`WasmStoreRef`].
+#[derive(Default)]
+pub struct WasmStoreData {
+    /// The host information attached to entities referenced via `wasm_ref_t`.
+    ///
+    /// The host information is keyed by the referenced entity so that it is shared by all
+    /// `wasm_ref_t` referencing the same entity. It is dropped together with the last of them.
+    pub(crate) host_infos: BTreeMap<RefKey, HostInfo>,
+    /// The number of live `wasm_ref_t` per referenced entity.
+    ref_counts: BTreeMap<RefKey, usize>,
 }
 
 impl WasmStoreRef {
@@ -27,7 +43,7 @@ impl WasmStoreRef {
     /// # Safety
     ///
     /// It is the callers responsibility to provide a valid `self`.
-    pub unsafe fn context(&self) -> StoreContext<'_, ()> {
+    pub unsafe fn context(&self) -> StoreContext<'_, WasmStoreData> {
         (*self.inner.get()).as_context()
     }
 
@@ -38,16 +54,71 @@ impl WasmStoreRef {
     /// # Safety
     ///
     /// It is the callers responsibility to provide a valid `self`.
-    pub unsafe fn context_mut(&mut self) -> StoreContextMut<'_, ()> {
+    pub unsafe fn context_mut(&mut self) -> StoreContextMut<'_, WasmStoreData> {
         (*self.inner.get()).as_context_mut()
     }
+
+    /// Registers a new `wasm_ref_t` referencing the entity identified by `key`.
+    pub(crate) fn retain_ref(&mut self, key: RefKey) {
+        // Safety: `wasm_ref_t` are only created from a valid `WasmStoreRef`.
+        let mut ctx = unsafe { self.context_mut() };
+        let data = ctx.data_mut();
+        *data.ref_counts.entry(key).or_default() += 1;
+    }
+
+    /// Unregisters a deleted `wasm_ref_t` referencing the entity identified by `key`.
+    ///
+    /// Returns the [`HostInfo`] of the entity if this was its last `wasm_ref_t`.
+    /// The caller is responsible to drop it which runs its finalizer.
+    #[must_use]
+    pub(crate) fn release_ref(&mut self, key: RefKey) -> Option<HostInfo> {
+        // Safety: `wasm_ref_t` keep their `WasmStoreRef` alive until they are deleted.
+        let mut ctx = unsafe { self.context_mut() };
+        let data = ctx.data_mut();
+        let Entry::Occupied(mut count) = data.ref_counts.entry(key) else {
+            return None;
+        };
+        *count.get_mut() -= 1;
+        if *count.get() != 0 {
+            return None;
+        }
+        count.remove();
+        data.host_infos.remove(&key)
+    }
+
+    /// Returns a [`WasmStoreWeak`] that does not keep the store alive.
+    pub(crate) fn downgrade(&self) -> WasmStoreWeak {
+        WasmStoreWeak {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+}
+
+/// A non-owning reference to the store of a [`WasmStoreRef`].
+///
+/// # Note
+///
+/// This is `Send` and `Sync` so that host functions can refer to their own store.
+/// Accessing the store is subject to the same aliasing rules as for [`WasmStoreRef`].
+pub(crate) struct WasmStoreWeak {
+    inner: Weak<UnsafeCell<Store<WasmStoreData>>>,
+}
+
+unsafe impl Send for WasmStoreWeak {}
+unsafe impl Sync for WasmStoreWeak {}
+
+impl WasmStoreWeak {
+    /// Returns the [`WasmStoreRef`] if its store is still alive.
+    pub(crate) fn upgrade(&self) -> Option<WasmStoreRef> {
+        self.inner.upgrade().map(|inner| WasmStoreRef { inner })
+    }
 }
 
 /// The Wasm store.
 ///
 /// The returned [`wasm_engine_t`] must be freed using [`wasm_store_delete`].
 ///
-/// Wraps [`wasmi::Store<()>`](wasmi::Store).
+/// Wraps [`wasmi::Store<WasmStoreData>`](wasmi::Store).
 #[repr(C)]
 #[derive(Clone)]
 pub struct wasm_store_t {
@@ -56,17 +127,17 @@ pub struct wasm_store_t {
 
 wasmi_c_api_macros::declare_own!(wasm_store_t);
 
-/// Creates a new [`Store<()>`](wasmi::Store) for the given `engine`.
+/// Creates a new [`Store<WasmStoreData>`](wasmi::Store) for the given `engine`.
 ///
 /// The returned [`wasm_store_t`] must be freed using [`wasm_store_delete`].
 ///
-/// Wraps [`<wasmi::Store<()>>::new`](wasmi::Store::new).
+/// Wraps [`<wasmi::Store<WasmStoreData>>::new`](wasmi::Store::new).
 #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
 #[allow(clippy::arc_with_non_send_sync)]
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
 pub extern "C" fn wasm_store_new(engine: &wasm_engine_t) -> Box<wasm_store_t> {
     let engine = &engine.inner;
-    let store = Store::new(engine, ());
+    let store = Store::new(engine, WasmStoreData::default());
     Box::new(wasm_store_t {
         inner: WasmStoreRef {
             inner: Arc::new(UnsafeCell::new(store)),
@@ -83,6 +83,9 @@ pub unsafe extern "C" fn wasm_table_new(
 ///
 /// Wraps [`Table::ty`].
 ///
+/// The minimum limit of the returned [`wasm_tabletype_t`] reflects the
+/// current size of the [`wasm_table_t`] which might have grown since its creation.
+///
 /// # Safety
 ///
 /// It is the caller's responsibility not to alias the [`wasm_table_t`]
@@ -91,14 +94,21 @@ pub unsafe extern "C" fn wasm_table_new(
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
 pub unsafe extern "C" fn wasm_table_type(t: &wasm_table_t) -> Box<wasm_tabletype_t> {
     let table = t.table();
-    let store = t.inner.store.context();
-    Box::new(wasm_tabletype_t::new(table.ty(store)))
+    let ty = table.ty(t.inner.store.context());
+    let size = table.size(t.inner.store.context());
+    let ty = TableType::new(ty.element(), size, ty.maximum());
+    Box::new(wasm_tabletype_t::new(ty))
 }
 
 /// Returns the element at `index` of [`wasm_table_t`] `t`.
 ///
+/// Returns `None` if `index` is out of bounds or the element is `null`.
+/// A returned function reference can be converted via [`wasm_ref_as_func`].
+///
 /// Wraps [`Table::get`].
 ///
+/// [`wasm_ref_as_func`]: crate::wasm_ref_as_func
+///
 /// # Safety
 ///
 /// It is the caller's responsibility not to alias the [`wasm_table_t`]
@@ -116,13 +126,18 @@ pub unsafe extern "C" fn wasm_table_get(
         wasmi::Val::ExternRef(r) => WasmRef::Extern(r),
         invalid => panic!("encountered invalid value in table at {index}: {invalid:?}"),
     };
-    wasm_ref_t::new(wasm_ref)
+    wasm_ref_t::new(wasm_ref, &t.inner.store)
 }
 
 /// Sets the value of the element at `index` of [`wasm_table_t`] to `new_value`.
 ///
+/// Returns `true` on success and `false` if `index` is out of bounds.
+/// Function references can be created via [`wasm_func_as_ref`].
+///
 /// Wraps [`Table::set`].
 ///
+/// [`wasm_func_as_ref`]: crate::wasm_func_as_ref
+///
 /// # Safety
 ///
 /// It is the caller's responsibility not to alias the [`wasm_table_t`]
@@ -1,5 +1,6 @@
 use crate::{wasm_frame_t, wasm_frame_vec_t, wasm_name_t, wasm_store_t};
 use alloc::{boxed::Box, format, string::String, vec::Vec};
+use core::ffi::c_char;
 use wasmi::Error;
 
 /// A Wasm trap.
@@ -59,12 +60,18 @@ pub extern "C" fn wasm_trap_new(
 
 /// Creates a new [`wasm_trap_t`] from the given `message` and `len` pair.
 ///
+/// Unlike [`wasm_trap_new`] the `message` does not need to be null-terminated.
+/// Invalid UTF-8 sequences within `message` are replaced.
+///
+/// The returned [`wasm_trap_t`] can be returned by host function callbacks
+/// in order to abort the execution of the calling Wasm function with `message`.
+///
 /// # Safety
 ///
 /// The caller is responsible to provide a valid `message` and `len` pair.
 #[no_mangle]
-pub unsafe extern "C" fn wasmi_trap_new(message: *const u8, len: usize) -> Box<wasm_trap_t> {
-    let bytes = crate::slice_from_raw_parts(message, len);
+pub unsafe extern "C" fn wasmi_trap_new(message: *const c_char, len: usize) -> Box<wasm_trap_t> {
+    let bytes = crate::slice_from_raw_parts(message.cast::<u8>(), len);
     let message = String::from_utf8_lossy(bytes);
     Box::new(wasm_trap_t {
         error: Error::new(message.into_owned()),
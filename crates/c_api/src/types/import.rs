@@ -67,6 +67,12 @@ pub extern "C" fn wasm_importtype_name(it: &wasm_importtype_t) -> &wasm_name_t {
 }
 
 /// Returns a shared reference to the extern type of the [`wasm_importtype_t`].
+///
+/// The returned [`wasm_externtype_t`] is owned by the [`wasm_importtype_t`] and
+/// remains valid for as long as the [`wasm_importtype_t`] is alive.
+/// Use [`wasm_externtype_kind`] to query its kind before downcasting it.
+///
+/// [`wasm_externtype_kind`]: crate::wasm_externtype_kind
 #[cfg_attr(not(feature = "prefix-symbols"), no_mangle)]
 #[cfg_attr(feature = "prefix-symbols", wasmi_c_api_macros::prefix_symbol)]
 pub extern "C" fn wasm_importtype_type(it: &wasm_importtype_t) -> &wasm_externtype_t {
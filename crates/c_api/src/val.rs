@@ -6,11 +6,13 @@ use crate::{
     wasm_ref_t,
     wasm_valkind_t,
     WasmRef,
+    WasmStoreRef,
 };
 use alloc::boxed::Box;
 use core::{mem::MaybeUninit, ptr};
 use wasmi::{
     core::{ValType, F32, F64},
+    ExternRef,
     FuncRef,
     Val,
 };
@@ -78,8 +80,9 @@ impl Default for wasm_val_t {
     }
 }
 
-impl From<Val> for wasm_val_t {
-    fn from(val: Val) -> Self {
+impl wasm_val_t {
+    /// Creates a new [`wasm_val_t`] from the [`Val`] owned by `store`.
+    pub(crate) fn from_val(val: Val, store: &WasmStoreRef) -> Self {
         match val {
             Val::I32(value) => Self {
                 kind: from_valtype(&ValType::I32),
@@ -100,24 +103,18 @@ impl From<Val> for wasm_val_t {
             Val::FuncRef(funcref) => Self {
                 kind: from_valtype(&ValType::FuncRef),
                 of: wasm_val_union {
-                    ref_: {
-                        match funcref.is_null() {
-                            true => ptr::null_mut(),
-                            false => Box::into_raw(Box::new(wasm_ref_t {
-                                inner: WasmRef::Func(funcref),
-                            })),
-                        }
-                    },
+                    ref_: into_raw_ref(WasmRef::Func(funcref), store),
+                },
+            },
+            Val::ExternRef(externref) => Self {
+                kind: from_valtype(&ValType::ExternRef),
+                of: wasm_val_union {
+                    ref_: into_raw_ref(WasmRef::Extern(externref), store),
                 },
             },
-            Val::ExternRef(_) => {
-                core::panic!("`wasm_val_t`: creating a `wasm_val_t` from an `externref`")
-            }
         }
     }
-}
 
-impl wasm_val_t {
     /// Creates a new [`Val`] from the [`wasm_val_t`].
     ///
     /// # Note
@@ -133,13 +130,21 @@ impl wasm_val_t {
                 true => Val::FuncRef(FuncRef::null()),
                 false => ref_to_val(unsafe { &*self.of.ref_ }),
             },
-            ValType::ExternRef => {
-                core::unreachable!("`wasm_val_t`: cannot contain non-function reference values")
-            }
+            ValType::ExternRef => match unsafe { self.of.ref_ }.is_null() {
+                true => Val::ExternRef(ExternRef::null()),
+                false => ref_to_val(unsafe { &*self.of.ref_ }),
+            },
         }
     }
 }
 
+/// Boxes the reference `inner` owned by `store` into a raw [`wasm_ref_t`] pointer.
+///
+/// Returns a null pointer if `inner` is `null`.
+fn into_raw_ref(inner: WasmRef, store: &WasmStoreRef) -> *mut wasm_ref_t {
+    wasm_ref_t::new(inner, store).map_or(ptr::null_mut(), Box::into_raw)
+}
+
 /// Copies the [`wasm_val_t`] and stores the result in `out`.
 ///
 /// # Safety
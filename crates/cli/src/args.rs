@@ -96,6 +96,19 @@ pub struct Args {
     #[clap(long = "verbose")]
     verbose: bool,
 
+    /// Print a summary of the resources requested by the Wasm module instead of executing it.
+    #[clap(long = "inspect")]
+    inspect: bool,
+
+    /// The format in which the results of the invoked function are printed.
+    #[clap(
+        long = "output-format",
+        visible_alias = "output",
+        value_enum,
+        default_value_t=OutputFormat::Human
+    )]
+    output_format: OutputFormat,
+
     /// Arguments given to the Wasm module or the invoked function.
     #[clap(value_name = "ARGS")]
     func_args: Vec<String>,
@@ -120,6 +133,20 @@ impl From<CompilationMode> for wasmi::CompilationMode {
     }
 }
 
+/// The format in which the Wasmi CLI prints the results of the invoked function.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable output.
+    #[default]
+    Human,
+    /// A JSON object describing the invocation, its results or error, elapsed time and consumed fuel.
+    ///
+    /// All other output is written to stderr.
+    Json,
+    /// A single line of comma-separated result values.
+    Csv,
+}
+
 impl Args {
     /// Returns the Wasm file path given to the CLI app.
     pub fn wasm_file(&self) -> &Path {
@@ -146,11 +173,21 @@ impl Args {
         self.compilation_mode.into()
     }
 
+    /// Returns the [`OutputFormat`] for the results of the invoked function.
+    pub fn output_format(&self) -> OutputFormat {
+        self.output_format
+    }
+
     /// Returns `true` if verbose messaging is enabled.
     pub fn verbose(&self) -> bool {
         self.verbose
     }
 
+    /// Returns `true` if the Wasm module is inspected instead of executed.
+    pub fn inspect(&self) -> bool {
+        self.inspect
+    }
+
     /// Pre-opens all directories given in `--dir` and returns them for use by the [`WasiCtx`].
     ///
     /// # Errors
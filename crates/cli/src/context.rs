@@ -1,8 +1,20 @@
 use anyhow::{anyhow, Error};
 use std::{fs, path::Path};
-use wasmi::{CompilationMode, Config, ExternType, Func, FuncType, Instance, Module, Store};
+use wasmi::{CompilationMode, Config, Engine, Func, FuncType, Instance, Module, Store};
 use wasmi_wasi::WasiCtx;
 
+/// Reads, parses and validates the Wasm module in `wasm_file` using `engine`.
+///
+/// # Errors
+///
+/// If reading, parsing, validating or compiling the Wasm module failed.
+pub fn load_module(engine: &Engine, wasm_file: &Path) -> Result<Module, Error> {
+    let wasm =
+        fs::read(wasm_file).map_err(|_| anyhow!("failed to read Wasm file {wasm_file:?}"))?;
+    wasmi::Module::new(engine, wasm)
+        .map_err(|error| anyhow!("failed to parse and validate Wasm module {wasm_file:?}: {error}"))
+}
+
 /// The [`Context`] for the Wasmi CLI application.
 ///
 /// This simply stores all the necessary data.
@@ -34,11 +46,7 @@ impl Context {
         }
         config.compilation_mode(compilation_mode);
         let engine = wasmi::Engine::new(&config);
-        let wasm =
-            fs::read(wasm_file).map_err(|_| anyhow!("failed to read Wasm file {wasm_file:?}"))?;
-        let module = wasmi::Module::new(&engine, wasm).map_err(|error| {
-            anyhow!("failed to parse and validate Wasm module {wasm_file:?}: {error}")
-        })?;
+        let module = load_module(&engine, wasm_file)?;
         let mut store = wasmi::Store::new(&engine, wasi_ctx);
         if let Some(fuel) = fuel {
             store.set_fuel(fuel).unwrap_or_else(|error| {
@@ -63,13 +71,7 @@ impl Context {
     ///
     /// [`Module`]: wasmi::Module
     pub fn exported_funcs(&self) -> impl Iterator<Item = (&str, FuncType)> {
-        self.module.exports().filter_map(|export| {
-            let name = export.name();
-            match export.ty() {
-                ExternType::Func(func_type) => Some((name, func_type.clone())),
-                _ => None,
-            }
-        })
+        self.module.func_exports()
     }
 
     /// Returns a shared reference to the [`Store`] of the [`Context`].
@@ -1,6 +1,6 @@
 use crate::context::Context;
 use std::fmt::{self, Display};
-use wasmi::{core::ValType, FuncType, Val};
+use wasmi::{core::ValType, FuncType, MemoryType, ModuleResources, TableType, Val};
 
 /// [`Display`]-wrapper type for [`ValType`].
 pub struct DisplayValueType<'a>(&'a ValType);
@@ -48,6 +48,34 @@ impl fmt::Display for DisplayValue<'_> {
     }
 }
 
+/// [`Display`]-wrapper type for [`Val`] as JSON value.
+///
+/// # Note
+///
+/// - Non-finite floats are displayed as JSON strings since JSON has no representation for them.
+/// - Reference values are displayed as `null` or as their type name as JSON strings.
+pub struct DisplayJsonValue<'a>(&'a Val);
+
+impl<'a> From<&'a Val> for DisplayJsonValue<'a> {
+    fn from(value: &'a Val) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for DisplayJsonValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Val::F32(value) if !f32::from(*value).is_finite() => write!(f, "\"{value}\""),
+            Val::F64(value) if !f64::from(*value).is_finite() => write!(f, "\"{value}\""),
+            Val::FuncRef(value) if value.is_null() => write!(f, "null"),
+            Val::ExternRef(value) if value.is_null() => write!(f, "null"),
+            Val::FuncRef(_) => write!(f, "\"funcref\""),
+            Val::ExternRef(_) => write!(f, "\"externref\""),
+            value => write!(f, "{}", DisplayValue::from(*value)),
+        }
+    }
+}
+
 /// [`Display`]-wrapper type around [`FuncType`].
 pub struct DisplayFuncType<'a> {
     name: Option<&'a str>,
@@ -168,3 +196,96 @@ impl Display for DisplayExportedFuncs<'_> {
         Ok(())
     }
 }
+
+/// [`Display`]-wrapper for the [`ModuleResources`] of a Wasm module.
+pub struct DisplayModuleResources<'a>(&'a ModuleResources);
+
+impl<'a> From<&'a ModuleResources> for DisplayModuleResources<'a> {
+    fn from(resources: &'a ModuleResources) -> Self {
+        Self(resources)
+    }
+}
+
+impl Display for DisplayModuleResources<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let resources = self.0;
+        writeln!(
+            f,
+            "memories: {} defined, {} imported",
+            resources.memories().len(),
+            resources.len_imported_memories()
+        )?;
+        for memory in resources.memories() {
+            writeln!(f, " - {}", DisplayMemoryType::from(memory))?;
+        }
+        writeln!(
+            f,
+            "tables: {} defined, {} imported",
+            resources.tables().len(),
+            resources.len_imported_tables()
+        )?;
+        for table in resources.tables() {
+            writeln!(f, " - {}", DisplayTableType::from(table))?;
+        }
+        writeln!(
+            f,
+            "globals: {} defined, {} imported",
+            resources.len_globals(),
+            resources.len_imported_globals()
+        )?;
+        writeln!(
+            f,
+            "functions: {} defined, {} imported",
+            resources.len_funcs(),
+            resources.len_imported_funcs()
+        )?;
+        writeln!(f, "data segment bytes: {}", resources.data_bytes())?;
+        writeln!(f, "element segment items: {}", resources.element_items())?;
+        write!(
+            f,
+            "estimated instantiation bytes: {}",
+            resources.estimated_instantiation_bytes()
+        )
+    }
+}
+
+/// [`Display`]-wrapper type for [`MemoryType`].
+pub struct DisplayMemoryType<'a>(&'a MemoryType);
+
+impl<'a> From<&'a MemoryType> for DisplayMemoryType<'a> {
+    fn from(memory_type: &'a MemoryType) -> Self {
+        Self(memory_type)
+    }
+}
+
+impl Display for DisplayMemoryType<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let min = u32::from(self.0.initial_pages());
+        write!(f, "memory: {min}..")?;
+        if let Some(max) = self.0.maximum_pages() {
+            write!(f, "{}", u32::from(max))?;
+        }
+        write!(f, " pages of {} bytes", self.0.page_size())
+    }
+}
+
+/// [`Display`]-wrapper type for [`TableType`].
+pub struct DisplayTableType<'a>(&'a TableType);
+
+impl<'a> From<&'a TableType> for DisplayTableType<'a> {
+    fn from(table_type: &'a TableType) -> Self {
+        Self(table_type)
+    }
+}
+
+impl Display for DisplayTableType<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let element = self.0.element();
+        let element = DisplayValueType::from(&element);
+        write!(f, "table: {element} {}..", self.0.minimum())?;
+        if let Some(max) = self.0.maximum() {
+            write!(f, "{max}")?;
+        }
+        write!(f, " elements")
+    }
+}
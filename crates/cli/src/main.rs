@@ -1,12 +1,26 @@
 use crate::{
-    args::Args,
-    display::{DisplayExportedFuncs, DisplayFuncType, DisplaySequence, DisplayValue},
+    args::{Args, OutputFormat},
+    display::{
+        DisplayExportedFuncs,
+        DisplayFuncType,
+        DisplayJsonValue,
+        DisplayModuleResources,
+        DisplaySequence,
+        DisplayValue,
+        DisplayValueType,
+    },
 };
 use anyhow::{anyhow, bail, Error, Result};
 use clap::Parser;
 use context::Context;
-use std::{path::Path, process};
-use wasmi::{Func, FuncType, Val};
+use core::fmt::Write as _;
+use std::{
+    path::Path,
+    process,
+    time::{Duration, Instant},
+};
+use wasmi::{Config, Engine, Func, FuncType, Val};
+use wasmi_wast::DisplayJsonStr;
 
 mod args;
 mod context;
@@ -18,17 +32,20 @@ mod tests;
 
 fn main() -> Result<()> {
     let args = Args::parse();
+    if args.inspect() {
+        return inspect_module(&args);
+    }
     let wasm_file = args.wasm_file();
     let wasi_ctx = args.wasi_context()?;
     let mut ctx = Context::new(wasm_file, wasi_ctx, args.fuel(), args.compilation_mode())?;
     let (func_name, func) = get_invoked_func(&args, &ctx)?;
     let ty = func.ty(ctx.store());
     let func_args = utils::decode_func_args(&ty, args.func_args())?;
-    let mut func_results = utils::prepare_func_results(&ty);
     typecheck_args(&func_name, &ty, &func_args)?;
 
+    let output_format = args.output_format();
     if args.verbose() {
-        print_execution_start(args.wasm_file(), &func_name, &func_args);
+        print_execution_start(output_format, args.wasm_file(), &func_name, &func_args);
     }
     if args.invoked().is_some() && ty.params().len() != args.func_args().len() {
         bail!(
@@ -39,19 +56,33 @@ fn main() -> Result<()> {
         )
     }
 
-    match func.call(ctx.store_mut(), &func_args, &mut func_results) {
-        Ok(()) => {
-            print_remaining_fuel(&args, &ctx);
-            print_pretty_results(&func_results);
+    let start = Instant::now();
+    let result = func.call_boxed(ctx.store_mut(), &func_args);
+    let elapsed = start.elapsed();
+    let fuel = consumed_fuel(&args, &ctx);
+    if output_format == OutputFormat::Json {
+        let report = format_json_report(&func_name, &func_args, result.as_deref(), elapsed, fuel);
+        println!("{report}");
+    }
+    match result {
+        Ok(func_results) => {
+            match output_format {
+                OutputFormat::Human => {
+                    print_remaining_fuel(&args, &ctx);
+                    print_pretty_results(&func_results);
+                }
+                OutputFormat::Csv => println!("{}", format_csv_results(&func_results)),
+                OutputFormat::Json => {}
+            }
             Ok(())
         }
         Err(error) => {
             if let Some(exit_code) = error.i32_exit_status() {
                 // We received an exit code from the WASI program,
-                // therefore we exit with the same exit code after
-                // pretty printing the results.
-                print_remaining_fuel(&args, &ctx);
-                print_pretty_results(&func_results);
+                // therefore we exit with the same exit code.
+                if output_format == OutputFormat::Human {
+                    print_remaining_fuel(&args, &ctx);
+                }
                 process::exit(exit_code)
             }
             bail!("failed during execution of {func_name}: {error}")
@@ -59,18 +90,45 @@ fn main() -> Result<()> {
     }
 }
 
+/// Prints a summary of the resources requested by the Wasm module without executing it.
+///
+/// # Errors
+///
+/// If reading, parsing, validating or compiling the Wasm module failed.
+fn inspect_module(args: &Args) -> Result<()> {
+    let mut config = Config::default();
+    config.compilation_mode(args.compilation_mode());
+    let engine = Engine::new(&config);
+    let module = context::load_module(&engine, args.wasm_file())?;
+    println!("{}", DisplayModuleResources::from(&module.resources()));
+    Ok(())
+}
+
 /// Prints the remaining fuel so far if fuel metering was enabled.
 fn print_remaining_fuel(args: &Args, ctx: &Context) {
-    if let Some(given_fuel) = args.fuel() {
-        let remaining = ctx
-            .store()
-            .get_fuel()
-            .unwrap_or_else(|error| panic!("could not get the remaining fuel: {error}"));
-        let consumed = given_fuel.saturating_sub(remaining);
+    if let (Some(consumed), Some(remaining)) = (consumed_fuel(args, ctx), remaining_fuel(args, ctx))
+    {
         println!("fuel consumed: {consumed}, fuel remaining: {remaining}");
     }
 }
 
+/// Returns the remaining fuel so far if fuel metering was enabled.
+fn remaining_fuel(args: &Args, ctx: &Context) -> Option<u64> {
+    args.fuel()?;
+    let remaining = ctx
+        .store()
+        .get_fuel()
+        .unwrap_or_else(|error| panic!("could not get the remaining fuel: {error}"));
+    Some(remaining)
+}
+
+/// Returns the consumed fuel so far if fuel metering was enabled.
+fn consumed_fuel(args: &Args, ctx: &Context) -> Option<u64> {
+    let given_fuel = args.fuel()?;
+    let remaining = remaining_fuel(args, ctx)?;
+    Some(given_fuel.saturating_sub(remaining))
+}
+
 /// Performs minor typecheck on the function signature.
 ///
 /// # Note
@@ -125,25 +183,118 @@ fn get_invoked_func(args: &Args, ctx: &Context) -> Result<(String, Func), Error>
 }
 
 /// Prints a signalling text that Wasm execution has started.
-fn print_execution_start(wasm_file: &Path, func_name: &str, func_args: &[Val]) {
-    println!(
+///
+/// The text is printed to stderr unless the `format` is [`OutputFormat::Human`].
+fn print_execution_start(
+    format: OutputFormat,
+    wasm_file: &Path,
+    func_name: &str,
+    func_args: &[Val],
+) {
+    let text = format!(
         "executing File({wasm_file:?})::{func_name}({}) ...",
         DisplaySequence::new(", ", func_args.iter().map(DisplayValue::from))
     );
+    match format {
+        OutputFormat::Human => println!("{text}"),
+        _ => eprintln!("{text}"),
+    }
 }
 
 /// Prints the results of the Wasm computation in a human readable form.
 fn print_pretty_results(results: &[Val]) {
+    if results.is_empty() {
+        return;
+    }
+    let output = format_human_results(results);
+    println!("{output}");
+}
+
+/// Formats the `results` of the Wasm computation in a human readable form.
+fn format_human_results(results: &[Val]) -> String {
     match results.len() {
-        0 => {}
-        1 => {
-            println!("{}", DisplayValue::from(&results[0]));
+        0 => String::new(),
+        1 => DisplayValue::from(&results[0]).to_string(),
+        _ => format!(
+            "[{}]",
+            DisplaySequence::new(", ", results.iter().map(DisplayValue::from))
+        ),
+    }
+}
+
+/// Formats the `results` of the Wasm computation as a single line of comma-separated values.
+fn format_csv_results(results: &[Val]) -> String {
+    DisplaySequence::new(",", results.iter().map(DisplayValue::from)).to_string()
+}
+
+/// Formats the invocation of `func_name` with `args` and its `outcome` as JSON object.
+///
+/// # Note
+///
+/// - Upon success the JSON object contains the typed `"results"`.
+/// - Upon a WASI exit the JSON object contains its `"exit_code"`.
+/// - Upon failure the JSON object contains an `"error"` object with the
+///   `"trap_code"` (or `null` if the error is not a trap) and the `"message"`.
+fn format_json_report(
+    func_name: &str,
+    args: &[Val],
+    outcome: Result<&[Val], &wasmi::Error>,
+    elapsed: Duration,
+    fuel: Option<u64>,
+) -> String {
+    let mut json = format!(
+        "{{\"func\": {}, \"args\": ",
+        DisplayJsonStr::from(func_name)
+    );
+    write_json_values(&mut json, args);
+    match outcome {
+        Ok(results) => {
+            json.push_str(", \"results\": ");
+            write_json_values(&mut json, results);
         }
-        _ => {
-            println!(
-                "[{}]",
-                DisplaySequence::new(", ", results.iter().map(DisplayValue::from))
-            );
+        Err(error) => match error.i32_exit_status() {
+            Some(exit_code) => {
+                _ = write!(json, ", \"exit_code\": {exit_code}");
+            }
+            None => {
+                json.push_str(", \"error\": {\"trap_code\": ");
+                match error.as_trap_code() {
+                    Some(trap_code) => _ = write!(json, "\"{trap_code:?}\""),
+                    None => json.push_str("null"),
+                }
+                let message = error.to_string();
+                _ = write!(
+                    json,
+                    ", \"message\": {}}}",
+                    DisplayJsonStr::from(&message[..])
+                );
+            }
+        },
+    }
+    write_json_stats(&mut json, elapsed, fuel);
+    json.push('}');
+    json
+}
+
+/// Writes the typed `values` as JSON array into `json`.
+fn write_json_values(json: &mut String, values: &[Val]) {
+    json.push('[');
+    for (n, value) in values.iter().enumerate() {
+        if n != 0 {
+            json.push_str(", ");
         }
+        let ty = value.ty();
+        let ty = DisplayValueType::from(&ty);
+        let value = DisplayJsonValue::from(value);
+        _ = write!(json, "{{\"type\": \"{ty}\", \"value\": {value}}}");
+    }
+    json.push(']');
+}
+
+/// Writes the `elapsed` time and the consumed `fuel` if any as JSON object fields into `json`.
+fn write_json_stats(json: &mut String, elapsed: Duration, fuel: Option<u64>) {
+    _ = write!(json, ", \"elapsed_ns\": {}", elapsed.as_nanos());
+    if let Some(fuel) = fuel {
+        _ = write!(json, ", \"fuel_consumed\": {fuel}");
     }
 }
@@ -58,3 +58,50 @@ fn display_4in_4out() {
         "fn(i32, i64, f32, f64) -> (i32, i64, f32, f64)",
     );
 }
+
+#[test]
+fn format_results_human() {
+    assert_eq!(format_human_results(&[]), "");
+    assert_eq!(format_human_results(&[Val::I32(42)]), "42");
+    assert_eq!(
+        format_human_results(&[Val::I32(1), Val::I64(-2)]),
+        "[1, -2]"
+    );
+}
+
+#[test]
+fn format_results_csv() {
+    let results = [Val::I32(1), Val::I64(-2), Val::F32(1.5.into())];
+    assert_eq!(format_csv_results(&results), "1,-2,1.5");
+}
+
+#[test]
+fn display_module_resources() {
+    let wasm = r#"
+        (module
+            (import "env" "f" (func))
+            (import "env" "g" (global i32))
+            (memory 1 2)
+            (table 2 externref)
+            (global i32 (i32.const 0))
+            (func $f)
+            (data (i32.const 0) "abc")
+            (elem declare func $f)
+        )
+    "#;
+    let engine = Engine::default();
+    let module = wasmi::Module::new(&engine, wasm).unwrap();
+    assert_eq!(
+        DisplayModuleResources::from(&module.resources()).to_string(),
+        "\
+memories: 1 defined, 0 imported
+ - memory: 1..2 pages of 65536 bytes
+tables: 1 defined, 0 imported
+ - table: externref 2.. elements
+globals: 1 defined, 1 imported
+functions: 1 defined, 1 imported
+data segment bytes: 3
+element segment items: 1
+estimated instantiation bytes: 65552",
+    );
+}
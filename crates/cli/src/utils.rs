@@ -6,13 +6,6 @@ use wasmi::{
     Val,
 };
 
-/// Returns a [`Val`] buffer capable of holding the return values.
-///
-/// The returned buffer can be used as function results for [`Func::call`](`wasmi::Func::call`).
-pub fn prepare_func_results(ty: &FuncType) -> Box<[Val]> {
-    ty.results().iter().copied().map(Val::default).collect()
-}
-
 /// Decode the given `args` for the [`FuncType`] `ty`.
 ///
 /// Returns the decoded `args` as a slice of [`Val`] which can be used
@@ -51,3 +51,103 @@ fn get_bin_path(name: &str) -> PathBuf {
 fn get_cmd() -> assert_cmd::Command {
     Command::cargo_bin("wasmi_cli").expect("could not create wasmi_cli command")
 }
+
+#[test]
+fn test_json_output() {
+    let mut cmd = get_cmd();
+    let assert = cmd
+        .arg("--output=json")
+        .arg("--verbose")
+        .arg("--fuel=100")
+        .arg("--invoke=add")
+        .arg(get_bin_path("arith"))
+        .args(["1", "2"])
+        .assert();
+    let output = assert.get_output();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["func"], "add");
+    assert_eq!(
+        json["args"],
+        serde_json::json!([{ "type": "i32", "value": 1 }, { "type": "i64", "value": 2 }])
+    );
+    assert_eq!(
+        json["results"],
+        serde_json::json!([{ "type": "i64", "value": 3 }])
+    );
+    assert!(json["elapsed_ns"].is_u64());
+    assert!(json["fuel_consumed"].as_u64().unwrap() > 0);
+    assert!(json.get("error").is_none());
+    // The verbose execution start message is written to stderr.
+    assert!(contains_slice(&output.stderr, b"::add(1, 2) ..."));
+    assert.success();
+}
+
+#[test]
+fn test_json_output_trap() {
+    let mut cmd = get_cmd();
+    let assert = cmd
+        .arg("--output=json")
+        .arg("--invoke=div")
+        .arg(get_bin_path("arith"))
+        .args(["1", "0"])
+        .assert();
+    let output = assert.get_output();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["func"], "div");
+    assert_eq!(json["error"]["trap_code"], "IntegerDivisionByZero");
+    assert!(json["error"]["message"].is_string());
+    assert!(json["elapsed_ns"].is_u64());
+    assert!(json.get("results").is_none());
+    assert!(json.get("fuel_consumed").is_none());
+    assert.failure();
+}
+
+#[test]
+fn test_json_output_exit() {
+    let mut cmd = get_cmd();
+    let assert = cmd
+        .arg("--output=json")
+        .arg(get_bin_path("proc_exit"))
+        .assert();
+    let output = assert.get_output();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["exit_code"], 1);
+    assert!(json["elapsed_ns"].is_u64());
+    assert!(json.get("results").is_none());
+    assert!(json.get("error").is_none());
+    assert.failure().code(1);
+}
+
+#[test]
+fn test_output_format_json() {
+    let mut cmd = get_cmd();
+    let assert = cmd
+        .args(["--output-format", "json"])
+        .arg("--fuel=100")
+        .arg("--invoke=add")
+        .arg(get_bin_path("arith"))
+        .args(["40", "2"])
+        .assert();
+    let output = assert.get_output();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        json["results"],
+        serde_json::json!([{ "type": "i64", "value": 42 }])
+    );
+    assert!(json["elapsed_ns"].is_u64());
+    assert!(json["fuel_consumed"].is_u64());
+    assert.success();
+}
+
+#[test]
+fn test_output_format_csv() {
+    let mut cmd = get_cmd();
+    let assert = cmd
+        .args(["--output-format", "csv"])
+        .arg("--invoke=add")
+        .arg(get_bin_path("arith"))
+        .args(["40", "2"])
+        .assert();
+    assert_eq!(assert.get_output().stdout, b"42\n");
+    assert.success();
+}
@@ -0,0 +1,11 @@
+;; Test Notes
+;; Tests the JSON output of successful and trapping function invocations
+
+(module
+  (func (export "add") (param i32 i64) (result i64)
+    (i64.add (i64.extend_i32_s (local.get 0)) (local.get 1))
+  )
+  (func (export "div") (param i32 i32) (result i32)
+    (i32.div_s (local.get 0) (local.get 1))
+  )
+)
@@ -102,6 +102,34 @@ where
         self.components[index].replace(component)
     }
 
+    /// Resizes the [`ComponentVec`] to hold components for `len` entities.
+    ///
+    /// - Entities at indices `len` and beyond lose their components.
+    /// - New entities are given components computed by `f`.
+    /// - Entities that already had a component slot are left untouched.
+    pub fn resize_with<F>(&mut self, len: usize, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.components.resize_with(len, || Some(f()));
+    }
+
+    /// Returns an exclusive reference to the component at `index`.
+    ///
+    /// Inserts the component computed by `f` first if there was none.
+    pub fn get_or_insert_with<F>(&mut self, index: Idx, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let index = index.into_usize();
+        if index >= self.components.len() {
+            // The underlying vector does not have enough capacity
+            // and is required to be enlarged.
+            self.components.resize_with(index + 1, || None);
+        }
+        self.components[index].get_or_insert_with(f)
+    }
+
     /// Unsets the component for the entity at `index` and returns it if any.
     #[inline]
     pub fn unset(&mut self, index: Idx) -> Option<T> {
@@ -202,6 +230,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn resize_with_works() {
+        let mut vec = <ComponentVec<usize, String>>::new();
+        add_components(&mut vec, 2);
+        // Growing keeps existing components and computes new ones.
+        let mut n = 0;
+        vec.resize_with(5, || {
+            n += 1;
+            format!("new {n}")
+        });
+        assert_eq!(n, 3);
+        assert_eq!(vec.get(0).map(String::as_str), Some("0"));
+        assert_eq!(vec.get(1).map(String::as_str), Some("1"));
+        assert_eq!(vec.get(2).map(String::as_str), Some("new 1"));
+        assert_eq!(vec.get(4).map(String::as_str), Some("new 3"));
+        assert!(vec.get(5).is_none());
+        // Shrinking removes the components of entities beyond the new length.
+        vec.resize_with(1, || unreachable!());
+        assert_eq!(vec.get(0).map(String::as_str), Some("0"));
+        assert!(vec.get(1).is_none());
+        assert!(vec.get(4).is_none());
+    }
+
+    #[test]
+    fn get_or_insert_with_works() {
+        let mut vec = <ComponentVec<usize, String>>::new();
+        // Inserts beyond the current length grow the vector.
+        assert_eq!(vec.get_or_insert_with(3, || String::from("a")), "a");
+        assert!(vec.get(0).is_none());
+        assert!(vec.get(2).is_none());
+        // Existing components are returned without computing new ones.
+        vec.get_or_insert_with(3, || unreachable!()).push('b');
+        assert_eq!(vec.get(3).map(String::as_str), Some("ab"));
+        // Empty slots within the current length are filled.
+        assert_eq!(vec.get_or_insert_with(1, String::new), "");
+        assert_eq!(vec.get(1).map(String::as_str), Some(""));
+    }
+
     #[test]
     fn debug_works() {
         let mut vec = <ComponentVec<usize, String>>::new();
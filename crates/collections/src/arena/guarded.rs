@@ -1,7 +1,7 @@
 use crate::arena::ArenaIndex;
 
 /// A guarded entity.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct GuardedEntity<GuardIdx, EntityIdx> {
     guard_idx: GuardIdx,
     entity_idx: EntityIdx,
@@ -33,4 +33,10 @@ where
         }
         Some(self.entity_idx)
     }
+
+    /// Returns `true` if the [`GuardedEntity`] is guarded by `guard_index`.
+    #[inline]
+    pub fn is_guarded_by(&self, guard_index: GuardIdx) -> bool {
+        self.guard_idx.into_usize() == guard_index.into_usize()
+    }
 }
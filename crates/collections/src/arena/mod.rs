@@ -13,6 +13,7 @@ use alloc::vec::Vec;
 use core::{
     iter::Enumerate,
     marker::PhantomData,
+    mem,
     ops::{Index, IndexMut, Range},
     slice,
 };
@@ -72,6 +73,12 @@ impl<Idx, T> Arena<Idx, T> {
         self.entities.len()
     }
 
+    /// Returns the number of entities the arena can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.entities.capacity()
+    }
+
     /// Returns `true` if the arena has not yet allocated entities.
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -101,6 +108,19 @@ impl<Idx, T> Arena<Idx, T> {
             marker: PhantomData,
         }
     }
+
+    /// Returns an iterator over the indices of the arena entities.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`Arena::iter`] this does not access the arena entities.
+    #[inline]
+    pub fn indices(&self) -> Indices<Idx> {
+        Indices {
+            iter: 0..self.len(),
+            marker: PhantomData,
+        }
+    }
 }
 
 impl<Idx, T> Arena<Idx, T>
@@ -166,6 +186,32 @@ where
         let snd = snd_set.get_mut(0)?;
         Some((fst, snd))
     }
+
+    /// Returns exclusive references to the entities at the given `indices` if any.
+    ///
+    /// The returned references are in the same order as their `indices`.
+    ///
+    /// Returns `None` if any two of the `indices` refer to the same entity.
+    /// Returns `None` if any of the `indices` is invalid for this [`Arena`].
+    pub fn get_disjoint_mut<const N: usize>(&mut self, indices: [Idx; N]) -> Option<[&mut T; N]> {
+        let indices = indices.map(Idx::into_usize);
+        // Visit the indices in ascending order so that the entities can be split off one by one.
+        let mut order: [usize; N] = core::array::from_fn(|n| n);
+        order.sort_unstable_by_key(|&n| indices[n]);
+        let mut refs: [Option<&mut T>; N] = core::array::from_fn(|_| None);
+        let mut rest: &mut [T] = &mut self.entities;
+        let mut offset = 0;
+        for n in order {
+            let index = indices[n];
+            // Duplicate indices are adjacent after sorting and thus precede `offset`.
+            let skip = index.checked_sub(offset)?;
+            let (entity, tail) = mem::take(&mut rest).get_mut(skip..)?.split_first_mut()?;
+            refs[n] = Some(entity);
+            rest = tail;
+            offset = index + 1;
+        }
+        Some(refs.map(|entity| entity.expect("all indices have been visited")))
+    }
 }
 
 impl<Idx, T> FromIterator<T> for Arena<Idx, T> {
@@ -303,6 +349,50 @@ where
     }
 }
 
+/// An iterator over the indices of arena entities.
+#[derive(Debug, Clone)]
+pub struct Indices<Idx> {
+    iter: Range<usize>,
+    marker: PhantomData<fn() -> Idx>,
+}
+
+impl<Idx> Iterator for Indices<Idx>
+where
+    Idx: ArenaIndex,
+{
+    type Item = Idx;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Idx::from_usize)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<Idx> DoubleEndedIterator for Indices<Idx>
+where
+    Idx: ArenaIndex,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Idx::from_usize)
+    }
+}
+
+impl<Idx> ExactSizeIterator for Indices<Idx>
+where
+    Idx: ArenaIndex,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
 impl<Idx, T> Arena<Idx, T> {
     /// Panics with an index out of bounds message.
     fn index_out_of_bounds(len: usize, index: usize) -> ! {
@@ -63,6 +63,16 @@ mod arena {
         assert!(arena.iter().eq(TEST_ENTITIES.iter().enumerate()));
     }
 
+    #[test]
+    fn indices_works() {
+        let mut arena = alloc_arena(TEST_ENTITIES);
+        assert_eq!(arena.indices().len(), TEST_ENTITIES.len());
+        assert!(arena.indices().eq(0..TEST_ENTITIES.len()));
+        assert!(arena.indices().rev().eq((0..TEST_ENTITIES.len()).rev()));
+        arena.clear();
+        assert_eq!(arena.indices().next(), None);
+    }
+
     #[test]
     fn from_iter_works() {
         let expected = alloc_arena(TEST_ENTITIES);
@@ -86,6 +96,37 @@ mod arena {
         // there is no deduplication of equal entities.
         assert_eq!(arena.len(), previous_len + TEST_ENTITIES.len());
     }
+
+    #[test]
+    fn get_disjoint_mut_works() {
+        let mut arena = alloc_arena(TEST_ENTITIES);
+        let [d, a, c] = arena.get_disjoint_mut([3, 0, 2]).unwrap();
+        assert_eq!([*d, *a, *c], ["d", "a", "c"]);
+        *d = "x";
+        *a = "y";
+        assert_eq!(arena[3], "x");
+        assert_eq!(arena[0], "y");
+        assert_eq!(arena.get_disjoint_mut([]), Some([]));
+        let all = arena.get_disjoint_mut([0, 1, 2, 3]).unwrap();
+        assert_eq!(all.map(|entity| *entity), ["y", "b", "c", "x"]);
+    }
+
+    #[test]
+    fn get_disjoint_mut_rejects_duplicates() {
+        let mut arena = alloc_arena(TEST_ENTITIES);
+        assert!(arena.get_disjoint_mut([1, 1]).is_none());
+        assert!(arena.get_disjoint_mut([2, 0, 2]).is_none());
+        assert!(arena.get_disjoint_mut([0, 3, 1, 3]).is_none());
+    }
+
+    #[test]
+    fn get_disjoint_mut_rejects_out_of_bounds() {
+        let mut arena = alloc_arena(TEST_ENTITIES);
+        let len = arena.len();
+        assert!(arena.get_disjoint_mut([len]).is_none());
+        assert!(arena.get_disjoint_mut([0, len]).is_none());
+        assert!(arena.get_disjoint_mut([len + 1, 2, 1]).is_none());
+    }
 }
 
 mod dedup_arena {
@@ -21,6 +21,42 @@ use core::{
 /// An untyped value.
 ///
 /// Provides a dense and simple interface to all functional Wasm operations.
+///
+/// # Untyped ABI
+///
+/// An [`UntypedVal`] does not know its own type. Its 64 bits encode Wasm values as follows:
+///
+/// - `i32` and `u32`: the 32-bit value zero-extended to 64 bits.
+/// - `i64` and `u64`: the 64-bit value as is.
+/// - `f32` and `f64`: the IEEE 754 bits of the value zero-extended to 64 bits.
+///   NaN payloads are preserved.
+/// - `bool`: `1` for `true` and `0` for `false`.
+///   Any non-zero value decodes to `true`.
+/// - `funcref` and `externref`: an opaque handle where `0` represents `null`.
+///
+/// Decoding an [`UntypedVal`] as a type that is narrower than 64 bits, e.g. `i32`,
+/// ignores the upper bits. Therefore, only decoding an [`UntypedVal`] with the
+/// type it was encoded from is guaranteed to round-trip.
+///
+/// The raw bits can be accessed via [`UntypedVal::to_bits`] and [`UntypedVal::from_bits`].
+/// Slices of [`UntypedVal`] can be converted from and to tuples of Wasm values via
+/// [`UntypedVal::encode_slice`] and [`UntypedVal::decode_slice`] which allows to
+/// implement custom host function trampolines on top of the untyped ABI.
+///
+/// # Example
+///
+/// ```
+/// # use wasmi_core::{UntypedVal, F32};
+/// let mut slice = [UntypedVal::default(); 3];
+/// UntypedVal::encode_slice(&mut slice, (-1_i32, 2.5_f32, u64::MAX)).unwrap();
+/// assert_eq!(slice[0].to_bits(), 0xFFFF_FFFF);
+/// assert_eq!(slice[1], UntypedVal::from(F32::from(2.5)));
+/// assert_eq!(slice[2], UntypedVal::from_bits(u64::MAX));
+/// let (a, b, c) = UntypedVal::decode_slice::<(i32, f32, u64)>(&slice).unwrap();
+/// assert_eq!((a, b, c), (-1, 2.5, u64::MAX));
+/// // The number of values must match the length of the slice.
+/// assert!(UntypedVal::decode_slice::<(i32, f32)>(&slice).is_err());
+/// ```
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct UntypedVal {
@@ -1452,3 +1488,94 @@ macro_rules! impl_encode_untyped_slice {
     };
 }
 for_each_tuple!(impl_encode_untyped_slice);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Bit patterns at the edges of all integer and float encodings.
+    ///
+    /// # Note
+    ///
+    /// Round-trips of arbitrary bit patterns are covered by the `untyped` fuzz target.
+    const BITS: &[u64] = &[
+        0,
+        1,
+        0x7F,
+        0x80,
+        0xFF,
+        0x7FFF,
+        0x8000,
+        0xFFFF,
+        0x7FFF_FFFF,
+        0x8000_0000,
+        0xFFFF_FFFF,
+        0x7F80_0000,
+        0x7FC0_0001,
+        0xFF80_0000,
+        0x0000_0001_0000_0000,
+        0x7FF0_0000_0000_0000,
+        0x7FF8_0000_0000_0001,
+        0xFFF0_0000_0000_0000,
+        0x7FFF_FFFF_FFFF_FFFF,
+        0x8000_0000_0000_0000,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0x0123_4567_89AB_CDEF,
+    ];
+
+    #[test]
+    fn round_trip_works() {
+        for &bits in BITS {
+            assert_eq!(UntypedVal::from_bits(bits).to_bits(), bits);
+            macro_rules! assert_round_trip {
+                ( $( $ty:ty ),* $(,)? ) => {
+                    $(
+                        let value = bits as $ty;
+                        assert_eq!(<$ty>::from(UntypedVal::from(value)), value);
+                    )*
+                };
+            }
+            assert_round_trip!(i8, i16, i32, i64, u8, u16, u32, u64);
+            let value = f32::from_bits(bits as u32);
+            assert_eq!(
+                f32::from(UntypedVal::from(value)).to_bits(),
+                value.to_bits()
+            );
+            assert_eq!(
+                UntypedVal::from(value).to_bits(),
+                u64::from(value.to_bits())
+            );
+            let value = f64::from_bits(bits);
+            assert_eq!(
+                f64::from(UntypedVal::from(value)).to_bits(),
+                value.to_bits()
+            );
+            assert_eq!(
+                UntypedVal::from(bits as i32).to_bits(),
+                u64::from(bits as u32)
+            );
+        }
+    }
+
+    #[test]
+    fn slice_round_trip_works() {
+        for (&bits, &rev) in BITS.iter().zip(BITS.iter().rev()) {
+            let input = (
+                bits as i32,
+                rev as i64,
+                F32::from_bits(rev as u32),
+                F64::from_bits(bits),
+            );
+            let mut slice = [UntypedVal::default(); 4];
+            UntypedVal::encode_slice(&mut slice, input).unwrap();
+            let output = UntypedVal::decode_slice::<(i32, i64, F32, F64)>(&slice).unwrap();
+            assert_eq!(output.0, input.0);
+            assert_eq!(output.1, input.1);
+            assert_eq!(output.2.to_bits(), input.2.to_bits());
+            assert_eq!(output.3.to_bits(), input.3.to_bits());
+        }
+        let mut slice = [UntypedVal::default(); 2];
+        assert!(UntypedVal::encode_slice(&mut slice, (1_i32,)).is_err());
+        assert!(UntypedVal::decode_slice::<(i32, i32, i32)>(&slice).is_err());
+    }
+}
@@ -13,6 +13,13 @@ pub struct FuzzWasmiConfig {
     pub validation_mode: ValidationMode,
     /// Is `true` if Wasmi shall use lazy translation.
     pub translation_mode: CompilationMode,
+    /// Is `true` if Wasmi shall reject Wasm modules using floating point.
+    ///
+    /// # Note
+    ///
+    /// This is never enabled by [`Arbitrary`] and must be set by fuzz targets explicitly,
+    /// usually together with [`FuzzSmithConfig::disable_floats`].
+    pub deny_floating_point: bool,
 }
 
 /// The Wasmi parsing mode.
@@ -38,6 +45,7 @@ impl From<FuzzWasmiConfig> for wasmi::Config {
         let mut config = wasmi::Config::default();
         config.compilation_mode(fuzz.translation_mode);
         config.consume_fuel(fuzz.consume_fuel);
+        config.deny_floating_point(fuzz.deny_floating_point);
         config
     }
 }
@@ -64,6 +72,7 @@ impl Arbitrary<'_> for FuzzWasmiConfig {
             parsing_mode,
             validation_mode,
             translation_mode,
+            deny_floating_point: false,
         })
     }
 
@@ -139,6 +148,24 @@ impl FuzzSmithConfig {
         self.inner.export_everything = true;
     }
 
+    /// Restrict generation to Wasm modules with deterministic behavior.
+    ///
+    /// # Note
+    ///
+    /// This disables imports since they may be backed by host state and
+    /// Wasm proposals such as `threads` and `relaxed-simd` which permit
+    /// non-deterministic execution by design.
+    pub fn enable_determinism(&mut self) {
+        self.inner.max_imports = 0;
+        self.inner.threads_enabled = false;
+        self.inner.relaxed_simd_enabled = false;
+    }
+
+    /// Disable generation of floating point types and instructions.
+    pub fn disable_floats(&mut self) {
+        self.inner.allow_floats = false;
+    }
+
     /// Disable the Wasm `multi-memory` proposal.
     pub fn disable_multi_memory(&mut self) {
         self.inner.multi_value_enabled = false;
@@ -23,3 +23,25 @@ pub enum TrapCode {
     StackOverflow,
     BadSignature,
 }
+
+impl From<wasmi::Error> for FuzzError {
+    fn from(error: wasmi::Error) -> Self {
+        use wasmi::core::TrapCode;
+        let Some(trap_code) = error.as_trap_code() else {
+            return FuzzError::Other;
+        };
+        let trap_code = match trap_code {
+            TrapCode::UnreachableCodeReached => crate::TrapCode::UnreachableCodeReached,
+            TrapCode::MemoryOutOfBounds => crate::TrapCode::MemoryOutOfBounds,
+            TrapCode::TableOutOfBounds => crate::TrapCode::TableOutOfBounds,
+            TrapCode::IndirectCallToNull => crate::TrapCode::IndirectCallToNull,
+            TrapCode::IntegerDivisionByZero => crate::TrapCode::IntegerDivisionByZero,
+            TrapCode::IntegerOverflow => crate::TrapCode::IntegerOverflow,
+            TrapCode::BadConversionToInteger => crate::TrapCode::BadConversionToInteger,
+            TrapCode::StackOverflow => crate::TrapCode::StackOverflow,
+            TrapCode::BadSignature => crate::TrapCode::BadSignature,
+            TrapCode::OutOfFuel | TrapCode::GrowthOperationLimited => return FuzzError::Other,
+        };
+        FuzzError::Trap(trap_code)
+    }
+}
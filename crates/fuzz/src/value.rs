@@ -90,3 +90,20 @@ impl From<FuzzVal> for wasmi::Val {
         }
     }
 }
+
+impl From<wasmi::Val> for FuzzVal {
+    fn from(value: wasmi::Val) -> Self {
+        match value {
+            wasmi::Val::I32(value) => Self::I32(value),
+            wasmi::Val::I64(value) => Self::I64(value),
+            wasmi::Val::F32(value) => Self::F32(value.into()),
+            wasmi::Val::F64(value) => Self::F64(value.into()),
+            wasmi::Val::FuncRef(value) => Self::FuncRef {
+                is_null: value.is_null(),
+            },
+            wasmi::Val::ExternRef(value) => Self::ExternRef {
+                is_null: value.is_null(),
+            },
+        }
+    }
+}
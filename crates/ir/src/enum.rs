@@ -136,6 +136,59 @@ macro_rules! define_result {
 }
 for_each_op::for_each_op!(define_result);
 
+macro_rules! define_code {
+    (
+        $(
+            $( #[doc = $doc:literal] )*
+            #[snake_name($snake_name:ident)]
+            $name:ident
+            $(
+                {
+                    $(
+                        @ $result_name:ident: $result_ty:ty,
+                    )?
+                    $(
+                        $( #[$field_docs:meta] )*
+                        $field_name:ident: $field_ty:ty
+                    ),*
+                    $(,)?
+                }
+            )?
+        ),* $(,)?
+    ) => {
+        /// The field-less kinds of all [`Instruction`]s.
+        #[repr(u16)]
+        enum InstructionCode {
+            $( $name ),*
+        }
+
+        impl Instruction {
+            /// The names of all kinds of [`Instruction`] indexed by their [`Instruction::code`].
+            pub const NAMES: &'static [&'static str] = &[
+                $( stringify!($name) ),*
+            ];
+
+            /// Returns the code of the kind of `self`.
+            ///
+            /// Codes are dense, starting at zero and index into [`Instruction::NAMES`].
+            pub fn code(&self) -> u16 {
+                let code = match self {
+                    $(
+                        Self::$name { .. } => InstructionCode::$name,
+                    )*
+                };
+                code as u16
+            }
+
+            /// Returns the name of the kind of `self`, e.g. `"I32Add"`.
+            pub fn name(&self) -> &'static str {
+                Self::NAMES[usize::from(self.code())]
+            }
+        }
+    };
+}
+for_each_op::for_each_op!(define_code);
+
 impl Instruction {
     /// Creates a new [`Instruction::ReturnReg2`] for the given [`Reg`] indices.
     pub fn return_reg2_ext(reg0: impl Into<Reg>, reg1: impl Into<Reg>) -> Self {
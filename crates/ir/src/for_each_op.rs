@@ -4558,6 +4558,22 @@ macro_rules! for_each_op {
                 /// The constant `index` value of the table element to get.
                 index: u32,
             },
+            /// Variant of [`Instruction::TableGetImm`] that omits the bounds check.
+            ///
+            /// # Note
+            ///
+            /// Only emitted if `index` is less than the minimum size of the table.
+            /// This is sound since tables never shrink.
+            ///
+            /// # Encoding
+            ///
+            /// This [`Instruction`] must be followed by an [`Instruction::TableIndex`].
+            #[snake_name(table_get_imm_in_bounds)]
+            TableGetImmInBounds {
+                @result: Reg,
+                /// The constant `index` value of the table element to get.
+                index: u32,
+            },
 
             /// A Wasm `table.size` instruction.
             #[snake_name(table_size)]
@@ -4591,6 +4607,23 @@ macro_rules! for_each_op {
                 /// The constant `index` of the instruction.
                 index: u32,
             },
+            /// Variant of [`Instruction::TableSetAt`] that omits the bounds check.
+            ///
+            /// # Note
+            ///
+            /// Only emitted if `index` is less than the minimum size of the table.
+            /// This is sound since tables never shrink.
+            ///
+            /// # Encoding
+            ///
+            /// This [`Instruction`] must be followed by an [`Instruction::TableIndex`].
+            #[snake_name(table_set_at_in_bounds)]
+            TableSetAtInBounds {
+                /// The register holding the `value` of the instruction.
+                value: Reg,
+                /// The constant `index` of the instruction.
+                index: u32,
+            },
 
             /// Wasm `table.copy <dst> <src>` instruction.
             ///
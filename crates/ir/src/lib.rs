@@ -11,6 +11,7 @@ mod immeditate;
 pub mod index;
 mod primitive;
 mod span;
+pub mod text;
 mod visit_regs;
 
 #[cfg(test)]
@@ -35,5 +36,6 @@ pub use self::{
     },
     r#enum::Instruction,
     span::{BoundedRegSpan, FixedRegSpan, RegSpan, RegSpanIter},
+    text::{display_ops, parse_ops, ParseError},
     visit_regs::VisitRegs,
 };
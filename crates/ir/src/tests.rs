@@ -1,4 +1,5 @@
-use crate::{Reg, RegSpan, RegSpanIter};
+use crate::{core::TrapCode, Instruction, Reg, RegSpan, RegSpanIter};
+use alloc::vec::Vec;
 
 #[test]
 fn has_overlapping_copy_spans_works() {
@@ -38,3 +39,85 @@ fn has_overlapping_copy_spans_works() {
     assert!(has_overlapping_copy_spans(span(4), span(1), 4));
     assert!(has_overlapping_copy_spans(span(4), span(0), 5));
 }
+
+#[test]
+fn instruction_code_and_name_works() {
+    let add = Instruction::i32_add(Reg::from(0), Reg::from(1), Reg::from(2));
+    assert_eq!(add.name(), "I32Add");
+    assert_eq!(Instruction::Return.name(), "Return");
+    assert_eq!(Instruction::NAMES[usize::from(add.code())], "I32Add");
+    assert_ne!(add.code(), Instruction::Return.code());
+}
+
+#[test]
+fn uses_and_defs_regs_works() {
+    fn regs(regs: &[i16]) -> Vec<Reg> {
+        regs.iter().copied().map(Reg::from).collect()
+    }
+    fn span(reg: i16) -> RegSpan {
+        RegSpan::new(Reg::from(reg))
+    }
+    let assert_regs = |instr: Instruction, uses: Option<&[i16]>, defs: Option<&[i16]>| {
+        assert_eq!(
+            instr.uses_regs().as_deref(),
+            uses.map(regs).as_deref(),
+            "uses of {instr:?}"
+        );
+        assert_eq!(
+            instr.defs_regs().as_deref(),
+            defs.map(regs).as_deref(),
+            "defs of {instr:?}"
+        );
+    };
+    assert_regs(Instruction::copy(1, 0), Some(&[0]), Some(&[1]));
+    assert_regs(Instruction::i32_add(2, 0, 1), Some(&[0, 1]), Some(&[2]));
+    assert_regs(
+        Instruction::copy2_ext(span(3), 0, 1),
+        Some(&[0, 1]),
+        Some(&[3, 4]),
+    );
+    assert_regs(
+        Instruction::copy_span(span(5), span(1), 3_u16),
+        Some(&[1, 2, 3]),
+        Some(&[5, 6, 7]),
+    );
+    assert_regs(
+        Instruction::copy_many_ext(span(5), 0, 1),
+        Some(&[0, 1]),
+        None,
+    );
+    assert_regs(
+        Instruction::register_list_ext(2, 3, 4),
+        Some(&[2, 3, 4]),
+        Some(&[]),
+    );
+    assert_regs(Instruction::return_reg(3), Some(&[3]), Some(&[]));
+    assert_regs(
+        Instruction::branch_table_target(span(4), 1),
+        Some(&[]),
+        None,
+    );
+    assert_regs(
+        Instruction::trap(TrapCode::UnreachableCodeReached),
+        Some(&[]),
+        Some(&[]),
+    );
+}
+
+#[test]
+fn uses_regs_finds_dead_copy() {
+    let instrs = [
+        Instruction::copy(2, 0),
+        Instruction::copy(3, 1),
+        Instruction::i32_add(4, 0, 3),
+        Instruction::return_reg(4),
+    ];
+    let is_dead = |instr: &Instruction| match instr {
+        Instruction::Copy { result, .. } => !instrs
+            .iter()
+            .any(|instr| instr.uses_regs().map_or(true, |uses| uses.contains(result))),
+        _ => false,
+    };
+    let dead = instrs.iter().map(is_dead).collect::<Vec<_>>();
+    assert_eq!(dead, [true, false, false, false]);
+}
@@ -0,0 +1,71 @@
+use super::TextValue;
+use crate::{for_each_op, Instruction};
+use core::fmt;
+
+macro_rules! impl_display {
+    (
+        $(
+            $( #[doc = $doc:literal] )*
+            #[snake_name($snake_name:ident)]
+            $name:ident
+            $(
+                {
+                    $( @ $result_name:ident: $result_ty:ty, )?
+                    $(
+                        $( #[$field_docs:meta] )*
+                        $field_name:ident: $field_ty:ty
+                    ),*
+                    $(,)?
+                }
+            )?
+        ),* $(,)?
+    ) => {
+        impl fmt::Display for Instruction {
+            /// Writes `self` in the Wasmi IR text format.
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match self {
+                    $(
+                        Self::$name { $( $( $result_name, )? $( $field_name, )* )? } => {
+                            write!(f, stringify!($name))?;
+                            $(
+                                $(
+                                    write!(f, concat!(" ", stringify!($result_name), "="))?;
+                                    $result_name.fmt_text(f)?;
+                                )?
+                                $(
+                                    write!(f, concat!(" ", stringify!($field_name), "="))?;
+                                    $field_name.fmt_text(f)?;
+                                )*
+                            )?
+                            Ok(())
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+for_each_op!(impl_display);
+
+/// Displays a sequence of [`Instruction`]s in the Wasmi IR text format.
+///
+/// Each [`Instruction`] is written on its own line.
+/// The output can be parsed back via [`parse_ops`](super::parse_ops).
+#[derive(Debug, Copy, Clone)]
+pub struct DisplayOps<'a> {
+    ops: &'a [Instruction],
+}
+
+/// Returns a [`DisplayOps`] that displays `ops` in the Wasmi IR text format.
+pub fn display_ops(ops: &[Instruction]) -> DisplayOps<'_> {
+    DisplayOps { ops }
+}
+
+impl fmt::Display for DisplayOps<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for op in self.ops {
+            writeln!(f, "{op}")?;
+        }
+        Ok(())
+    }
+}
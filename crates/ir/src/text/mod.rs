@@ -0,0 +1,52 @@
+//! A textual format for sequences of Wasmi [`Instruction`]s.
+//!
+//! This is primarily used to write readable expectations for translation tests.
+//!
+//! # Syntax
+//!
+//! Each non-empty line holds a single [`Instruction`] written as its name
+//! followed by its fields as `name=value` pairs in any order.
+//! Comments start with `;;` and extend to the end of the line.
+//!
+//! ```text
+//! ;; Adds `r1` and the function local constant `r-1`.
+//! I32Add result=r0 lhs=r1 rhs=r-1
+//! Copy2 results=r0..r2 values=[r3, r4]
+//! CallInternal results=r0.. func=2
+//! Trap trap_code=UnreachableCodeReached
+//! Return
+//! ```
+//!
+//! Field values are written as follows:
+//!
+//! - [`Reg`]: `r` followed by its signed index, e.g. `r5` or `r-1`.
+//! - [`RegSpan`]: its head register followed by `..`, e.g. `r5..`.
+//! - [`BoundedRegSpan`] and [`FixedRegSpan`]: an exclusive register range, e.g. `r5..r8`.
+//! - Register arrays: a bracketed list, e.g. `[r1, r2]`.
+//! - Integers, indices and branch offsets: decimal or `0x` prefixed hexadecimal, e.g. `-3` or `0xFF`.
+//! - Floating point constants: decimal, `inf`, `-inf` or `nan:0x` followed by the raw `f32` bits.
+//! - [`Sign`]: either `pos` or `neg`.
+//! - [`TrapCode`]: the name of the trap code, e.g. `IntegerOverflow`.
+//!
+//! [`Reg`]: crate::Reg
+//! [`RegSpan`]: crate::RegSpan
+//! [`BoundedRegSpan`]: crate::BoundedRegSpan
+//! [`FixedRegSpan`]: crate::FixedRegSpan
+//! [`Sign`]: crate::Sign
+//! [`TrapCode`]: crate::core::TrapCode
+
+mod display;
+mod parse;
+mod value;
+
+#[cfg(test)]
+mod tests;
+
+use self::value::TextValue;
+pub use self::{
+    display::{display_ops, DisplayOps},
+    parse::{parse_ops, ParseError, ParseErrorKind},
+};
+
+#[cfg(doc)]
+use crate::Instruction;
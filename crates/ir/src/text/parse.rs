@@ -0,0 +1,322 @@
+use super::TextValue;
+use crate::{for_each_op, Instruction};
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+
+/// An error that may occur upon parsing the Wasmi IR text format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The 1-based line of the error.
+    line: usize,
+    /// The 1-based column of the error.
+    column: usize,
+    /// The kind of the error.
+    kind: ParseErrorKind,
+}
+
+/// The kind of a [`ParseError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Expected the name of an instruction.
+    ExpectedInstruction,
+    /// Encountered an unknown instruction name.
+    UnknownInstruction(Box<str>),
+    /// Expected a field in the form `name=value`.
+    ExpectedField,
+    /// Encountered a field that the instruction does not have.
+    UnknownField(Box<str>),
+    /// Encountered a field more than once.
+    DuplicateField(Box<str>),
+    /// A field of the instruction is missing.
+    MissingField(&'static str),
+    /// The value of a field is invalid for its type.
+    InvalidValue(&'static str),
+}
+
+impl ParseError {
+    /// Creates a new [`ParseError`] at `line` and `column`.
+    fn new(line: usize, column: usize, kind: ParseErrorKind) -> Self {
+        Self { line, column, kind }
+    }
+
+    /// Returns the 1-based line of the [`ParseError`].
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the 1-based column of the [`ParseError`].
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the [`ParseErrorKind`] of the [`ParseError`].
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExpectedInstruction => write!(f, "expected instruction name"),
+            Self::UnknownInstruction(name) => write!(f, "unknown instruction: {name}"),
+            Self::ExpectedField => write!(f, "expected field of the form `name=value`"),
+            Self::UnknownField(name) => write!(f, "unknown field: {name}"),
+            Self::DuplicateField(name) => write!(f, "duplicate field: {name}"),
+            Self::MissingField(name) => write!(f, "missing field: {name}"),
+            Self::InvalidValue(name) => write!(f, "invalid value for field: {name}"),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Parses `text` in the Wasmi IR text format into a sequence of [`Instruction`]s.
+///
+/// See the [`text`](crate::text) module documentation for the syntax.
+///
+/// # Errors
+///
+/// If `text` is malformed or describes an invalid [`Instruction`].
+pub fn parse_ops(text: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut ops = Vec::new();
+    for (n, line) in text.lines().enumerate() {
+        let line = match line.split_once(";;") {
+            Some((line, _comment)) => line,
+            None => line,
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        ops.push(LineParser::new(n + 1, line).parse()?);
+    }
+    Ok(ops)
+}
+
+/// Parses a single line of the Wasmi IR text format.
+struct LineParser<'a> {
+    /// The 1-based line number.
+    line: usize,
+    /// The text of the line.
+    text: &'a str,
+    /// The current byte position within `text`.
+    pos: usize,
+}
+
+/// A parsed but not yet typed `name=value` field.
+struct Field<'a> {
+    /// The name of the field.
+    name: &'a str,
+    /// The column of the field name.
+    name_column: usize,
+    /// The unparsed value of the field.
+    value: &'a str,
+    /// The column of the field value.
+    value_column: usize,
+}
+
+/// The fields of an instruction that have not yet been consumed.
+struct Fields<'a> {
+    /// The 1-based line number of the instruction.
+    line: usize,
+    /// The column at the end of the line.
+    end_column: usize,
+    /// The fields not yet consumed.
+    fields: Vec<Field<'a>>,
+}
+
+impl<'a> LineParser<'a> {
+    /// Creates a new [`LineParser`] for `text` at `line`.
+    fn new(line: usize, text: &'a str) -> Self {
+        Self { line, text, pos: 0 }
+    }
+
+    /// Returns the 1-based column of byte position `pos`.
+    fn column_of(&self, pos: usize) -> usize {
+        self.text[..pos].chars().count() + 1
+    }
+
+    /// Returns a [`ParseError`] with `kind` at byte position `pos`.
+    fn error_at(&self, pos: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(self.line, self.column_of(pos), kind)
+    }
+
+    /// Returns the remaining unparsed text.
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    /// Skips all whitespace at the current position.
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes an identifier at the current position and returns it.
+    fn identifier(&mut self) -> &'a str {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Consumes a field value at the current position and returns it.
+    ///
+    /// A field value extends up to the next whitespace outside of brackets.
+    fn value(&mut self) -> &'a str {
+        let rest = self.rest();
+        let mut depth = 0_usize;
+        let len = rest
+            .find(|c: char| {
+                match c {
+                    '[' => depth += 1,
+                    ']' => depth = depth.saturating_sub(1),
+                    c if c.is_whitespace() && depth == 0 => return true,
+                    _ => {}
+                }
+                false
+            })
+            .unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Parses the line into an [`Instruction`].
+    fn parse(mut self) -> Result<Instruction, ParseError> {
+        self.skip_whitespace();
+        let name_pos = self.pos;
+        let name = self.identifier();
+        if name.is_empty() {
+            return Err(self.error_at(name_pos, ParseErrorKind::ExpectedInstruction));
+        }
+        let mut fields = Vec::new();
+        loop {
+            let start = self.pos;
+            self.skip_whitespace();
+            if self.rest().is_empty() {
+                break;
+            }
+            if start == self.pos {
+                return Err(self.error_at(self.pos, ParseErrorKind::ExpectedField));
+            }
+            let field_pos = self.pos;
+            let field_name = self.identifier();
+            if field_name.is_empty() || !self.rest().starts_with('=') {
+                return Err(self.error_at(field_pos, ParseErrorKind::ExpectedField));
+            }
+            self.pos += 1;
+            let value_pos = self.pos;
+            let value = self.value();
+            if value.is_empty() {
+                return Err(self.error_at(value_pos, ParseErrorKind::ExpectedField));
+            }
+            if fields.iter().any(|field: &Field| field.name == field_name) {
+                return Err(
+                    self.error_at(field_pos, ParseErrorKind::DuplicateField(field_name.into()))
+                );
+            }
+            fields.push(Field {
+                name: field_name,
+                name_column: self.column_of(field_pos),
+                value,
+                value_column: self.column_of(value_pos),
+            });
+        }
+        let mut fields = Fields {
+            line: self.line,
+            end_column: self.column_of(self.text.len()),
+            fields,
+        };
+        let Some(op) = build_op(name, &mut fields)? else {
+            return Err(self.error_at(name_pos, ParseErrorKind::UnknownInstruction(name.into())));
+        };
+        fields.finish()?;
+        Ok(op)
+    }
+}
+
+impl Fields<'_> {
+    /// Consumes the field `name` and parses its value as `T`.
+    ///
+    /// # Errors
+    ///
+    /// - If there is no field `name`.
+    /// - If the value of the field is invalid for `T`.
+    fn take<T: TextValue>(&mut self, name: &'static str) -> Result<T, ParseError> {
+        let Some(index) = self.fields.iter().position(|field| field.name == name) else {
+            return Err(ParseError::new(
+                self.line,
+                self.end_column,
+                ParseErrorKind::MissingField(name),
+            ));
+        };
+        let field = self.fields.remove(index);
+        T::parse_text(field.value).ok_or_else(|| {
+            ParseError::new(
+                self.line,
+                field.value_column,
+                ParseErrorKind::InvalidValue(name),
+            )
+        })
+    }
+
+    /// Returns an error if there are fields that have not been consumed.
+    fn finish(self) -> Result<(), ParseError> {
+        match self.fields.first() {
+            Some(field) => Err(ParseError::new(
+                self.line,
+                field.name_column,
+                ParseErrorKind::UnknownField(field.name.into()),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+macro_rules! define_build_op {
+    (
+        $(
+            $( #[doc = $doc:literal] )*
+            #[snake_name($snake_name:ident)]
+            $name:ident
+            $(
+                {
+                    $( @ $result_name:ident: $result_ty:ty, )?
+                    $(
+                        $( #[$field_docs:meta] )*
+                        $field_name:ident: $field_ty:ty
+                    ),*
+                    $(,)?
+                }
+            )?
+        ),* $(,)?
+    ) => {
+        /// Builds the [`Instruction`] called `name` from its `fields`.
+        ///
+        /// Returns `None` if there is no [`Instruction`] called `name`.
+        fn build_op(name: &str, fields: &mut Fields) -> Result<Option<Instruction>, ParseError> {
+            let op = match name {
+                $(
+                    stringify!($name) => Instruction::$name {
+                        $(
+                            $( $result_name: fields.take(stringify!($result_name))?, )?
+                            $( $field_name: fields.take(stringify!($field_name))?, )*
+                        )?
+                    },
+                )*
+                _ => return Ok(None),
+            };
+            Ok(Some(op))
+        }
+    };
+}
+for_each_op!(define_build_op);
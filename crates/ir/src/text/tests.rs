@@ -0,0 +1,310 @@
+use super::*;
+use crate::{core::TrapCode, for_each_op, index::*, *};
+use ::core::num::{NonZeroI32, NonZeroI64, NonZeroU32, NonZeroU64};
+use alloc::{string::ToString, vec::Vec};
+
+/// A simple deterministic pseudo random number generator.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        // xorshift64
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// Types that can be randomly generated.
+trait Arbitrary {
+    fn arbitrary(rng: &mut Rng) -> Self;
+}
+
+macro_rules! impl_arbitrary_for_int {
+    ( $($ty:ty),* $(,)? ) => {
+        $(
+            impl Arbitrary for $ty {
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    rng.next_u64() as $ty
+                }
+            }
+        )*
+    };
+}
+impl_arbitrary_for_int!(u8, i8, i16, u16, u32, i32);
+
+macro_rules! impl_arbitrary_via {
+    ( $( $ty:ty => $from:ty ),* $(,)? ) => {
+        $(
+            impl Arbitrary for $ty {
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    <$ty>::from(<$from>::arbitrary(rng))
+                }
+            }
+        )*
+    };
+}
+impl_arbitrary_via!(
+    Reg => i16,
+    Func => u32,
+    FuncType => u32,
+    InternalFunc => u32,
+    Global => u32,
+    Memory => u32,
+    Table => u32,
+    Data => u32,
+    Elem => u32,
+    BranchOffset => i32,
+    BranchOffset16 => i16,
+    BlockFuel => u32,
+    AnyConst16 => i16,
+    AnyConst32 => u32,
+    Const16<i32> => i16,
+    Const16<u32> => u16,
+    Const16<i64> => i16,
+    Const16<u64> => u16,
+    Const32<i64> => i32,
+);
+
+macro_rules! impl_arbitrary_for_non_zero {
+    ( $( $ty:ty => $from:ty ),* $(,)? ) => {
+        $(
+            impl Arbitrary for Const16<$ty> {
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    let value = <$from>::arbitrary(rng);
+                    let value = <$ty>::new(value.into()).unwrap_or(<$ty>::MIN);
+                    Self::try_from(value).unwrap()
+                }
+            }
+        )*
+    };
+}
+impl_arbitrary_for_non_zero!(
+    NonZeroI32 => i16,
+    NonZeroU32 => u16,
+    NonZeroI64 => i16,
+    NonZeroU64 => u16,
+);
+
+impl Arbitrary for RegSpan {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        Self::new(Reg::arbitrary(rng))
+    }
+}
+
+impl<const N: usize> Arbitrary for [Reg; N] {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        ::core::array::from_fn(|_| Reg::arbitrary(rng))
+    }
+}
+
+impl Arbitrary for BoundedRegSpan {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        Self::new(RegSpan::arbitrary(rng), u16::arbitrary(rng))
+    }
+}
+
+impl<const N: u16> Arbitrary for FixedRegSpan<N> {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        let head = i16::arbitrary(rng).min(i16::MAX - N as i16);
+        Self::new(RegSpan::new(Reg::from(head))).unwrap()
+    }
+}
+
+impl Arbitrary for Const32<f64> {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        Self::from(f32::from_bits(u32::arbitrary(rng)))
+    }
+}
+
+macro_rules! impl_arbitrary_for_sign {
+    ( $($ty:ty),* $(,)? ) => {
+        $(
+            impl Arbitrary for Sign<$ty> {
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    match rng.next_u64() % 2 {
+                        0 => Self::pos(),
+                        _ => Self::neg(),
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_arbitrary_for_sign!(f32, f64);
+
+macro_rules! impl_arbitrary_for_shift_amount {
+    ( $( ($ty:ty, $bits:literal) ),* $(,)? ) => {
+        $(
+            impl Arbitrary for ShiftAmount<$ty> {
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    let value = (rng.next_u64() % ($bits - 1)) as $ty + 1;
+                    Self::new(value).unwrap()
+                }
+            }
+        )*
+    };
+}
+impl_arbitrary_for_shift_amount!((i32, 32), (i64, 64));
+
+impl Arbitrary for TrapCode {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        const TRAP_CODES: [TrapCode; 11] = [
+            TrapCode::UnreachableCodeReached,
+            TrapCode::MemoryOutOfBounds,
+            TrapCode::TableOutOfBounds,
+            TrapCode::IndirectCallToNull,
+            TrapCode::IntegerDivisionByZero,
+            TrapCode::IntegerOverflow,
+            TrapCode::BadConversionToInteger,
+            TrapCode::StackOverflow,
+            TrapCode::BadSignature,
+            TrapCode::OutOfFuel,
+            TrapCode::GrowthOperationLimited,
+        ];
+        TRAP_CODES[rng.next_u64() as usize % TRAP_CODES.len()]
+    }
+}
+
+macro_rules! define_arbitrary_ops {
+    (
+        $(
+            $( #[doc = $doc:literal] )*
+            #[snake_name($snake_name:ident)]
+            $name:ident
+            $(
+                {
+                    $( @ $result_name:ident: $result_ty:ty, )?
+                    $(
+                        $( #[$field_docs:meta] )*
+                        $field_name:ident: $field_ty:ty
+                    ),*
+                    $(,)?
+                }
+            )?
+        ),* $(,)?
+    ) => {
+        /// Returns one randomly generated [`Instruction`] for every kind of [`Instruction`].
+        fn arbitrary_ops(rng: &mut Rng) -> Vec<Instruction> {
+            alloc::vec![
+                $(
+                    Instruction::$name {
+                        $(
+                            $( $result_name: Arbitrary::arbitrary(rng), )?
+                            $( $field_name: Arbitrary::arbitrary(rng), )*
+                        )?
+                    },
+                )*
+            ]
+        }
+    };
+}
+for_each_op!(define_arbitrary_ops);
+
+#[test]
+fn round_trip_arbitrary_ops() {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    for _ in 0..100 {
+        let ops = arbitrary_ops(&mut rng);
+        let text = display_ops(&ops).to_string();
+        let parsed = parse_ops(&text).unwrap_or_else(|error| panic!("{error}"));
+        assert_eq!(ops, parsed);
+    }
+}
+
+#[test]
+fn parse_works() {
+    let text = r#"
+        ;; A comment on its own line.
+        I32Add result=r0 lhs=r1 rhs=r-1
+        Copy2 results=r0..r2 values=[r3, r4] ;; a trailing comment
+        ReturnSpan values=r2..r5
+        Trap trap_code=IntegerOverflow
+        F32CopysignImm result=r1 lhs=r2 rhs=neg
+        ReturnF64Imm32 value=nan:0x7FC00001
+        I64AddImm16 rhs=-0x10 result=r3 lhs=r4
+        Return
+    "#;
+    let expected = [
+        Instruction::i32_add(Reg::from(0), Reg::from(1), Reg::from(-1)),
+        Instruction::copy2_ext(RegSpan::new(Reg::from(0)), Reg::from(3), Reg::from(4)),
+        Instruction::return_span(BoundedRegSpan::new(RegSpan::new(Reg::from(2)), 3)),
+        Instruction::trap(TrapCode::IntegerOverflow),
+        Instruction::f32_copysign_imm(Reg::from(1), Reg::from(2), Sign::neg()),
+        Instruction::return_f64imm32(Const32::from(f32::from_bits(0x7FC0_0001))),
+        Instruction::i64_add_imm16(Reg::from(3), Reg::from(4), -16_i16),
+        Instruction::Return,
+    ];
+    assert_eq!(parse_ops(text).unwrap(), expected);
+}
+
+#[test]
+fn display_works() {
+    let ops = [
+        Instruction::i32_add(Reg::from(0), Reg::from(1), Reg::from(-1)),
+        Instruction::copy2_ext(RegSpan::new(Reg::from(0)), Reg::from(3), Reg::from(4)),
+        Instruction::return_f64imm32(Const32::from(1.5_f32)),
+        Instruction::Return,
+    ];
+    assert_eq!(
+        display_ops(&ops).to_string(),
+        "I32Add result=r0 lhs=r1 rhs=r-1\n\
+         Copy2 results=r0..r2 values=[r3, r4]\n\
+         ReturnF64Imm32 value=1.5\n\
+         Return\n",
+    );
+}
+
+#[test]
+fn parse_errors() {
+    fn assert_error(text: &str, line: usize, column: usize, kind: ParseErrorKind) {
+        let error = parse_ops(text).unwrap_err();
+        assert_eq!(error.kind(), &kind, "{error}");
+        assert_eq!((error.line(), error.column()), (line, column), "{error}");
+    }
+    assert_error("\n  = ", 2, 3, ParseErrorKind::ExpectedInstruction);
+    assert_error(
+        "Return\nFoo x=r0",
+        2,
+        1,
+        ParseErrorKind::UnknownInstruction("Foo".into()),
+    );
+    assert_error("ReturnReg value", 1, 11, ParseErrorKind::ExpectedField);
+    assert_error("ReturnReg value=", 1, 17, ParseErrorKind::ExpectedField);
+    assert_error(
+        "ReturnReg value=r0 x=r1",
+        1,
+        20,
+        ParseErrorKind::UnknownField("x".into()),
+    );
+    assert_error(
+        "ReturnReg value=r0 value=r1",
+        1,
+        20,
+        ParseErrorKind::DuplicateField("value".into()),
+    );
+    assert_error("ReturnReg", 1, 10, ParseErrorKind::MissingField("value"));
+    assert_error(
+        "ReturnReg value=0",
+        1,
+        17,
+        ParseErrorKind::InvalidValue("value"),
+    );
+    assert_error(
+        "Copy2 results=r0..r3 values=[r1, r2]",
+        1,
+        15,
+        ParseErrorKind::InvalidValue("results"),
+    );
+    assert_error(
+        "I32AddImm16 result=r0 lhs=r1 rhs=40000",
+        1,
+        34,
+        ParseErrorKind::InvalidValue("rhs"),
+    );
+    assert_eq!(
+        parse_ops("ReturnReg value=0").unwrap_err().to_string(),
+        "1:17: invalid value for field: value",
+    );
+}
@@ -0,0 +1,379 @@
+use crate::{core::TrapCode, index::*, *};
+use ::core::{
+    fmt,
+    num::{NonZeroI32, NonZeroI64, NonZeroU32, NonZeroU64},
+};
+
+/// Field values of an [`Instruction`] in the Wasmi IR text format.
+pub trait TextValue: Sized {
+    /// Writes `self` in its textual form to `f`.
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result;
+
+    /// Parses `text` into a value of `Self`.
+    ///
+    /// Returns `None` if `text` does not represent a valid value.
+    fn parse_text(text: &str) -> Option<Self>;
+}
+
+/// Parses `text` as decimal or `0x` prefixed hexadecimal integer.
+fn parse_int(text: &str) -> Option<i128> {
+    let (is_negative, digits) = match text.as_bytes().first()? {
+        b'-' => (true, &text[1..]),
+        b'+' => (false, &text[1..]),
+        _ => (false, text),
+    };
+    let magnitude = match digits.strip_prefix("0x") {
+        Some(hex) => {
+            if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            i128::from_str_radix(hex, 16).ok()?
+        }
+        None => {
+            if !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            digits.parse::<i128>().ok()?
+        }
+    };
+    match is_negative {
+        true => Some(-magnitude),
+        false => Some(magnitude),
+    }
+}
+
+macro_rules! impl_text_value_for_int {
+    ( $($ty:ty),* $(,)? ) => {
+        $(
+            impl TextValue for $ty {
+                fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "{self}")
+                }
+
+                fn parse_text(text: &str) -> Option<Self> {
+                    parse_int(text)?.try_into().ok()
+                }
+            }
+        )*
+    };
+}
+impl_text_value_for_int!(u8, i8, i16, u16, u32, i32, u64, i64);
+
+macro_rules! impl_text_value_for_non_zero {
+    ( $($ty:ty),* $(,)? ) => {
+        $(
+            impl TextValue for $ty {
+                fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "{self}")
+                }
+
+                fn parse_text(text: &str) -> Option<Self> {
+                    Self::new(parse_int(text)?.try_into().ok()?)
+                }
+            }
+        )*
+    };
+}
+impl_text_value_for_non_zero!(NonZeroI32, NonZeroU32, NonZeroI64, NonZeroU64);
+
+macro_rules! impl_text_value_for_index {
+    ( $($ty:ty),* $(,)? ) => {
+        $(
+            impl TextValue for $ty {
+                fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    u32::from(*self).fmt_text(f)
+                }
+
+                fn parse_text(text: &str) -> Option<Self> {
+                    u32::parse_text(text).map(Self::from)
+                }
+            }
+        )*
+    };
+}
+impl_text_value_for_index!(
+    Func,
+    FuncType,
+    InternalFunc,
+    Global,
+    Memory,
+    Table,
+    Data,
+    Elem
+);
+
+impl TextValue for Reg {
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "r{}", i16::from(*self))
+    }
+
+    fn parse_text(text: &str) -> Option<Self> {
+        text.strip_prefix('r')
+            .and_then(i16::parse_text)
+            .map(Self::from)
+    }
+}
+
+impl<const N: usize> TextValue for [Reg; N] {
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        for (n, reg) in self.iter().enumerate() {
+            if n != 0 {
+                write!(f, ", ")?;
+            }
+            reg.fmt_text(f)?;
+        }
+        write!(f, "]")
+    }
+
+    fn parse_text(text: &str) -> Option<Self> {
+        let items = text.strip_prefix('[')?.strip_suffix(']')?;
+        let mut regs = [Reg::from(0); N];
+        let mut items = items.split(',').map(str::trim);
+        for reg in &mut regs {
+            *reg = Reg::parse_text(items.next()?)?;
+        }
+        if items.next().is_some() {
+            return None;
+        }
+        Some(regs)
+    }
+}
+
+impl TextValue for RegSpan {
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.head().fmt_text(f)?;
+        write!(f, "..")
+    }
+
+    fn parse_text(text: &str) -> Option<Self> {
+        text.strip_suffix("..")
+            .and_then(Reg::parse_text)
+            .map(Self::new)
+    }
+}
+
+/// Writes the register range starting at `head` with `len` registers to `f`.
+fn fmt_reg_range(head: Reg, len: u16, f: &mut fmt::Formatter) -> fmt::Result {
+    let end = i32::from(i16::from(head)) + i32::from(len);
+    head.fmt_text(f)?;
+    write!(f, "..r{end}")
+}
+
+/// Parses `text` as exclusive register range and returns its head and length.
+fn parse_reg_range(text: &str) -> Option<(Reg, u16)> {
+    let (head, end) = text.split_once("..")?;
+    let head = Reg::parse_text(head)?;
+    let end = i32::parse_text(end.strip_prefix('r')?)?;
+    let len = end.checked_sub(i32::from(i16::from(head)))?;
+    Some((head, u16::try_from(len).ok()?))
+}
+
+impl TextValue for BoundedRegSpan {
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_reg_range(self.span().head(), self.len(), f)
+    }
+
+    fn parse_text(text: &str) -> Option<Self> {
+        let (head, len) = parse_reg_range(text)?;
+        Some(Self::new(RegSpan::new(head), len))
+    }
+}
+
+impl<const N: u16> TextValue for FixedRegSpan<N> {
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_reg_range(self.span().head(), N, f)
+    }
+
+    fn parse_text(text: &str) -> Option<Self> {
+        let (head, len) = parse_reg_range(text)?;
+        if len != N {
+            return None;
+        }
+        Self::new(RegSpan::new(head)).ok()
+    }
+}
+
+impl TextValue for BranchOffset {
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_i32().fmt_text(f)
+    }
+
+    fn parse_text(text: &str) -> Option<Self> {
+        i32::parse_text(text).map(Self::from)
+    }
+}
+
+impl TextValue for BranchOffset16 {
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_i16().fmt_text(f)
+    }
+
+    fn parse_text(text: &str) -> Option<Self> {
+        i16::parse_text(text).map(Self::from)
+    }
+}
+
+impl TextValue for BlockFuel {
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_u64().fmt_text(f)
+    }
+
+    fn parse_text(text: &str) -> Option<Self> {
+        u32::parse_text(text).map(Self::from)
+    }
+}
+
+impl TextValue for AnyConst16 {
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        i16::from(*self).fmt_text(f)
+    }
+
+    fn parse_text(text: &str) -> Option<Self> {
+        let value = parse_int(text)?;
+        if let Ok(value) = i16::try_from(value) {
+            return Some(Self::from(value));
+        }
+        u16::try_from(value).ok().map(Self::from)
+    }
+}
+
+impl TextValue for AnyConst32 {
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        i32::from(*self).fmt_text(f)
+    }
+
+    fn parse_text(text: &str) -> Option<Self> {
+        let value = parse_int(text)?;
+        if let Ok(value) = i32::try_from(value) {
+            return Some(Self::from(value));
+        }
+        u32::try_from(value).ok().map(Self::from)
+    }
+}
+
+macro_rules! impl_text_value_for_const16 {
+    ( $($ty:ty),* $(,)? ) => {
+        $(
+            impl TextValue for Const16<$ty> {
+                fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    <$ty>::from(*self).fmt_text(f)
+                }
+
+                fn parse_text(text: &str) -> Option<Self> {
+                    Self::try_from(<$ty>::parse_text(text)?).ok()
+                }
+            }
+        )*
+    };
+}
+impl_text_value_for_const16!(i32, u32, i64, u64, NonZeroI32, NonZeroU32, NonZeroI64, NonZeroU64,);
+
+impl TextValue for Const32<i64> {
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        i64::from(*self).fmt_text(f)
+    }
+
+    fn parse_text(text: &str) -> Option<Self> {
+        Self::try_from(i64::parse_text(text)?).ok()
+    }
+}
+
+impl TextValue for Const32<f64> {
+    fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = f32::from(AnyConst32::from(*self));
+        if value.is_nan() {
+            return write!(f, "nan:0x{:08X}", value.to_bits());
+        }
+        write!(f, "{value}")
+    }
+
+    fn parse_text(text: &str) -> Option<Self> {
+        if let Some(bits) = text.strip_prefix("nan:") {
+            let value = f32::from_bits(u32::parse_text(bits)?);
+            if !value.is_nan() {
+                return None;
+            }
+            return Some(Self::from(value));
+        }
+        text.parse::<f32>().ok().map(Self::from)
+    }
+}
+
+macro_rules! impl_text_value_for_sign {
+    ( $($ty:ty),* $(,)? ) => {
+        $(
+            impl TextValue for Sign<$ty> {
+                fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    match <$ty>::from(*self).is_sign_positive() {
+                        true => write!(f, "pos"),
+                        false => write!(f, "neg"),
+                    }
+                }
+
+                fn parse_text(text: &str) -> Option<Self> {
+                    match text {
+                        "pos" => Some(Self::pos()),
+                        "neg" => Some(Self::neg()),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_text_value_for_sign!(f32, f64);
+
+macro_rules! impl_text_value_for_shift_amount {
+    ( $( ($ty:ty, $bits:literal) ),* $(,)? ) => {
+        $(
+            impl TextValue for ShiftAmount<$ty> {
+                fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    <$ty>::from(*self).fmt_text(f)
+                }
+
+                fn parse_text(text: &str) -> Option<Self> {
+                    let value = <$ty>::parse_text(text)?;
+                    if !(1..$bits).contains(&value) {
+                        return None;
+                    }
+                    Self::new(value)
+                }
+            }
+        )*
+    };
+}
+impl_text_value_for_shift_amount!((i32, 32), (i64, 64));
+
+macro_rules! impl_text_value_for_trap_code {
+    ( $($name:ident),* $(,)? ) => {
+        impl TextValue for TrapCode {
+            fn fmt_text(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let name = match self {
+                    $( Self::$name => stringify!($name), )*
+                };
+                write!(f, "{name}")
+            }
+
+            fn parse_text(text: &str) -> Option<Self> {
+                match text {
+                    $( stringify!($name) => Some(Self::$name), )*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+impl_text_value_for_trap_code!(
+    UnreachableCodeReached,
+    MemoryOutOfBounds,
+    TableOutOfBounds,
+    IndirectCallToNull,
+    IntegerDivisionByZero,
+    IntegerOverflow,
+    BadConversionToInteger,
+    StackOverflow,
+    BadSignature,
+    OutOfFuel,
+    GrowthOperationLimited,
+);
@@ -1,10 +1,114 @@
 use crate::{core::TrapCode, index::*, *};
+use smallvec::SmallVec;
 
 impl Instruction {
     /// Visit [`Reg`]s of `self` via the `visitor`.
     pub fn visit_regs<V: VisitRegs>(&mut self, visitor: &mut V) {
         HostVisitor::host_visitor(self, visitor)
     }
+
+    /// Returns all [`Reg`]s read by `self`.
+    ///
+    /// Returns `None` if `self` reads a span of [`Reg`]s whose length is not encoded within `self`.
+    /// In this case an analysis must conservatively assume that all [`Reg`]s starting
+    /// at the head of the span are read.
+    ///
+    /// # Note
+    ///
+    /// Some [`Instruction`]s are encoded as a sequence of multiple instruction words,
+    /// e.g. [`Instruction::BranchTableMany`] followed by [`Instruction::RegisterList`].
+    /// Those trailing instruction words report the [`Reg`]s they encode on their own
+    /// and thus an analysis must always consume the full instruction word sequence.
+    pub fn uses_regs(&self) -> Option<SmallVec<[Reg; 4]>> {
+        self.collect_regs().uses
+    }
+
+    /// Returns all [`Reg`]s written to by `self`.
+    ///
+    /// Returns `None` if `self` writes to a span of [`Reg`]s whose length is not encoded within `self`.
+    /// For example, the length of the result span of [`Instruction::CallInternal`] depends
+    /// on the type of the called function.
+    ///
+    /// For more information see [`Instruction::uses_regs`].
+    pub fn defs_regs(&self) -> Option<SmallVec<[Reg; 2]>> {
+        self.collect_regs().defs
+    }
+
+    /// Returns the [`Reg`]s read and written to by `self`.
+    fn collect_regs(&self) -> CollectRegs {
+        let mut collector = CollectRegs::default();
+        match *self {
+            Self::CopySpan {
+                mut results,
+                mut values,
+                len,
+            }
+            | Self::CopySpanNonOverlapping {
+                mut results,
+                mut values,
+                len,
+            } => {
+                // Note: the length of both spans is encoded in a separate field.
+                collector.visit_input_regs(&mut values, Some(len));
+                collector.visit_result_regs(&mut results, Some(len));
+            }
+            Self::BranchTableTarget { mut results, .. }
+            | Self::BranchTableTargetNonOverlapping { mut results, .. } => {
+                // Note: branch table targets write to their `results` before branching.
+                //       The length of `results` is encoded in the preceding branch table.
+                collector.visit_result_regs(&mut results, None);
+            }
+            mut instr => instr.visit_regs(&mut collector),
+        }
+        collector
+    }
+}
+
+/// A [`VisitRegs`] visitor collecting the [`Reg`]s of an [`Instruction`].
+///
+/// The collected [`Reg`]s are `None` if a span of [`Reg`]s of unknown length was visited.
+struct CollectRegs {
+    /// The [`Reg`]s read by the [`Instruction`].
+    uses: Option<SmallVec<[Reg; 4]>>,
+    /// The [`Reg`]s written to by the [`Instruction`].
+    defs: Option<SmallVec<[Reg; 2]>>,
+}
+
+impl Default for CollectRegs {
+    fn default() -> Self {
+        Self {
+            uses: Some(SmallVec::new()),
+            defs: Some(SmallVec::new()),
+        }
+    }
+}
+
+impl VisitRegs for CollectRegs {
+    fn visit_result_reg(&mut self, reg: &mut Reg) {
+        if let Some(defs) = &mut self.defs {
+            defs.push(*reg);
+        }
+    }
+
+    fn visit_result_regs(&mut self, regs: &mut RegSpan, len: Option<u16>) {
+        match (&mut self.defs, len) {
+            (Some(defs), Some(len)) => defs.extend(regs.iter(len)),
+            (defs, _) => *defs = None,
+        }
+    }
+
+    fn visit_input_reg(&mut self, reg: &mut Reg) {
+        if let Some(uses) = &mut self.uses {
+            uses.push(*reg);
+        }
+    }
+
+    fn visit_input_regs(&mut self, regs: &mut RegSpan, len: Option<u16>) {
+        match (&mut self.uses, len) {
+            (Some(uses), Some(len)) => uses.extend(regs.iter(len)),
+            (uses, _) => *uses = None,
+        }
+    }
 }
 
 /// Implemented by [`Reg`] visitors to visit [`Reg`]s of an [`Instruction`] via [`Instruction::visit_regs`].
@@ -6,6 +6,8 @@ pub mod snapshots;
 
 pub use wasi_common::sync::*;
 
+#[doc(inline)]
+pub use self::snapshots::preview_0::add_wasi_snapshot_preview0_to_linker as define_wasi_with_snapshot_preview0;
 #[doc(inline)]
 pub use self::snapshots::preview_1::{
     add_wasi_snapshot_preview1_to_linker as add_to_linker,
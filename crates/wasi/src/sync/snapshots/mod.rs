@@ -1 +1,2 @@
+pub mod preview_0;
 pub mod preview_1;
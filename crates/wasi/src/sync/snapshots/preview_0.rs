@@ -0,0 +1,140 @@
+use super::preview_1::{
+    add_funcs_to_linker,
+    add_wasi_snapshot_preview1_to_linker,
+    run_in_dummy_executor,
+};
+use crate::WasmiGuestMemory;
+use wasi_common::{
+    snapshots::{
+        preview_0::wasi_unstable::{self, WasiUnstable},
+        preview_1::wasi_snapshot_preview1::WasiSnapshotPreview1,
+    },
+    Error,
+};
+use wasmi::{Caller, Extern, Linker};
+
+/// Adds the entire WASI API to the Wasmi [`Linker`] under both the `wasi_unstable`
+/// and `wasi_snapshot_preview1` module names.
+///
+/// This allows to run older Wasm binaries that import WASI functions via the
+/// `wasi_unstable` module name, as produced by toolchains from before 2019.
+///
+/// # Note
+///
+/// The `wasi_unstable` definitions implement the `preview_0` ABI which differs from
+/// the `preview_1` ABI, e.g. in the encoding of `whence` and the `filestat` layout.
+/// For more information view [`add_wasi_snapshot_preview1_to_linker`].
+pub fn add_wasi_snapshot_preview0_to_linker<T, U>(
+    linker: &mut Linker<T>,
+    wasi_ctx: impl Fn(&mut T) -> &mut U + Send + Sync + Copy + 'static,
+) -> Result<(), Error>
+where
+    U: WasiSnapshotPreview1 + WasiUnstable,
+{
+    add_wasi_snapshot_preview1_to_linker(linker, wasi_ctx)?;
+    add_wasi_unstable_to_linker(linker, wasi_ctx)
+}
+
+/// Adds all `wasi preview_0` functions to the [`Linker`] under the `wasi_unstable` module name.
+fn add_wasi_unstable_to_linker<T, U>(
+    linker: &mut Linker<T>,
+    wasi_ctx: impl Fn(&mut T) -> &mut U + Send + Sync + Copy + 'static,
+) -> Result<(), Error>
+where
+    U: WasiUnstable,
+{
+    // The `preview_0` functions have the same signatures as their `preview_1` counterparts.
+    // Only `sock_accept` does not exist in `preview_0`.
+    add_funcs_to_linker! {
+        linker,
+        wasi_ctx,
+        "wasi_unstable",
+        wasi_unstable,
+        fn args_get(argv: i32, argv_buf: i32) -> i32;
+        fn args_sizes_get(offset0: i32, offset1: i32) -> i32;
+        fn environ_get(environ: i32, environ_buf: i32) -> i32;
+        fn environ_sizes_get(offset0: i32, offset1: i32) -> i32;
+        fn clock_res_get(id: i32, offset0: i32) -> i32;
+        fn clock_time_get(id: i32, precision: i64, offset0: i32) -> i32;
+        fn fd_advise(fd: i32, offset: i64, len: i64, advice: i32) -> i32;
+        fn fd_allocate(fd: i32, offset: i64, len: i64) -> i32;
+        fn fd_close(fd: i32) -> i32;
+        fn fd_datasync(fd: i32) -> i32;
+        fn fd_fdstat_get(fd: i32, offset0: i32) -> i32;
+        fn fd_fdstat_set_flags(fd: i32, flags: i32) -> i32;
+        fn fd_fdstat_set_rights(fd: i32, fs_rights_base: i64, fs_rights_inheriting: i64) -> i32;
+        fn fd_filestat_get(fd: i32, offset0: i32) -> i32;
+        fn fd_filestat_set_size(fd: i32, size: i64) -> i32;
+        fn fd_filestat_set_times(fd: i32, atim: i64, mtim: i64, fst_flags: i32) -> i32;
+        fn fd_pread(fd: i32, iov_buf: i32, iov_buf_len: i32, offset: i64, offset0: i32) -> i32;
+        fn fd_prestat_get(fd: i32, offset0: i32) -> i32;
+        fn fd_prestat_dir_name(fd: i32, path: i32, path_len: i32) -> i32;
+        fn fd_pwrite(fd: i32, ciov_buf: i32, ciov_buf_len: i32, offset: i64, offset0: i32) -> i32;
+        fn fd_read(fd: i32, iov_buf: i32, iov_buf_len: i32, offset1: i32) -> i32;
+        fn fd_readdir(fd: i32, buf: i32, buf_len: i32, cookie: i64, offset0: i32) -> i32;
+        fn fd_renumber(fd: i32, to: i32) -> i32;
+        fn fd_seek(fd: i32, offset: i64, whence: i32, offset0: i32) -> i32;
+        fn fd_sync(fd: i32) -> i32;
+        fn fd_tell(fd: i32, offset0: i32) -> i32;
+        fn fd_write(fd: i32, ciov_buf: i32, ciov_buf_len: i32, offset0: i32) -> i32;
+        fn path_create_directory(fd: i32, offset: i32, length: i32) -> i32;
+        fn path_filestat_get(fd: i32, flags: i32, offset: i32, length: i32, offset0: i32) -> i32;
+        fn path_filestat_set_times(
+            fd: i32,
+            flags: i32,
+            offset: i32,
+            length: i32,
+            atim: i64,
+            mtim: i64,
+            fst_flags: i32,
+        ) -> i32;
+        fn path_link(
+            old_fd: i32,
+            old_flags: i32,
+            old_offset: i32,
+            old_length: i32,
+            new_fd: i32,
+            new_offset: i32,
+            new_length: i32,
+        ) -> i32;
+        fn path_open(
+            fd: i32,
+            dirflags: i32,
+            offset: i32,
+            length: i32,
+            oflags: i32,
+            fs_rights_base: i64,
+            fdflags: i64,
+            fs_rights_inheriting: i32,
+            offset0: i32,
+        ) -> i32;
+        fn path_readlink(fd: i32, offset: i32, length: i32, buf: i32, buf_len: i32, offset0: i32) -> i32;
+        fn path_remove_directory(fd: i32, offset: i32, length: i32) -> i32;
+        fn path_rename(
+            fd: i32,
+            old_offset: i32,
+            old_length: i32,
+            new_fd: i32,
+            new_offset: i32,
+            new_length: i32,
+        ) -> i32;
+        fn path_symlink(old_offset: i32, old_length: i32, fd: i32, new_offset: i32, new_length: i32) -> i32;
+        fn path_unlink_file(fd: i32, offset: i32, length: i32) -> i32;
+        fn poll_oneoff(in_: i32, out: i32, nsubscriptions: i32, offset0: i32) -> i32;
+        fn proc_exit(rval: i32) -> ();
+        fn proc_raise(sig: i32) -> i32;
+        fn sched_yield() -> i32;
+        fn random_get(buf: i32, buf_len: i32) -> i32;
+        fn sock_recv(
+            fd: i32,
+            iov_buf: i32,
+            iov_buf_len: i32,
+            ri_flags: i32,
+            offset0: i32,
+            offset1: i32,
+        ) -> i32;
+        fn sock_send(fd: i32, ciov_buf: i32, ciov_buf_len: i32, si_flags: i32, offset0: i32) -> i32;
+        fn sock_shutdown(fd: i32, how: i32) -> i32;
+    }
+    Ok(())
+}
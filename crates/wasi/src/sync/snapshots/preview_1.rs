@@ -3,7 +3,10 @@ use std::{
     pin::Pin,
     task::{Context, RawWaker, RawWakerVTable, Waker},
 };
-use wasi_common::{snapshots::preview_1::wasi_snapshot_preview1::WasiSnapshotPreview1, Error};
+use wasi_common::{
+    snapshots::preview_1::wasi_snapshot_preview1::{self, WasiSnapshotPreview1},
+    Error,
+};
 use wasmi::{state::Constructing, Caller, Extern, Linker, LinkerBuilder};
 
 // Creates a dummy `RawWaker`. We can only create Wakers from `RawWaker`s
@@ -21,7 +24,9 @@ fn dummy_raw_waker() -> RawWaker {
 
 // Creates a dummy waker which does *nothing*, as the future itself polls to ready at first poll
 // A waker is needed to do any polling at all, as it is the primary constituent of the `Context` for polling
-fn run_in_dummy_executor<F: std::future::Future>(f: F) -> Result<F::Output, wasmi::Error> {
+pub(super) fn run_in_dummy_executor<F: std::future::Future>(
+    f: F,
+) -> Result<F::Output, wasmi::Error> {
     let mut f = Pin::from(Box::new(f));
     let waker = unsafe { Waker::from_raw(dummy_raw_waker()) };
     let mut cx = Context::from_waker(&waker);
@@ -81,60 +86,83 @@ where
     <LinkerBuilder<Constructing, T> as AddWasi<T>>::add_wasi(linker, wasi_ctx)
 }
 
-// Creates the function item `add_wasi_snapshot_preview1_to_wasmi_linker` which when called adds all
-// `wasi preview_1` functions to the linker
+// Adds all given `wasi` functions of the `$snapshot` module of `wasi_common`
+// to `$linker` under the `$module` name.
+//
+// Used by both `wasi_snapshot_preview1` and the older `wasi_unstable` (`preview_0`).
 macro_rules! add_funcs_to_linker {
     (
-        $linker:ty,
+        $linker:ident,
+        $wasi_ctx:ident,
+        $module:literal,
+        $snapshot:ident,
         $(
             $( #[$docs:meta] )*
             fn $fname:ident ($( $arg:ident : $typ:ty ),* $(,)? ) -> $ret:tt
         );+ $(;)?
     ) => {
-        impl<T> AddWasi<T> for $linker {
-            fn add_wasi<U>(
-                &mut self,
-                wasi_ctx: impl Fn(&mut T) -> &mut U + Send + Sync + Copy + 'static,
-            ) -> Result<(), Error>
-            where
-                U: WasiSnapshotPreview1,
-            {
-                $(
-                    // $(#[$docs])* // TODO: find place for docs
-                    self.func_wrap(
-                        "wasi_snapshot_preview1",
-                        stringify!($fname),
-                        move |mut caller: Caller<'_, T>, $($arg : $typ,)*| -> Result<$ret, wasmi::Error> {
-                            let result = async {
-                                let memory = match caller.get_export("memory") {
-                                    Some(Extern::Memory(m)) => m,
-                                    _ => return Err(wasmi::Error::new(String::from("missing required WASI memory export"))),
-                                };
-                                let(memory, ctx) = memory.data_and_store_mut(&mut caller);
-                                let ctx = wasi_ctx(ctx);
-                                let mut memory = WasmiGuestMemory::Unshared(memory);
-                                match wasi_common::snapshots::preview_1::wasi_snapshot_preview1::$fname(ctx, &mut memory, $($arg,)*).await {
-                                    Ok(r) => Ok(<$ret>::from(r)),
-                                    Err(e) => match e.downcast::<wasi_common::I32Exit>() {
-                                        Ok(wasi_common::I32Exit(status)) => Err(wasmi::Error::i32_exit(status)),
-                                        Err(e) => Err(wasmi::Error::new(e.to_string())),
-                                    }
-                                }
-                            };
-                            run_in_dummy_executor(result)?
+        $(
+            // $(#[$docs])* // TODO: find place for docs
+            $linker.func_wrap(
+                $module,
+                stringify!($fname),
+                move |mut caller: Caller<'_, T>, $($arg : $typ,)*| -> Result<$ret, wasmi::Error> {
+                    let result = async {
+                        let memory = match caller.get_export("memory") {
+                            Some(Extern::Memory(m)) => m,
+                            _ => return Err(wasmi::Error::new(String::from("missing required WASI memory export"))),
+                        };
+                        let(memory, ctx) = memory.data_and_store_mut(&mut caller);
+                        let ctx = $wasi_ctx(ctx);
+                        let mut memory = WasmiGuestMemory::Unshared(memory);
+                        match $snapshot::$fname(ctx, &mut memory, $($arg,)*).await {
+                            Ok(r) => Ok(<$ret>::from(r)),
+                            Err(e) => match e.downcast::<wasi_common::I32Exit>() {
+                                Ok(wasi_common::I32Exit(status)) => Err(wasmi::Error::i32_exit(status)),
+                                Err(e) => Err(wasmi::Error::new(e.to_string())),
+                            }
                         }
-                    ).map_err(wiggle::anyhow::Error::from).map_err(wasi_common::Error::trap)?;
-                )*
-                Ok(())
-            }
-        }
+                    };
+                    run_in_dummy_executor(result)?
+                }
+            ).map_err(wiggle::anyhow::Error::from).map_err(wasi_common::Error::trap)?;
+        )*
     }
 }
+pub(super) use add_funcs_to_linker;
+
+// Implements `AddWasi` for the given linker types which adds all
+// `wasi preview_1` functions to the linker when called.
+macro_rules! impl_add_wasi {
+    ( $( $linker:ty ),* $(,)? ) => {
+        $(
+            impl<T> AddWasi<T> for $linker {
+                fn add_wasi<U>(
+                    &mut self,
+                    wasi_ctx: impl Fn(&mut T) -> &mut U + Send + Sync + Copy + 'static,
+                ) -> Result<(), Error>
+                where
+                    U: WasiSnapshotPreview1,
+                {
+                    let linker = self;
+                    apply_wasi_definitions! {
+                        add_funcs_to_linker,
+                        linker,
+                        wasi_ctx,
+                        "wasi_snapshot_preview1",
+                        wasi_snapshot_preview1
+                    }
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
 
 macro_rules! apply_wasi_definitions {
-    ($mac:ident, $linker:ty) => {
+    ($mac:ident, $( $args:tt )*) => {
         $mac! {
-            $linker,
+            $( $args )*,
 
             /// Read command-line argument data.
             ///
@@ -780,5 +808,4 @@ macro_rules! apply_wasi_definitions {
     };
 }
 
-apply_wasi_definitions!(add_funcs_to_linker, Linker<T>);
-apply_wasi_definitions!(add_funcs_to_linker, LinkerBuilder<Constructing, T>);
+impl_add_wasi!(Linker<T>, LinkerBuilder<Constructing, T>);
@@ -1,8 +1,15 @@
 use wasi_common::sync::WasiCtxBuilder;
 use wasmi::{Config, Engine, Extern, Instance, Linker, Module, Store};
-use wasmi_wasi::{add_to_linker, WasiCtx};
+use wasmi_wasi::{add_to_linker, define_wasi_with_snapshot_preview0, WasiCtx};
 
 pub fn load_instance_from_wat(wasm: &[u8]) -> (Store<WasiCtx>, wasmi::Instance) {
+    load_instance_from_wat_with(wasm, |linker| add_to_linker(linker, |ctx| ctx).unwrap())
+}
+
+pub fn load_instance_from_wat_with(
+    wasm: &[u8],
+    add_wasi: impl FnOnce(&mut Linker<WasiCtx>),
+) -> (Store<WasiCtx>, wasmi::Instance) {
     let config = Config::default();
     let engine = Engine::new(&config);
     let module = Module::new(&engine, wasm).unwrap();
@@ -15,7 +22,7 @@ pub fn load_instance_from_wat(wasm: &[u8]) -> (Store<WasiCtx>, wasmi::Instance)
         .build();
     let mut store = Store::new(&engine, wasi);
 
-    add_to_linker(&mut linker, |ctx| ctx).unwrap();
+    add_wasi(&mut linker);
     let instance = linker
         .instantiate(&mut store, &module)
         .unwrap()
@@ -39,3 +46,33 @@ fn test_hello_world() {
     let mut result = [];
     f.call(&mut store, &[], &mut result).unwrap();
 }
+
+#[test]
+fn test_hello_world_preview0() {
+    let bytes = include_bytes!("wat/hello_world_preview0.wat");
+    let (mut store, instance) = load_instance_from_wat_with(bytes, |linker| {
+        define_wasi_with_snapshot_preview0(linker, |ctx| ctx).unwrap()
+    });
+    let f = instance
+        .get_export(&store, "_start")
+        .and_then(Extern::into_func)
+        .unwrap();
+    let mut result = [];
+    f.call(&mut store, &[], &mut result).unwrap();
+}
+
+#[test]
+fn test_preview0_has_no_sock_accept() {
+    let wasm = r#"
+        (module
+            (import "wasi_unstable" "sock_accept" (func (param i32 i32 i32) (result i32)))
+            (memory (export "memory") 1)
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut linker = <Linker<WasiCtx>>::new(&engine);
+    define_wasi_with_snapshot_preview0(&mut linker, |ctx| ctx).unwrap();
+    let mut store = Store::new(&engine, WasiCtxBuilder::new().build());
+    assert!(linker.instantiate(&mut store, &module).is_err());
+}
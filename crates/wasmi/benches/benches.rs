@@ -32,6 +32,7 @@ use wasmi::{
     Linker,
     Memory,
     Module,
+    StackRecycler,
     Store,
     TypedFunc,
     Val,
@@ -68,6 +69,7 @@ criterion_group!(
         bench_instantiate_tiny_keccak,
         bench_instantiate_reverse_complement,
         bench_instantiate_regex_redux,
+        bench_instantiate_big_elem_segment,
         // bench_instantiate_erc20,
         // bench_instantiate_erc721,
         // bench_instantiate_erc1155,
@@ -83,6 +85,10 @@ criterion_group!(
         bench_overhead_call_typed_16,
         bench_overhead_call_untyped_0,
         bench_overhead_call_untyped_16,
+        bench_overhead_typed_16,
+        bench_overhead_call_stack_pooling,
+        bench_overhead_call_stack_recycling,
+        bench_overhead_func_signature,
 );
 criterion_group!(
     name = bench_group_linker;
@@ -126,6 +132,7 @@ criterion_group! {
         bench_execute_memory_fill,
         bench_execute_vec_add,
         bench_execute_bulk_ops,
+        bench_execute_call_indirect,
 }
 
 criterion_main!(
@@ -453,16 +460,66 @@ fn bench_instantiate_using(c: &mut Criterion, name: &str) {
     });
 }
 
+fn bench_instantiate_pre_using(c: &mut Criterion, name: &str) {
+    let id = format!("instantiate_pre/{name}");
+    c.bench_function(&id, |b| {
+        let path = format!("benches/rust/cases/{name}/out.wasm");
+        let module = load_module_from_file(&path);
+        let linker = <Linker<()>>::new(module.engine());
+        let pre = linker.instantiate_pre(&module).unwrap();
+        b.iter(|| {
+            let mut store = Store::new(module.engine(), ());
+            let _instance = pre.instantiate(&mut store).unwrap();
+        })
+    });
+}
+
 fn bench_instantiate_tiny_keccak(c: &mut Criterion) {
     bench_instantiate_using(c, "tiny_keccak");
+    bench_instantiate_pre_using(c, "tiny_keccak");
 }
 
 fn bench_instantiate_reverse_complement(c: &mut Criterion) {
     bench_instantiate_using(c, "reverse_complement");
+    bench_instantiate_pre_using(c, "reverse_complement");
 }
 
 fn bench_instantiate_regex_redux(c: &mut Criterion) {
     bench_instantiate_using(c, "regex_redux");
+    bench_instantiate_pre_using(c, "regex_redux");
+}
+
+fn bench_instantiate_big_elem_segment(c: &mut Criterion) {
+    const LEN_ITEMS: usize = 50_000;
+    let items = (0..LEN_ITEMS)
+        .map(|n| format!("$f{}", n % 4))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let wasm = wat2wasm(
+        format!(
+            "(module
+                (table {LEN_ITEMS} funcref)
+                (func $f0) (func $f1) (func $f2) (func $f3)
+                (elem (i32.const 0) func {items})
+            )"
+        )
+        .as_bytes(),
+    );
+    for lazy in [false, true] {
+        let mode = if lazy { "lazy" } else { "eager" };
+        let id = format!("instantiate/big_elem_segment/{mode}");
+        c.bench_function(&id, |b| {
+            let mut config = bench_config();
+            config.lazy_table_init(lazy);
+            let engine = Engine::new(&config);
+            let module = Module::new(&engine, &wasm[..]).unwrap();
+            let linker = <Linker<()>>::new(&engine);
+            b.iter(|| {
+                let mut store = Store::new(&engine, ());
+                let _instance = linker.instantiate(&mut store, &module).unwrap();
+            })
+        });
+    }
 }
 
 fn bench_linker_build_finish_same(c: &mut Criterion) {
@@ -922,6 +979,88 @@ fn bench_overhead_call_typed_0(c: &mut Criterion) {
     });
 }
 
+fn bench_overhead_call_stack_pooling(c: &mut Criterion) {
+    const REPETITIONS: usize = 20_000;
+    const ITERATIONS: i32 = 10;
+    let mut g = c.benchmark_group("overhead/call/pooling");
+    for pooling in [true, false] {
+        let id = if pooling { "enabled" } else { "disabled" };
+        g.bench_function(id, |b| {
+            let mut config = bench_config();
+            config.enable_stack_pooling(pooling);
+            let engine = Engine::new(&config);
+            let module = Module::new(&engine, include_bytes!("wat/counter.wat")).unwrap();
+            let mut store = Store::new(&engine, ());
+            let instance = Linker::new(&engine)
+                .instantiate(&mut store, &module)
+                .unwrap()
+                .start(&mut store)
+                .unwrap();
+            let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+            b.iter(|| {
+                for _ in 0..REPETITIONS {
+                    let result = run.call(&mut store, ITERATIONS).unwrap();
+                    assert_eq!(result, ITERATIONS);
+                }
+            })
+        });
+    }
+}
+
+fn bench_overhead_call_stack_recycling(c: &mut Criterion) {
+    const REPETITIONS: usize = 20_000;
+    const ITERATIONS: i32 = 10;
+    let mut g = c.benchmark_group("overhead/call/recycling");
+    for recycling in [true, false] {
+        let id = if recycling { "enabled" } else { "disabled" };
+        g.bench_function(id, |b| {
+            let (mut store, instance) = load_instance_from_wat(include_bytes!("wat/counter.wat"));
+            let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+            let mut recycler = StackRecycler::new(store.engine());
+            b.iter(|| {
+                for _ in 0..REPETITIONS {
+                    let result = match recycling {
+                        true => run.call_with_stack(&mut store, ITERATIONS, &mut recycler),
+                        false => run.call(&mut store, ITERATIONS),
+                    };
+                    assert_eq!(result.unwrap(), ITERATIONS);
+                }
+            })
+        });
+    }
+}
+
+fn bench_overhead_func_signature(c: &mut Criterion) {
+    const REPETITIONS: usize = 20_000;
+    let mut g = c.benchmark_group("overhead/func/signature");
+    let (store, instance) = load_instance_from_wat(include_bytes!("wat/bare_call.wat"));
+    let func = instance.get_func(&store, "bare_call/16").unwrap();
+    let ty = func.ty(&store);
+    let (params, results) = (ty.params(), ty.results());
+    g.bench_function("ty", |b| {
+        b.iter(|| {
+            for _ in 0..REPETITIONS {
+                let ty = func.ty(&store);
+                assert!(ty.params() == params && ty.results() == results);
+            }
+        })
+    });
+    g.bench_function("matches_signature", |b| {
+        b.iter(|| {
+            for _ in 0..REPETITIONS {
+                assert!(func.matches_signature(&store, params, results));
+            }
+        })
+    });
+    g.bench_function("result_len", |b| {
+        b.iter(|| {
+            for _ in 0..REPETITIONS {
+                assert_eq!(func.result_len(&store), results.len());
+            }
+        })
+    });
+}
+
 fn bench_overhead_call_typed_16(c: &mut Criterion) {
     const REPETITIONS: usize = 20_000;
     type InOut = (
@@ -977,6 +1116,37 @@ fn bench_overhead_call_typed_16(c: &mut Criterion) {
     });
 }
 
+fn bench_overhead_typed_16(c: &mut Criterion) {
+    const REPETITIONS: usize = 1_000_000;
+    type InOut = (
+        i32,
+        i64,
+        F32,
+        F64,
+        i32,
+        i64,
+        F32,
+        F64,
+        i32,
+        i64,
+        F32,
+        F64,
+        i32,
+        i64,
+        F32,
+        F64,
+    );
+    c.bench_function("overhead/typed/16", |b| {
+        let (store, instance) = load_instance_from_wat(include_bytes!("wat/bare_call.wat"));
+        let bare_call = instance.get_func(&store, "bare_call/16").unwrap();
+        b.iter(|| {
+            for _ in 0..REPETITIONS {
+                bare_call.typed::<InOut, InOut>(&store).unwrap();
+            }
+        })
+    });
+}
+
 fn bench_overhead_call_untyped_0(c: &mut Criterion) {
     const REPETITIONS: usize = 20_000;
     c.bench_function("overhead/call/untyped/0", |b| {
@@ -1466,3 +1636,14 @@ fn bench_execute_bulk_ops(c: &mut Criterion) {
         })
     });
 }
+
+fn bench_execute_call_indirect(c: &mut Criterion) {
+    const ITERATIONS: i32 = 100_000;
+    c.bench_function("execute/call_indirect/vtable", |b| {
+        let (mut store, instance) = load_instance_from_wat(include_bytes!("wat/call_indirect.wat"));
+        let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+        b.iter(|| {
+            run.call(&mut store, ITERATIONS).unwrap();
+        })
+    });
+}
@@ -0,0 +1,30 @@
+;; Vtable-style dynamic dispatch: repeatedly calls through a few stable table slots.
+(module
+  (type $method (func (param i32) (result i32)))
+  (table $vtable 4 funcref)
+  (elem (table $vtable) (i32.const 0) func $inc $dec $double $identity)
+  (func $inc (type $method) (i32.add (local.get 0) (i32.const 1)))
+  (func $dec (type $method) (i32.sub (local.get 0) (i32.const 1)))
+  (func $double (type $method) (i32.shl (local.get 0) (i32.const 1)))
+  (func $identity (type $method) (local.get 0))
+  (func (export "run") (param $n i32) (result i32)
+    (local $i i32)
+    (local $acc i32)
+    (loop $continue
+      (local.set $acc
+        (call_indirect $vtable (type $method)
+          (local.get $acc)
+          (i32.and (local.get $i) (i32.const 3))
+        )
+      )
+      (br_if
+        $continue
+        (i32.ne
+          (local.tee $i (i32.add (local.get $i) (i32.const 1)))
+          (local.get $n)
+        )
+      )
+    )
+    (local.get $acc)
+  )
+)
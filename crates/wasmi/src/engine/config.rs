@@ -6,6 +6,83 @@ use wasmparser::WasmFeatures;
 /// The default amount of stacks kept in the cache at most.
 const DEFAULT_CACHED_STACKS: usize = 2;
 
+/// A Wasm proposal that may be enabled for an [`Engine`].
+///
+/// Query if a proposal is enabled via [`Engine::is_enabled`].
+///
+/// [`Engine`]: crate::Engine
+/// [`Engine::is_enabled`]: crate::Engine::is_enabled
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WasmProposal {
+    /// The [`mutable-global`](https://github.com/WebAssembly/mutable-global) proposal.
+    MutableGlobal,
+    /// The [`sign-extension`](https://github.com/WebAssembly/sign-extension-ops) proposal.
+    SignExtension,
+    /// The [`saturating-float-to-int`](https://github.com/WebAssembly/nontrapping-float-to-int-conversions) proposal.
+    SaturatingFloatToInt,
+    /// The [`multi-value`](https://github.com/WebAssembly/multi-value) proposal.
+    MultiValue,
+    /// The [`multi-memory`](https://github.com/WebAssembly/multi-memory) proposal.
+    MultiMemory,
+    /// The [`bulk-memory`](https://github.com/WebAssembly/bulk-memory-operations) proposal.
+    BulkMemory,
+    /// The [`reference-types`](https://github.com/WebAssembly/reference-types) proposal.
+    ReferenceTypes,
+    /// The [`tail-call`](https://github.com/WebAssembly/tail-call) proposal.
+    TailCall,
+    /// The [`extended-const`](https://github.com/WebAssembly/extended-const) proposal.
+    ExtendedConst,
+    /// The [`simd`](https://github.com/WebAssembly/simd) proposal.
+    ///
+    /// Not supported by Wasmi.
+    Simd,
+    /// The [`relaxed-simd`](https://github.com/WebAssembly/relaxed-simd) proposal.
+    ///
+    /// Not supported by Wasmi.
+    RelaxedSimd,
+    /// The [`memory64`](https://github.com/WebAssembly/memory64) proposal.
+    ///
+    /// Not supported by Wasmi.
+    Memory64,
+    /// The [`threads`](https://github.com/WebAssembly/threads) proposal.
+    ///
+    /// Not supported by Wasmi.
+    Threads,
+    /// The [`exception-handling`](https://github.com/WebAssembly/exception-handling) proposal.
+    ///
+    /// Not supported by Wasmi.
+    ExceptionHandling,
+    /// The [`gc`](https://github.com/WebAssembly/gc) proposal.
+    ///
+    /// Not supported by Wasmi.
+    Gc,
+}
+
+impl WasmProposal {
+    /// Returns the [`WasmFeatures`] representing `self` if supported by Wasmi.
+    fn features(self) -> Option<WasmFeatures> {
+        let features = match self {
+            Self::MutableGlobal => WasmFeatures::MUTABLE_GLOBAL,
+            Self::SignExtension => WasmFeatures::SIGN_EXTENSION,
+            Self::SaturatingFloatToInt => WasmFeatures::SATURATING_FLOAT_TO_INT,
+            Self::MultiValue => WasmFeatures::MULTI_VALUE,
+            Self::MultiMemory => WasmFeatures::MULTI_MEMORY,
+            Self::BulkMemory => WasmFeatures::BULK_MEMORY,
+            Self::ReferenceTypes => WasmFeatures::REFERENCE_TYPES,
+            Self::TailCall => WasmFeatures::TAIL_CALL,
+            Self::ExtendedConst => WasmFeatures::EXTENDED_CONST,
+            Self::Simd
+            | Self::RelaxedSimd
+            | Self::Memory64
+            | Self::Threads
+            | Self::ExceptionHandling
+            | Self::Gc => return None,
+        };
+        Some(features)
+    }
+}
+
 /// Configuration for an [`Engine`].
 ///
 /// [`Engine`]: [`crate::Engine`]
@@ -27,6 +104,10 @@ pub struct Config {
     compilation_mode: CompilationMode,
     /// Enforced limits for Wasm module parsing and compilation.
     limits: EnforcedLimits,
+    /// Is `true` if active element segments shall lazily initialize `funcref` tables.
+    lazy_table_init: bool,
+    /// Is `true` if Wasm modules using floating point types or instructions shall be rejected.
+    deny_floating_point: bool,
 }
 
 /// Type storing all kinds of fuel costs of instructions.
@@ -135,7 +216,7 @@ impl Default for FuelCosts {
 }
 
 /// The chosen mode of Wasm to Wasmi bytecode compilation.
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub enum CompilationMode {
     /// The Wasm code is compiled eagerly to Wasmi bytecode.
     #[default]
@@ -162,6 +243,8 @@ impl Default for Config {
             fuel_costs: FuelCosts::default(),
             compilation_mode: CompilationMode::default(),
             limits: EnforcedLimits::default(),
+            lazy_table_init: false,
+            deny_floating_point: false,
         }
     }
 }
@@ -190,8 +273,24 @@ impl Config {
         self
     }
 
+    /// Sets the maximum number of nested calls that are allowed at the same time.
+    ///
+    /// Calls exceeding this limit trap with [`TrapCode::StackOverflow`]
+    /// independent of how many stack slots are used by each call frame.
+    ///
+    /// # Note
+    ///
+    /// - Defaults to 1024.
+    /// - This overwrites [`StackLimits::maximum_recursion_depth`].
+    ///
+    /// [`TrapCode::StackOverflow`]: crate::core::TrapCode::StackOverflow
+    pub fn max_call_depth(&mut self, depth: usize) -> &mut Self {
+        self.stack_limits.maximum_recursion_depth = depth;
+        self
+    }
+
     /// Returns the [`StackLimits`] of the [`Config`].
-    pub(super) fn stack_limits(&self) -> StackLimits {
+    pub fn stack_limits(&self) -> StackLimits {
         self.stack_limits
     }
 
@@ -205,8 +304,38 @@ impl Config {
         self
     }
 
+    /// Enables or disables pooling of execution stacks for the [`Config`].
+    ///
+    /// When enabled, the [`Engine`] keeps the stacks of finished top-level calls
+    /// and reuses them for subsequent calls instead of allocating new ones.
+    /// This reduces the per-call overhead for workloads that call into Wasm often.
+    ///
+    /// Enabling stack pooling restores the default amount of cached stacks if caching
+    /// was previously disabled. Use [`Config::set_cached_stacks`] for finer control.
+    ///
+    /// # Memory Retention
+    ///
+    /// Pooled stacks are retained by the [`Engine`] until it is dropped.
+    /// Since stacks grow on demand, a pooled stack keeps the capacity of its
+    /// deepest execution which may be as large as the configured [`StackLimits`]
+    /// for each cached stack.
+    ///
+    /// # Note
+    ///
+    /// Enabled by default.
+    ///
+    /// [`Engine`]: crate::Engine
+    pub fn enable_stack_pooling(&mut self, enable: bool) -> &mut Self {
+        self.cached_stacks = match enable {
+            true if self.cached_stacks == 0 => DEFAULT_CACHED_STACKS,
+            true => self.cached_stacks,
+            false => 0,
+        };
+        self
+    }
+
     /// Returns the maximum amount of cached stacks for reuse of the [`Config`].
-    pub(super) fn cached_stacks(&self) -> usize {
+    pub fn cached_stacks(&self) -> usize {
         self.cached_stacks
     }
 
@@ -274,6 +403,10 @@ impl Config {
 
     /// Enable or disable the [`bulk-memory`] Wasm proposal for the [`Config`].
     ///
+    /// When disabled, validation rejects Wasm modules using instructions such as
+    /// `memory.copy`, `memory.fill` or `table.copy` as well as passive data and
+    /// element segments.
+    ///
     /// # Note
     ///
     /// Enabled by default.
@@ -323,12 +456,48 @@ impl Config {
 
     /// Enable or disable Wasm floating point (`f32` and `f64`) instructions and types.
     ///
+    /// If disabled, Wasm modules that use floating point are rejected by Wasm validation
+    /// and, like with [`Config::deny_floating_point`], while parsing the Wasm module.
+    ///
     /// Enabled by default.
     pub fn floats(&mut self, enable: bool) -> &mut Self {
         self.features.set(WasmFeatures::FLOATS, enable);
         self
     }
 
+    /// Configures whether Wasmi rejects Wasm modules that use floating point.
+    ///
+    /// If enabled, parsing a Wasm module fails with a [`FloatingPointError`] naming the
+    /// first function type, global variable, local variable or instruction that uses
+    /// `f32` or `f64` values.
+    ///
+    /// # Note
+    ///
+    /// - This is checked while parsing the Wasm module, independent of Wasm validation
+    ///   and the [`CompilationMode`]. Unlike [`Config::floats`] it does not affect Wasm validation.
+    /// - Useful for embedders that require bit-exact determinism across platforms.
+    ///
+    /// Default value: `false`
+    ///
+    /// [`FloatingPointError`]: crate::errors::FloatingPointError
+    pub fn deny_floating_point(&mut self, deny: bool) -> &mut Self {
+        self.deny_floating_point = deny;
+        self
+    }
+
+    /// Returns `true` if the [`Config`] rejects Wasm modules that use floating point.
+    pub fn get_deny_floating_point(&self) -> bool {
+        self.deny_floating_point
+    }
+
+    /// Returns `true` if floating point types and instructions are unsupported by the [`Config`].
+    ///
+    /// This is the case if either [`Config::deny_floating_point`] is enabled
+    /// or floating point is disabled via [`Config::floats`].
+    pub(crate) fn floats_denied(&self) -> bool {
+        self.deny_floating_point || !self.features.floats()
+    }
+
     /// Configures whether Wasmi will consume fuel during execution to either halt execution as desired.
     ///
     /// # Note
@@ -354,7 +523,7 @@ impl Config {
     /// Returns `true` if the [`Config`] enables fuel consumption by the [`Engine`].
     ///
     /// [`Engine`]: crate::Engine
-    pub(crate) fn get_consume_fuel(&self) -> bool {
+    pub fn get_consume_fuel(&self) -> bool {
         self.consume_fuel
     }
 
@@ -367,12 +536,12 @@ impl Config {
     }
 
     /// Returns `true` if the [`Config`] mandates to ignore Wasm custom sections when parsing Wasm modules.
-    pub(crate) fn get_ignore_custom_sections(&self) -> bool {
+    pub fn get_ignore_custom_sections(&self) -> bool {
         self.ignore_custom_sections
     }
 
     /// Returns the configured [`FuelCosts`].
-    pub(crate) fn fuel_costs(&self) -> &FuelCosts {
+    pub fn fuel_costs(&self) -> &FuelCosts {
         &self.fuel_costs
     }
 
@@ -389,7 +558,7 @@ impl Config {
     /// Returns the [`CompilationMode`] used for the [`Engine`].
     ///
     /// [`Engine`]: crate::Engine
-    pub(super) fn get_compilation_mode(&self) -> CompilationMode {
+    pub fn get_compilation_mode(&self) -> CompilationMode {
         self.compilation_mode
     }
 
@@ -406,10 +575,45 @@ impl Config {
     /// Returns the [`EnforcedLimits`] used for the [`Engine`].
     ///
     /// [`Engine`]: crate::Engine
-    pub(crate) fn get_enforced_limits(&self) -> &EnforcedLimits {
+    pub fn get_enforced_limits(&self) -> &EnforcedLimits {
         &self.limits
     }
 
+    /// Configures whether active element segments lazily initialize `funcref` tables.
+    ///
+    /// If enabled, instantiation records the active element segments of a
+    /// Wasm module instead of eagerly writing their items into the tables.
+    /// Table elements are then evaluated from their element segment items
+    /// upon access until they are overwritten.
+    ///
+    /// This speeds up instantiation of Wasm modules with huge element
+    /// segments, e.g. vtables, with indistinguishable semantics.
+    ///
+    /// # Note
+    ///
+    /// - Only element segments with `ref.func` or `ref.null` items are applied lazily.
+    /// - Out of bounds active element segments still fail instantiation.
+    ///
+    /// Default value: `false`
+    pub fn lazy_table_init(&mut self, enable: bool) -> &mut Self {
+        self.lazy_table_init = enable;
+        self
+    }
+
+    /// Returns `true` if the [`Config`] enables lazy table initialization.
+    pub fn get_lazy_table_init(&self) -> bool {
+        self.lazy_table_init
+    }
+
+    /// Returns `true` if the Wasm `proposal` is enabled by the [`Config`].
+    ///
+    /// Wasm proposals that are not supported by Wasmi are never enabled.
+    pub fn is_enabled(&self, proposal: WasmProposal) -> bool {
+        proposal
+            .features()
+            .is_some_and(|features| self.features.contains(features))
+    }
+
     /// Returns the [`WasmFeatures`] represented by the [`Config`].
     pub(crate) fn wasm_features(&self) -> WasmFeatures {
         self.features
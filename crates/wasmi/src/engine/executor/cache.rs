@@ -5,7 +5,7 @@ use crate::{
     ir::index,
     memory::DataSegment,
     module::DEFAULT_MEMORY_INDEX,
-    store::StoreInner,
+    store::{FuelMultiplier, StoreInner},
     table::ElementSegment,
     Func,
     Global,
@@ -24,17 +24,20 @@ pub struct CachedInstance {
     pub memory: CachedMemory,
     /// The cached value of the global variable at index 0.
     pub global: CachedGlobal,
+    /// The cached [`FuelMultiplier`] of the currently used instance.
+    pub fuel_multiplier: FuelMultiplier,
 }
 
 impl CachedInstance {
     /// Creates a new [`CachedInstance`].
     #[inline]
     pub fn new(ctx: &mut StoreInner, instance: &Instance) -> Self {
-        let (instance, memory, global) = Self::load_caches(ctx, instance);
+        let (instance, memory, global, fuel_multiplier) = Self::load_caches(ctx, instance);
         Self {
             instance,
             memory,
             global,
+            fuel_multiplier,
         }
     }
 
@@ -44,15 +47,21 @@ impl CachedInstance {
         ctx.resolve_instance(instance)
     }
 
-    /// Loads the cached global and linear memory.
+    /// Loads the cached global, linear memory and fuel multiplier.
     #[inline]
     fn load_caches(
         ctx: &mut StoreInner,
         instance: &Instance,
-    ) -> (NonNull<InstanceEntity>, CachedMemory, CachedGlobal) {
+    ) -> (
+        NonNull<InstanceEntity>,
+        CachedMemory,
+        CachedGlobal,
+        FuelMultiplier,
+    ) {
         let entity = Self::load_instance(ctx, instance);
         let memory = entity.get_memory(DEFAULT_MEMORY_INDEX);
         let global = entity.get_global(0);
+        let fuel_multiplier = entity.fuel_multiplier();
         let instance = entity.into();
         let memory = memory
             .map(|memory| CachedMemory::new(ctx, &memory))
@@ -60,13 +69,18 @@ impl CachedInstance {
         let global = global
             .map(|global| CachedGlobal::new(ctx, &global))
             .unwrap_or_default();
-        (instance, memory, global)
+        (instance, memory, global, fuel_multiplier)
     }
 
-    /// Update the cached instance, linear memory and global variable.
+    /// Update the cached instance, linear memory, global variable and fuel multiplier.
     #[inline]
     pub fn update(&mut self, ctx: &mut StoreInner, instance: &Instance) {
-        (self.instance, self.memory, self.global) = Self::load_caches(ctx, instance);
+        (
+            self.instance,
+            self.memory,
+            self.global,
+            self.fuel_multiplier,
+        ) = Self::load_caches(ctx, instance);
     }
 
     /// Returns a shared reference to the cached [`InstanceEntity`].
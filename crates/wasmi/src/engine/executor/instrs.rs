@@ -21,6 +21,7 @@ use crate::{
     Store,
     Table,
 };
+use core::fmt;
 
 #[cfg(doc)]
 use crate::Instance;
@@ -51,6 +52,45 @@ macro_rules! forward_return {
 /// Tells if execution loop shall continue or break (return) to the execution's caller.
 type ControlFlow = ::core::ops::ControlFlow<(), ()>;
 
+/// Error returned when running out of fuel in a resumable state.
+///
+/// # Note
+///
+/// This is only returned for [`Instruction::ConsumeFuel`] since its execution
+/// can be repeated without side effects once enough fuel has been added.
+#[derive(Debug)]
+pub struct ResumableOutOfFuelError {
+    /// The minimum amount of fuel that must be added to resume execution.
+    required_fuel: u64,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ResumableOutOfFuelError {}
+
+impl fmt::Display for ResumableOutOfFuelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&TrapCode::OutOfFuel, f)
+    }
+}
+
+impl ResumableOutOfFuelError {
+    /// Creates a new [`ResumableOutOfFuelError`].
+    #[cold]
+    fn new(required_fuel: u64) -> Self {
+        Self { required_fuel }
+    }
+
+    /// Returns the minimum amount of fuel that must be added to resume execution.
+    pub(crate) fn required_fuel(&self) -> u64 {
+        self.required_fuel
+    }
+
+    /// Consumes `self` to return the non-resumable [`TrapCode::OutOfFuel`] [`Error`].
+    pub(crate) fn into_error(self) -> Error {
+        Error::from(TrapCode::OutOfFuel)
+    }
+}
+
 /// Executes compiled function instructions until execution returns from the root function.
 ///
 /// # Errors
@@ -115,6 +155,8 @@ impl<'engine> Executor<'engine> {
     fn execute<T>(mut self, store: &mut Store<T>) -> Result<(), Error> {
         use Instruction as Instr;
         loop {
+            #[cfg(feature = "profiling")]
+            self.stack.profile.record(self.ip.get());
             match *self.ip.get() {
                 Instr::Trap { trap_code } => self.execute_trap(trap_code)?,
                 Instr::ConsumeFuel { block_fuel } => {
@@ -1117,6 +1159,9 @@ impl<'engine> Executor<'engine> {
                 Instr::TableGetImm { result, index } => {
                     self.execute_table_get_imm(&store.inner, result, index)?
                 }
+                Instr::TableGetImmInBounds { result, index } => {
+                    self.execute_table_get_imm_in_bounds(&store.inner, result, index)
+                }
                 Instr::TableSize { result, table } => {
                     self.execute_table_size(&store.inner, result, table)
                 }
@@ -1126,6 +1171,9 @@ impl<'engine> Executor<'engine> {
                 Instr::TableSetAt { index, value } => {
                     self.execute_table_set_at(&mut store.inner, index, value)?
                 }
+                Instr::TableSetAtInBounds { index, value } => {
+                    self.execute_table_set_at_in_bounds(&mut store.inner, index, value)
+                }
                 Instr::TableCopy { dst, src, len } => {
                     self.execute_table_copy(&mut store.inner, dst, src, len)?
                 }
@@ -1678,12 +1726,31 @@ impl Executor<'_> {
         // We do not have to check if fuel metering is enabled since
         // [`Instruction::ConsumeFuel`] are only generated if fuel metering
         // is enabled to begin with.
-        store
-            .fuel_mut()
-            .consume_fuel_unchecked(block_fuel.to_u64())?;
+        let fuel = self.cache.fuel_multiplier.apply(block_fuel.to_u64());
+        if store.fuel_mut().consume_fuel_unchecked(fuel).is_err() {
+            return Err(self.resumable_out_of_fuel(store, fuel));
+        }
         self.try_next_instr()
     }
 
+    /// Returns a [`ResumableOutOfFuelError`] for an [`Instruction::ConsumeFuel`] requiring `fuel`.
+    ///
+    /// # Note
+    ///
+    /// This stores the [`InstructionPtr`] pointing to the [`Instruction::ConsumeFuel`]
+    /// into the current [`CallFrame`] so that execution repeats it upon resumption.
+    #[cold]
+    #[inline(never)]
+    fn resumable_out_of_fuel(&mut self, store: &mut StoreInner, fuel: u64) -> Error {
+        let remaining = store.fuel_mut().get_fuel().unwrap_or(0);
+        self.stack
+            .calls
+            .peek_mut()
+            .expect("must have a call frame on the stack while executing")
+            .update_instr_ptr(self.ip);
+        Error::from(ResumableOutOfFuelError::new(fuel - remaining))
+    }
+
     /// Executes an [`Instruction::RefFunc`].
     fn execute_ref_func(&mut self, result: Reg, func_index: index::Func) {
         let func = self.get_func(func_index);
@@ -3,12 +3,16 @@ use crate::{
     core::TrapCode,
     engine::{
         code_map::CompiledFuncRef,
-        executor::stack::{CallFrame, FrameParams, ValueStack},
+        executor::{
+            stack::{CallFrame, FrameParams, ValueStack},
+            stack_overflow_in,
+        },
         utils::unreachable_unchecked,
         EngineFunc,
         FuncParams,
+        StackOverflowError,
     },
-    func::{FuncEntity, HostFuncEntity},
+    func::{FuncEntity, HostFuncEntity, IndirectCallError},
     ir::{index, Instruction, Reg, RegSpan},
     store::StoreInner,
     CallHook,
@@ -229,7 +233,7 @@ impl Executor<'_> {
         &mut self,
         results: RegSpan,
         func: CompiledFuncRef,
-    ) -> Result<CallFrame, Error> {
+    ) -> Result<CallFrame, StackOverflowError> {
         // We have to reinstantiate the `self.sp` [`FrameRegisters`] since we just called
         // [`ValueStack::alloc_call_frame`] which might invalidate all live [`FrameRegisters`].
         let caller = self
@@ -313,8 +317,13 @@ impl Executor<'_> {
         func: EngineFunc,
         mut instance: Option<Instance>,
     ) -> Result<(), Error> {
-        let func = self.code_map.get(Some(store.fuel_mut()), func)?;
-        let mut called = self.dispatch_compiled_func::<C>(results, func)?;
+        let compiled_func = self.code_map.get(Some(store.fuel_mut()), func)?;
+        let mut called = self
+            .dispatch_compiled_func::<C>(results, compiled_func)
+            .map_err(|error| {
+                let instance = instance.as_ref().or_else(|| self.stack.calls.instance());
+                stack_overflow_in(store, error, func, instance)
+            })?;
         match <C as CallContext>::KIND {
             CallKind::Nested => {
                 // We need to update the instruction pointer of the caller call frame.
@@ -338,7 +347,10 @@ impl Executor<'_> {
             }
         }
         self.init_call_frame(&called);
-        self.stack.calls.push(called, instance)?;
+        self.stack.calls.push(called, instance).map_err(|error| {
+            let instance = instance.as_ref().or_else(|| self.stack.calls.instance());
+            stack_overflow_in(store, error, func, instance)
+        })?;
         Ok(())
     }
 
@@ -482,6 +494,7 @@ impl Executor<'_> {
             FuncEntity::Host(host_func) => {
                 let host_func = *host_func;
 
+                store.inner.consume_host_func_fuel(&host_func)?;
                 store.invoke_call_hook(CallHook::CallingHost)?;
                 let control = self.execute_host_func::<C, T>(store, results, func, host_func)?;
                 store.invoke_call_hook(CallHook::ReturningFromHost)?;
@@ -736,18 +749,39 @@ impl Executor<'_> {
         index: u32,
         table: index::Table,
     ) -> Result<ControlFlow, Error> {
+        let table_index = table;
         let table = self.get_table(table);
-        let funcref = store
-            .inner
-            .resolve_table(&table)
+        let expected_signature = self.get_func_type_dedup(func_type);
+        let table_entity = store.inner.resolve_table(&table);
+        let funcref = table_entity
             .get_untyped(index)
             .map(FuncRef::from)
             .ok_or(TrapCode::TableOutOfBounds)?;
-        let func = funcref.func().ok_or(TrapCode::IndirectCallToNull)?;
-        let actual_signature = store.inner.resolve_func(func).ty_dedup();
-        let expected_signature = &self.get_func_type_dedup(func_type);
-        if actual_signature != expected_signature {
-            return Err(Error::from(TrapCode::BadSignature));
+        // Note: the signature check is skipped if the last call through the
+        //       same table element already matched the expected signature.
+        let is_cached = table_entity.has_cached_signature(index, &expected_signature);
+        let indirect_call_error = |trap_code, actual| {
+            Error::from(IndirectCallError::new(
+                trap_code,
+                u32::from(table_index),
+                index,
+                u32::from(func_type),
+                actual,
+            ))
+        };
+        let Some(func) = funcref.func() else {
+            return Err(indirect_call_error(TrapCode::IndirectCallToNull, None));
+        };
+        if !is_cached {
+            let actual_signature = store.inner.resolve_func(func).ty_dedup();
+            if actual_signature != &expected_signature {
+                let actual = store.inner.resolve_func_type(actual_signature);
+                return Err(indirect_call_error(TrapCode::BadSignature, Some(actual)));
+            }
+            store
+                .inner
+                .resolve_table_mut(&table)
+                .cache_signature(index, expected_signature);
         }
         self.execute_call_imported_impl::<C, T>(store, results, func)
     }
@@ -71,6 +71,17 @@ impl Executor<'_> {
         self.execute_table_get_impl(store, result, index)
     }
 
+    /// Executes an [`Instruction::TableGetImmInBounds`].
+    pub fn execute_table_get_imm_in_bounds(&mut self, store: &StoreInner, result: Reg, index: u32) {
+        let table_index = self.fetch_table_index(1);
+        let table = self.get_table(table_index);
+        // Safety: Wasmi translation only emits this instruction if `index` is
+        //         less than the minimum size of the table and tables never shrink.
+        let value = unsafe { store.resolve_table(&table).get_untyped_unchecked(index) };
+        self.set_register(result, value);
+        self.next_instr_at(2)
+    }
+
     /// Executes a `table.get` instruction generically.
     fn execute_table_get_impl(
         &mut self,
@@ -122,6 +133,26 @@ impl Executor<'_> {
         self.execute_table_set_impl(store, index, value)
     }
 
+    /// Executes an [`Instruction::TableSetAtInBounds`].
+    pub fn execute_table_set_at_in_bounds(
+        &mut self,
+        store: &mut StoreInner,
+        index: u32,
+        value: Reg,
+    ) {
+        let table_index = self.fetch_table_index(1);
+        let table = self.get_table(table_index);
+        let value = self.get_register(value);
+        // Safety: Wasmi translation only emits this instruction if `index` is
+        //         less than the minimum size of the table and tables never shrink.
+        unsafe {
+            store
+                .resolve_table_mut(&table)
+                .set_untyped_unchecked(index, value)
+        };
+        self.next_instr_at(2)
+    }
+
     /// Executes a generic `table.set` instruction.
     fn execute_table_set_impl(
         &mut self,
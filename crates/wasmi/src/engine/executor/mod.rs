@@ -1,18 +1,31 @@
-pub use self::instrs::ResumableHostError;
 pub(crate) use self::stack::Stack;
 use self::{
     instr_ptr::InstructionPtr,
     instrs::{dispatch_host_func, execute_instrs},
     stack::CallFrame,
 };
+pub use self::{
+    instrs::{ResumableHostError, ResumableOutOfFuelError},
+    stack::StackRecycler,
+};
 use crate::{
-    engine::{CallParams, CallResults, EngineInner, ResumableCallBase, ResumableInvocation},
+    engine::{
+        CallParams,
+        CallResults,
+        EngineFunc,
+        EngineInner,
+        ResumableCallBase,
+        ResumableInvocation,
+        StackOverflowError,
+    },
     func::HostFuncEntity,
     ir::{Reg, RegSpan},
+    store::StoreInner,
     CallHook,
     Error,
     Func,
     FuncEntity,
+    Instance,
     Store,
     StoreContextMut,
 };
@@ -46,12 +59,87 @@ impl EngineInner {
         Results: CallResults,
     {
         let mut stack = self.stacks.lock().reuse_or_new();
+        #[cfg(feature = "profiling")]
+        self.profiler.begin(&mut stack.profile);
         let results = EngineExecutor::new(&self.code_map, &mut stack)
-            .execute_root_func(ctx.store, func, params, results)
-            .map_err(|error| match error.into_resumable() {
-                Ok(error) => error.into_error(),
-                Err(error) => error,
-            });
+            .execute_root_func(ctx.store, func, params, results, None)
+            .map_err(non_resumable);
+        #[cfg(feature = "profiling")]
+        self.profiler.end(&mut stack.profile);
+        observe_stack_usage(ctx.store, &stack);
+        self.stacks.lock().recycle(stack);
+        results
+    }
+
+    /// Executes the given [`Func`] with the given `params` and returns the `results`.
+    ///
+    /// Executes using the [`Stack`] of `recycler` and keeps the used [`Stack`] in it afterwards.
+    ///
+    /// Uses the [`StoreContextMut`] for context information about the Wasm [`Store`].
+    ///
+    /// # Errors
+    ///
+    /// If the Wasm execution traps or runs out of resources.
+    pub fn execute_func_with_stack<T, Results>(
+        &self,
+        ctx: StoreContextMut<T>,
+        func: &Func,
+        params: impl CallParams,
+        results: Results,
+        recycler: &mut StackRecycler,
+    ) -> Result<<Results as CallResults>::Results, Error>
+    where
+        Results: CallResults,
+    {
+        let limits = self.config.stack_limits();
+        let mut stack = recycler
+            .take(limits)
+            .unwrap_or_else(|| self.stacks.lock().reuse_or_new());
+        #[cfg(feature = "profiling")]
+        self.profiler.begin(&mut stack.profile);
+        let results = EngineExecutor::new(&self.code_map, &mut stack)
+            .execute_root_func(ctx.store, func, params, results, None)
+            .map_err(non_resumable);
+        #[cfg(feature = "profiling")]
+        self.profiler.end(&mut stack.profile);
+        observe_stack_usage(ctx.store, &stack);
+        recycler.keep(limits, stack);
+        results
+    }
+
+    /// Executes the given [`Func`] with the given `params` and returns the `results`.
+    ///
+    /// Upon running out of fuel `budget` is queried with the minimum amount of fuel
+    /// required to continue. Execution resumes after adding the returned amount of fuel
+    /// to the [`Store`] or traps with [`TrapCode::OutOfFuel`] if `budget` returns `None` or `Some(0)`.
+    ///
+    /// Uses the [`StoreContextMut`] for context information about the Wasm [`Store`].
+    ///
+    /// # Errors
+    ///
+    /// If the Wasm execution traps or runs out of resources.
+    ///
+    /// [`TrapCode::OutOfFuel`]: crate::core::TrapCode::OutOfFuel
+    pub fn execute_func_with_fuel_budget<T, Results>(
+        &self,
+        ctx: StoreContextMut<T>,
+        func: &Func,
+        params: impl CallParams,
+        results: Results,
+        budget: &mut dyn FnMut(u64) -> Option<u64>,
+    ) -> Result<<Results as CallResults>::Results, Error>
+    where
+        Results: CallResults,
+    {
+        let mut stack = self.stacks.lock().reuse_or_new();
+        #[cfg(feature = "profiling")]
+        self.profiler.begin(&mut stack.profile);
+        let results = EngineExecutor::new(&self.code_map, &mut stack)
+            .execute_root_func(ctx.store, func, params, results, Some(budget))
+            .map_err(non_resumable);
+        #[cfg(feature = "profiling")]
+        self.profiler.end(&mut stack.profile);
+        observe_stack_usage(ctx.store, &stack);
         self.stacks.lock().recycle(stack);
         results
     }
@@ -75,8 +163,13 @@ impl EngineInner {
     {
         let store = ctx.store;
         let mut stack = self.stacks.lock().reuse_or_new();
+        #[cfg(feature = "profiling")]
+        self.profiler.begin(&mut stack.profile);
         let results = EngineExecutor::new(&self.code_map, &mut stack)
-            .execute_root_func(store, func, params, results);
+            .execute_root_func(store, func, params, results, None);
+        #[cfg(feature = "profiling")]
+        self.profiler.end(&mut stack.profile);
+        observe_stack_usage(store, &stack);
         match results {
             Ok(results) => {
                 self.stacks.lock().recycle(stack);
@@ -98,7 +191,7 @@ impl EngineInner {
                 }
                 Err(error) => {
                     self.stacks.lock().recycle(stack);
-                    Err(error)
+                    Err(non_resumable(error))
                 }
             },
         }
@@ -123,6 +216,8 @@ impl EngineInner {
     {
         let host_func = invocation.host_func();
         let caller_results = invocation.caller_results();
+        #[cfg(feature = "profiling")]
+        self.profiler.begin(&mut invocation.stack.profile);
         let results = EngineExecutor::new(&self.code_map, &mut invocation.stack).resume_func(
             ctx.store,
             host_func,
@@ -130,6 +225,9 @@ impl EngineInner {
             caller_results,
             results,
         );
+        #[cfg(feature = "profiling")]
+        self.profiler.end(&mut invocation.stack.profile);
+        observe_stack_usage(ctx.store, &invocation.stack);
         match results {
             Ok(results) => {
                 self.stacks.lock().recycle(invocation.take_stack());
@@ -144,13 +242,36 @@ impl EngineInner {
                 }
                 Err(error) => {
                     self.stacks.lock().recycle(invocation.take_stack());
-                    Err(error)
+                    Err(non_resumable(error))
                 }
             },
         }
     }
 }
 
+/// Converts a resumable `error` into its non-resumable [`Error`].
+///
+/// Returns `error` unchanged if it is not resumable.
+#[cold]
+fn non_resumable(error: Error) -> Error {
+    match error.into_resumable() {
+        Ok(error) => error.into_error(),
+        Err(error) => match error.into_resumable_out_of_fuel() {
+            Ok(error) => error.into_error(),
+            Err(error) => error,
+        },
+    }
+}
+
+/// Updates the stack high-water marks of the `store` with the usage of `stack`.
+#[inline]
+fn observe_stack_usage<T>(store: &mut Store<T>, stack: &Stack) {
+    store.inner.observe_stack_usage(
+        stack.max_observed_value_stack(),
+        stack.max_observed_call_depth(),
+    );
+}
+
 /// The internal state of the Wasmi engine.
 #[derive(Debug)]
 pub struct EngineExecutor<'engine> {
@@ -164,6 +285,24 @@ pub struct EngineExecutor<'engine> {
 #[inline]
 fn do_nothing<T>(_: &mut T) {}
 
+/// Converts a stack overflow `error` upon calling `func` of `instance` into an [`Error`].
+///
+/// # Note
+///
+/// The `error` is attributed to the index of `func` within the module of `instance` if possible.
+#[cold]
+#[inline(never)]
+fn stack_overflow_in(
+    store: &StoreInner,
+    error: StackOverflowError,
+    func: EngineFunc,
+    instance: Option<&Instance>,
+) -> Error {
+    let func_index =
+        instance.and_then(|instance| store.resolve_instance(instance).get_func_index(func));
+    Error::from(error.with_func(func_index))
+}
+
 impl<'engine> EngineExecutor<'engine> {
     /// Creates a new [`EngineExecutor`] with the given [`StackLimits`].
     fn new(code_map: &'engine CodeMap, stack: &'engine mut Stack) -> Self {
@@ -179,12 +318,14 @@ impl<'engine> EngineExecutor<'engine> {
     /// - If the given `params` do not match the expected parameters of `func`.
     /// - If the given `results` do not match the the length of the expected results of `func`.
     /// - When encountering a Wasm or host trap during the execution of `func`.
+    /// - When running out of fuel and `budget` is `None` or does not provide more fuel.
     fn execute_root_func<T, Results>(
         &mut self,
         store: &mut Store<T>,
         func: &Func,
         params: impl CallParams,
         results: Results,
+        budget: Option<&mut dyn FnMut(u64) -> Option<u64>>,
     ) -> Result<<Results as CallResults>::Results, Error>
     where
         Results: CallResults,
@@ -194,30 +335,43 @@ impl<'engine> EngineExecutor<'engine> {
             FuncEntity::Wasm(wasm_func) => {
                 // We reserve space on the stack to write the results of the root function execution.
                 let len_results = results.len_results();
-                self.stack.values.extend_by(len_results, do_nothing)?;
                 let instance = *wasm_func.instance();
                 let engine_func = wasm_func.func_body();
+                let stack_overflow = |store: &Store<T>, error| {
+                    stack_overflow_in(&store.inner, error, engine_func, Some(&instance))
+                };
+                self.stack
+                    .values
+                    .extend_by(len_results, do_nothing)
+                    .map_err(|error| stack_overflow(store, error))?;
                 let compiled_func = self
                     .code_map
                     .get(Some(store.inner.fuel_mut()), engine_func)?;
                 let (mut uninit_params, offsets) = self
                     .stack
                     .values
-                    .alloc_call_frame(compiled_func, do_nothing)?;
+                    .alloc_call_frame(compiled_func, do_nothing)
+                    .map_err(|error| stack_overflow(store, error))?;
                 for value in params.call_params() {
                     unsafe { uninit_params.init_next(value) };
                 }
                 uninit_params.init_zeroes();
-                self.stack.calls.push(
-                    CallFrame::new(
-                        InstructionPtr::new(compiled_func.instrs().as_ptr()),
-                        offsets,
-                        RegSpan::new(Reg::from(0)),
-                    ),
-                    Some(instance),
-                )?;
+                self.stack
+                    .calls
+                    .push(
+                        CallFrame::new(
+                            InstructionPtr::new(compiled_func.instrs().as_ptr()),
+                            offsets,
+                            RegSpan::new(Reg::from(0)),
+                        ),
+                        Some(instance),
+                    )
+                    .map_err(|error| stack_overflow(store, error))?;
                 store.invoke_call_hook(CallHook::CallingWasm)?;
-                self.execute_func(store)?;
+                match budget {
+                    Some(budget) => self.execute_func_with_fuel_budget(store, budget)?,
+                    None => self.execute_func(store)?,
+                }
                 store.invoke_call_hook(CallHook::ReturningFromWasm)?;
             }
             FuncEntity::Host(host_func) => {
@@ -289,6 +443,32 @@ impl<'engine> EngineExecutor<'engine> {
         execute_instrs(store, self.stack, self.code_map)
     }
 
+    /// Executes the top most Wasm function on the [`Stack`] until the [`Stack`] is empty.
+    ///
+    /// Upon running out of fuel `budget` is queried with the minimum amount of fuel
+    /// required to continue and execution resumes after adding the returned amount of fuel.
+    ///
+    /// # Errors
+    ///
+    /// - When encountering a Wasm or host trap during execution.
+    /// - When running out of fuel and `budget` returns `None` or `Some(0)`.
+    fn execute_func_with_fuel_budget<T>(
+        &mut self,
+        store: &mut Store<T>,
+        budget: &mut dyn FnMut(u64) -> Option<u64>,
+    ) -> Result<(), Error> {
+        loop {
+            let Err(error) = self.execute_func(store) else {
+                return Ok(());
+            };
+            let error = error.into_resumable_out_of_fuel()?;
+            let Some(delta) = budget(error.required_fuel()).filter(|&delta| delta != 0) else {
+                return Err(error.into_error());
+            };
+            store.inner.fuel_mut().add_fuel(delta)?;
+        }
+    }
+
     /// Convenience forwarder to [`dispatch_host_func`].
     #[inline(always)]
     fn dispatch_host_func<T>(
@@ -1,8 +1,7 @@
-use super::{err_stack_overflow, BaseValueStackOffset, FrameValueStackOffset};
+use super::{BaseValueStackOffset, FrameValueStackOffset};
 use crate::{
     collections::HeadVec,
-    core::TrapCode,
-    engine::executor::InstructionPtr,
+    engine::{executor::InstructionPtr, StackOverflowError},
     ir::RegSpan,
     Instance,
 };
@@ -10,6 +9,7 @@ use alloc::vec::Vec;
 
 #[cfg(doc)]
 use crate::{
+    core::TrapCode,
     engine::executor::stack::ValueStack,
     engine::EngineFunc,
     ir::Instruction,
@@ -32,6 +32,8 @@ pub struct CallStack {
     ///
     /// A [`TrapCode::StackOverflow`] is raised if the recursion limit is exceeded.
     recursion_limit: usize,
+    /// The maximum number of [`CallFrame`]s observed since the last reset.
+    max_observed_len: usize,
 }
 
 impl CallStack {
@@ -41,6 +43,7 @@ impl CallStack {
             frames: Vec::new(),
             instances: HeadVec::default(),
             recursion_limit,
+            max_observed_len: 0,
         }
     }
 
@@ -56,6 +59,12 @@ impl CallStack {
     pub fn reset(&mut self) {
         self.frames.clear();
         self.instances.clear();
+        self.max_observed_len = 0;
+    }
+
+    /// Returns the maximum number of [`CallFrame`]s observed since the last reset.
+    pub fn max_observed_len(&self) -> usize {
+        self.max_observed_len
     }
 
     /// Returns the number of [`CallFrame`]s on the [`CallStack`].
@@ -99,14 +108,15 @@ impl CallStack {
         &mut self,
         mut call: CallFrame,
         instance: Option<Instance>,
-    ) -> Result<(), TrapCode> {
+    ) -> Result<(), StackOverflowError> {
         if self.len() == self.recursion_limit {
-            return Err(err_stack_overflow());
+            return Err(StackOverflowError::call_stack(self.recursion_limit));
         }
         if let Some(instance) = instance {
             call.changed_instance = self.push_instance(instance);
         }
         self.frames.push(call);
+        self.max_observed_len = self.max_observed_len.max(self.len());
         Ok(())
     }
 
@@ -1,8 +1,10 @@
 mod calls;
+mod recycler;
 mod values;
 
 pub use self::{
     calls::{CallFrame, CallStack, StackOffsets},
+    recycler::StackRecycler,
     values::{
         BaseValueStackOffset,
         FrameParams,
@@ -11,13 +13,9 @@ pub use self::{
         ValueStack,
     },
 };
-use crate::{core::TrapCode, Instance, StackLimits};
-
-/// Returns a [`TrapCode`] signalling a stack overflow.
-#[cold]
-fn err_stack_overflow() -> TrapCode {
-    TrapCode::StackOverflow
-}
+#[cfg(feature = "profiling")]
+use crate::engine::StackProfile;
+use crate::{Instance, StackLimits};
 
 /// Data structure that combines both value stack and call stack.
 #[derive(Debug, Default)]
@@ -26,6 +24,9 @@ pub struct Stack {
     pub calls: CallStack,
     /// The value stack.
     pub values: ValueStack,
+    /// The executed instructions of the current execution if profiling is enabled.
+    #[cfg(feature = "profiling")]
+    pub profile: StackProfile,
 }
 
 impl Stack {
@@ -38,7 +39,12 @@ impl Stack {
             limits.initial_value_stack_height,
             limits.maximum_value_stack_height,
         );
-        Self { calls, values }
+        Self {
+            calls,
+            values,
+            #[cfg(feature = "profiling")]
+            profile: StackProfile::default(),
+        }
     }
 
     /// Resets the [`Stack`] for clean reuse.
@@ -56,6 +62,8 @@ impl Stack {
         Self {
             values: ValueStack::empty(),
             calls: CallStack::default(),
+            #[cfg(feature = "profiling")]
+            profile: StackProfile::default(),
         }
     }
 
@@ -64,6 +72,16 @@ impl Stack {
         self.values.capacity()
     }
 
+    /// Returns the maximum value stack height observed since the last [`Stack::reset`].
+    pub fn max_observed_value_stack(&self) -> usize {
+        self.values.max_observed_len()
+    }
+
+    /// Returns the maximum call depth observed since the last [`Stack::reset`].
+    pub fn max_observed_call_depth(&self) -> usize {
+        self.calls.max_observed_len()
+    }
+
     /// Merge the two top-most [`CallFrame`] with respect to a tail call.
     ///
     /// # Panics (Debug)
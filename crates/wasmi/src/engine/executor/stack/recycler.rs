@@ -0,0 +1,44 @@
+use super::Stack;
+use crate::{Engine, StackLimits};
+
+/// A user owned [`Stack`] that is reused across calls to reduce allocation pressure.
+///
+/// Pass a [`StackRecycler`] to [`Func::call_with_stack`] or [`TypedFunc::call_with_stack`]
+/// in order to execute using its [`Stack`] instead of one of the [`Engine`].
+///
+/// # Note
+///
+/// - [`StackRecycler::default`] creates its [`Stack`] lazily upon first use.
+/// - [`StackRecycler::new`] preallocates its [`Stack`] for the [`Engine`].
+/// - The [`Stack`] is replaced when used with an [`Engine`] with different [`StackLimits`].
+///
+/// [`Func::call_with_stack`]: crate::Func::call_with_stack
+/// [`TypedFunc::call_with_stack`]: crate::TypedFunc::call_with_stack
+#[derive(Debug, Default)]
+pub struct StackRecycler {
+    /// The recycled [`Stack`] and the [`StackLimits`] it was created with if any.
+    stack: Option<(StackLimits, Stack)>,
+}
+
+impl StackRecycler {
+    /// Creates a new [`StackRecycler`] with a preallocated [`Stack`] for `engine`.
+    pub fn new(engine: &Engine) -> Self {
+        let limits = engine.config().stack_limits();
+        Self {
+            stack: Some((limits, Stack::new(limits))),
+        }
+    }
+
+    /// Takes the recycled [`Stack`] if it was created with the given `limits`.
+    pub(crate) fn take(&mut self, limits: StackLimits) -> Option<Stack> {
+        match self.stack.take() {
+            Some((stack_limits, stack)) if stack_limits == limits => Some(stack),
+            _ => None,
+        }
+    }
+
+    /// Stores the `stack` created with `limits` for reuse.
+    pub(crate) fn keep(&mut self, limits: StackLimits, stack: Stack) {
+        self.stack = Some((limits, stack));
+    }
+}
@@ -1,7 +1,7 @@
-use super::{err_stack_overflow, StackOffsets};
+use super::StackOffsets;
 use crate::{
-    core::{TrapCode, UntypedVal},
-    engine::code_map::CompiledFuncRef,
+    core::UntypedVal,
+    engine::{code_map::CompiledFuncRef, StackOverflowError},
     ir::Reg,
 };
 use alloc::vec::Vec;
@@ -23,6 +23,8 @@ pub struct ValueStack {
     values: Vec<UntypedVal>,
     /// Maximal possible `sp` value.
     max_len: usize,
+    /// The maximum length of the [`ValueStack`] observed since the last reset.
+    max_observed_len: usize,
 }
 
 impl ValueStack {
@@ -81,6 +83,7 @@ impl ValueStack {
         Self {
             values: Vec::with_capacity(initial_len),
             max_len: maximum_len,
+            max_observed_len: 0,
         }
     }
 
@@ -94,6 +97,7 @@ impl ValueStack {
         Self {
             values: Vec::new(),
             max_len: 0,
+            max_observed_len: 0,
         }
     }
 
@@ -107,6 +111,12 @@ impl ValueStack {
     /// provide a clean slate for all executions.
     pub fn reset(&mut self) {
         self.values.clear();
+        self.max_observed_len = 0;
+    }
+
+    /// Returns the maximum length of the [`ValueStack`] observed since the last reset.
+    pub fn max_observed_len(&self) -> usize {
+        self.max_observed_len
     }
 
     /// Returns the root [`FrameRegisters`] pointing to the first value on the [`ValueStack`].
@@ -138,9 +148,9 @@ impl ValueStack {
         &mut self,
         additional: usize,
         on_resize: impl FnOnce(&mut Self),
-    ) -> Result<&mut [MaybeUninit<UntypedVal>], TrapCode> {
+    ) -> Result<&mut [MaybeUninit<UntypedVal>], StackOverflowError> {
         if additional >= self.max_len() - self.len() {
-            return Err(err_stack_overflow());
+            return Err(StackOverflowError::value_stack(self.max_len()));
         }
         let prev_capacity = self.capacity();
         self.values.reserve(additional);
@@ -149,6 +159,7 @@ impl ValueStack {
         }
         let spare = self.values.spare_capacity_mut().as_mut_ptr();
         unsafe { self.values.set_len(self.values.len() + additional) };
+        self.max_observed_len = self.max_observed_len.max(self.values.len());
         Ok(unsafe { slice::from_raw_parts_mut(spare, additional) })
     }
 
@@ -221,7 +232,7 @@ impl ValueStack {
         &mut self,
         func: CompiledFuncRef,
         on_resize: impl FnMut(&mut Self),
-    ) -> Result<(FrameParams, StackOffsets), TrapCode> {
+    ) -> Result<(FrameParams, StackOffsets), StackOverflowError> {
         let len_registers = func.len_registers();
         let len_consts = func.consts().len();
         let len = self.len();
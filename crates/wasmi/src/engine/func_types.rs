@@ -36,7 +36,7 @@ impl ArenaIndex for DedupFuncTypeIdx {
 ///
 /// - Requires another indirection to acquire information such as parameter
 ///   or result types of the underlying [`FuncType`].
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct DedupFuncType(GuardedEntity<EngineIdx, DedupFuncTypeIdx>);
 
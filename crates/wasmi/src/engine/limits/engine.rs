@@ -1,60 +1,179 @@
 use core::fmt::{self, Display};
 
 /// An error that can occur upon parsing or compiling a Wasm module when [`EnforcedLimits`] are set.
+///
+/// Every variant carries the exceeded `limit` and the offending `value` of the Wasm module.
 #[derive(Debug, Copy, Clone)]
 pub enum EnforcedLimitsError {
     /// When a Wasm module exceeds the global variable limit.
-    TooManyGlobals { limit: u32 },
+    TooManyGlobals { limit: u32, value: u32 },
     /// When a Wasm module exceeds the table limit.
-    TooManyTables { limit: u32 },
+    TooManyTables { limit: u32, value: u32 },
     /// When a Wasm module exceeds the function limit.
-    TooManyFunctions { limit: u32 },
+    TooManyFunctions { limit: u32, value: u32 },
     /// When a Wasm module exceeds the linear memory limit.
-    TooManyMemories { limit: u32 },
+    TooManyMemories { limit: u32, value: u32 },
     /// When a Wasm module exceeds the element segment limit.
-    TooManyElementSegments { limit: u32 },
+    TooManyElementSegments { limit: u32, value: u32 },
     /// When a Wasm module exceeds the data segment limit.
-    TooManyDataSegments { limit: u32 },
+    TooManyDataSegments { limit: u32, value: u32 },
+    /// When a Wasm module exceeds the initial linear memory pages limit.
+    TooManyMemoryPages { limit: u32, value: u64 },
     /// When a Wasm module exceeds the function parameter limit.
-    TooManyParameters { limit: usize },
+    TooManyParameters { limit: usize, value: usize },
     /// When a Wasm module exceeds the function results limit.
-    TooManyResults { limit: usize },
+    TooManyResults { limit: usize, value: usize },
+    /// When a Wasm function exceeds the local variable limit.
+    TooManyLocals {
+        limit: u32,
+        value: u64,
+        func_index: u32,
+    },
+    /// When a Wasm `br_table` exceeds the branch target limit.
+    TooManyBrTableTargets { limit: u32, value: u32 },
     /// When a Wasm module exceeds the average bytes per function limit.
     MinAvgBytesPerFunction { limit: u32, avg: u32 },
 }
 
+/// The kind of limit of [`EnforcedLimits`] that caused an [`EnforcedLimitsError`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EnforcedLimitKind {
+    /// The limit of global variables of a Wasm module.
+    Globals,
+    /// The limit of tables of a Wasm module.
+    Tables,
+    /// The limit of functions of a Wasm module.
+    Functions,
+    /// The limit of linear memories of a Wasm module.
+    Memories,
+    /// The limit of active element segments of a Wasm module.
+    ElementSegments,
+    /// The limit of active data segments of a Wasm module.
+    DataSegments,
+    /// The limit of initial pages of a linear memory of a Wasm module.
+    MemoryPages,
+    /// The limit of parameters of a function type of a Wasm module.
+    Parameters,
+    /// The limit of results of a function type of a Wasm module.
+    Results,
+    /// The limit of local variables of a Wasm function.
+    Locals,
+    /// The limit of branch targets of a Wasm `br_table`.
+    BrTableTargets,
+    /// The minimum average bytes per function of a Wasm module.
+    MinAvgBytesPerFunction,
+}
+
+impl EnforcedLimitsError {
+    /// Returns the kind of limit that has been exceeded.
+    pub fn kind(&self) -> EnforcedLimitKind {
+        match self {
+            Self::TooManyGlobals { .. } => EnforcedLimitKind::Globals,
+            Self::TooManyTables { .. } => EnforcedLimitKind::Tables,
+            Self::TooManyFunctions { .. } => EnforcedLimitKind::Functions,
+            Self::TooManyMemories { .. } => EnforcedLimitKind::Memories,
+            Self::TooManyElementSegments { .. } => EnforcedLimitKind::ElementSegments,
+            Self::TooManyDataSegments { .. } => EnforcedLimitKind::DataSegments,
+            Self::TooManyMemoryPages { .. } => EnforcedLimitKind::MemoryPages,
+            Self::TooManyParameters { .. } => EnforcedLimitKind::Parameters,
+            Self::TooManyResults { .. } => EnforcedLimitKind::Results,
+            Self::TooManyLocals { .. } => EnforcedLimitKind::Locals,
+            Self::TooManyBrTableTargets { .. } => EnforcedLimitKind::BrTableTargets,
+            Self::MinAvgBytesPerFunction { .. } => EnforcedLimitKind::MinAvgBytesPerFunction,
+        }
+    }
+
+    /// Returns the limit that has been exceeded.
+    pub fn limit(&self) -> u64 {
+        match *self {
+            Self::TooManyGlobals { limit, .. }
+            | Self::TooManyTables { limit, .. }
+            | Self::TooManyFunctions { limit, .. }
+            | Self::TooManyMemories { limit, .. }
+            | Self::TooManyElementSegments { limit, .. }
+            | Self::TooManyDataSegments { limit, .. }
+            | Self::TooManyMemoryPages { limit, .. }
+            | Self::TooManyLocals { limit, .. }
+            | Self::TooManyBrTableTargets { limit, .. }
+            | Self::MinAvgBytesPerFunction { limit, .. } => u64::from(limit),
+            Self::TooManyParameters { limit, .. } | Self::TooManyResults { limit, .. } => {
+                limit as u64
+            }
+        }
+    }
+
+    /// Returns the offending value of the Wasm module that exceeded the limit.
+    ///
+    /// For [`EnforcedLimitKind::MinAvgBytesPerFunction`] this is the average
+    /// number of bytes per function of the Wasm module.
+    pub fn value(&self) -> u64 {
+        match *self {
+            Self::TooManyGlobals { value, .. }
+            | Self::TooManyTables { value, .. }
+            | Self::TooManyFunctions { value, .. }
+            | Self::TooManyMemories { value, .. }
+            | Self::TooManyElementSegments { value, .. }
+            | Self::TooManyDataSegments { value, .. }
+            | Self::TooManyBrTableTargets { value, .. }
+            | Self::MinAvgBytesPerFunction { avg: value, .. } => u64::from(value),
+            Self::TooManyMemoryPages { value, .. } | Self::TooManyLocals { value, .. } => value,
+            Self::TooManyParameters { value, .. } | Self::TooManyResults { value, .. } => {
+                value as u64
+            }
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for EnforcedLimitsError {}
 
 impl Display for EnforcedLimitsError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::TooManyGlobals { limit } => write!(
+            Self::TooManyGlobals { limit, value } => write!(
                 f,
-                "the Wasm module exceeds the limit of {limit} global variables"
+                "the Wasm module declares {value} global variables, limit is {limit}"
             ),
-            Self::TooManyTables { limit } => {
-                write!(f, "the Wasm module exceeds the limit of {limit} tables")
+            Self::TooManyTables { limit, value } => {
+                write!(f, "the Wasm module declares {value} tables, limit is {limit}")
             }
-            Self::TooManyFunctions { limit } => {
-                write!(f, "the Wasm modules exceeds the limit of {limit} functions")
+            Self::TooManyFunctions { limit, value } => {
+                write!(f, "the Wasm module declares {value} functions, limit is {limit}")
             }
-            Self::TooManyMemories { limit } => {
-                write!(f, "the Wasm module exceeds the limit of {limit} memories")
+            Self::TooManyMemories { limit, value } => {
+                write!(f, "the Wasm module declares {value} memories, limit is {limit}")
             }
-            Self::TooManyElementSegments { limit } => write!(
+            Self::TooManyElementSegments { limit, value } => write!(
+                f,
+                "the Wasm module declares {value} element segments, limit is {limit}"
+            ),
+            Self::TooManyDataSegments { limit, value } => write!(
                 f,
-                "the Wasm module exceeds the limit of {limit} active element segments"
+                "the Wasm module declares {value} data segments, limit is {limit}",
             ),
-            Self::TooManyDataSegments { limit } => write!(
+            Self::TooManyMemoryPages { limit, value } => write!(
                 f,
-                "the Wasm module exceeds the limit of {limit} active data segments",
+                "a linear memory of the Wasm module declares {value} initial pages, limit is {limit}",
             ),
-            Self::TooManyParameters { limit } => {
-                write!(f, "a function type exceeds the limit of {limit} parameters",)
+            Self::TooManyParameters { limit, value } => {
+                write!(f, "a function type declares {value} parameters, limit is {limit}")
+            }
+            Self::TooManyResults { limit, value } => {
+                write!(f, "a function type declares {value} results, limit is {limit}")
             }
-            Self::TooManyResults { limit } => {
-                write!(f, "a function type exceeds the limit of {limit} results",)
+            Self::TooManyLocals {
+                limit,
+                value,
+                func_index,
+            } => write!(
+                f,
+                "the function at index {func_index} declares {value} local variables, limit is {limit}"
+            ),
+            Self::TooManyBrTableTargets { limit, value } => {
+                write!(
+                    f,
+                    "a `br_table` has {value} branch targets, limit is {limit}"
+                )
             }
             Self::MinAvgBytesPerFunction { limit, avg } => write!(
                 f,
@@ -137,6 +256,17 @@ pub struct EnforcedLimits {
     /// [`Module::new`]: crate::Module::new
     /// [`Module::new_unchecked`]: crate::Module::new_unchecked
     pub(crate) max_data_segments: Option<u32>,
+    /// Number of initial pages a single linear memory of a Wasm module can have at most.
+    ///
+    /// # Note
+    ///
+    /// - This is checked in [`Module::new`] or [`Module::new_unchecked`].
+    /// - Imported linear memories are not affected by this limit.
+    /// - `None` means the limit is not enforced.
+    ///
+    /// [`Module::new`]: crate::Module::new
+    /// [`Module::new_unchecked`]: crate::Module::new_unchecked
+    pub(crate) max_memory_pages: Option<u32>,
     /// Limits the number of parameter of all functions and control structures.
     ///
     /// # Note
@@ -160,6 +290,29 @@ pub struct EnforcedLimits {
     /// [`Module::new`]: crate::Module::new
     /// [`Module::new_unchecked`]: crate::Module::new_unchecked
     pub(crate) max_results: Option<usize>,
+    /// Number of local variables a single Wasm function can have at most.
+    ///
+    /// # Note
+    ///
+    /// - This is checked in [`Module::new`] or [`Module::new_unchecked`].
+    /// - Function parameters do not count as local variables.
+    /// - `None` means the limit is not enforced.
+    ///
+    /// [`Module::new`]: crate::Module::new
+    /// [`Module::new_unchecked`]: crate::Module::new_unchecked
+    pub(crate) max_locals_per_function: Option<u32>,
+    /// Number of branch targets a single Wasm `br_table` can have at most.
+    ///
+    /// # Note
+    ///
+    /// - This is checked upon translation of the function body which happens
+    ///   in [`Module::new`] or [`Module::new_unchecked`] unless lazy compilation is used.
+    /// - The default branch target of a `br_table` does not count towards this limit.
+    /// - `None` means the limit is not enforced.
+    ///
+    /// [`Module::new`]: crate::Module::new
+    /// [`Module::new_unchecked`]: crate::Module::new_unchecked
+    pub(crate) max_br_table_targets: Option<u32>,
     /// Minimum number of bytes a function must have on average.
     ///
     /// # Note
@@ -175,7 +328,7 @@ pub struct EnforcedLimits {
 }
 
 /// The limit for average bytes per function limit and the threshold at which it is enforced.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct AvgBytesPerFunctionLimit {
     /// The number of Wasm module bytes at which the limit is actually enforced.
     ///
@@ -197,6 +350,19 @@ impl EnforcedLimits {
     /// This set of strict enforced rules can be used by Wasmi users in order
     /// to safeguard themselves against malicious actors trying to attack the Wasmi
     /// compilation procedures.
+    ///
+    /// # Note
+    ///
+    /// The limits for local variables per function and `br_table` targets are set to 10_000:
+    ///
+    /// - Wasmi maps local variables to registers which are addressed via `i16` indices.
+    ///   Local variables share those 32_766 registers with function parameters, temporaries
+    ///   and function local constants. 10_000 local variables keep a function well within
+    ///   this register space instead of failing deep into its translation.
+    /// - Wasmi encodes every `br_table` target as at least one instruction of its own.
+    ///   Bounding the targets bounds the amount of bytecode a single `br_table` can generate.
+    ///
+    /// Both limits are far above what optimizing Wasm producers generate for regular code.
     pub fn strict() -> Self {
         Self {
             max_globals: Some(1000),
@@ -205,8 +371,13 @@ impl EnforcedLimits {
             max_element_segments: Some(1000),
             max_memories: Some(1),
             max_data_segments: Some(1000),
+            max_memory_pages: None,
             max_params: Some(32),
             max_results: Some(32),
+            // Local variables and temporaries share the `i16` register space of a function.
+            max_locals_per_function: Some(10_000),
+            // Each `br_table` target is translated into at least one Wasmi instruction.
+            max_br_table_targets: Some(10_000),
             min_avg_bytes_per_function: Some(AvgBytesPerFunctionLimit {
                 // If all function bodies combined use a total of at least 1000 bytes
                 // the average bytes per function body limit is enforced.
@@ -218,4 +389,244 @@ impl EnforcedLimits {
             }),
         }
     }
+
+    /// A conservative set of limits for Wasm modules from untrusted sources.
+    ///
+    /// This builds upon [`EnforcedLimits::strict`] and additionally bounds the
+    /// number of tables, the initial size of linear memories as well as the size of
+    /// individual function bodies in terms of local variables and `br_table` targets.
+    ///
+    /// # Note
+    ///
+    /// Embedders may start from this set of limits and adjust individual limits
+    /// to their needs using the setters of [`EnforcedLimits`].
+    pub fn for_untrusted_input() -> Self {
+        Self {
+            max_tables: Some(1),
+            // 1024 pages of 64 KiB each equal 64 MiB of initial linear memory.
+            max_memory_pages: Some(1024),
+            max_locals_per_function: Some(1000),
+            max_br_table_targets: Some(1000),
+            ..Self::strict()
+        }
+    }
+
+    /// Sets the maximum number of global variables a single Wasm module can have.
+    pub fn max_globals(&mut self, limit: Option<u32>) -> &mut Self {
+        self.max_globals = limit;
+        self
+    }
+
+    /// Sets the maximum number of functions a single Wasm module can have.
+    pub fn max_functions(&mut self, limit: Option<u32>) -> &mut Self {
+        self.max_functions = limit;
+        self
+    }
+
+    /// Sets the maximum number of tables a single Wasm module can have.
+    pub fn max_tables(&mut self, limit: Option<u32>) -> &mut Self {
+        self.max_tables = limit;
+        self
+    }
+
+    /// Sets the maximum number of table element segments a single Wasm module can have.
+    pub fn max_element_segments(&mut self, limit: Option<u32>) -> &mut Self {
+        self.max_element_segments = limit;
+        self
+    }
+
+    /// Sets the maximum number of linear memories a single Wasm module can have.
+    pub fn max_memories(&mut self, limit: Option<u32>) -> &mut Self {
+        self.max_memories = limit;
+        self
+    }
+
+    /// Sets the maximum number of linear memory data segments a single Wasm module can have.
+    pub fn max_data_segments(&mut self, limit: Option<u32>) -> &mut Self {
+        self.max_data_segments = limit;
+        self
+    }
+
+    /// Sets the maximum number of initial pages of a single linear memory of a Wasm module.
+    pub fn max_memory_pages(&mut self, limit: Option<u32>) -> &mut Self {
+        self.max_memory_pages = limit;
+        self
+    }
+
+    /// Sets the maximum number of parameters of all functions and control structures.
+    pub fn max_params(&mut self, limit: Option<usize>) -> &mut Self {
+        self.max_params = limit;
+        self
+    }
+
+    /// Sets the maximum number of results of all functions and control structures.
+    pub fn max_results(&mut self, limit: Option<usize>) -> &mut Self {
+        self.max_results = limit;
+        self
+    }
+
+    /// Sets the maximum number of local variables of a single Wasm function.
+    pub fn max_locals_per_function(&mut self, limit: Option<u32>) -> &mut Self {
+        self.max_locals_per_function = limit;
+        self
+    }
+
+    /// Sets the maximum number of branch targets of a single Wasm `br_table`.
+    pub fn max_br_table_targets(&mut self, limit: Option<u32>) -> &mut Self {
+        self.max_br_table_targets = limit;
+        self
+    }
+
+    /// Sets the minimum number of bytes a function must have on average.
+    pub fn min_avg_bytes_per_function(
+        &mut self,
+        limit: Option<AvgBytesPerFunctionLimit>,
+    ) -> &mut Self {
+        self.min_avg_bytes_per_function = limit;
+        self
+    }
+
+    /// Returns the maximum number of global variables a single Wasm module can have if enforced.
+    pub fn get_max_globals(&self) -> Option<u32> {
+        self.max_globals
+    }
+
+    /// Returns the maximum number of functions a single Wasm module can have if enforced.
+    pub fn get_max_functions(&self) -> Option<u32> {
+        self.max_functions
+    }
+
+    /// Returns the maximum number of tables a single Wasm module can have if enforced.
+    pub fn get_max_tables(&self) -> Option<u32> {
+        self.max_tables
+    }
+
+    /// Returns the maximum number of table element segments a single Wasm module can have if enforced.
+    pub fn get_max_element_segments(&self) -> Option<u32> {
+        self.max_element_segments
+    }
+
+    /// Returns the maximum number of linear memories a single Wasm module can have if enforced.
+    pub fn get_max_memories(&self) -> Option<u32> {
+        self.max_memories
+    }
+
+    /// Returns the maximum number of linear memory data segments a single Wasm module can have if enforced.
+    pub fn get_max_data_segments(&self) -> Option<u32> {
+        self.max_data_segments
+    }
+
+    /// Returns the maximum number of initial pages of a linear memory of a single Wasm module if enforced.
+    pub fn get_max_memory_pages(&self) -> Option<u32> {
+        self.max_memory_pages
+    }
+
+    /// Returns the maximum number of parameters of a single function type if enforced.
+    pub fn get_max_params(&self) -> Option<usize> {
+        self.max_params
+    }
+
+    /// Returns the maximum number of results of a single function type if enforced.
+    pub fn get_max_results(&self) -> Option<usize> {
+        self.max_results
+    }
+
+    /// Returns the maximum number of local variables of a single Wasm function if enforced.
+    pub fn get_max_locals_per_function(&self) -> Option<u32> {
+        self.max_locals_per_function
+    }
+
+    /// Returns the maximum number of branch targets of a single Wasm `br_table` if enforced.
+    pub fn get_max_br_table_targets(&self) -> Option<u32> {
+        self.max_br_table_targets
+    }
+
+    /// Returns the minimum number of bytes a function must have on average if enforced.
+    pub fn get_min_avg_bytes_per_function(&self) -> Option<AvgBytesPerFunctionLimit> {
+        self.min_avg_bytes_per_function
+    }
+
+    /// Returns the kind of the first limit of `self` that is loosened by `other` if any.
+    ///
+    /// A limit is loosened if it is enforced by `self` but either exceeded
+    /// or not enforced at all by `other`.
+    pub(crate) fn loosened_by(&self, other: &Self) -> Option<EnforcedLimitKind> {
+        fn is_looser<T: PartialOrd>(this: Option<T>, other: Option<T>) -> bool {
+            match (this, other) {
+                (Some(this), Some(other)) => other > this,
+                (Some(_), None) => true,
+                (None, _) => false,
+            }
+        }
+        let max_limits = [
+            (
+                self.max_globals,
+                other.max_globals,
+                EnforcedLimitKind::Globals,
+            ),
+            (
+                self.max_functions,
+                other.max_functions,
+                EnforcedLimitKind::Functions,
+            ),
+            (self.max_tables, other.max_tables, EnforcedLimitKind::Tables),
+            (
+                self.max_element_segments,
+                other.max_element_segments,
+                EnforcedLimitKind::ElementSegments,
+            ),
+            (
+                self.max_memories,
+                other.max_memories,
+                EnforcedLimitKind::Memories,
+            ),
+            (
+                self.max_data_segments,
+                other.max_data_segments,
+                EnforcedLimitKind::DataSegments,
+            ),
+            (
+                self.max_memory_pages,
+                other.max_memory_pages,
+                EnforcedLimitKind::MemoryPages,
+            ),
+            (
+                self.max_locals_per_function,
+                other.max_locals_per_function,
+                EnforcedLimitKind::Locals,
+            ),
+            (
+                self.max_br_table_targets,
+                other.max_br_table_targets,
+                EnforcedLimitKind::BrTableTargets,
+            ),
+        ];
+        if let Some((_, _, kind)) = max_limits
+            .into_iter()
+            .find(|(this, other, _)| is_looser(*this, *other))
+        {
+            return Some(kind);
+        }
+        if is_looser(self.max_params, other.max_params) {
+            return Some(EnforcedLimitKind::Parameters);
+        }
+        if is_looser(self.max_results, other.max_results) {
+            return Some(EnforcedLimitKind::Results);
+        }
+        let avg_is_looser = match (
+            self.min_avg_bytes_per_function,
+            other.min_avg_bytes_per_function,
+        ) {
+            (Some(this), Some(other)) => {
+                other.req_funcs_bytes > this.req_funcs_bytes
+                    || other.min_avg_bytes_per_function < this.min_avg_bytes_per_function
+            }
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        if avg_is_looser {
+            return Some(EnforcedLimitKind::MinAvgBytesPerFunction);
+        }
+        None
+    }
 }
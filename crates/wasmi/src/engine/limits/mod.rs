@@ -5,6 +5,6 @@ mod stack;
 mod tests;
 
 pub use self::{
-    engine::{EnforcedLimits, EnforcedLimitsError},
-    stack::StackLimits,
+    engine::{AvgBytesPerFunctionLimit, EnforcedLimitKind, EnforcedLimits, EnforcedLimitsError},
+    stack::{StackLimits, StackOverflowError, StackOverflowKind},
 };
@@ -1,4 +1,4 @@
-use crate::core::UntypedVal;
+use crate::core::{TrapCode, UntypedVal};
 use core::{
     fmt::{self, Display},
     mem::size_of,
@@ -14,7 +14,7 @@ const DEFAULT_MAX_VALUE_STACK_HEIGHT: usize = 1024 * DEFAULT_MIN_VALUE_STACK_HEI
 const DEFAULT_MAX_RECURSION_DEPTH: usize = 1024;
 
 /// The configured limits of the Wasm stack.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct StackLimits {
     /// The initial value stack height that the Wasm stack prepares.
     pub initial_value_stack_height: usize,
@@ -66,6 +66,23 @@ impl StackLimits {
     }
 }
 
+impl StackLimits {
+    /// Returns the initial value stack height that the Wasm stack prepares.
+    pub fn initial_value_stack_height(&self) -> usize {
+        self.initial_value_stack_height
+    }
+
+    /// Returns the maximum value stack height in use that the Wasm stack allows.
+    pub fn maximum_value_stack_height(&self) -> usize {
+        self.maximum_value_stack_height
+    }
+
+    /// Returns the maximum number of nested calls that the Wasm stack allows.
+    pub fn maximum_recursion_depth(&self) -> usize {
+        self.maximum_recursion_depth
+    }
+}
+
 impl Default for StackLimits {
     fn default() -> Self {
         let register_len = size_of::<UntypedVal>();
@@ -78,3 +95,108 @@ impl Default for StackLimits {
         }
     }
 }
+
+/// The limit of the Wasm stack that has been exhausted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StackOverflowKind {
+    /// The value stack exceeded [`StackLimits::maximum_value_stack_height`].
+    ValueStack,
+    /// The call stack exceeded [`StackLimits::maximum_recursion_depth`].
+    CallStack,
+}
+
+/// A trap that occurred upon exhausting the Wasm stack with information about its cause.
+///
+/// # Note
+///
+/// This is reported for all [`TrapCode::StackOverflow`] traps and its
+/// [`TrapCode`] is available via [`Error::as_trap_code`].
+///
+/// [`Error::as_trap_code`]: crate::Error::as_trap_code
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct StackOverflowError {
+    /// The exhausted limit.
+    kind: StackOverflowKind,
+    /// The configured value of the exhausted limit.
+    limit: usize,
+    /// The index of the called function within its module if known.
+    func: Option<u32>,
+}
+
+impl StackOverflowError {
+    /// Creates a new [`StackOverflowError`] for an exhausted value stack.
+    pub(crate) fn value_stack(limit: usize) -> Self {
+        Self {
+            kind: StackOverflowKind::ValueStack,
+            limit,
+            func: None,
+        }
+    }
+
+    /// Creates a new [`StackOverflowError`] for an exhausted call stack.
+    pub(crate) fn call_stack(limit: usize) -> Self {
+        Self {
+            kind: StackOverflowKind::CallStack,
+            limit,
+            func: None,
+        }
+    }
+
+    /// Returns the [`StackOverflowError`] attributed to the function at index `func`.
+    pub(crate) fn with_func(self, func: Option<u32>) -> Self {
+        Self { func, ..self }
+    }
+
+    /// Returns the [`TrapCode`] of the [`StackOverflowError`].
+    ///
+    /// This is always [`TrapCode::StackOverflow`].
+    pub fn trap_code(&self) -> TrapCode {
+        TrapCode::StackOverflow
+    }
+
+    /// Returns the [`StackOverflowKind`] of the exhausted limit.
+    pub fn kind(&self) -> StackOverflowKind {
+        self.kind
+    }
+
+    /// Returns the configured value of the exhausted limit.
+    ///
+    /// # Note
+    ///
+    /// The unit is the same as the one of the respective [`StackLimits`] field.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the index of the called function within its Wasm module.
+    ///
+    /// Returns `None` if the called function is a host function or
+    /// if the function could not be determined.
+    pub fn func_index(&self) -> Option<u32> {
+        self.func
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StackOverflowError {}
+
+impl Display for StackOverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let trap_code = self.trap_code();
+        let limit = self.limit;
+        match self.kind {
+            StackOverflowKind::ValueStack => write!(
+                f,
+                "{trap_code}: exceeded maximum value stack height of {limit}"
+            )?,
+            StackOverflowKind::CallStack => write!(
+                f,
+                "{trap_code}: exceeded maximum recursion depth of {limit}"
+            )?,
+        }
+        match self.func {
+            Some(func) => write!(f, " upon calling function {func}"),
+            None => Ok(()),
+        }
+    }
+}
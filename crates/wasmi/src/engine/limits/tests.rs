@@ -1,6 +1,6 @@
-use self::engine::AvgBytesPerFunctionLimit;
 use super::*;
 use crate::{error::ErrorKind, Config, Engine, Error, Module};
+use std::string::ToString;
 
 /// Parses and returns the Wasm module `wasm` with the given [`EnforcedLimits`] `limits`.
 fn parse_with(wasm: &str, limits: EnforcedLimits) -> Result<Module, Error> {
@@ -43,7 +43,7 @@ fn max_globals_err() {
     };
     assert!(matches!(
         parse_with(wasm, limits).unwrap_err().kind(),
-        ErrorKind::Limits(EnforcedLimitsError::TooManyGlobals { limit: 2 }),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyGlobals { limit: 2, value: 3 }),
     ))
 }
 
@@ -80,7 +80,7 @@ fn max_functions_err() {
     };
     assert!(matches!(
         parse_with(wasm, limits).unwrap_err().kind(),
-        ErrorKind::Limits(EnforcedLimitsError::TooManyFunctions { limit: 2 }),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyFunctions { limit: 2, value: 3 }),
     ))
 }
 
@@ -117,7 +117,7 @@ fn max_tables_err() {
     };
     assert!(matches!(
         parse_with(wasm, limits).unwrap_err().kind(),
-        ErrorKind::Limits(EnforcedLimitsError::TooManyTables { limit: 2 }),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyTables { limit: 2, value: 3 }),
     ))
 }
 
@@ -156,7 +156,7 @@ fn max_memories_err() {
     };
     assert!(matches!(
         parse_with(wasm, limits).unwrap_err().kind(),
-        ErrorKind::Limits(EnforcedLimitsError::TooManyMemories { limit: 2 }),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyMemories { limit: 2, value: 3 }),
     ))
 }
 
@@ -197,7 +197,7 @@ fn max_element_segments_err() {
     };
     assert!(matches!(
         parse_with(wasm, limits).unwrap_err().kind(),
-        ErrorKind::Limits(EnforcedLimitsError::TooManyElementSegments { limit: 2 }),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyElementSegments { limit: 2, value: 3 }),
     ))
 }
 
@@ -236,7 +236,7 @@ fn max_data_segments_err() {
     };
     assert!(matches!(
         parse_with(wasm, limits).unwrap_err().kind(),
-        ErrorKind::Limits(EnforcedLimitsError::TooManyDataSegments { limit: 2 }),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyDataSegments { limit: 2, value: 3 }),
     ))
 }
 
@@ -270,7 +270,7 @@ fn max_params_func_err() {
     };
     assert!(matches!(
         parse_with(wasm, limits).unwrap_err().kind(),
-        ErrorKind::Limits(EnforcedLimitsError::TooManyParameters { limit: 2 }),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyParameters { limit: 2, value: 3 }),
     ))
 }
 
@@ -319,7 +319,7 @@ fn max_params_control_err() {
     };
     assert!(matches!(
         parse_with(wasm, limits).unwrap_err().kind(),
-        ErrorKind::Limits(EnforcedLimitsError::TooManyParameters { limit: 2 }),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyParameters { limit: 2, value: 3 }),
     ))
 }
 
@@ -360,7 +360,7 @@ fn max_results_func_err() {
     };
     assert!(matches!(
         parse_with(wasm, limits).unwrap_err().kind(),
-        ErrorKind::Limits(EnforcedLimitsError::TooManyResults { limit: 2 }),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyResults { limit: 2, value: 3 }),
     ))
 }
 
@@ -410,7 +410,89 @@ fn max_results_control_err() {
     };
     assert!(matches!(
         parse_with(wasm, limits).unwrap_err().kind(),
-        ErrorKind::Limits(EnforcedLimitsError::TooManyResults { limit: 2 }),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyResults { limit: 2, value: 3 }),
+    ))
+}
+
+#[test]
+fn max_locals_ok() {
+    let wasm = "
+        (module
+            (func (param i32 i32) (local i32) (local i64 f32))
+        )
+    ";
+    parse_with(
+        wasm,
+        EnforcedLimits {
+            max_locals_per_function: Some(3),
+            ..EnforcedLimits::default()
+        },
+    )
+    .unwrap();
+}
+
+#[test]
+fn max_locals_err() {
+    let wasm = "
+        (module
+            (import \"env\" \"f\" (func))
+            (func (local i32))
+            (func (local i32) (local i64 f32 f64))
+        )
+    ";
+    let limits = EnforcedLimits {
+        max_locals_per_function: Some(3),
+        ..EnforcedLimits::default()
+    };
+    assert!(matches!(
+        parse_with(wasm, limits).unwrap_err().kind(),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyLocals {
+            limit: 3,
+            value: 4,
+            func_index: 2
+        }),
+    ))
+}
+
+#[test]
+fn max_br_table_targets_ok() {
+    let wasm = "
+        (module
+            (func (param i32)
+                (block
+                    (br_table 0 0 0 (local.get 0))
+                )
+            )
+        )
+    ";
+    parse_with(
+        wasm,
+        EnforcedLimits {
+            max_br_table_targets: Some(2),
+            ..EnforcedLimits::default()
+        },
+    )
+    .unwrap();
+}
+
+#[test]
+fn max_br_table_targets_err() {
+    let wasm = "
+        (module
+            (func (param i32)
+                (block
+                    (br_table 0 0 0 0 (local.get 0))
+                )
+            )
+        )
+    ";
+    let limits = EnforcedLimits {
+        max_br_table_targets: Some(2),
+        ..EnforcedLimits::default()
+    };
+    assert!(matches!(
+        parse_with(wasm, limits).unwrap_err().kind(),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyBrTableTargets { limit: 2, value: 3 }),
     ))
 }
 
@@ -494,3 +576,143 @@ fn min_avg_code_bytes_ok_threshold() {
     };
     parse_with(wasm, limits).unwrap();
 }
+
+#[test]
+fn untrusted_input_accepts_regular_module() {
+    let wasm = "
+        (module
+            (memory 16)
+            (global (mut i32) (i32.const 0))
+            (func (export \"f\") (param i32) (result i32)
+                (local i32 i64)
+                (block
+                    (br_table 0 0 (local.get 0))
+                )
+                (local.get 0)
+            )
+        )
+    ";
+    parse_with(wasm, EnforcedLimits::for_untrusted_input()).unwrap();
+}
+
+#[test]
+fn untrusted_input_rejects_oversized_memory() {
+    let wasm = "(module (memory 1025))";
+    assert!(matches!(
+        parse_with(wasm, EnforcedLimits::for_untrusted_input())
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyMemoryPages {
+            limit: 1024,
+            value: 1025
+        }),
+    ))
+}
+
+#[test]
+fn untrusted_input_rejects_oversized_function() {
+    let wasm = "(module (func (local i32 i32) (local i64)))";
+    let mut limits = EnforcedLimits::for_untrusted_input();
+    limits.max_locals_per_function(Some(2));
+    assert!(matches!(
+        parse_with(wasm, limits).unwrap_err().kind(),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyLocals {
+            limit: 2,
+            value: 3,
+            func_index: 0
+        }),
+    ));
+    let wasm = std::format!("(module (func (local {})))", "i32 ".repeat(1001));
+    assert!(matches!(
+        parse_with(&wasm, EnforcedLimits::for_untrusted_input())
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyLocals {
+            limit: 1000,
+            value: 1001,
+            func_index: 0
+        }),
+    ));
+}
+
+#[test]
+fn strict_locals_boundary() {
+    let wasm = std::format!("(module (func (local {})))", "i32 ".repeat(10_000));
+    parse_with(&wasm, EnforcedLimits::strict()).unwrap();
+    let wasm = std::format!("(module (func (local {})))", "i32 ".repeat(10_001));
+    assert!(matches!(
+        parse_with(&wasm, EnforcedLimits::strict())
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyLocals {
+            limit: 10_000,
+            value: 10_001,
+            func_index: 0
+        }),
+    ));
+}
+
+#[test]
+fn strict_br_table_targets_boundary() {
+    let br_table = |len_targets: usize| {
+        std::format!(
+            "(module (func (param i32) (block (br_table {} 0 (local.get 0)))))",
+            "0 ".repeat(len_targets)
+        )
+    };
+    parse_with(&br_table(10_000), EnforcedLimits::strict()).unwrap();
+    assert!(matches!(
+        parse_with(&br_table(10_001), EnforcedLimits::strict())
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyBrTableTargets {
+            limit: 10_000,
+            value: 10_001,
+        }),
+    ));
+}
+
+#[test]
+fn untrusted_input_rejects_too_many_globals() {
+    let wasm = std::format!("(module {})", "(global i32 (i32.const 0))".repeat(1001));
+    assert!(matches!(
+        parse_with(&wasm, EnforcedLimits::for_untrusted_input())
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyGlobals {
+            limit: 1000,
+            value: 1001
+        }),
+    ))
+}
+
+#[test]
+fn untrusted_input_rejects_tiny_functions() {
+    let wasm = std::format!("(module {})", "(func)".repeat(500));
+    assert!(matches!(
+        parse_with(&wasm, EnforcedLimits::for_untrusted_input())
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Limits(EnforcedLimitsError::MinAvgBytesPerFunction { limit: 40, .. }),
+    ))
+}
+
+#[test]
+fn error_reports_kind_limit_and_value() {
+    let wasm = std::format!("(module {})", "(func)".repeat(5));
+    let limits = EnforcedLimits {
+        max_functions: Some(4),
+        ..EnforcedLimits::default()
+    };
+    let error = parse_with(&wasm, limits).unwrap_err();
+    let ErrorKind::Limits(error) = error.kind() else {
+        panic!("expected a limits error but found: {error}")
+    };
+    assert_eq!(error.kind(), EnforcedLimitKind::Functions);
+    assert_eq!(error.limit(), 4);
+    assert_eq!(error.value(), 5);
+    assert_eq!(
+        error.to_string(),
+        "the Wasm module declares 5 functions, limit is 4",
+    );
+}
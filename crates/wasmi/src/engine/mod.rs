@@ -7,6 +7,8 @@ mod executor;
 mod func_args;
 mod func_types;
 mod limits;
+#[cfg(feature = "profiling")]
+mod profiling;
 mod resumable;
 mod traits;
 mod translator;
@@ -15,6 +17,12 @@ mod utils;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "profiling")]
+use self::profiling::Profiler;
+#[cfg(feature = "profiling")]
+pub(crate) use self::profiling::StackProfile;
+#[cfg(feature = "profiling")]
+pub use self::profiling::{InstrProfile, ProfileReport};
 pub(crate) use self::{
     block_type::BlockType,
     config::FuelCosts,
@@ -37,9 +45,17 @@ use self::{
 };
 pub use self::{
     code_map::{EngineFunc, EngineFuncSpan, EngineFuncSpanIter},
-    config::{CompilationMode, Config},
-    executor::ResumableHostError,
-    limits::{EnforcedLimits, EnforcedLimitsError, StackLimits},
+    config::{CompilationMode, Config, WasmProposal},
+    executor::{ResumableHostError, ResumableOutOfFuelError, StackRecycler},
+    limits::{
+        AvgBytesPerFunctionLimit,
+        EnforcedLimitKind,
+        EnforcedLimits,
+        EnforcedLimitsError,
+        StackLimits,
+        StackOverflowError,
+        StackOverflowKind,
+    },
     resumable::{ResumableCall, ResumableInvocation, TypedResumableCall, TypedResumableInvocation},
     traits::{CallParams, CallResults},
     translator::{Instr, TranslationError},
@@ -68,7 +84,7 @@ use crate::Store;
 /// # Note
 ///
 /// Used to protect against invalid entity indices.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct EngineIdx(u32);
 
 impl ArenaIndex for EngineIdx {
@@ -155,11 +171,44 @@ impl Engine {
         self.inner.config()
     }
 
+    /// Returns `true` if the Wasm `proposal` is enabled for the [`Engine`].
+    ///
+    /// # Note
+    ///
+    /// - This reflects the effective Wasm features of the [`Config`] of the [`Engine`].
+    /// - Wasm proposals that are not supported by Wasmi are never enabled.
+    pub fn is_enabled(&self, proposal: WasmProposal) -> bool {
+        self.config().is_enabled(proposal)
+    }
+
     /// Returns `true` if both [`Engine`] references `a` and `b` refer to the same [`Engine`].
     pub fn same(a: &Engine, b: &Engine) -> bool {
         Arc::ptr_eq(&a.inner, &b.inner)
     }
 
+    /// Returns the number of strong references to the [`Engine`].
+    ///
+    /// # Note
+    ///
+    /// Every [`Engine`] clone counts as a strong reference, including the ones
+    /// held by [`Module`] and [`Store`] instances. This is primarily useful to
+    /// diagnose why an [`Engine`] is kept alive.
+    ///
+    /// [`Module`]: crate::Module
+    /// [`Store`]: crate::Store
+    pub fn strong_count(&self) -> usize {
+        Arc::strong_count(&self.inner)
+    }
+
+    /// Returns the number of weak references to the [`Engine`].
+    ///
+    /// # Note
+    ///
+    /// Weak references are created via [`Engine::weak`].
+    pub fn weak_count(&self) -> usize {
+        Arc::weak_count(&self.inner)
+    }
+
     /// Allocates a new function type to the [`Engine`].
     pub(super) fn alloc_func_type(&self, func_type: FuncType) -> DedupFuncType {
         self.inner.alloc_func_type(func_type)
@@ -187,11 +236,12 @@ impl Engine {
 
     /// Translates the Wasm function using the [`Engine`].
     ///
-    /// - Uses the internal [`Config`] to drive the function translation as mandated.
+    /// - Uses the given [`CompilationMode`] to drive the function translation as mandated.
     /// - Reuses translation and validation allocations to be more efficient when used for many translation units.
     ///
     /// # Parameters
     ///
+    /// - `compilation_mode`: The [`CompilationMode`] of the Wasm module of the translated function.
     /// - `func_index`: The index of the translated function within its Wasm module.
     /// - `engine_func`: The index of the translated function in the [`Engine`].
     /// - `offset`: The global offset of the Wasm function body within the Wasm binary.
@@ -203,8 +253,10 @@ impl Engine {
     ///
     /// - If function translation fails.
     /// - If function validation fails.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn translate_func(
         &self,
+        compilation_mode: CompilationMode,
         func_index: FuncIdx,
         engine_func: EngineFunc,
         offset: usize,
@@ -213,6 +265,7 @@ impl Engine {
         func_to_validate: Option<FuncToValidate<ValidatorResources>>,
     ) -> Result<(), Error> {
         self.inner.translate_func(
+            compilation_mode,
             func_index,
             engine_func,
             offset,
@@ -302,6 +355,80 @@ impl Engine {
         self.inner.execute_func(ctx, func, params, results)
     }
 
+    /// Executes the given [`Func`] with parameters `params` resuming upon running out of fuel.
+    ///
+    /// Stores the execution result into `results` upon a successful execution.
+    /// Upon running out of fuel `budget` is queried with the minimum amount of fuel
+    /// required to continue. Execution resumes after adding the returned amount of fuel,
+    /// or traps with [`TrapCode::OutOfFuel`] if `budget` returns `None` or `Some(0)`.
+    ///
+    /// # Note
+    ///
+    /// - Assumes that the `params` and `results` are well typed.
+    ///   Type checks are done at the [`Func::call`] API or when creating
+    ///   a new [`TypedFunc`] instance via [`Func::typed`].
+    /// - The `params` out parameter is in a valid but unspecified state if this
+    ///   function returns with an error.
+    ///
+    /// # Errors
+    ///
+    /// - If `params` are overflowing or underflowing the expected amount of parameters.
+    /// - If the given `results` do not match the the length of the expected results of `func`.
+    /// - When encountering a Wasm or host trap during the execution of `func`.
+    ///
+    /// [`TypedFunc`]: [`crate::TypedFunc`]
+    /// [`TrapCode::OutOfFuel`]: crate::core::TrapCode::OutOfFuel
+    #[inline]
+    pub(crate) fn execute_func_with_fuel_budget<T, Results>(
+        &self,
+        ctx: StoreContextMut<T>,
+        func: &Func,
+        params: impl CallParams,
+        results: Results,
+        budget: &mut dyn FnMut(u64) -> Option<u64>,
+    ) -> Result<<Results as CallResults>::Results, Error>
+    where
+        Results: CallResults,
+    {
+        self.inner
+            .execute_func_with_fuel_budget(ctx, func, params, results, budget)
+    }
+
+    /// Executes the given [`Func`] with parameters `params` using the stack of the [`StackRecycler`].
+    ///
+    /// Stores the execution result into `results` upon a successful execution.
+    ///
+    /// # Note
+    ///
+    /// - Assumes that the `params` and `results` are well typed.
+    ///   Type checks are done at the [`Func::call`] API or when creating
+    ///   a new [`TypedFunc`] instance via [`Func::typed`].
+    /// - The `params` out parameter is in a valid but unspecified state if this
+    ///   function returns with an error.
+    ///
+    /// # Errors
+    ///
+    /// - If `params` are overflowing or underflowing the expected amount of parameters.
+    /// - If the given `results` do not match the the length of the expected results of `func`.
+    /// - When encountering a Wasm or host trap during the execution of `func`.
+    ///
+    /// [`TypedFunc`]: [`crate::TypedFunc`]
+    #[inline]
+    pub(crate) fn execute_func_with_stack<T, Results>(
+        &self,
+        ctx: StoreContextMut<T>,
+        func: &Func,
+        params: impl CallParams,
+        results: Results,
+        recycler: &mut StackRecycler,
+    ) -> Result<<Results as CallResults>::Results, Error>
+    where
+        Results: CallResults,
+    {
+        self.inner
+            .execute_func_with_stack(ctx, func, params, results, recycler)
+    }
+
     /// Executes the given [`Func`] resumably with parameters `params` and returns.
     ///
     /// Stores the execution result into `results` upon a successful execution.
@@ -403,6 +530,9 @@ pub struct EngineInner {
     /// operate on. Therefore a Wasm engine is required to provide stacks and
     /// ideally recycles old ones since creation of a new stack is rather expensive.
     stacks: Mutex<EngineStacks>,
+    /// Collects the executed instructions if profiling is enabled.
+    #[cfg(feature = "profiling")]
+    profiler: Profiler,
 }
 
 /// Stacks to hold and distribute reusable allocations.
@@ -516,9 +646,17 @@ impl EngineInner {
             func_types: RwLock::new(FuncTypeRegistry::new(engine_idx)),
             allocs: Mutex::new(ReusableAllocationStack::default()),
             stacks: Mutex::new(EngineStacks::new(config)),
+            #[cfg(feature = "profiling")]
+            profiler: Profiler::default(),
         }
     }
 
+    /// Returns a shared reference to the [`Profiler`] of the [`EngineInner`].
+    #[cfg(feature = "profiling")]
+    fn profiler(&self) -> &Profiler {
+        &self.profiler
+    }
+
     /// Returns a shared reference to the [`Config`] of the [`EngineInner`].
     fn config(&self) -> &Config {
         &self.config
@@ -552,8 +690,10 @@ impl EngineInner {
     /// Translates the Wasm function using the [`Engine`].
     ///
     /// For more information read [`Engine::translate_func`].
+    #[allow(clippy::too_many_arguments)]
     fn translate_func(
         &self,
+        compilation_mode: CompilationMode,
         func_index: FuncIdx,
         engine_func: EngineFunc,
         offset: usize,
@@ -562,7 +702,7 @@ impl EngineInner {
         func_to_validate: Option<FuncToValidate<ValidatorResources>>,
     ) -> Result<(), Error> {
         let features = self.config().wasm_features();
-        match (self.config.get_compilation_mode(), func_to_validate) {
+        match (compilation_mode, func_to_validate) {
             (CompilationMode::Eager, Some(func_to_validate)) => {
                 let (translation_allocs, validation_allocs) = self.get_allocs();
                 let validator = func_to_validate.into_validator(validation_allocs);
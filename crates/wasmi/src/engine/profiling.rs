@@ -0,0 +1,184 @@
+//! Instruction execution profiling of the Wasmi executor.
+//!
+//! Only available with the `profiling` crate feature enabled.
+
+use crate::{ir::Instruction, Engine};
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, Ordering};
+use spin::Mutex;
+
+/// The number of executions of a single kind of Wasmi [`Instruction`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct InstrProfile {
+    /// The name of the kind of instruction, e.g. `"I32Add"`.
+    name: &'static str,
+    /// The number of times the instruction kind was executed.
+    count: u64,
+}
+
+impl InstrProfile {
+    /// Returns the name of the profiled kind of instruction, e.g. `"I32Add"`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the number of times the profiled kind of instruction was executed.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+/// A report of the Wasmi [`Instruction`]s executed while profiling an [`Engine`].
+///
+/// - Only kinds of instructions that were executed at least once are reported.
+/// - The most executed kinds of instructions come first.
+/// - With the `serde` crate feature enabled this can be serialized, e.g. to JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ProfileReport {
+    /// The profiled kinds of instructions sorted by their execution count.
+    instrs: Vec<InstrProfile>,
+}
+
+impl ProfileReport {
+    /// Returns the profiled kinds of instructions, most executed first.
+    pub fn instrs(&self) -> &[InstrProfile] {
+        &self.instrs
+    }
+
+    /// Returns the total number of executed instructions.
+    pub fn total(&self) -> u64 {
+        self.instrs.iter().map(InstrProfile::count).sum()
+    }
+
+    /// Returns the number of executions of the instruction kind with `name`.
+    ///
+    /// Returns `0` if no such instruction was executed.
+    pub fn count(&self, name: &str) -> u64 {
+        self.instrs
+            .iter()
+            .find(|instr| instr.name == name)
+            .map_or(0, InstrProfile::count)
+    }
+}
+
+/// The execution counts of a single execution collected on its [`Stack`].
+///
+/// [`Stack`]: super::Stack
+#[derive(Debug, Default)]
+pub struct StackProfile {
+    /// `true` if instructions are counted for the current execution.
+    enabled: bool,
+    /// The execution counts indexed by [`Instruction::code`].
+    counts: Vec<u64>,
+}
+
+impl StackProfile {
+    /// Counts an execution of `instr` if profiling is enabled.
+    #[inline(always)]
+    pub fn record(&mut self, instr: &Instruction) {
+        if self.enabled {
+            self.counts[usize::from(instr.code())] += 1;
+        }
+    }
+}
+
+/// The profiler of an [`Engine`] accumulating the execution counts of all executions.
+#[derive(Debug, Default)]
+pub struct Profiler {
+    /// `true` if the [`Profiler`] is collecting execution counts.
+    enabled: AtomicBool,
+    /// The accumulated execution counts indexed by [`Instruction::code`].
+    counts: Mutex<Vec<u64>>,
+}
+
+impl Profiler {
+    /// Starts or stops collecting execution counts.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Discards all collected execution counts.
+    pub fn reset(&self) {
+        self.counts.lock().clear();
+    }
+
+    /// Prepares the `profile` of a [`Stack`] for an upcoming execution.
+    ///
+    /// [`Stack`]: super::Stack
+    pub fn begin(&self, profile: &mut StackProfile) {
+        profile.enabled = self.enabled.load(Ordering::Relaxed);
+        if profile.enabled {
+            profile.counts.clear();
+            profile.counts.resize(Instruction::NAMES.len(), 0);
+        }
+    }
+
+    /// Accumulates the execution counts of the `profile` of a [`Stack`] after an execution.
+    ///
+    /// [`Stack`]: super::Stack
+    pub fn end(&self, profile: &mut StackProfile) {
+        if !profile.enabled {
+            return;
+        }
+        profile.enabled = false;
+        let mut counts = self.counts.lock();
+        counts.resize(Instruction::NAMES.len(), 0);
+        for (count, delta) in counts.iter_mut().zip(&profile.counts) {
+            *count += delta;
+        }
+    }
+
+    /// Returns a [`ProfileReport`] of all collected execution counts and discards them.
+    ///
+    /// Returns `None` if no instructions have been executed since the last reset.
+    pub fn take(&self) -> Option<ProfileReport> {
+        let counts = core::mem::take(&mut *self.counts.lock());
+        let mut instrs = counts
+            .into_iter()
+            .zip(Instruction::NAMES)
+            .filter(|(count, _)| *count != 0)
+            .map(|(count, name)| InstrProfile { name, count })
+            .collect::<Vec<_>>();
+        if instrs.is_empty() {
+            return None;
+        }
+        instrs.sort_by(|lhs, rhs| rhs.count.cmp(&lhs.count).then(lhs.name.cmp(rhs.name)));
+        Some(ProfileReport { instrs })
+    }
+}
+
+impl Engine {
+    /// Starts collecting the executed Wasmi instructions of all executions of the [`Engine`].
+    ///
+    /// # Note
+    ///
+    /// - Only available with the `profiling` crate feature enabled.
+    /// - Executions that already started before are not profiled.
+    /// - Profiling counts every executed instruction and thus slows down execution.
+    ///   Even without starting profiling the `profiling` crate feature adds some overhead.
+    pub fn start_profiling(&self) {
+        self.inner.profiler().set_enabled(true);
+    }
+
+    /// Stops collecting the executed Wasmi instructions of the [`Engine`].
+    ///
+    /// The instructions collected so far are kept until [`Engine::take_profile`]
+    /// or [`Engine::reset_profiling`] is called.
+    pub fn stop_profiling(&self) {
+        self.inner.profiler().set_enabled(false);
+    }
+
+    /// Discards all Wasmi instructions collected so far by the [`Engine`].
+    pub fn reset_profiling(&self) {
+        self.inner.profiler().reset();
+    }
+
+    /// Returns a [`ProfileReport`] of the Wasmi instructions collected so far and discards them.
+    ///
+    /// Returns `None` if no instructions have been collected since the last reset.
+    pub fn take_profile(&self) -> Option<ProfileReport> {
+        self.inner.profiler().take()
+    }
+}
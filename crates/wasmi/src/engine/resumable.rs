@@ -45,6 +45,26 @@ impl ResumableCall {
             ResumableCallBase::Resumable(invocation) => Self::Resumable(invocation),
         }
     }
+
+    /// Returns `true` if the resumable call has finished properly.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Finished)
+    }
+
+    /// Returns `true` if the resumable call encountered a host error and can be resumed.
+    pub fn is_host_trap(&self) -> bool {
+        matches!(self, Self::Resumable(_))
+    }
+
+    /// Returns the [`ResumableInvocation`] if the resumable call encountered a host error.
+    ///
+    /// Returns `None` if the resumable call has finished properly.
+    pub fn into_host_trap(self) -> Option<ResumableInvocation> {
+        match self {
+            Self::Finished => None,
+            Self::Resumable(invocation) => Some(invocation),
+        }
+    }
 }
 
 /// State required to resume a [`Func`] invocation.
@@ -251,6 +271,26 @@ impl<Results> TypedResumableCall<Results> {
             }
         }
     }
+
+    /// Returns `true` if the resumable call has finished properly.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Finished(_))
+    }
+
+    /// Returns `true` if the resumable call encountered a host error and can be resumed.
+    pub fn is_host_trap(&self) -> bool {
+        matches!(self, Self::Resumable(_))
+    }
+
+    /// Returns the [`TypedResumableInvocation`] if the resumable call encountered a host error.
+    ///
+    /// Returns `None` if the resumable call has finished properly.
+    pub fn into_host_trap(self) -> Option<TypedResumableInvocation<Results>> {
+        match self {
+            Self::Finished(_) => None,
+            Self::Resumable(invocation) => Some(invocation),
+        }
+    }
 }
 
 /// State required to resume a [`TypedFunc`] invocation.
@@ -1,5 +1,7 @@
 mod host_calls;
 mod many_inout;
+mod proposals;
+mod stack_pooling;
 
 use super::{
     code_map::{CompiledFuncRef, EngineFunc},
@@ -114,3 +116,22 @@ impl EngineInner {
         self.resolve_func(func, |func| func.consts().iter().rev().nth(index).copied())
     }
 }
+
+#[test]
+fn engine_ref_counts() {
+    let engine = Engine::default();
+    assert_eq!(engine.strong_count(), 1);
+    assert_eq!(engine.weak_count(), 0);
+    let cloned = engine.clone();
+    assert_eq!(engine.strong_count(), 2);
+    let weak = engine.weak();
+    assert_eq!(engine.weak_count(), 1);
+    drop(cloned);
+    assert_eq!(engine.strong_count(), 1);
+    let upgraded = weak.upgrade().unwrap();
+    assert_eq!(engine.strong_count(), 2);
+    drop(upgraded);
+    drop(weak);
+    assert_eq!(engine.strong_count(), 1);
+    assert_eq!(engine.weak_count(), 0);
+}
@@ -0,0 +1,42 @@
+use crate::{Config, Engine, WasmProposal};
+
+#[test]
+fn default_proposals() {
+    let engine = Engine::default();
+    for proposal in [
+        WasmProposal::MutableGlobal,
+        WasmProposal::SignExtension,
+        WasmProposal::SaturatingFloatToInt,
+        WasmProposal::MultiValue,
+        WasmProposal::MultiMemory,
+        WasmProposal::BulkMemory,
+        WasmProposal::ReferenceTypes,
+        WasmProposal::TailCall,
+        WasmProposal::ExtendedConst,
+    ] {
+        assert!(engine.is_enabled(proposal), "{proposal:?} must be enabled");
+    }
+    for proposal in [
+        WasmProposal::Simd,
+        WasmProposal::RelaxedSimd,
+        WasmProposal::Memory64,
+        WasmProposal::Threads,
+        WasmProposal::ExceptionHandling,
+        WasmProposal::Gc,
+    ] {
+        assert!(
+            !engine.is_enabled(proposal),
+            "{proposal:?} must be disabled"
+        );
+    }
+}
+
+#[test]
+fn disabled_proposals() {
+    let mut config = Config::default();
+    config.wasm_tail_call(false).wasm_reference_types(false);
+    let engine = Engine::new(&config);
+    assert!(!engine.is_enabled(WasmProposal::TailCall));
+    assert!(!engine.is_enabled(WasmProposal::ReferenceTypes));
+    assert!(engine.is_enabled(WasmProposal::BulkMemory));
+}
@@ -0,0 +1,54 @@
+use crate::{Config, Engine, Linker, Module, Store};
+
+/// Calls a simple Wasm function `n` times and returns the amount of pooled stacks afterwards.
+fn pooled_stacks_after_calls(config: &Config, n: usize) -> usize {
+    let wasm = r#"
+        (module
+            (func (export "inc") (param i32) (result i32)
+                (i32.add (local.get 0) (i32.const 1))
+            )
+        )
+    "#;
+    let engine = Engine::new(config);
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let inc = instance.get_typed_func::<i32, i32>(&store, "inc").unwrap();
+    for i in 0..n {
+        assert_eq!(inc.call(&mut store, i as i32).unwrap(), i as i32 + 1);
+    }
+    let pooled = engine.inner.stacks.lock().stacks.len();
+    pooled
+}
+
+#[test]
+fn stack_pooling_enabled_by_default() {
+    assert_eq!(pooled_stacks_after_calls(&Config::default(), 10), 1);
+}
+
+#[test]
+fn stack_pooling_disabled() {
+    let mut config = Config::default();
+    config.enable_stack_pooling(false);
+    assert_eq!(pooled_stacks_after_calls(&config, 10), 0);
+}
+
+#[test]
+fn stack_pooling_reenabled() {
+    let mut config = Config::default();
+    config
+        .enable_stack_pooling(false)
+        .enable_stack_pooling(true);
+    assert_eq!(pooled_stacks_after_calls(&config, 10), 1);
+}
+
+#[test]
+fn stack_pooling_keeps_cached_stacks() {
+    let mut config = Config::default();
+    config.set_cached_stacks(5).enable_stack_pooling(true);
+    assert_eq!(config.cached_stacks(), 5);
+}
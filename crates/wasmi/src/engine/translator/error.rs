@@ -29,6 +29,11 @@ pub enum TranslationError {
     TooManyFunctionParams,
     /// The function failed to compiled lazily.
     LazyCompilationFailed,
+    /// Encountered a passive data segment while the `bulk-memory` proposal is disabled.
+    PassiveDataSegment {
+        /// The offset of the passive data segment within the Wasm binary.
+        offset: usize,
+    },
 }
 
 impl TranslationError {
@@ -103,6 +108,12 @@ impl Display for TranslationError {
                     "lazy function compilation encountered a Wasm validation or translation error"
                 )
             }
+            Self::PassiveDataSegment { offset } => {
+                write!(
+                    f,
+                    "bulk memory must be enabled for passive data segments (at offset 0x{offset:x})"
+                )
+            }
         }
     }
 }
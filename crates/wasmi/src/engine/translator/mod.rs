@@ -58,7 +58,7 @@ use crate::{
         ShiftAmount,
         Sign,
     },
-    module::{FuncIdx, FuncTypeIdx, ModuleHeader},
+    module::{FuncIdx, FuncTypeIdx, ModuleHeader, TableIdx},
     Engine,
     Error,
     ExternRef,
@@ -787,6 +787,15 @@ impl FuncTranslator {
             .resolve_func_type(dedup_func_type, Clone::clone)
     }
 
+    /// Returns `true` if `index` is always in bounds of the indexed table.
+    ///
+    /// This is the case if `index` is less than the minimum size of the table
+    /// since tables never shrink.
+    fn is_table_index_in_bounds(&self, table_index: u32, index: u32) -> bool {
+        let table_type = self.module.get_type_of_table(TableIdx::from(table_index));
+        index < table_type.minimum()
+    }
+
     /// Returns `true` if the code at the current translation position is reachable.
     fn is_reachable(&self) -> bool {
         self.reachable
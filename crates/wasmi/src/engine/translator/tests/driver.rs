@@ -57,6 +57,17 @@ impl ExpectedFunc {
         }
     }
 
+    /// Create a new [`ExpectedFunc`] from its Wasmi IR text format representation.
+    ///
+    /// # Panics
+    ///
+    /// If `text` is not a valid Wasmi IR text format representation.
+    pub fn from_text(text: &str) -> Self {
+        let instrs = crate::ir::parse_ops(text)
+            .unwrap_or_else(|error| panic!("failed to parse expected instructions: {error}"));
+        Self::new(instrs)
+    }
+
     /// Add expected function local constant values to this [`ExpectedFunc`].
     ///
     /// # Note
@@ -209,6 +220,15 @@ impl TranslationTest {
         self.expect_func(ExpectedFunc::new(instrs))
     }
 
+    /// Add an expected function with its instructions in the Wasmi IR text format.
+    ///
+    /// # Note
+    ///
+    /// This is a convenience method for [`TranslationTest::expect_func`] with [`ExpectedFunc::from_text`].
+    pub fn expect_func_text(&mut self, text: &str) -> &mut Self {
+        self.expect_func(ExpectedFunc::from_text(text))
+    }
+
     /// Add an [`ExpectedFunc`].
     pub fn expect_func(&mut self, func: ExpectedFunc) -> &mut Self {
         self.expected_funcs.push(func);
@@ -1,6 +1,6 @@
 use super::*;
 
-use crate::{core::ValType, ir::index::Global};
+use crate::core::ValType;
 use core::fmt::Display;
 use wasm_type::WasmTy;
 
@@ -23,10 +23,12 @@ where
     "#,
     );
     TranslationTest::new(&wasm)
-        .expect_func_instrs([
-            Instruction::global_set(Reg::from(0), Global::from(0)),
-            Instruction::Return,
-        ])
+        .expect_func_text(
+            "
+            GlobalSet input=r0 global=0
+            Return
+            ",
+        )
         .run()
 }
 
@@ -59,10 +61,12 @@ where
     );
     TranslationTest::new(&wasm)
         .expect_func(
-            ExpectedFunc::new([
-                Instruction::global_set(Reg::from(-1), Global::from(0)),
-                Instruction::Return,
-            ])
+            ExpectedFunc::from_text(
+                "
+                GlobalSet input=r-1 global=0
+                Return
+                ",
+            )
             .consts([value]),
         )
         .run()
@@ -95,13 +99,13 @@ fn test_i32imm16(value: i32) {
         )
     "#,
     );
-    let imm16 = <Const16<i32>>::try_from(value)
-        .unwrap_or_else(|_| panic!("cannot convert `value` to 16-bit encoding: {value}"));
     TranslationTest::new(&wasm)
-        .expect_func_instrs([
-            Instruction::global_set_i32imm16(imm16, Global::from(0)),
-            Instruction::Return,
-        ])
+        .expect_func_text(&format!(
+            "
+            GlobalSetI32Imm16 input={value} global=0
+            Return
+            "
+        ))
         .run()
 }
 
@@ -129,13 +133,13 @@ fn test_i64imm16(value: i64) {
         )
     "#,
     );
-    let imm16 = <Const16<i64>>::try_from(value)
-        .unwrap_or_else(|_| panic!("cannot convert `value` to 16-bit encoding: {value}"));
     TranslationTest::new(&wasm)
-        .expect_func_instrs([
-            Instruction::global_set_i64imm16(imm16, Global::from(0)),
-            Instruction::Return,
-        ])
+        .expect_func_text(&format!(
+            "
+            GlobalSetI64Imm16 input={value} global=0
+            Return
+            "
+        ))
         .run()
 }
 
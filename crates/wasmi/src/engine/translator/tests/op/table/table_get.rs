@@ -42,9 +42,13 @@ fn test_imm(ty: ValType, index: u32) {
             )
         )",
     );
+    let table_get = match index < 10 {
+        true => Instruction::table_get_imm_in_bounds(Reg::from(0), index),
+        false => Instruction::table_get_imm(Reg::from(0), index),
+    };
     TranslationTest::new(&wasm)
         .expect_func_instrs([
-            Instruction::table_get_imm(Reg::from(0), index),
+            table_get,
             Instruction::table_index(0),
             Instruction::return_reg(Reg::from(0)),
         ])
@@ -60,5 +64,34 @@ fn imm() {
     }
     test_for(0);
     test_for(1);
+    test_for(9);
+    test_for(10);
     test_for(u32::MAX);
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn imm_imported_table() {
+    let wasm = r#"
+        (module
+            (import "env" "table" (table $t 2 funcref))
+            (func (result funcref)
+                (table.get $t (i32.const 1))
+            )
+            (func (result funcref)
+                (table.get $t (i32.const 2))
+            )
+        )"#;
+    TranslationTest::new(wasm)
+        .expect_func_instrs([
+            Instruction::table_get_imm_in_bounds(Reg::from(0), 1_u32),
+            Instruction::table_index(0),
+            Instruction::return_reg(Reg::from(0)),
+        ])
+        .expect_func_instrs([
+            Instruction::table_get_imm(Reg::from(0), 2_u32),
+            Instruction::table_index(0),
+            Instruction::return_reg(Reg::from(0)),
+        ])
+        .run();
+}
@@ -1,6 +1,14 @@
 use super::*;
 use crate::core::ValType;
 
+/// Returns the expected `table.set` [`Instruction`] with constant `index` for tables with 10 elements.
+fn table_set_at(value: Reg, index: u32) -> Instruction {
+    match index < 10 {
+        true => Instruction::table_set_at_in_bounds(value, index),
+        false => Instruction::table_set_at(value, index),
+    }
+}
+
 fn test_reg(ty: ValType) {
     let display_ty = DisplayValueType::from(ty);
     let wasm = format!(
@@ -46,7 +54,7 @@ fn test_reg_at(index: u32, value_type: ValType) {
     );
     TranslationTest::new(&wasm)
         .expect_func_instrs([
-            Instruction::table_set_at(Reg::from(0), index),
+            table_set_at(Reg::from(0), index),
             Instruction::table_index(0),
             Instruction::Return,
         ])
@@ -61,6 +69,8 @@ fn reg_at() {
         test_reg_at(index, ValType::ExternRef);
     }
     test_for(0);
+    test_for(9);
+    test_for(10);
     test_for(u32::MAX);
 }
 
@@ -99,7 +109,7 @@ fn test_at_imm_funcref(index: u32) {
     TranslationTest::new(&wasm)
         .expect_func_instrs([
             Instruction::ref_func(Reg::from(0), 0),
-            Instruction::table_set_at(Reg::from(0), index),
+            table_set_at(Reg::from(0), index),
             Instruction::table_index(0),
             Instruction::Return,
         ])
@@ -110,6 +120,8 @@ fn test_at_imm_funcref(index: u32) {
 #[cfg_attr(miri, ignore)]
 fn at_imm_funcref() {
     test_at_imm_funcref(0);
+    test_at_imm_funcref(9);
+    test_at_imm_funcref(10);
     test_at_imm_funcref(u32::MAX);
 }
 
@@ -167,7 +179,7 @@ fn test_at_imm_null(index: u32, value_type: ValType) {
     TranslationTest::new(&wasm)
         .expect_func(
             ExpectedFunc::new([
-                Instruction::table_set_at(Reg::from(-1), index),
+                table_set_at(Reg::from(-1), index),
                 Instruction::table_index(0),
                 Instruction::Return,
             ])
@@ -184,5 +196,7 @@ fn at_imm_null() {
         test_at_imm_null(index, ValType::ExternRef);
     }
     test_for(0);
+    test_for(9);
+    test_for(10);
     test_for(u32::MAX);
 }
@@ -19,6 +19,7 @@ use crate::{
     engine::{
         translator::{AcquiredTarget, Provider},
         BlockType,
+        EnforcedLimitsError,
         FuelCosts,
     },
     ir::{self, index, index::FuncType, BoundedRegSpan, Const16, Instruction, Reg},
@@ -468,6 +469,14 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
     }
 
     fn visit_br_table(&mut self, targets: wasmparser::BrTable<'a>) -> Self::Output {
+        if let Some(limit) = self.module.enforced_limits().max_br_table_targets {
+            if targets.len() > limit {
+                return Err(Error::from(EnforcedLimitsError::TooManyBrTableTargets {
+                    limit,
+                    value: targets.len(),
+                }));
+            }
+        }
         bail_unreachable!(self);
         self.translate_br_table(targets)
     }
@@ -3306,10 +3315,12 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
                 self.push_fueled_instr(Instruction::table_get(result, index), FuelCosts::entity)?;
             }
             TypedProvider::Const(index) => {
-                self.push_fueled_instr(
-                    Instruction::table_get_imm(result, u32::from(index)),
-                    FuelCosts::entity,
-                )?;
+                let index = u32::from(index);
+                let instr = match self.is_table_index_in_bounds(table, index) {
+                    true => Instruction::table_get_imm_in_bounds(result, index),
+                    false => Instruction::table_get_imm(result, index),
+                };
+                self.push_fueled_instr(instr, FuelCosts::entity)?;
             }
         }
         self.alloc
@@ -3327,7 +3338,13 @@ impl<'a> VisitOperator<'a> for FuncTranslator {
         };
         let instr = match index {
             TypedProvider::Register(index) => Instruction::table_set(index, value),
-            TypedProvider::Const(index) => Instruction::table_set_at(value, u32::from(index)),
+            TypedProvider::Const(index) => {
+                let index = u32::from(index);
+                match self.is_table_index_in_bounds(table, index) {
+                    true => Instruction::table_set_at_in_bounds(value, index),
+                    false => Instruction::table_set_at(value, index),
+                }
+            }
         };
         self.push_fueled_instr(instr, FuelCosts::entity)?;
         self.alloc
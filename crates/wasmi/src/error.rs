@@ -1,17 +1,22 @@
 use super::errors::{
     EnforcedLimitsError,
+    FloatingPointError,
     FuelError,
     FuncError,
     GlobalError,
+    IndirectCallError,
     InstantiationError,
     IrError,
     LinkerError,
     MemoryError,
+    ModuleOptionsError,
+    SnapshotError,
+    StackOverflowError,
     TableError,
 };
 use crate::{
     core::{HostError, TrapCode},
-    engine::{ResumableHostError, TranslationError},
+    engine::{ResumableHostError, ResumableOutOfFuelError, TranslationError},
     module::ReadError,
 };
 use alloc::{boxed::Box, string::String};
@@ -79,6 +84,29 @@ impl Error {
     }
 
     /// Returns a reference to [`TrapCode`] if [`Error`] is a [`TrapCode`].
+    ///
+    /// # Note
+    ///
+    /// All failures that are caused by the executed Wasm code are reported as [`TrapCode`]:
+    ///
+    /// - Wasm traps such as `unreachable`, out of bounds memory or table accesses,
+    ///   integer division by zero or bad signatures of indirect calls map to their
+    ///   respective [`TrapCode`].
+    /// - Exhausting the call stack or the value stack yields [`TrapCode::StackOverflow`].
+    ///   Its cause is available via [`ErrorKind::StackOverflow`].
+    /// - Running out of fuel, also during lazy compilation, yields [`TrapCode::OutOfFuel`].
+    /// - Growth operations for which the installed [`ResourceLimiter`] returns an error,
+    ///   for example via [`StoreLimitsBuilder::trap_on_grow_failure`], yield
+    ///   [`TrapCode::GrowthOperationLimited`].
+    ///
+    /// Failures to compile a lazily compiled function upon its first call are not [`TrapCode`]s
+    /// but reported as the original compilation error, e.g. [`ErrorKind::Wasm`].
+    ///
+    /// Errors that are returned by host functions, such as [`Error::i32_exit`] or custom
+    /// [`HostError`]s, as well as errors raised by the embedder API are not [`TrapCode`]s.
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    /// [`StoreLimitsBuilder::trap_on_grow_failure`]: crate::StoreLimitsBuilder::trap_on_grow_failure
     pub fn as_trap_code(&self) -> Option<TrapCode> {
         self.kind().as_trap_code()
     }
@@ -139,6 +167,16 @@ impl Error {
         }
         Err(self)
     }
+
+    pub(crate) fn into_resumable_out_of_fuel(self) -> Result<ResumableOutOfFuelError, Error> {
+        if matches!(&*self.kind, ErrorKind::ResumableOutOfFuel(_)) {
+            let ErrorKind::ResumableOutOfFuel(error) = *self.kind else {
+                unreachable!("asserted that out of fuel error is resumable")
+            };
+            return Ok(error);
+        }
+        Err(self)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -171,6 +209,15 @@ pub enum ErrorKind {
     /// actually reach user code thus we hide its documentation.
     #[doc(hidden)]
     ResumableHost(ResumableHostError),
+    /// An error upon running out of fuel with resumable state information.
+    ///
+    /// # Note
+    ///
+    /// This variant is meant for internal uses only in order to store data necessary
+    /// to resume execution after more fuel has been added. This should never
+    /// actually reach user code thus we hide its documentation.
+    #[doc(hidden)]
+    ResumableOutOfFuel(ResumableOutOfFuelError),
     /// A global variable error.
     Global(GlobalError),
     /// A linear memory error.
@@ -183,8 +230,18 @@ pub enum ErrorKind {
     Instantiation(InstantiationError),
     /// A fuel error.
     Fuel(FuelError),
+    /// A store snapshot error.
+    Snapshot(SnapshotError),
     /// A function error.
     Func(FuncError),
+    /// A trap of a Wasm `call_indirect` with information about its cause.
+    ///
+    /// Use [`ErrorKind::as_trap_code`] to query its [`TrapCode`].
+    IndirectCall(IndirectCallError),
+    /// A trap upon exhausting the Wasm stack with information about its cause.
+    ///
+    /// Use [`ErrorKind::as_trap_code`] to query its [`TrapCode`].
+    StackOverflow(StackOverflowError),
     /// Encountered when there is a problem with the Wasm input stream.
     Read(ReadError),
     /// Encountered when there is a Wasm parsing or validation error.
@@ -193,6 +250,10 @@ pub enum ErrorKind {
     Translation(TranslationError),
     /// Encountered when an enforced limit is exceeded.
     Limits(EnforcedLimitsError),
+    /// Encountered when [`ModuleOptions`](crate::ModuleOptions) loosen the [`Config`](crate::Config).
+    ModuleOptions(ModuleOptionsError),
+    /// Encountered when floating point is used while denied by the [`Config`](crate::Config).
+    FloatingPoint(FloatingPointError),
     /// Encountered for Wasmi bytecode related errors.
     Ir(IrError),
     /// Encountered an error from the `wat` crate.
@@ -202,9 +263,18 @@ pub enum ErrorKind {
 
 impl ErrorKind {
     /// Returns a reference to [`TrapCode`] if [`ErrorKind`] is a [`TrapCode`].
+    ///
+    /// # Note
+    ///
+    /// This also returns the [`TrapCode`] of traps that carry information about their
+    /// cause, such as [`ErrorKind::IndirectCall`] or [`ErrorKind::StackOverflow`], which
+    /// are not reported as [`ErrorKind::TrapCode`]. Prefer this over matching on
+    /// [`ErrorKind::TrapCode`].
     pub fn as_trap_code(&self) -> Option<TrapCode> {
         match self {
             Self::TrapCode(trap_code) => Some(*trap_code),
+            Self::IndirectCall(error) => Some(error.trap_code()),
+            Self::StackOverflow(error) => Some(error.trap_code()),
             _ => None,
         }
     }
@@ -257,13 +327,19 @@ impl Display for ErrorKind {
             Self::Table(error) => Display::fmt(error, f),
             Self::Linker(error) => Display::fmt(error, f),
             Self::Func(error) => Display::fmt(error, f),
+            Self::IndirectCall(error) => Display::fmt(error, f),
+            Self::StackOverflow(error) => Display::fmt(error, f),
             Self::Instantiation(error) => Display::fmt(error, f),
             Self::Fuel(error) => Display::fmt(error, f),
+            Self::Snapshot(error) => Display::fmt(error, f),
             Self::Read(error) => Display::fmt(error, f),
             Self::Wasm(error) => Display::fmt(error, f),
             Self::Translation(error) => Display::fmt(error, f),
             Self::Limits(error) => Display::fmt(error, f),
+            Self::ModuleOptions(error) => Display::fmt(error, f),
+            Self::FloatingPoint(error) => Display::fmt(error, f),
             Self::ResumableHost(error) => Display::fmt(error, f),
+            Self::ResumableOutOfFuel(error) => Display::fmt(error, f),
             Self::Ir(error) => Display::fmt(error, f),
             #[cfg(feature = "wat")]
             Self::Wat(error) => Display::fmt(error, f),
@@ -295,9 +371,15 @@ impl_from! {
     impl From<WasmError> for Error::Wasm;
     impl From<ReadError> for Error::Read;
     impl From<FuelError> for Error::Fuel;
+    impl From<SnapshotError> for Error::Snapshot;
     impl From<FuncError> for Error::Func;
+    impl From<IndirectCallError> for Error::IndirectCall;
+    impl From<StackOverflowError> for Error::StackOverflow;
     impl From<EnforcedLimitsError> for Error::Limits;
+    impl From<ModuleOptionsError> for Error::ModuleOptions;
+    impl From<FloatingPointError> for Error::FloatingPoint;
     impl From<ResumableHostError> for Error::ResumableHost;
+    impl From<ResumableOutOfFuelError> for Error::ResumableOutOfFuel;
     impl From<IrError> for Error::Ir;
 }
 #[cfg(feature = "wat")]
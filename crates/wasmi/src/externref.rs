@@ -173,6 +173,11 @@ impl ExternRef {
         Self { inner: None }
     }
 
+    /// Returns the underlying [`ExternObject`] if [`ExternRef`] is not `null`.
+    pub(crate) fn as_object(&self) -> Option<&ExternObject> {
+        self.inner.as_ref()
+    }
+
     /// Returns a shared reference to the underlying data for this [`ExternRef`].
     ///
     /// # Panics
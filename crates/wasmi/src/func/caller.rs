@@ -34,16 +34,36 @@ impl<'a, T> Caller<'a, T> {
             .and_then(|instance| instance.get_export(self, name))
     }
 
+    /// Returns the [`Instance`] of the caller if any.
+    ///
+    /// Returns `None` if the host function was not called from Wasm,
+    /// e.g. when it was called directly from the host side.
+    pub fn instance(&self) -> Option<Instance> {
+        self.instance
+    }
+
     /// Returns a shared reference to the user provided host data.
+    ///
+    /// Same as [`Store::data`](crate::Store::data).
     pub fn data(&self) -> &T {
         self.ctx.store.data()
     }
 
     /// Returns an exclusive reference to the user provided host data.
+    ///
+    /// Same as [`Store::data_mut`](crate::Store::data_mut).
     pub fn data_mut(&mut self) -> &mut T {
         self.ctx.store.data_mut()
     }
 
+    /// Converts the [`Caller`] into the [`StoreContextMut`] of the calling [`Store`](crate::Store).
+    ///
+    /// This allows to use the full [`StoreContextMut`] API for the remaining lifetime
+    /// of the [`Caller`] at the cost of losing access to the [`Caller::instance`].
+    pub fn into_store_context_mut(self) -> StoreContextMut<'a, T> {
+        self.ctx
+    }
+
     /// Returns a shared reference to the used [`Engine`].
     pub fn engine(&self) -> &Engine {
         self.ctx.store.engine()
@@ -62,7 +82,13 @@ impl<'a, T> Caller<'a, T> {
 
     /// Sets the remaining fuel of the [`Store`](crate::Store) to `value` if fuel metering is enabled.
     ///
-    /// For more information see [`Store::get_fuel`](crate::Store::set_fuel).
+    /// For more information see [`Store::set_fuel`](crate::Store::set_fuel).
+    ///
+    /// # Note
+    ///
+    /// The new amount of fuel is used by the remainder of the current Wasm execution
+    /// once the host function returns. This can be used to grant bonus fuel to or to
+    /// withdraw fuel from the calling Wasm code.
     ///
     /// # Errors
     ///
@@ -70,6 +96,33 @@ impl<'a, T> Caller<'a, T> {
     pub fn set_fuel(&mut self, fuel: u64) -> Result<(), Error> {
         self.ctx.store.set_fuel(fuel)
     }
+
+    /// Adds `delta` fuel to the remaining fuel of the [`Store`](crate::Store) if fuel metering is enabled.
+    ///
+    /// For more information see [`Store::add_fuel`](crate::Store::add_fuel).
+    ///
+    /// # Errors
+    ///
+    /// If fuel metering is disabled.
+    pub fn add_fuel(&mut self, delta: u64) -> Result<u64, Error> {
+        self.ctx.store.add_fuel(delta)
+    }
+
+    /// Consumes `delta` fuel from the remaining fuel of the [`Store`](crate::Store) if fuel metering is enabled.
+    ///
+    /// For more information see [`Store::consume_fuel`](crate::Store::consume_fuel).
+    ///
+    /// # Note
+    ///
+    /// This can be used by host functions to charge the calling Wasm code for their work.
+    ///
+    /// # Errors
+    ///
+    /// - If fuel metering is disabled.
+    /// - If the remaining fuel is less than `delta`.
+    pub fn consume_fuel(&mut self, delta: u64) -> Result<u64, Error> {
+        self.ctx.store.consume_fuel(delta)
+    }
 }
 
 impl<T> AsContext for Caller<'_, T> {
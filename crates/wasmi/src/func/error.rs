@@ -1,3 +1,4 @@
+use crate::{core::TrapCode, FuncType};
 use core::{fmt, fmt::Display};
 
 /// Errors that can occur upon type checking function signatures.
@@ -13,6 +14,8 @@ pub enum FuncError {
     MismatchingResultType,
     /// Specified an incorrect number of results.
     MismatchingResultLen,
+    /// The function or one of its parameters does not belong to the store used for the call.
+    CrossStore,
 }
 
 #[cfg(feature = "std")]
@@ -36,6 +39,101 @@ impl Display for FuncError {
             FuncError::MismatchingResultLen => {
                 write!(f, "encountered an incorrect number of results")
             }
+            FuncError::CrossStore => {
+                write!(
+                    f,
+                    "encountered function or parameter from a different store"
+                )
+            }
+        }
+    }
+}
+
+/// A trap that occurred upon executing a Wasm `call_indirect` with information about its cause.
+///
+/// # Note
+///
+/// This is reported for [`TrapCode::IndirectCallToNull`] and [`TrapCode::BadSignature`]
+/// traps of `call_indirect` and its [`TrapCode`] is available via [`Error::as_trap_code`].
+///
+/// [`Error::as_trap_code`]: crate::Error::as_trap_code
+#[derive(Debug)]
+pub struct IndirectCallError {
+    /// The trap code of the failed indirect call.
+    trap_code: TrapCode,
+    /// The index of the table within its instance.
+    table: u32,
+    /// The index of the called element within the table.
+    index: u32,
+    /// The index of the expected function type within its instance.
+    expected: u32,
+    /// The function type of the called function if not `null`.
+    actual: Option<FuncType>,
+}
+
+impl IndirectCallError {
+    /// Creates a new [`IndirectCallError`].
+    pub(crate) fn new(
+        trap_code: TrapCode,
+        table: u32,
+        index: u32,
+        expected: u32,
+        actual: Option<FuncType>,
+    ) -> Self {
+        Self {
+            trap_code,
+            table,
+            index,
+            expected,
+            actual,
+        }
+    }
+
+    /// Returns the [`TrapCode`] of the failed indirect call.
+    pub fn trap_code(&self) -> TrapCode {
+        self.trap_code
+    }
+
+    /// Returns the index of the called table within its instance.
+    pub fn table_index(&self) -> u32 {
+        self.table
+    }
+
+    /// Returns the index of the called element within the table.
+    pub fn element_index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the index of the expected function type within its instance.
+    pub fn expected_type_index(&self) -> u32 {
+        self.expected
+    }
+
+    /// Returns the [`FuncType`] of the called function if it is not `null`.
+    pub fn actual_type(&self) -> Option<&FuncType> {
+        self.actual.as_ref()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndirectCallError {}
+
+impl Display for IndirectCallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            trap_code,
+            table,
+            index,
+            expected,
+            actual,
+        } = self;
+        write!(
+            f,
+            "{trap_code}: call_indirect of element {index} of table {table} expected type {expected}"
+        )?;
+        match actual {
+            Some(actual) => write!(f, " but found {actual:?}"),
+            None => write!(f, " but found null"),
         }
     }
 }
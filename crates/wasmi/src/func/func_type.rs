@@ -220,6 +220,14 @@ impl fmt::Debug for FuncType {
 }
 
 impl FuncType {
+    /// The maximum number of parameter types allowed of a [`FuncType`].
+    #[cfg(feature = "serde")]
+    pub(crate) const MAX_LEN_PARAMS: usize = FuncTypeInner::MAX_LEN_PARAMS;
+
+    /// The maximum number of result types allowed of a [`FuncType`].
+    #[cfg(feature = "serde")]
+    pub(crate) const MAX_LEN_RESULTS: usize = FuncTypeInner::MAX_LEN_RESULTS;
+
     /// Creates a new [`FuncType`].
     pub fn new<P, R>(params: P, results: R) -> Self
     where
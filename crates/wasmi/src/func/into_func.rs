@@ -10,6 +10,7 @@ use crate::{
     FuncRef,
     FuncType,
 };
+use alloc::sync::Arc;
 use core::{array, iter::FusedIterator};
 
 /// Closures and functions that can be used as host functions.
@@ -42,16 +43,20 @@ macro_rules! impl_into_func {
 
             #[allow(non_snake_case)]
             fn into_func(self) -> (FuncType, TrampolineEntity<T>) {
-                IntoFunc::into_func(
-                    move |
-                        _: Caller<'_, T>,
-                        $(
-                            $tuple: $tuple,
-                        )*
-                    | {
-                        (self)($($tuple),*)
-                    }
-                )
+                let signature = FuncType::new(
+                    <Self::Params as WasmTyList>::types(),
+                    <Self::Results as WasmTyList>::types(),
+                );
+                let host_fn = Arc::new(self);
+                let func = host_fn.clone();
+                let trampoline = TrampolineEntity::new(
+                    move |_: Caller<T>, params_results: FuncParams| -> Result<FuncFinished, Error> {
+                        let (($($tuple,)*), func_results): (Self::Params, FuncResults) = params_results.decode_params();
+                        let results: Self::Results = (func)($($tuple),*).into_fallible()?;
+                        Ok(func_results.encode_results(results))
+                    },
+                );
+                (signature, trampoline.with_host_fn(host_fn))
             }
         }
 
@@ -73,15 +78,17 @@ macro_rules! impl_into_func {
                     <Self::Params as WasmTyList>::types(),
                     <Self::Results as WasmTyList>::types(),
                 );
+                let host_fn = Arc::new(self);
+                let func = host_fn.clone();
                 let trampoline = TrampolineEntity::new(
                     move |caller: Caller<T>, params_results: FuncParams| -> Result<FuncFinished, Error> {
                         let (($($tuple,)*), func_results): (Self::Params, FuncResults) = params_results.decode_params();
                         let results: Self::Results =
-                            (self)(caller, $($tuple),*).into_fallible()?;
+                            (func)(caller, $($tuple),*).into_fallible()?;
                         Ok(func_results.encode_results(results))
                     },
                 );
-                (signature, trampoline)
+                (signature, trampoline.with_host_fn(host_fn))
             }
         }
     };
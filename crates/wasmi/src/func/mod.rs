@@ -5,26 +5,36 @@ mod funcref;
 mod into_func;
 mod typed_func;
 
-pub(crate) use self::typed_func::CallResultsTuple;
+pub(crate) use self::typed_func::{CallResultsTuple, TypedFuncCache};
 pub use self::{
     caller::Caller,
-    error::FuncError,
+    error::{FuncError, IndirectCallError},
     func_type::FuncType,
     funcref::FuncRef,
     into_func::{IntoFunc, WasmRet, WasmTy, WasmTyList},
     typed_func::{TypedFunc, WasmParams, WasmResults},
 };
 use super::{
-    engine::{DedupFuncType, EngineFunc, FuncFinished, FuncParams},
+    engine::{DedupFuncType, EngineFunc, FuncFinished, FuncParams, FuncResults},
     AsContext,
     AsContextMut,
     Instance,
     StoreContext,
     Stored,
 };
-use crate::{collections::arena::ArenaIndex, engine::ResumableCall, Engine, Error, Val};
+use crate::{
+    collections::arena::ArenaIndex,
+    core::ValType,
+    engine::ResumableCall,
+    store::{StoreIdx, StoreRebind},
+    Engine,
+    Error,
+    StackRecycler,
+    Val,
+};
 use alloc::{boxed::Box, sync::Arc};
-use core::{fmt, fmt::Debug, num::NonZeroU32};
+use core::{any::Any, fmt, fmt::Debug, num::NonZeroU32};
+use spin::Mutex;
 
 /// A raw index to a function entity.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -78,7 +88,7 @@ impl Trampoline {
 }
 
 /// A Wasm or host function instance.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum FuncEntity {
     /// A Wasm function.
     Wasm(WasmFuncEntity),
@@ -154,6 +164,14 @@ impl FuncEntity {
             Self::Host(func) => func.ty_dedup(),
         }
     }
+
+    /// Rebinds the entity references of the [`FuncEntity`] to the copied [`Store`].
+    pub(crate) fn rebind(&mut self, rebind: &StoreRebind) {
+        match self {
+            Self::Wasm(func) => func.instance = rebind.instance(&func.instance),
+            Self::Host(func) => func.func = rebind.trampoline(&func.func),
+        }
+    }
 }
 
 /// A Wasm function instance.
@@ -252,6 +270,64 @@ impl<T> HostFuncTrampolineEntity<T> {
         Self { ty, trampoline }
     }
 
+    /// Creates a new host function trampoline from the given statically typed `FnMut` closure.
+    ///
+    /// The parameters of `func` are passed as a single [`WasmTyList`].
+    ///
+    /// # Note
+    ///
+    /// Concurrent calls from different [`Store`]s wait for each other since `func`
+    /// is shared. Since a [`Store`] cannot be used by multiple threads at the same
+    /// time, a call while `func` is held by the same [`Store`] is re-entrant.
+    ///
+    /// # Panics (Execution)
+    ///
+    /// If the host function is called re-entrantly, e.g. when `func` calls back
+    /// into Wasm which then calls this host function again.
+    ///
+    /// [`Store`]: crate::Store
+    pub fn wrap_mut<Params, Results, F>(func: F) -> Self
+    where
+        Params: WasmTyList,
+        Results: WasmRet,
+        F: FnMut(Caller<'_, T>, Params) -> Results + Send + 'static,
+    {
+        /// Clears the [`Store`] that holds the `func` of `wrap_mut` when dropped.
+        ///
+        /// [`Store`]: crate::Store
+        struct HolderGuard<'a>(&'a Mutex<Option<StoreIdx>>);
+
+        impl Drop for HolderGuard<'_> {
+            fn drop(&mut self) {
+                *self.0.lock() = None;
+            }
+        }
+
+        let ty = FuncType::new(
+            <Params as WasmTyList>::types(),
+            <<Results as WasmRet>::Ok as WasmTyList>::types(),
+        );
+        let func = Mutex::new(func);
+        let holder = Mutex::new(None);
+        let trampoline = TrampolineEntity::new(
+            move |caller: Caller<T>, params_results: FuncParams| -> Result<FuncFinished, Error> {
+                let (params, func_results): (Params, FuncResults) = params_results.decode_params();
+                let store = caller.as_context().store.inner.store_idx();
+                if *holder.lock() == Some(store) {
+                    panic!("host function created via `wrap_mut` was called re-entrantly")
+                }
+                let mut func = func.lock();
+                *holder.lock() = Some(store);
+                // Note: The guard is dropped before `func` so that the holder is
+                //       cleared before another `Store` can acquire `func`.
+                let _holder = HolderGuard(&holder);
+                let results = (func)(caller, params).into_fallible()?;
+                Ok(func_results.encode_results(results))
+            },
+        );
+        Self { ty, trampoline }
+    }
+
     /// Returns the [`FuncType`] of the host function.
     pub fn func_type(&self) -> &FuncType {
         &self.ty
@@ -266,8 +342,14 @@ impl<T> HostFuncTrampolineEntity<T> {
 type TrampolineFn<T> =
     dyn Fn(Caller<T>, FuncParams) -> Result<FuncFinished, Error> + Send + Sync + 'static;
 
+/// The boxed host closure accepted by [`Func::new_boxed`].
+type BoxedHostFn<T> =
+    Box<dyn Fn(Caller<'_, T>, &[Val], &mut [Val]) -> Result<(), Error> + Send + Sync + 'static>;
+
 pub struct TrampolineEntity<T> {
     closure: Arc<TrampolineFn<T>>,
+    /// The original closure of the host function if created via [`Func::wrap`].
+    host_fn: Option<Arc<dyn Any + Send + Sync>>,
 }
 
 impl<T> Debug for TrampolineEntity<T> {
@@ -284,9 +366,21 @@ impl<T> TrampolineEntity<T> {
     {
         Self {
             closure: Arc::new(trampoline),
+            host_fn: None,
         }
     }
 
+    /// Attaches the original `host_fn` closure to the [`TrampolineEntity`].
+    pub(crate) fn with_host_fn(mut self, host_fn: Arc<dyn Any + Send + Sync>) -> Self {
+        self.host_fn = Some(host_fn);
+        self
+    }
+
+    /// Returns the original closure of the host function if it is of type `F`.
+    pub fn host_fn<F: 'static>(&self) -> Option<&F> {
+        self.host_fn.as_deref()?.downcast_ref::<F>()
+    }
+
     /// Calls the host function trampoline with the given inputs.
     ///
     /// The result is written back into the `outputs` buffer.
@@ -305,6 +399,7 @@ impl<T> Clone for TrampolineEntity<T> {
     fn clone(&self) -> Self {
         Self {
             closure: self.closure.clone(),
+            host_fn: self.host_fn.clone(),
         }
     }
 }
@@ -325,6 +420,24 @@ impl Func {
         &self.0
     }
 
+    /// Returns `true` if this [`Func`] originates from `store`.
+    ///
+    /// This allows to check if the [`Func`] can be used with `store`
+    /// before calling methods that would otherwise panic.
+    pub fn is_from_store(&self, store: impl AsContext) -> bool {
+        store.as_context().store.inner.owns(self.as_inner())
+    }
+
+    /// Returns `true` if both [`Func`] references `a` and `b` refer to the same [`Func`].
+    ///
+    /// # Note
+    ///
+    /// This is useful to check which [`Func`] is stored in a table slot,
+    /// for example via [`FuncRef::func`].
+    pub fn same(a: &Func, b: &Func) -> bool {
+        a.as_inner() == b.as_inner()
+    }
+
     /// Creates a new [`Func`] with the given arguments.
     ///
     /// This is typically used to create a host-defined function to pass as an import to a Wasm module.
@@ -363,6 +476,28 @@ impl Func {
             .alloc_func(host_func.into())
     }
 
+    /// Creates a new [`Func`] with the given arguments from a boxed closure.
+    ///
+    /// This works the same as [`Func::new`] but accepts the host closure as boxed trait
+    /// object so that host functions can be constructed at runtime, for example from
+    /// reflection data of a scripting language, and stored in collections of
+    /// heterogeneous host functions before they are turned into [`Func`]s.
+    ///
+    /// # Note
+    ///
+    /// - The same notes as for [`Func::new`] apply.
+    /// - Every call of the resulting [`Func`] dynamically dispatches through the box in
+    ///   addition to the untyped parameter and result conversions of [`Func::new`].
+    ///   Prefer [`Func::wrap`] if the signature of the host function is known at compile
+    ///   time since it avoids both kinds of overhead.
+    pub fn new_boxed<T: 'static>(
+        ctx: impl AsContextMut<Data = T>,
+        ty: FuncType,
+        func: BoxedHostFn<T>,
+    ) -> Self {
+        Self::new(ctx, ty, func)
+    }
+
     /// Creates a new host function from the given closure.
     pub fn wrap<T, Params, Results>(
         mut ctx: impl AsContextMut<Data = T>,
@@ -379,6 +514,35 @@ impl Func {
             .alloc_func(host_func.into())
     }
 
+    /// Returns a shared reference to the original closure of the host [`Func`] if it is of type `F`.
+    ///
+    /// This allows to call host functions directly from Rust without
+    /// going through the Wasm calling convention.
+    ///
+    /// Returns `None` if:
+    ///
+    /// - the [`Func`] is a Wasm function,
+    /// - the [`Func`] was not created via [`Func::wrap`] or [`Linker::func_wrap`],
+    /// - the closure of the [`Func`] is not of type `F`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Func`].
+    ///
+    /// [`Linker::func_wrap`]: crate::Linker::func_wrap
+    pub fn downcast_host_fn<'a, T: 'a, F: 'static>(
+        &self,
+        ctx: impl Into<StoreContext<'a, T>>,
+    ) -> Option<&'a F> {
+        let store = ctx.into().store;
+        let FuncEntity::Host(host_func) = store.inner.resolve_func(self) else {
+            return None;
+        };
+        store
+            .resolve_trampoline(host_func.trampoline())
+            .host_fn::<F>()
+    }
+
     /// Returns the signature of the function.
     pub(crate) fn ty_dedup<'a, T: 'a>(
         &self,
@@ -389,10 +553,58 @@ impl Func {
 
     /// Returns the function type of the [`Func`].
     pub fn ty(&self, ctx: impl AsContext) -> FuncType {
+        self.with_ty(ctx, FuncType::clone)
+    }
+
+    /// Returns the function type of the [`Func`].
+    ///
+    /// This is an alias of [`Func::ty`] that mirrors [`ExternType::func`].
+    ///
+    /// [`ExternType::func`]: crate::ExternType::func
+    pub fn func_type(&self, ctx: impl AsContext) -> FuncType {
+        self.ty(ctx)
+    }
+
+    /// Calls `f` on the function type of the [`Func`] and returns its result.
+    ///
+    /// Unlike [`Func::ty`] this does not clone the [`FuncType`].
+    fn with_ty<R>(&self, ctx: impl AsContext, f: impl FnOnce(&FuncType) -> R) -> R {
         ctx.as_context()
             .store
             .inner
-            .resolve_func_type(self.ty_dedup(&ctx))
+            .resolve_func_type_with(self.ty_dedup(&ctx), f)
+    }
+
+    /// Returns the number of parameters of the [`Func`].
+    ///
+    /// # Note
+    ///
+    /// This is more efficient than querying the parameters of [`Func::ty`].
+    pub fn param_len(&self, ctx: impl AsContext) -> usize {
+        self.with_ty(ctx, |ty| ty.params().len())
+    }
+
+    /// Returns the number of results of the [`Func`].
+    ///
+    /// # Note
+    ///
+    /// This is more efficient than querying the results of [`Func::ty`].
+    pub fn result_len(&self, ctx: impl AsContext) -> usize {
+        self.with_ty(ctx, |ty| ty.results().len())
+    }
+
+    /// Returns `true` if the [`Func`] has the given `params` and `results` types.
+    ///
+    /// # Note
+    ///
+    /// This is more efficient than comparing against the [`FuncType`] of [`Func::ty`].
+    pub fn matches_signature(
+        &self,
+        ctx: impl AsContext,
+        params: &[ValType],
+        results: &[ValType],
+    ) -> bool {
+        self.with_ty(ctx, |ty| ty.params() == params && ty.results() == results)
     }
 
     /// Calls the Wasm or host function with the given inputs.
@@ -408,6 +620,7 @@ impl Func {
     ///   inputs required by the function signature of `self`.
     /// - If the number of output values does not match the expected number of
     ///   outputs required by the function signature of `self`.
+    /// - If `self` or any of the `inputs` does not belong to `ctx`.
     pub fn call<T>(
         &self,
         mut ctx: impl AsContextMut<Data = T>,
@@ -425,6 +638,130 @@ impl Func {
         Ok(())
     }
 
+    /// Calls the Wasm or host function with the given inputs using the stack of `recycler`.
+    ///
+    /// The result is written back into the `outputs` buffer.
+    ///
+    /// Unlike [`Func::call`] this reuses the stack held by the [`StackRecycler`]
+    /// instead of acquiring one from the [`Engine`] which reduces allocation and
+    /// synchronization overhead for frequent calls.
+    ///
+    /// # Errors
+    ///
+    /// - If the function returned a [`Error`].
+    /// - If the types of the `inputs` do not match the expected types for the
+    ///   function signature of `self`.
+    /// - If the number of input values does not match the expected number of
+    ///   inputs required by the function signature of `self`.
+    /// - If the number of output values does not match the expected number of
+    ///   outputs required by the function signature of `self`.
+    /// - If `self` or any of the `inputs` does not belong to `ctx`.
+    ///
+    /// [`Engine`]: crate::Engine
+    pub fn call_with_stack<T>(
+        &self,
+        mut ctx: impl AsContextMut<Data = T>,
+        inputs: &[Val],
+        outputs: &mut [Val],
+        recycler: &mut StackRecycler,
+    ) -> Result<(), Error> {
+        self.verify_and_prepare_inputs_outputs(ctx.as_context(), inputs, outputs)?;
+        // Note: Cloning an [`Engine`] is intentionally a cheap operation.
+        ctx.as_context()
+            .store
+            .engine()
+            .clone()
+            .execute_func_with_stack(ctx.as_context_mut(), self, inputs, outputs, recycler)?;
+        Ok(())
+    }
+
+    /// Calls the Wasm or host function with the given inputs, adding fuel on demand.
+    ///
+    /// The result is written back into the `outputs` buffer.
+    ///
+    /// Whenever the execution runs out of fuel `budget` is called with the minimum
+    /// amount of fuel required to continue. If `budget` returns `Some(fuel)` with non-zero
+    /// `fuel` then `fuel` is added to the [`Store`] and execution resumes where it ran out
+    /// of fuel. Otherwise execution traps with [`TrapCode::OutOfFuel`].
+    ///
+    /// # Note
+    ///
+    /// - Host errors are returned unchanged and cannot be resumed.
+    /// - Only running out of fuel for the execution of Wasm instruction blocks
+    ///   is resumable. Running out of fuel during bulk operations, such as
+    ///   `memory.copy`, or during lazy compilation traps as usual.
+    ///
+    /// # Errors
+    ///
+    /// - If the function returned a [`Error`].
+    /// - If `budget` returns `None` or `Some(0)` upon running out of fuel.
+    /// - If the types of the `inputs` do not match the expected types for the
+    ///   function signature of `self`.
+    /// - If the number of input values does not match the expected number of
+    ///   inputs required by the function signature of `self`.
+    /// - If the number of output values does not match the expected number of
+    ///   outputs required by the function signature of `self`.
+    /// - If `self` or any of the `inputs` does not belong to `ctx`.
+    ///
+    /// [`Store`]: crate::Store
+    /// [`TrapCode::OutOfFuel`]: crate::core::TrapCode::OutOfFuel
+    pub fn call_with_fuel_budget<T>(
+        &self,
+        mut ctx: impl AsContextMut<Data = T>,
+        inputs: &[Val],
+        outputs: &mut [Val],
+        mut budget: impl FnMut(u64) -> Option<u64>,
+    ) -> Result<(), Error> {
+        self.verify_and_prepare_inputs_outputs(ctx.as_context(), inputs, outputs)?;
+        // Note: Cloning an [`Engine`] is intentionally a cheap operation.
+        ctx.as_context()
+            .store
+            .engine()
+            .clone()
+            .execute_func_with_fuel_budget(
+                ctx.as_context_mut(),
+                self,
+                inputs,
+                outputs,
+                &mut budget,
+            )?;
+        Ok(())
+    }
+
+    /// Calls the Wasm or host function with the given inputs.
+    ///
+    /// Returns the results of the call in a newly allocated buffer that
+    /// is sized and typed according to the function signature of `self`.
+    ///
+    /// # Note
+    ///
+    /// This is a convenience wrapper around [`Func::call`] which allocates the
+    /// results buffer. Use [`Func::call`] in performance critical code to reuse
+    /// an existing results buffer.
+    ///
+    /// # Errors
+    ///
+    /// - If the function returned a [`Error`].
+    /// - If the types of the `inputs` do not match the expected types for the
+    ///   function signature of `self`.
+    /// - If the number of input values does not match the expected number of
+    ///   inputs required by the function signature of `self`.
+    pub fn call_boxed<T>(
+        &self,
+        mut ctx: impl AsContextMut<Data = T>,
+        inputs: &[Val],
+    ) -> Result<Box<[Val]>, Error> {
+        let mut outputs = self
+            .ty(&ctx)
+            .results()
+            .iter()
+            .copied()
+            .map(Val::default)
+            .collect::<Box<[Val]>>();
+        self.call(&mut ctx, inputs, &mut outputs[..])?;
+        Ok(outputs)
+    }
+
     /// Calls the Wasm or host function with the given inputs.
     ///
     /// The result is written back into the `outputs` buffer.
@@ -448,6 +785,7 @@ impl Func {
     ///   inputs required by the function signature of `self`.
     /// - If the number of output values does not match the expected number of
     ///   outputs required by the function signature of `self`.
+    /// - If `self` or any of the `inputs` does not belong to `ctx`.
     pub fn call_resumable<T>(
         &self,
         mut ctx: impl AsContextMut<Data = T>,
@@ -483,6 +821,10 @@ impl Func {
         inputs: &[Val],
         outputs: &mut [Val],
     ) -> Result<(), FuncError> {
+        let store = &ctx.as_context().store.inner;
+        if !store.owns(self.as_inner()) || !inputs.iter().all(|input| store.owns_val(input)) {
+            return Err(FuncError::CrossStore);
+        }
         let fn_type = self.ty_dedup(ctx.as_context());
         ctx.as_context()
             .store
@@ -505,15 +847,16 @@ impl Func {
     ///
     /// # Errors
     ///
-    /// If the function signature of `self` does not match `Params` and `Results`
-    /// as parameter types and result types respectively.
+    /// - If `ctx` does not own this [`Func`].
+    /// - If the function signature of `self` does not match `Params` and `Results`
+    ///   as parameter types and result types respectively.
     pub fn typed<Params, Results>(
         &self,
         ctx: impl AsContext,
     ) -> Result<TypedFunc<Params, Results>, Error>
     where
-        Params: WasmParams,
-        Results: WasmResults,
+        Params: WasmParams + 'static,
+        Results: WasmResults + 'static,
     {
         TypedFunc::new(ctx, *self)
     }
@@ -1,13 +1,16 @@
-use super::{into_func::WasmTyList, Func};
+use super::{into_func::WasmTyList, Func, FuncError};
 use crate::{
+    collections::Set,
     core::UntypedVal,
-    engine::{CallParams, CallResults},
+    engine::{CallParams, CallResults, DedupFuncType},
     AsContext,
     AsContextMut,
     Error,
+    StackRecycler,
     TypedResumableCall,
 };
-use core::{fmt, fmt::Debug, marker::PhantomData};
+use core::{any::TypeId, fmt, fmt::Debug, marker::PhantomData};
+use spin::Mutex;
 
 /// A typed [`Func`] instance.
 ///
@@ -63,16 +66,40 @@ where
     ///
     /// # Errors
     ///
-    /// If the provided static types `Params` and `Results` for the parameters
-    /// and result types of `func` mismatch the signature of `func`.
-    pub(crate) fn new(ctx: impl AsContext, func: Func) -> Result<Self, Error> {
-        let func_type = func.ty(&ctx);
-        let (actual_params, actual_results) = (
-            <Params as WasmTyList>::types(),
-            <Results as WasmTyList>::types(),
-        );
-        func_type.match_params(actual_params.as_ref())?;
-        func_type.match_results(actual_results.as_ref(), true)?;
+    /// - If `ctx` does not own `func`.
+    /// - If the provided static types `Params` and `Results` for the parameters
+    ///   and result types of `func` mismatch the signature of `func`.
+    ///
+    /// # Note
+    ///
+    /// Successful signature checks are cached by the [`Store`] so that repeatedly
+    /// creating a [`TypedFunc`] with the same signature does not resolve the
+    /// [`FuncType`] of `func` again.
+    ///
+    /// [`Store`]: crate::Store
+    /// [`FuncType`]: crate::FuncType
+    pub(crate) fn new(ctx: impl AsContext, func: Func) -> Result<Self, Error>
+    where
+        Params: 'static,
+        Results: 'static,
+    {
+        let store = &ctx.as_context().store.inner;
+        if !store.owns(func.as_inner()) {
+            return Err(Error::from(FuncError::CrossStore));
+        }
+        let func_type = *store.resolve_func(&func).ty_dedup();
+        let signature = TypeId::of::<fn(Params) -> Results>();
+        let cache = store.typed_func_cache();
+        if !cache.contains(func_type, signature) {
+            let resolved = func.ty(&ctx);
+            let (actual_params, actual_results) = (
+                <Params as WasmTyList>::types(),
+                <Results as WasmTyList>::types(),
+            );
+            resolved.match_params(actual_params.as_ref())?;
+            resolved.match_results(actual_results.as_ref(), true)?;
+            cache.insert(func_type, signature);
+        }
         Ok(Self {
             signature: PhantomData,
             func,
@@ -86,14 +113,12 @@ where
     /// For more information, see the [`Func::typed`] and [`Func::call`]
     /// documentation.
     ///
-    /// # Panics
-    ///
-    /// Panics if `ctx` does not own this [`TypedFunc`].
-    ///
     /// # Errors
     ///
-    /// If the execution of the called Wasm function traps.
+    /// - If `ctx` does not own this [`TypedFunc`].
+    /// - If the execution of the called Wasm function traps.
     pub fn call(&self, mut ctx: impl AsContextMut, params: Params) -> Result<Results, Error> {
+        self.ensure_owned_by(&ctx)?;
         // Note: Cloning an [`Engine`] is intentionally a cheap operation.
         ctx.as_context().store.engine().clone().execute_func(
             ctx.as_context_mut(),
@@ -103,6 +128,72 @@ where
         )
     }
 
+    /// Calls this Wasm or host function with the specified parameters using the stack of `recycler`.
+    ///
+    /// For more information, see [`Func::call_with_stack`].
+    ///
+    /// # Errors
+    ///
+    /// - If `ctx` does not own this [`TypedFunc`].
+    /// - If the execution of the called Wasm function traps.
+    pub fn call_with_stack(
+        &self,
+        mut ctx: impl AsContextMut,
+        params: Params,
+        recycler: &mut StackRecycler,
+    ) -> Result<Results, Error> {
+        self.ensure_owned_by(&ctx)?;
+        // Note: Cloning an [`Engine`] is intentionally a cheap operation.
+        ctx.as_context()
+            .store
+            .engine()
+            .clone()
+            .execute_func_with_stack(
+                ctx.as_context_mut(),
+                &self.func,
+                params,
+                <CallResultsTuple<Results>>::default(),
+                recycler,
+            )
+    }
+
+    /// Calls this Wasm or host function with the specified parameters, adding fuel on demand.
+    ///
+    /// Whenever the execution runs out of fuel `budget` is called with the minimum
+    /// amount of fuel required to continue. If `budget` returns `Some(fuel)` with non-zero
+    /// `fuel` then `fuel` is added to the [`Store`] and execution resumes where it ran out
+    /// of fuel. Otherwise execution traps with [`TrapCode::OutOfFuel`].
+    ///
+    /// For more information, see [`Func::call_with_fuel_budget`].
+    ///
+    /// # Errors
+    ///
+    /// - If `ctx` does not own this [`TypedFunc`].
+    /// - If the execution of the called Wasm function traps.
+    ///
+    /// [`Store`]: crate::Store
+    /// [`TrapCode::OutOfFuel`]: crate::core::TrapCode::OutOfFuel
+    pub fn call_with_fuel_budget(
+        &self,
+        mut ctx: impl AsContextMut,
+        params: Params,
+        mut budget: impl FnMut(u64) -> Option<u64>,
+    ) -> Result<Results, Error> {
+        self.ensure_owned_by(&ctx)?;
+        // Note: Cloning an [`Engine`] is intentionally a cheap operation.
+        ctx.as_context()
+            .store
+            .engine()
+            .clone()
+            .execute_func_with_fuel_budget(
+                ctx.as_context_mut(),
+                &self.func,
+                params,
+                <CallResultsTuple<Results>>::default(),
+                &mut budget,
+            )
+    }
+
     /// Calls this Wasm or host function with the specified parameters.
     ///
     /// Returns a resumable handle to the function invocation upon
@@ -117,12 +208,14 @@ where
     ///
     /// # Errors
     ///
-    /// If the function returned a [`Error`] originating from WebAssembly.
+    /// - If `ctx` does not own this [`TypedFunc`].
+    /// - If the function returned a [`Error`] originating from WebAssembly.
     pub fn call_resumable(
         &self,
         mut ctx: impl AsContextMut,
         params: Params,
     ) -> Result<TypedResumableCall<Results>, Error> {
+        self.ensure_owned_by(&ctx)?;
         // Note: Cloning an [`Engine`] is intentionally a cheap operation.
         ctx.as_context()
             .store
@@ -136,6 +229,14 @@ where
             )
             .map(TypedResumableCall::new)
     }
+
+    /// Returns an error if `ctx` does not own this [`TypedFunc`].
+    fn ensure_owned_by(&self, ctx: impl AsContext) -> Result<(), FuncError> {
+        if !ctx.as_context().store.inner.owns(self.func.as_inner()) {
+            return Err(FuncError::CrossStore);
+        }
+        Ok(())
+    }
 }
 
 impl<Params> CallParams for Params
@@ -200,3 +301,37 @@ impl<T> WasmParams for T where T: WasmTyList {}
 /// The typed results of a [`TypedFunc`].
 pub trait WasmResults: WasmTyList {}
 impl<T> WasmResults for T where T: WasmTyList {}
+
+/// Caches the successful signature checks of [`TypedFunc`] construction.
+///
+/// # Note
+///
+/// Entries are keyed by the deduplicated function type of the checked [`Func`] and
+/// the [`TypeId`] of the Rust signature of the [`TypedFunc`]. Unlike [`Func`] indices
+/// deduplicated function types are never invalidated, e.g. by [`Store::restore`].
+///
+/// [`Store::restore`]: crate::Store::restore
+#[derive(Debug, Default)]
+pub struct TypedFuncCache {
+    checked: Mutex<Set<(DedupFuncType, TypeId)>>,
+}
+
+impl Clone for TypedFuncCache {
+    fn clone(&self) -> Self {
+        Self {
+            checked: Mutex::new(self.checked.lock().clone()),
+        }
+    }
+}
+
+impl TypedFuncCache {
+    /// Returns `true` if `signature` has already been checked successfully for `func_type`.
+    fn contains(&self, func_type: DedupFuncType, signature: TypeId) -> bool {
+        self.checked.lock().contains(&(func_type, signature))
+    }
+
+    /// Records a successful check of `signature` for `func_type`.
+    fn insert(&self, func_type: DedupFuncType, signature: TypeId) {
+        self.checked.lock().insert((func_type, signature));
+    }
+}
@@ -2,6 +2,7 @@ use super::{AsContext, AsContextMut, Stored};
 use crate::{
     collections::arena::ArenaIndex,
     core::{UntypedVal, ValType},
+    store::StoreRebind,
     value::WithType,
     Val,
 };
@@ -44,6 +45,8 @@ pub enum GlobalError {
         /// The required [`GlobalType`].
         required: GlobalType,
     },
+    /// Occurs when a value refers to an entity of a different store than the global variable.
+    CrossStore,
 }
 
 #[cfg(feature = "std")]
@@ -73,6 +76,12 @@ impl Display for GlobalError {
                     satisfy requirements of {required:?}",
                 )
             }
+            Self::CrossStore => {
+                write!(
+                    f,
+                    "encountered global variable value from a different store"
+                )
+            }
         }
     }
 }
@@ -144,7 +153,7 @@ impl GlobalType {
 }
 
 /// A global variable entity.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GlobalEntity {
     /// The current value of the global variable.
     value: UntypedVal,
@@ -176,7 +185,7 @@ impl GlobalEntity {
         if !self.ty().mutability().is_mut() {
             return Err(GlobalError::ImmutableWrite);
         }
-        if self.ty().content() != new_value.ty() {
+        if !new_value.matches_type(self.ty().content()) {
             return Err(GlobalError::TypeMismatch {
                 expected: self.ty().content(),
                 encountered: new_value.ty(),
@@ -212,6 +221,15 @@ impl GlobalEntity {
     pub(crate) fn get_untyped_ptr(&mut self) -> NonNull<UntypedVal> {
         NonNull::from(&mut self.value)
     }
+
+    /// Rebinds the function reference held by the global variable to the copied [`Store`].
+    ///
+    /// [`Store`]: [`crate::Store`]
+    pub(crate) fn rebind(&mut self, rebind: &StoreRebind) {
+        if matches!(self.ty.content(), ValType::FuncRef) {
+            self.value = rebind.funcref(self.value);
+        }
+    }
 }
 
 /// A Wasm global variable reference.
@@ -236,6 +254,14 @@ impl Global {
         &self.0
     }
 
+    /// Returns `true` if this [`Global`] originates from `store`.
+    ///
+    /// This allows to check if the [`Global`] can be used with `store`
+    /// before calling methods that would otherwise panic.
+    pub fn is_from_store(&self, store: impl AsContext) -> bool {
+        store.as_context().store.inner.owns(self.as_inner())
+    }
+
     /// Creates a new global variable to the store.
     pub fn new(mut ctx: impl AsContextMut, initial_value: Val, mutability: Mutability) -> Self {
         ctx.as_context_mut()
@@ -249,22 +275,36 @@ impl Global {
         ctx.as_context().store.inner.resolve_global(self).ty()
     }
 
+    /// Returns the [`GlobalType`] of the global variable.
+    ///
+    /// This is an alias of [`Global::ty`] that mirrors [`ExternType::global`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Global`].
+    ///
+    /// [`ExternType::global`]: crate::ExternType::global
+    pub fn global_type(&self, ctx: impl AsContext) -> GlobalType {
+        self.ty(ctx)
+    }
+
     /// Sets a new value to the global variable.
     ///
     /// # Errors
     ///
     /// - If the global variable is immutable.
     /// - If there is a type mismatch between the global variable and the new value.
+    /// - If `new_value` refers to an entity that does not belong to `ctx`.
     ///
     /// # Panics
     ///
     /// Panics if `ctx` does not own this [`Global`].
     pub fn set(&self, mut ctx: impl AsContextMut, new_value: Val) -> Result<(), GlobalError> {
-        ctx.as_context_mut()
-            .store
-            .inner
-            .resolve_global_mut(self)
-            .set(new_value)
+        let inner = &mut ctx.as_context_mut().store.inner;
+        if !inner.owns_val(&new_value) {
+            return Err(GlobalError::CrossStore);
+        }
+        inner.resolve_global_mut(self).set(new_value)
     }
 
     /// Returns the current value of the global variable.
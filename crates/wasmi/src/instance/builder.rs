@@ -6,7 +6,6 @@ use crate::{
     module::FuncIdx,
     ElementSegment,
     Extern,
-    ExternType,
     Func,
     Global,
     Memory,
@@ -18,6 +17,7 @@ use alloc::{boxed::Box, sync::Arc, vec::Vec};
 /// A module instance entity builder.
 #[derive(Debug)]
 pub struct InstanceEntityBuilder {
+    module: Module,
     func_types: Arc<[DedupFuncType]>,
     tables: Vec<Table>,
     funcs: Vec<Func>,
@@ -25,6 +25,7 @@ pub struct InstanceEntityBuilder {
     globals: Vec<Global>,
     start_fn: Option<FuncIdx>,
     exports: Map<Box<str>, Extern>,
+    export_names: Vec<Box<str>>,
     data_segments: Vec<DataSegment>,
     elem_segments: Vec<ElementSegment>,
 }
@@ -37,34 +38,16 @@ impl InstanceEntityBuilder {
             v.reserve_exact(capacity);
             v
         }
-        let mut len_funcs = module.len_funcs();
-        let mut len_globals = module.len_globals();
-        let mut len_tables = module.len_tables();
-        let mut len_memories = module.len_memories();
-        for import in module.imports() {
-            match import.ty() {
-                ExternType::Func(_) => {
-                    len_funcs += 1;
-                }
-                ExternType::Table(_) => {
-                    len_tables += 1;
-                }
-                ExternType::Memory(_) => {
-                    len_memories += 1;
-                }
-                ExternType::Global(_) => {
-                    len_globals += 1;
-                }
-            }
-        }
         Self {
+            module: module.clone(),
             func_types: module.func_types_cloned(),
-            tables: vec_with_capacity_exact(len_tables),
-            funcs: vec_with_capacity_exact(len_funcs),
-            memories: vec_with_capacity_exact(len_memories),
-            globals: vec_with_capacity_exact(len_globals),
+            tables: vec_with_capacity_exact(module.num_tables()),
+            funcs: vec_with_capacity_exact(module.num_functions()),
+            memories: vec_with_capacity_exact(module.num_memories()),
+            globals: vec_with_capacity_exact(module.num_globals()),
             start_fn: None,
             exports: Map::default(),
+            export_names: Vec::new(),
             data_segments: Vec::new(),
             elem_segments: Vec::new(),
         }
@@ -137,6 +120,11 @@ impl InstanceEntityBuilder {
             .unwrap_or_else(|| panic!("missing `Func` at index: {index}"))
     }
 
+    /// Returns all functions of the [`InstanceEntity`] under construction.
+    pub fn funcs(&self) -> &[Func] {
+        &self.funcs[..]
+    }
+
     /// Pushes a new [`Memory`] to the [`InstanceEntity`] under construction.
     pub fn push_memory(&mut self, memory: Memory) {
         self.memories.push(memory);
@@ -159,6 +147,8 @@ impl InstanceEntityBuilder {
 
     /// Pushes a new [`Extern`] under the given `name` to the [`InstanceEntity`] under construction.
     ///
+    /// The order in which exports are pushed determines their export index.
+    ///
     /// # Panics
     ///
     /// If the name has already been used by an already pushed [`Extern`].
@@ -169,7 +159,9 @@ impl InstanceEntityBuilder {
                 but name is already used by {old_value:?}",
             )
         }
-        self.exports.insert(name.into(), new_value);
+        let name: Box<str> = name.into();
+        self.exports.insert(name.clone(), new_value);
+        self.export_names.push(name);
     }
 
     /// Pushes the [`DataSegment`] to the [`InstanceEntity`] under construction.
@@ -186,14 +178,17 @@ impl InstanceEntityBuilder {
     pub fn finish(self) -> InstanceEntity {
         InstanceEntity {
             initialized: true,
+            module: Some(self.module),
             func_types: self.func_types,
             tables: self.tables.into(),
             funcs: self.funcs.into(),
             memories: self.memories.into(),
             globals: self.globals.into(),
             exports: self.exports,
+            export_names: self.export_names.into(),
             data_segments: self.data_segments.into(),
             elem_segments: self.elem_segments.into(),
+            start_fn: self.start_fn,
         }
     }
 }
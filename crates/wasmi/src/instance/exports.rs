@@ -11,7 +11,7 @@ use crate::{
     TableType,
 };
 use alloc::boxed::Box;
-use core::iter::FusedIterator;
+use core::{iter::FusedIterator, slice::Iter as SliceIter};
 
 /// An external item to a WebAssembly module.
 ///
@@ -96,6 +96,16 @@ impl Extern {
         None
     }
 
+    /// Returns `true` if this [`Extern`] originates from `store`.
+    pub fn is_from_store(&self, store: impl AsContext) -> bool {
+        match self {
+            Extern::Global(global) => global.is_from_store(store),
+            Extern::Table(table) => table.is_from_store(store),
+            Extern::Memory(memory) => memory.is_from_store(store),
+            Extern::Func(func) => func.is_from_store(store),
+        }
+    }
+
     /// Returns the type associated with this [`Extern`].
     ///
     /// # Panics
@@ -114,7 +124,7 @@ impl Extern {
 /// The type of an [`Extern`] item.
 ///
 /// A list of all possible types which can be externally referenced from a WebAssembly module.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ExternType {
     /// The type of an [`Extern::Global`].
     Global(GlobalType),
@@ -275,3 +285,39 @@ impl<'instance> Iterator for ExportsIter<'instance> {
 
 impl ExactSizeIterator for ExportsIter<'_> {}
 impl FusedIterator for ExportsIter<'_> {}
+
+/// An iterator over the export names of an [`Instance`](crate::Instance).
+///
+/// The names are yielded in the order of their declaration in the Wasm module.
+#[derive(Debug)]
+pub struct ExportNamesIter<'instance> {
+    iter: SliceIter<'instance, Box<str>>,
+}
+
+impl<'instance> ExportNamesIter<'instance> {
+    /// Creates a new [`ExportNamesIter`].
+    pub(super) fn new(names: &'instance [Box<str>]) -> Self {
+        Self { iter: names.iter() }
+    }
+}
+
+impl<'instance> Iterator for ExportNamesIter<'instance> {
+    type Item = &'instance str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|name| &name[..])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ExportNamesIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|name| &name[..])
+    }
+}
+
+impl ExactSizeIterator for ExportNamesIter<'_> {}
+impl FusedIterator for ExportNamesIter<'_> {}
@@ -1,7 +1,7 @@
 pub(crate) use self::builder::InstanceEntityBuilder;
-pub use self::exports::{Export, ExportsIter, Extern, ExternType};
+pub use self::exports::{Export, ExportNamesIter, ExportsIter, Extern, ExternType};
 use super::{
-    engine::DedupFuncType,
+    engine::{DedupFuncType, EngineFunc},
     AsContext,
     Func,
     Global,
@@ -15,6 +15,8 @@ use crate::{
     collections::{arena::ArenaIndex, Map},
     func::FuncError,
     memory::DataSegment,
+    module::{FuncIdx, InstantiationError},
+    store::{FuelMultiplier, StoreRebind},
     AsContextMut,
     ElementSegment,
     Error,
@@ -48,17 +50,21 @@ impl ArenaIndex for InstanceIdx {
 }
 
 /// A module instance entity.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct InstanceEntity {
     initialized: bool,
+    module: Option<Module>,
     func_types: Arc<[DedupFuncType]>,
     tables: Box<[Table]>,
     funcs: Box<[Func]>,
     memories: Box<[Memory]>,
     globals: Box<[Global]>,
     exports: Map<Box<str>, Extern>,
+    export_names: Box<[Box<str>]>,
     data_segments: Box<[DataSegment]>,
     elem_segments: Box<[ElementSegment]>,
+    /// The `start` function that has not yet been run if any.
+    start_fn: Option<FuncIdx>,
 }
 
 impl InstanceEntity {
@@ -66,14 +72,17 @@ impl InstanceEntity {
     pub fn uninitialized() -> InstanceEntity {
         Self {
             initialized: false,
+            module: None,
             func_types: Arc::new([]),
             tables: [].into(),
             funcs: [].into(),
             memories: [].into(),
             globals: [].into(),
             exports: Map::new(),
+            export_names: [].into(),
             data_segments: [].into(),
             elem_segments: [].into(),
+            start_fn: None,
         }
     }
 
@@ -87,6 +96,59 @@ impl InstanceEntity {
         self.initialized
     }
 
+    /// Rebinds the entity references of the [`InstanceEntity`] to the copied [`Store`].
+    ///
+    /// [`Store`]: crate::Store
+    pub(crate) fn rebind(&mut self, rebind: &StoreRebind) {
+        fn rebind_all<T>(entities: &mut [T], rebind: impl Fn(&T) -> T) {
+            for entity in entities {
+                *entity = rebind(entity);
+            }
+        }
+        rebind_all(&mut self.tables, |table| rebind.table(table));
+        rebind_all(&mut self.funcs, |func| rebind.func(func));
+        rebind_all(&mut self.memories, |memory| rebind.memory(memory));
+        rebind_all(&mut self.globals, |global| rebind.global(global));
+        rebind_all(&mut self.data_segments, |segment| {
+            rebind.data_segment(segment)
+        });
+        rebind_all(&mut self.elem_segments, |segment| {
+            rebind.element_segment(segment)
+        });
+        for external in self.exports.values_mut() {
+            *external = rebind.external(external);
+        }
+    }
+
+    /// Returns the [`Module`] from which the [`InstanceEntity`] has been instantiated.
+    ///
+    /// # Panics
+    ///
+    /// If the [`InstanceEntity`] has not yet been fully initialized.
+    pub fn module(&self) -> &Module {
+        self.module
+            .as_ref()
+            .unwrap_or_else(|| panic!("uninitialized instance has no module"))
+    }
+
+    /// Returns the index of `func` within the [`Module`] of the [`InstanceEntity`].
+    ///
+    /// Returns `None` if the [`InstanceEntity`] is uninitialized or if its
+    /// [`Module`] does not define `func`.
+    pub fn get_func_index(&self, func: EngineFunc) -> Option<u32> {
+        self.module.as_ref()?.get_func_index(func)
+    }
+
+    /// Returns the [`FuelMultiplier`] of the [`Module`] of the [`InstanceEntity`].
+    ///
+    /// Returns the default [`FuelMultiplier`] if the [`InstanceEntity`] is uninitialized.
+    pub fn fuel_multiplier(&self) -> FuelMultiplier {
+        self.module
+            .as_ref()
+            .map(Module::fuel_multiplier)
+            .unwrap_or_default()
+    }
+
     /// Returns the linear memory at the `index` if any.
     pub fn get_memory(&self, index: u32) -> Option<Memory> {
         self.memories.get(index as usize).copied()
@@ -122,17 +184,39 @@ impl InstanceEntity {
         self.elem_segments.get(index as usize).copied()
     }
 
+    /// Takes the `start` function that has not yet been run if any.
+    ///
+    /// Afterwards the [`InstanceEntity`] no longer has a pending `start` function.
+    pub fn take_start_fn(&mut self) -> Option<FuncIdx> {
+        self.start_fn.take()
+    }
+
     /// Returns the value exported to the given `name` if any.
     pub fn get_export(&self, name: &str) -> Option<Extern> {
         self.exports.get(name).copied()
     }
 
+    /// Returns the value exported at the given export `index` if any.
+    ///
+    /// Export indices follow the order of declaration in the Wasm module.
+    pub fn get_export_by_index(&self, index: u32) -> Option<Extern> {
+        let name = self.export_names.get(index as usize)?;
+        self.get_export(name)
+    }
+
     /// Returns an iterator over the exports of the [`Instance`].
     ///
     /// The order of the yielded exports is not specified.
     pub fn exports(&self) -> ExportsIter {
         ExportsIter::new(self.exports.iter())
     }
+
+    /// Returns an iterator over the export names of the [`Instance`].
+    ///
+    /// The names are yielded in the order of their declaration in the Wasm module.
+    pub fn export_names(&self) -> ExportNamesIter<'_> {
+        ExportNamesIter::new(&self.export_names)
+    }
 }
 
 /// An instantiated WebAssembly [`Module`].
@@ -208,12 +292,79 @@ impl Instance {
         &self.0
     }
 
+    /// Returns `true` if this [`Instance`] originates from `store`.
+    ///
+    /// This allows to check if the [`Instance`] can be used with `store`
+    /// before calling methods that would otherwise panic.
+    pub fn is_from_store(&self, store: impl AsContext) -> bool {
+        store.as_context().store.inner.owns(self.as_inner())
+    }
+
+    /// Runs the `start` function of the [`Instance`].
+    ///
+    /// # Note
+    ///
+    /// - This is only required for instances created via
+    ///   [`InstancePre::instantiate_without_start`](crate::InstancePre::instantiate_without_start).
+    /// - The `start` function is no longer pending afterwards, even if executing it traps.
+    ///
+    /// # Errors
+    ///
+    /// - If the [`Instance`] has no pending `start` function, e.g. because its [`Module`]
+    ///   has no `start` function or because it already has been run.
+    /// - If executing the `start` function traps.
+    /// - If `store` does not own this [`Instance`].
+    pub fn run_start(&self, store: impl AsContextMut) -> Result<(), Error> {
+        if !self.run_pending_start(store)? {
+            return Err(Error::from(InstantiationError::NoPendingStartFn));
+        }
+        Ok(())
+    }
+
+    /// Runs the pending `start` function of the [`Instance`] if any.
+    ///
+    /// Returns `false` if the [`Instance`] has no pending `start` function.
+    ///
+    /// # Errors
+    ///
+    /// - If `store` does not own this [`Instance`].
+    /// - If executing the `start` function traps.
+    ///
+    /// # Panics
+    ///
+    /// If the `start` function is invalid albeit successful validation.
+    pub(crate) fn run_pending_start(&self, mut store: impl AsContextMut) -> Result<bool, Error> {
+        if !self.is_from_store(&store) {
+            return Err(Error::from(FuncError::CrossStore));
+        }
+        let Some(start_fn) = store
+            .as_context_mut()
+            .store
+            .inner
+            .resolve_instance_mut(self)
+            .take_start_fn()
+        else {
+            return Ok(false);
+        };
+        let start_index = start_fn.into_u32();
+        let start_func = self
+            .get_func_by_index(&store, start_index)
+            .unwrap_or_else(|| {
+                panic!("encountered invalid start function after validation: {start_index}")
+            });
+        start_func.call(store.as_context_mut(), &[], &mut [])?;
+        Ok(true)
+    }
+
     /// Returns the function at the `index` if any.
     ///
+    /// The `index` refers to the function index space of the instantiated [`Module`]
+    /// which includes imported functions, e.g. as used by `call` or `ref.func`.
+    ///
     /// # Panics
     ///
     /// Panics if `store` does not own this [`Instance`].
-    pub(crate) fn get_func_by_index(&self, store: impl AsContext, index: u32) -> Option<Func> {
+    pub fn get_func_by_index(&self, store: impl AsContext, index: u32) -> Option<Func> {
         store
             .as_context()
             .store
@@ -268,8 +419,8 @@ impl Instance {
         name: &str,
     ) -> Result<TypedFunc<Params, Results>, Error>
     where
-        Params: WasmParams,
-        Results: WasmResults,
+        Params: WasmParams + 'static,
+        Results: WasmResults + 'static,
     {
         self.get_export(&store, name)
             .and_then(Extern::into_func)
@@ -313,6 +464,15 @@ impl Instance {
         self.get_export(store, name)?.into_memory()
     }
 
+    /// Returns the [`Module`] from which the [`Instance`] has been instantiated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this [`Instance`].
+    pub fn module<'ctx, T: 'ctx>(&self, store: impl Into<StoreContext<'ctx, T>>) -> &'ctx Module {
+        store.into().store.inner.resolve_instance(self).module()
+    }
+
     /// Returns an iterator over the exports of the [`Instance`].
     ///
     /// The order of the yielded exports is not specified.
@@ -326,4 +486,44 @@ impl Instance {
     ) -> ExportsIter<'ctx> {
         store.into().store.inner.resolve_instance(self).exports()
     }
+
+    /// Returns an iterator over the export names of the [`Instance`].
+    ///
+    /// The names are yielded in the order of their declaration in the Wasm module's
+    /// export section. The position of a name in this iteration is its export index
+    /// as used by [`Instance::export_by_index`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this [`Instance`].
+    pub fn export_names<'ctx, T: 'ctx>(
+        &self,
+        store: impl Into<StoreContext<'ctx, T>>,
+    ) -> ExportNamesIter<'ctx> {
+        store
+            .into()
+            .store
+            .inner
+            .resolve_instance(self)
+            .export_names()
+    }
+
+    /// Returns the value exported at the given export `index` if any.
+    ///
+    /// Export indices follow the order of declaration in the Wasm module's export section,
+    /// matching the order of [`Instance::export_names`].
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own this [`Instance`].
+    pub fn export_by_index(&self, store: impl AsContext, index: u32) -> Option<Extern> {
+        store
+            .as_context()
+            .store
+            .inner
+            .resolve_instance(self)
+            .get_export_by_index(index)
+    }
 }
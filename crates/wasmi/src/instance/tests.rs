@@ -2,6 +2,7 @@ use super::*;
 use crate::{
     core::{TrapCode, ValType},
     error::ErrorKind,
+    func::FuncError,
     global::GlobalError,
     memory::MemoryError,
     module::InstantiationError,
@@ -10,6 +11,7 @@ use crate::{
     Engine,
     ExternRef,
     FuncRef,
+    Linker,
     MemoryType,
     Mutability,
     Store,
@@ -216,3 +218,261 @@ fn instantiate_with_invalid_func_import() {
         ErrorKind::Instantiation(InstantiationError::SignatureMismatch { .. })
     ));
 }
+
+#[test]
+fn export_names_in_declaration_order() {
+    let wasm = r#"
+        (module
+            (func (export "z"))
+            (memory (export "b") 1)
+            (global (export "y") i32 (i32.const 0))
+            (table (export "a") 0 funcref)
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[]).unwrap();
+    assert!(instance
+        .export_names(&store)
+        .eq(["z", "b", "y", "a"].iter().copied()));
+    assert!(instance
+        .export_by_index(&store, 0)
+        .unwrap()
+        .into_func()
+        .is_some());
+    assert!(instance
+        .export_by_index(&store, 1)
+        .unwrap()
+        .into_memory()
+        .is_some());
+    assert!(instance
+        .export_by_index(&store, 2)
+        .unwrap()
+        .into_global()
+        .is_some());
+    assert!(instance
+        .export_by_index(&store, 3)
+        .unwrap()
+        .into_table()
+        .is_some());
+    assert!(instance.export_by_index(&store, 4).is_none());
+}
+
+#[test]
+fn is_from_store_works() {
+    let wasm = r#"
+        (module
+            (func (export "f"))
+            (memory (export "m") 1)
+            (global (export "g") i32 (i32.const 0))
+            (table (export "t") 0 funcref)
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    let other = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[]).unwrap();
+    let func = instance.get_func(&store, "f").unwrap();
+    let memory = instance.get_memory(&store, "m").unwrap();
+    let global = instance.get_global(&store, "g").unwrap();
+    let table = instance.get_table(&store, "t").unwrap();
+    assert!(instance.is_from_store(&store));
+    assert!(func.is_from_store(&store));
+    assert!(memory.is_from_store(&store));
+    assert!(global.is_from_store(&store));
+    assert!(table.is_from_store(&store));
+    assert!(!instance.is_from_store(&other));
+    assert!(!func.is_from_store(&other));
+    assert!(!memory.is_from_store(&other));
+    assert!(!global.is_from_store(&other));
+    assert!(!table.is_from_store(&other));
+}
+
+#[test]
+fn cross_store_entities_are_errors() {
+    let wasm = r#"
+        (module
+            (import "env" "m" (memory 1))
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    let mut other = Store::new(&engine, ());
+    // Instantiation with an import from a different store.
+    let memory = Memory::new(&mut other, MemoryType::new(1, None).unwrap()).unwrap();
+    let error = Instance::new(&mut store, &module, &[Extern::from(memory)]).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Instantiation(InstantiationError::CrossStore { .. })
+    ));
+    // Table operations with a function reference from a different store.
+    let foreign = Func::wrap(&mut other, || ());
+    let foreign_ref = Val::from(FuncRef::new(foreign));
+    let table_ty = TableType::new(ValType::FuncRef, 1, None);
+    assert!(matches!(
+        Table::new(&mut store, table_ty, foreign_ref.clone()),
+        Err(TableError::CrossStore)
+    ));
+    let table = Table::new(&mut store, table_ty, Val::from(FuncRef::null())).unwrap();
+    assert!(matches!(
+        table.set(&mut store, 0, foreign_ref.clone()),
+        Err(TableError::CrossStore)
+    ));
+    assert!(matches!(
+        table.grow(&mut store, 1, foreign_ref.clone()),
+        Err(TableError::CrossStore)
+    ));
+    // Global variable write with a function reference from a different store.
+    let global = Global::new(&mut store, Val::from(FuncRef::null()), Mutability::Var);
+    assert!(matches!(
+        global.set(&mut store, foreign_ref.clone()),
+        Err(GlobalError::CrossStore)
+    ));
+    // Calls of functions from a different store or with foreign parameters.
+    let error = foreign.call(&mut store, &[], &mut []).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Func(FuncError::CrossStore)
+    ));
+    let error = foreign.typed::<(), ()>(&store).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Func(FuncError::CrossStore)
+    ));
+    let typed = foreign.typed::<(), ()>(&other).unwrap();
+    let error = typed.call(&mut store, ()).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Func(FuncError::CrossStore)
+    ));
+    let local = Func::wrap(&mut store, |_: FuncRef| ());
+    let error = local.call(&mut store, &[foreign_ref], &mut []).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Func(FuncError::CrossStore)
+    ));
+}
+
+#[test]
+fn module_works() {
+    let wasm = r#"
+        (module
+            (func (export "f"))
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+    let other = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    assert!(Module::same(instance.module(&store), &module));
+    assert!(Module::same(instance.module(&store), &module.clone()));
+    assert!(!Module::same(instance.module(&store), &other));
+    assert!(instance
+        .module(&store)
+        .exports()
+        .map(|export| export.name())
+        .eq(instance.export_names(&store)));
+}
+
+#[test]
+fn run_start_separately() {
+    let wasm = r#"
+        (module
+            (global $g (export "g") (mut i32) (i32.const 0))
+            (func $init
+                (global.set $g (i32.const 42))
+            )
+            (start $init)
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+    assert!(module.has_start());
+    let mut store = Store::new(&engine, ());
+    let linker = <Linker<()>>::new(&engine);
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .instantiate_without_start(&mut store)
+        .unwrap();
+    let global = instance.get_global(&store, "g").unwrap();
+    // The `start` function has not yet been run.
+    assert_eq!(global.get(&store).i32(), Some(0));
+    instance.run_start(&mut store).unwrap();
+    assert_eq!(global.get(&store).i32(), Some(42));
+    // The `start` function must only be run once.
+    assert!(matches!(
+        instance.run_start(&mut store).unwrap_err().kind(),
+        ErrorKind::Instantiation(InstantiationError::NoPendingStartFn)
+    ));
+}
+
+#[test]
+fn run_start_without_start_fn() {
+    let wasm = r#"
+        (module
+            (func (export "f"))
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+    assert!(!module.has_start());
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[]).unwrap();
+    assert!(matches!(
+        instance.run_start(&mut store).unwrap_err().kind(),
+        ErrorKind::Instantiation(InstantiationError::NoPendingStartFn)
+    ));
+}
+
+#[test]
+fn run_start_after_start() {
+    let wasm = r#"
+        (module
+            (func $f)
+            (start $f)
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    // `Instance::new` already runs the `start` function.
+    let instance = Instance::new(&mut store, &module, &[]).unwrap();
+    assert!(matches!(
+        instance.run_start(&mut store).unwrap_err().kind(),
+        ErrorKind::Instantiation(InstantiationError::NoPendingStartFn)
+    ));
+}
+
+#[test]
+fn run_start_cross_store() {
+    let wasm = r#"
+        (module
+            (func $f)
+            (start $f)
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    let mut other = Store::new(&engine, ());
+    let instance = <Linker<()>>::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .instantiate_without_start(&mut store)
+        .unwrap();
+    assert!(matches!(
+        instance.run_start(&mut other).unwrap_err().kind(),
+        ErrorKind::Func(FuncError::CrossStore)
+    ));
+    // The `start` function is still pending for the owning store.
+    instance.run_start(&mut store).unwrap();
+}
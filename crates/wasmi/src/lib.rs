@@ -55,6 +55,9 @@
 //!     instance
 //!         .get_typed_func::<(), ()>(&store, "hello")?
 //!         .call(&mut store, ())?;
+//!     // Exported entities also provide direct access to their specific types.
+//!     let hello = instance.get_func(&store, "hello").unwrap();
+//!     assert!(hello.func_type(&store).params().is_empty());
 //!     Ok(())
 //! }
 //! ```
@@ -111,21 +114,24 @@ use wasmi_ir as ir;
 /// Defines some errors that may occur upon interaction with Wasmi.
 pub mod errors {
     pub use super::{
-        engine::EnforcedLimitsError,
+        engine::{EnforcedLimitKind, EnforcedLimitsError, StackOverflowError, StackOverflowKind},
         error::ErrorKind,
-        func::FuncError,
+        func::{FuncError, IndirectCallError},
         global::GlobalError,
         ir::Error as IrError,
         linker::LinkerError,
         memory::MemoryError,
-        module::{InstantiationError, ReadError},
-        store::FuelError,
+        module::{FloatingPointError, InstantiationError, ModuleOptionsError, ReadError},
+        store::{FuelError, SnapshotError},
         table::TableError,
     };
 }
 
+#[cfg(feature = "profiling")]
+pub use self::engine::{InstrProfile, ProfileReport};
 pub use self::{
     engine::{
+        AvgBytesPerFunctionLimit,
         CompilationMode,
         Config,
         EnforcedLimits,
@@ -134,8 +140,10 @@ pub use self::{
         ResumableCall,
         ResumableInvocation,
         StackLimits,
+        StackRecycler,
         TypedResumableCall,
         TypedResumableInvocation,
+        WasmProposal,
     },
     error::Error,
     externref::ExternRef,
@@ -153,22 +161,36 @@ pub use self::{
         WasmTyList,
     },
     global::{Global, GlobalType, Mutability},
-    instance::{Export, ExportsIter, Extern, ExternType, Instance},
+    instance::{Export, ExportNamesIter, ExportsIter, Extern, ExternType, Instance},
     limits::{ResourceLimiter, StoreLimits, StoreLimitsBuilder},
-    linker::{state, Linker, LinkerBuilder},
+    linker::{state, InstancePre2, Linker, LinkerBuilder, ScopedLinkerGuard},
     memory::{Memory, MemoryType},
     module::{
         CustomSection,
         CustomSectionsIter,
+        ExportInterface,
         ExportType,
+        ImportInterface,
         ImportType,
         InstancePre,
         Module,
         ModuleExportsIter,
         ModuleImportsIter,
+        ModuleInterface,
+        ModuleOptions,
+        ModuleResources,
         Read,
     },
-    store::{AsContext, AsContextMut, CallHook, Store, StoreContext, StoreContextMut},
+    store::{
+        AsContext,
+        AsContextMut,
+        CallHook,
+        FuelCheckpoint,
+        Store,
+        StoreContext,
+        StoreContextMut,
+        StoreSnapshot,
+    },
     table::{Table, TableType},
     value::Val,
 };
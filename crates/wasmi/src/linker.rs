@@ -3,11 +3,14 @@ use crate::{
         string_interner::{InternHint, Sym as Symbol},
         StringInterner,
     },
+    core::ValType,
+    errors::{MemoryError, TableError},
     func::{FuncEntity, HostFuncEntity, HostFuncTrampolineEntity},
     module::{ImportName, ImportType},
     AsContext,
     AsContextMut,
     Caller,
+    Config,
     Engine,
     Error,
     Extern,
@@ -22,15 +25,22 @@ use crate::{
     Module,
     TableType,
     Val,
+    WasmProposal,
+    WasmRet,
+    WasmTyList,
 };
 use alloc::{
+    boxed::Box,
     collections::{btree_map::Entry, BTreeMap},
     sync::Arc,
     vec::Vec,
 };
 use core::{
     fmt::{self, Debug, Display},
+    iter,
     marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr,
 };
 
 /// An error that may occur upon operating with [`Linker`] instances.
@@ -93,6 +103,25 @@ pub enum LinkerError {
         /// The mismatching [`GlobalType`] found.
         found: GlobalType,
     },
+    /// Encountered when a definition does not belong to the store used for instantiation.
+    CrossStore {
+        /// The name of the import for which the definition belongs to a different store.
+        name: ImportName,
+    },
+    /// Encountered when aliasing a definition that does not exist.
+    UndefinedAlias {
+        /// The module name of the missing definition that was supposed to be aliased.
+        module: Box<str>,
+        /// The item name of the missing definition that was supposed to be aliased.
+        name: Box<str>,
+    },
+    /// Encountered when a definition is incompatible with the [`Config`] of an [`Engine`].
+    IncompatibleExtern {
+        /// The name of the incompatible definition.
+        name: ImportName,
+        /// Describes why the definition is incompatible.
+        description: &'static str,
+    },
 }
 
 impl LinkerError {
@@ -140,6 +169,11 @@ impl LinkerError {
         }
     }
 
+    /// Create a new [`LinkerError`] for when a definition belongs to a different store.
+    fn cross_store(name: &ImportName) -> Self {
+        Self::CrossStore { name: name.clone() }
+    }
+
     /// Create a new [`LinkerError`] for when a [`GlobalType`] mismatched.
     fn global_type_mismatch(name: &ImportName, expected: &GlobalType, found: &GlobalType) -> Self {
         Self::GlobalTypeMismatch {
@@ -187,16 +221,18 @@ impl Display for LinkerError {
                 )
             }
             Self::InvalidTableSubtype { name, ty, other } => {
-                write!(
-                    f,
-                    "import {name}: table type {ty:?} is not a subtype of {other:?}"
-                )
+                let error = TableError::InvalidSubtype {
+                    ty: *ty,
+                    other: *other,
+                };
+                write!(f, "import {name}: {error}")
             }
             Self::InvalidMemorySubtype { name, ty, other } => {
-                write!(
-                    f,
-                    "import {name}: memory type {ty:?} is not a subtype of {other:?}"
-                )
+                let error = MemoryError::InvalidSubtype {
+                    ty: *ty,
+                    other: *other,
+                };
+                write!(f, "import {name}: {error}")
             }
             Self::GlobalTypeMismatch {
                 name,
@@ -209,6 +245,21 @@ impl Display for LinkerError {
                     expected {expected:?} but found {found:?}",
                 )
             }
+            Self::CrossStore { name } => {
+                write!(
+                    f,
+                    "found definition for import {name} from a different store"
+                )
+            }
+            Self::UndefinedAlias { module, name } => {
+                write!(f, "cannot alias undefined definition {module}::{name}")
+            }
+            Self::IncompatibleExtern { name, description } => {
+                write!(
+                    f,
+                    "definition {name} is incompatible with the config: {description}"
+                )
+            }
         }
     }
 }
@@ -253,6 +304,36 @@ impl ImportKey {
     }
 }
 
+/// Checks that `func_type` is supported by `config`.
+///
+/// Returns a description of the incompatibility otherwise.
+fn check_func_type(func_type: &FuncType, config: &Config) -> Result<(), &'static str> {
+    let limits = config.get_enforced_limits();
+    if limits
+        .get_max_params()
+        .is_some_and(|max| func_type.params().len() > max)
+    {
+        return Err("too many function parameters");
+    }
+    if limits
+        .get_max_results()
+        .is_some_and(|max| func_type.results().len() > max)
+    {
+        return Err("too many function results");
+    }
+    if func_type.results().len() > 1 && !config.is_enabled(WasmProposal::MultiValue) {
+        return Err("multiple function results require the multi-value proposal");
+    }
+    let mut tys = func_type.params().iter().chain(func_type.results());
+    if tys.clone().any(ValType::is_ref) && !config.is_enabled(WasmProposal::ReferenceTypes) {
+        return Err("reference types require the reference-types proposal");
+    }
+    if tys.any(|ty| matches!(ty, ValType::F32 | ValType::F64)) && config.floats_denied() {
+        return Err("floating point types are denied");
+    }
+    Ok(())
+}
+
 /// A [`Linker`] definition.
 #[derive(Debug)]
 enum Definition<T> {
@@ -320,6 +401,86 @@ impl<T> Definition<T> {
             _ => None,
         }
     }
+
+    /// Returns the [`Extern`] of `self` used to satisfy `import`.
+    ///
+    /// # Note
+    ///
+    /// This allocates a new [`Func`] on the `context` if `self` is
+    /// a [`Linker`] defined host function.
+    ///
+    /// # Errors
+    ///
+    /// If `self` does not satisfy constraints set by the `import`.
+    fn resolve_import(
+        &self,
+        mut context: impl AsContextMut<Data = T>,
+        import: &ImportType,
+    ) -> Result<Extern, Error> {
+        let import_name = import.import_name();
+        if let Some(item) = self.as_extern() {
+            if !item.is_from_store(&context) {
+                return Err(Error::from(LinkerError::cross_store(import_name)));
+            }
+        }
+        let invalid_type = || LinkerError::invalid_type_definition(import, &self.ty(&context));
+        match import.ty() {
+            ExternType::Func(expected_type) => {
+                let found_type = self.ty(&context).func().cloned().ok_or_else(invalid_type)?;
+                if &found_type != expected_type {
+                    return Err(Error::from(LinkerError::func_type_mismatch(
+                        import_name,
+                        expected_type,
+                        &found_type,
+                    )));
+                }
+                let func = self
+                    .as_func(&mut context)
+                    .expect("already asserted that `self` is a function");
+                Ok(Extern::Func(func))
+            }
+            ExternType::Table(expected_type) => {
+                let table = self
+                    .as_extern()
+                    .copied()
+                    .and_then(Extern::into_table)
+                    .ok_or_else(invalid_type)?;
+                let found_type = table.dynamic_ty(context);
+                found_type.is_subtype_or_err(expected_type).map_err(|_| {
+                    LinkerError::table_type_mismatch(import_name, &found_type, expected_type)
+                })?;
+                Ok(Extern::Table(table))
+            }
+            ExternType::Memory(expected_type) => {
+                let memory = self
+                    .as_extern()
+                    .copied()
+                    .and_then(Extern::into_memory)
+                    .ok_or_else(invalid_type)?;
+                let found_type = memory.dynamic_ty(context);
+                found_type.is_subtype_or_err(expected_type).map_err(|_| {
+                    LinkerError::invalid_memory_subtype(import_name, &found_type, expected_type)
+                })?;
+                Ok(Extern::Memory(memory))
+            }
+            ExternType::Global(expected_type) => {
+                let global = self
+                    .as_extern()
+                    .copied()
+                    .and_then(Extern::into_global)
+                    .ok_or_else(invalid_type)?;
+                let found_type = global.ty(context);
+                if &found_type != expected_type {
+                    return Err(Error::from(LinkerError::global_type_mismatch(
+                        import_name,
+                        expected_type,
+                        &found_type,
+                    )));
+                }
+                Ok(Extern::Global(global))
+            }
+        }
+    }
 }
 
 /// A linker used to define module imports and instantiate module instances.
@@ -336,8 +497,16 @@ pub struct Linker<T> {
     ///
     /// `None` if no [`LinkerBuilder`] was used for creation of the [`Linker`].
     shared: Option<Arc<LinkerInner<T>>>,
+    /// The parent [`Linker`] used to resolve names that are not defined by this [`Linker`].
+    ///
+    /// `None` if the [`Linker`] was not created via [`Linker::with_parent`].
+    parent: Option<Arc<Linker<T>>>,
     /// Inner linker implementation details.
-    inner: LinkerInner<T>,
+    ///
+    /// # Note
+    ///
+    /// This is copied on write so that [`Linker::with_parent`] is cheap.
+    inner: Arc<LinkerInner<T>>,
 }
 
 impl<T> Clone for Linker<T> {
@@ -345,6 +514,7 @@ impl<T> Clone for Linker<T> {
         Self {
             engine: self.engine.clone(),
             shared: self.shared.clone(),
+            parent: self.parent.clone(),
             inner: self.inner.clone(),
         }
     }
@@ -362,10 +532,65 @@ impl<T> Linker<T> {
         Self {
             engine: engine.clone(),
             shared: None,
-            inner: LinkerInner::default(),
+            parent: None,
+            inner: Arc::new(LinkerInner::default()),
+        }
+    }
+
+    /// Creates a new child [`Linker`] that falls back to the definitions of `self`.
+    ///
+    /// Names are resolved by the child [`Linker`] first and by `self` afterwards.
+    /// The child [`Linker`] shares the definitions of `self` instead of copying them.
+    ///
+    /// # Note
+    ///
+    /// - The child [`Linker`] may always override definitions of `self`.
+    ///   [`Linker::allow_shadowing`] of the child only applies to its own definitions.
+    /// - Defining items in the child [`Linker`] never affects `self` and vice versa.
+    ///   The child [`Linker`] observes the definitions of `self` at the time of its creation.
+    pub fn with_parent(&self) -> Self {
+        Self {
+            engine: self.engine.clone(),
+            shared: None,
+            parent: Some(Arc::new(self.clone())),
+            inner: Arc::new(LinkerInner::default()),
         }
     }
 
+    /// Returns the number of definitions that can be resolved by this [`Linker`].
+    ///
+    /// Definitions of a parent [`Linker`] that are overridden are not counted.
+    pub fn len(&self) -> usize {
+        self.definitions().count()
+    }
+
+    /// Returns `true` if this [`Linker`] has no definitions.
+    pub fn is_empty(&self) -> bool {
+        self.definitions().next().is_none()
+    }
+
+    /// Returns an exclusive reference to the [`LinkerInner`] of `self`.
+    ///
+    /// Copies the [`LinkerInner`] if it is shared with another [`Linker`].
+    fn inner_mut(&mut self) -> &mut LinkerInner<T> {
+        Arc::make_mut(&mut self.inner)
+    }
+
+    /// Returns an iterator over the module names, item names and [`Definition`]s
+    /// that can be resolved by this [`Linker`].
+    fn definitions(&self) -> impl Iterator<Item = (&str, &str, &Definition<T>)> {
+        iter::successors(Some(self), |linker| linker.parent.as_deref())
+            .flat_map(|linker| {
+                let shared = linker.shared.iter().flat_map(|shared| shared.definitions());
+                shared.chain(linker.inner.definitions())
+            })
+            .filter(|(module, name, definition)| {
+                // Filter out overridden definitions of the parent linkers.
+                self.lookup_definition(module, name)
+                    .is_some_and(|resolved| ptr::eq(resolved, *definition))
+            })
+    }
+
     /// Creates a new [`LinkerBuilder`] to construct a [`Linker`].
     pub fn build() -> LinkerBuilder<state::Constructing, T> {
         LinkerBuilder {
@@ -383,7 +608,7 @@ impl<T> Linker<T> {
     ///
     /// Disabled by default.
     pub fn allow_shadowing(&mut self, allow: bool) -> &mut Self {
-        self.inner.allow_shadowing(allow);
+        self.inner_mut().allow_shadowing(allow);
         self
     }
 
@@ -417,11 +642,43 @@ impl<T> Linker<T> {
         item: impl Into<Extern>,
     ) -> Result<&mut Self, LinkerError> {
         self.ensure_undefined(module, name)?;
-        let key = self.inner.new_import_key(module, name);
-        self.inner.insert(key, Definition::Extern(item.into()))?;
+        let key = self.inner_mut().new_import_key(module, name);
+        self.inner_mut()
+            .insert(key, Definition::Extern(item.into()))?;
         Ok(self)
     }
 
+    /// Temporarily defines `item` under `module` and `name` in this [`Linker`].
+    ///
+    /// Overrides any previous definition under the same name until the returned
+    /// [`ScopedLinkerGuard`] is dropped which then restores the previous definition
+    /// or removes the item if there was none.
+    ///
+    /// The [`Linker`] can be accessed through the [`ScopedLinkerGuard`] while it is alive.
+    /// Definitions made via the guard under the same name are overwritten upon drop.
+    ///
+    /// # Errors
+    ///
+    /// If there is a definition under the same name in the shared definitions
+    /// of a [`LinkerBuilder`] since those cannot be overridden.
+    pub fn scoped_define(
+        &mut self,
+        module: &str,
+        name: &str,
+        item: impl Into<Extern>,
+    ) -> Result<ScopedLinkerGuard<'_, T>, LinkerError> {
+        self.ensure_undefined(module, name)?;
+        let key = self.inner_mut().new_import_key(module, name);
+        let previous = self
+            .inner_mut()
+            .replace(key, Some(Definition::Extern(item.into())));
+        Ok(ScopedLinkerGuard {
+            linker: self,
+            key,
+            previous,
+        })
+    }
+
     /// Creates a new named [`Func::new`]-style host [`Func`] for this [`Linker`].
     ///
     /// For more information see [`Linker::func_wrap`].
@@ -438,8 +695,8 @@ impl<T> Linker<T> {
     ) -> Result<&mut Self, LinkerError> {
         self.ensure_undefined(module, name)?;
         let func = HostFuncTrampolineEntity::new(ty, func);
-        let key = self.inner.new_import_key(module, name);
-        self.inner.insert(key, Definition::HostFunc(func))?;
+        let key = self.inner_mut().new_import_key(module, name);
+        self.inner_mut().insert(key, Definition::HostFunc(func))?;
         Ok(self)
     }
 
@@ -472,8 +729,47 @@ impl<T> Linker<T> {
     ) -> Result<&mut Self, LinkerError> {
         self.ensure_undefined(module, name)?;
         let func = HostFuncTrampolineEntity::wrap(func);
-        let key = self.inner.new_import_key(module, name);
-        self.inner.insert(key, Definition::HostFunc(func))?;
+        let key = self.inner_mut().new_import_key(module, name);
+        self.inner_mut().insert(key, Definition::HostFunc(func))?;
+        Ok(self)
+    }
+
+    /// Creates a new named host [`Func`] from a `FnMut` closure for this [`Linker`].
+    ///
+    /// This is similar to [`Linker::func_wrap`] but allows `func` to mutate its
+    /// captured state across calls without requiring synchronization by the user.
+    /// The parameters of `func` are passed as a single tuple, e.g. `(i32, i64)`.
+    ///
+    /// # Note
+    ///
+    /// The host function is shared by all [`Store`] entities instantiated via this
+    /// [`Linker`] and calls to it are serialized. A call from one [`Store`] waits
+    /// until a concurrent call from another [`Store`] on another thread has returned.
+    ///
+    /// # Panics (Execution)
+    ///
+    /// If the host function is called re-entrantly by the same [`Store`], e.g. when
+    /// `func` calls back into Wasm which then calls the same host function again.
+    ///
+    /// # Errors
+    ///
+    /// If there already is a definition under the same name for this [`Linker`].
+    ///
+    /// [`Store`]: crate::Store
+    pub fn func_wrap_mut<Params, Results>(
+        &mut self,
+        module: &str,
+        name: &str,
+        func: impl FnMut(Caller<'_, T>, Params) -> Results + Send + 'static,
+    ) -> Result<&mut Self, LinkerError>
+    where
+        Params: WasmTyList,
+        Results: WasmRet,
+    {
+        self.ensure_undefined(module, name)?;
+        let func = HostFuncTrampolineEntity::wrap_mut(func);
+        let key = self.inner_mut().new_import_key(module, name);
+        self.inner_mut().insert(key, Definition::HostFunc(func))?;
         Ok(self)
     }
 
@@ -514,12 +810,22 @@ impl<T> Linker<T> {
             context.as_context().store.engine(),
             self.engine()
         ));
+        self.lookup_definition(module, name)
+    }
+
+    /// Looks up a [`Definition`] by name in this [`Linker`] without a store context.
+    ///
+    /// Returns `None` if this name was not previously defined in this [`Linker`].
+    fn lookup_definition(&self, module: &str, name: &str) -> Option<&Definition<T>> {
         if let Some(shared) = &self.shared {
             if let Some(item) = shared.get_definition(module, name) {
                 return Some(item);
             }
         }
-        self.inner.get_definition(module, name)
+        if let Some(item) = self.inner.get_definition(module, name) {
+            return Some(item);
+        }
+        self.parent.as_ref()?.lookup_definition(module, name)
     }
 
     /// Convenience wrapper to define an entire [`Instance`]` in this [`Linker`].
@@ -549,10 +855,101 @@ impl<T> Linker<T> {
         ));
         let mut store = store.as_context_mut();
         for export in instance.exports(&mut store) {
-            let key = self.inner.new_import_key(module_name, export.name());
+            let key = self.inner_mut().new_import_key(module_name, export.name());
             let def = Definition::Extern(export.into_extern());
-            self.inner.insert(key, def)?;
+            self.inner_mut().insert(key, def)?;
+        }
+        Ok(self)
+    }
+
+    /// Instantiates `module` and defines all of its exports in this [`Linker`].
+    ///
+    /// This is a convenience wrapper around [`Linker::instantiate`], running the start
+    /// function of the resulting [`Instance`], and [`Linker::instance`]. The module name
+    /// for each export is `module_name` and the field name for each export is its name in
+    /// `module`. This allows to register the exports of a "prelude" [`Module`] as imports
+    /// for subsequently instantiated [`Module`]s.
+    ///
+    /// Returns the new [`Instance`] of `module`.
+    ///
+    /// # Errors
+    ///
+    /// - If any export of `module` would re-define an item in `self` that cannot be shadowed.
+    /// - If `module` cannot be instantiated with the definitions of `self`.
+    /// - If the start function of `module` traps.
+    ///
+    /// In all these cases no item is defined in `self`.
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of this [`Linker`] and the [`Engine`] of `store` are not the same.
+    pub fn define_from_module(
+        &mut self,
+        mut store: impl AsContextMut<Data = T>,
+        module_name: &str,
+        module: &Module,
+    ) -> Result<Instance, Error> {
+        for export in module.exports() {
+            let name = export.name();
+            self.ensure_undefined(module_name, name)?;
+            if !self.inner.allow_shadowing && self.inner.has_definition(module_name, name) {
+                return Err(Error::from(LinkerError::DuplicateDefinition {
+                    import_name: ImportName::new(module_name, name),
+                }));
+            }
         }
+        let instance = self.instantiate(&mut store, module)?.start(&mut store)?;
+        self.instance(&mut store, module_name, instance)?;
+        Ok(instance)
+    }
+
+    /// Instantiates `module` and defines all of its exports under `module_name`.
+    ///
+    /// This is the same as [`Linker::define_from_module`] but does not return the
+    /// new [`Instance`] which allows to compose multiple [`Module`]s purely by name.
+    ///
+    /// # Errors
+    ///
+    /// If [`Linker::define_from_module`] fails.
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of this [`Linker`] and the [`Engine`] of `store` are not the same.
+    pub fn module(
+        &mut self,
+        store: impl AsContextMut<Data = T>,
+        module_name: &str,
+        module: &Module,
+    ) -> Result<(), Error> {
+        self.define_from_module(store, module_name, module)?;
+        Ok(())
+    }
+
+    /// Aliases the definition of `module` and `name` as `as_module` and `as_name`.
+    ///
+    /// This allows to re-export individual items under different names.
+    /// The definition is looked up in this [`Linker`] including its parents.
+    ///
+    /// # Errors
+    ///
+    /// - If there is no definition for `module` and `name`.
+    /// - If there already is a definition for `as_module` and `as_name` that cannot be shadowed.
+    pub fn alias(
+        &mut self,
+        module: &str,
+        name: &str,
+        as_module: &str,
+        as_name: &str,
+    ) -> Result<&mut Self, LinkerError> {
+        let Some(definition) = self.lookup_definition(module, name).cloned() else {
+            return Err(LinkerError::UndefinedAlias {
+                module: module.into(),
+                name: name.into(),
+            });
+        };
+        self.ensure_undefined(as_module, as_name)?;
+        let key = self.inner_mut().new_import_key(as_module, as_name);
+        self.inner_mut().insert(key, definition)?;
         Ok(self)
     }
 
@@ -561,16 +958,107 @@ impl<T> Linker<T> {
     /// This method will alias all currently defined under `module` to also be
     /// defined under the name `as_module` too.
     ///
+    /// The definitions under `module` are looked up in this [`Linker`] including its parents.
+    ///
     /// # Errors
     ///
     /// Returns an error if any shadowing violations happen while defining new
     /// items.
     pub fn alias_module(&mut self, module: &str, as_module: &str) -> Result<(), Error> {
-        self.inner.alias_module(module, as_module)
+        let items = self
+            .definitions()
+            .filter(|(item_module, _, _)| *item_module == module)
+            .map(|(_, name, definition)| (Box::<str>::from(name), definition.clone()))
+            .collect::<Vec<_>>();
+        for (name, definition) in items {
+            self.ensure_undefined(as_module, &name)?;
+            let key = self.inner_mut().new_import_key(as_module, &name);
+            self.inner_mut().insert(key, definition)?;
+        }
+        Ok(())
+    }
+
+    /// Merges all definitions of `other` into this [`Linker`].
+    ///
+    /// This allows to define the common host imports once in a base [`Linker`]
+    /// and then [`Clone`] it or [`extend`](Linker::extend) per-request [`Linker`]s with it
+    /// instead of rebuilding the common host imports every time.
+    ///
+    /// # Shadowing
+    ///
+    /// If both [`Linker`]s define the same name:
+    ///
+    /// - The definition of `other` replaces the definition of `self` if `self`
+    ///   allows shadowing via [`Linker::allow_shadowing`].
+    /// - Otherwise [`LinkerError::DuplicateDefinition`] is returned.
+    ///
+    /// Definitions shared via the [`LinkerBuilder`] of `self` can never be shadowed.
+    ///
+    /// # Errors
+    ///
+    /// If `other` defines a name that is already defined by `self` and cannot be shadowed.
+    /// In this case `self` remains unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of `self` and the [`Engine`] of `other` are not the same.
+    pub fn extend(&mut self, other: &Linker<T>) -> Result<&mut Self, LinkerError> {
+        assert!(Engine::same(self.engine(), other.engine()));
+        for (module, name, _) in other.definitions() {
+            self.ensure_undefined(module, name)?;
+            if !self.inner.allow_shadowing && self.inner.has_definition(module, name) {
+                return Err(LinkerError::DuplicateDefinition {
+                    import_name: ImportName::new(module, name),
+                });
+            }
+        }
+        for (module, name, definition) in other.definitions() {
+            let key = self.inner_mut().new_import_key(module, name);
+            self.inner_mut().insert(key, definition.clone())?;
+        }
+        Ok(self)
+    }
+
+    /// Checks that all host functions defined in this [`Linker`] are compatible with `config`.
+    ///
+    /// This is useful if the [`Linker`] is populated before the [`Config`] of the
+    /// [`Engine`] is finalized. The check is static and does not execute anything.
+    ///
+    /// # Note
+    ///
+    /// Only [`Linker`] host functions, as defined via [`Linker::func_wrap`] or
+    /// [`Linker::func_new`], are checked since their types are known without a [`Store`].
+    /// The types of items defined via [`Linker::define`] are checked upon instantiation.
+    ///
+    /// # Errors
+    ///
+    /// If a host function uses value types or a number of parameters or results
+    /// that are not supported by the Wasm proposals or the [`EnforcedLimits`] of `config`.
+    ///
+    /// [`Store`]: crate::Store
+    /// [`EnforcedLimits`]: crate::EnforcedLimits
+    pub fn check_against_config(&self, config: &Config) -> Result<(), LinkerError> {
+        for (module, name, definition) in self.definitions() {
+            let Definition::HostFunc(host_func) = definition else {
+                continue;
+            };
+            check_func_type(host_func.func_type(), config).map_err(|description| {
+                LinkerError::IncompatibleExtern {
+                    name: ImportName::new(module, name),
+                    description,
+                }
+            })?;
+        }
+        Ok(())
     }
 
     /// Instantiates the given [`Module`] using the definitions in the [`Linker`].
     ///
+    /// # Note
+    ///
+    /// This resolves all imports of the [`Module`] by name each time.
+    /// Use [`Linker::instantiate_pre`] to instantiate the same [`Module`] many times.
+    ///
     /// # Panics
     ///
     /// If the [`Engine`] of the [`Linker`] and `context` are not the same.
@@ -585,6 +1073,8 @@ impl<T> Linker<T> {
         module: &Module,
     ) -> Result<InstancePre, Error> {
         assert!(Engine::same(self.engine(), context.as_context().engine()));
+        #[cfg(feature = "extra-checks")]
+        debug_assert!(self.check_against_config(self.engine().config()).is_ok());
         // TODO: possibly add further resource limtation here on number of externals.
         // Not clear that user can't import the same external lots of times to inflate this.
         let externals = module
@@ -605,80 +1095,139 @@ impl<T> Linker<T> {
     /// If the imported item does not satisfy constraints set by the [`Module`].
     fn process_import(
         &self,
-        mut context: impl AsContextMut<Data = T>,
+        context: impl AsContextMut<Data = T>,
         import: ImportType,
     ) -> Result<Extern, Error> {
         assert!(Engine::same(self.engine(), context.as_context().engine()));
-        let import_name = import.import_name();
-        let module_name = import.module();
-        let field_name = import.name();
         let resolved = self
-            .get_definition(context.as_context(), module_name, field_name)
+            .get_definition(context.as_context(), import.module(), import.name())
             .ok_or_else(|| LinkerError::missing_definition(&import))?;
-        let invalid_type = || LinkerError::invalid_type_definition(&import, &resolved.ty(&context));
-        match import.ty() {
-            ExternType::Func(expected_type) => {
-                let found_type = resolved
-                    .ty(&context)
-                    .func()
-                    .cloned()
-                    .ok_or_else(invalid_type)?;
-                if &found_type != expected_type {
-                    return Err(Error::from(LinkerError::func_type_mismatch(
-                        import_name,
-                        expected_type,
-                        &found_type,
-                    )));
-                }
-                let func = resolved
-                    .as_func(&mut context)
-                    .expect("already asserted that `resolved` is a function");
-                Ok(Extern::Func(func))
-            }
-            ExternType::Table(expected_type) => {
-                let table = resolved
-                    .as_extern()
-                    .copied()
-                    .and_then(Extern::into_table)
-                    .ok_or_else(invalid_type)?;
-                let found_type = table.dynamic_ty(context);
-                found_type.is_subtype_or_err(expected_type).map_err(|_| {
-                    LinkerError::table_type_mismatch(import_name, expected_type, &found_type)
-                })?;
-                Ok(Extern::Table(table))
-            }
-            ExternType::Memory(expected_type) => {
-                let memory = resolved
-                    .as_extern()
-                    .copied()
-                    .and_then(Extern::into_memory)
-                    .ok_or_else(invalid_type)?;
-                let found_type = memory.dynamic_ty(context);
-                found_type.is_subtype_or_err(expected_type).map_err(|_| {
-                    LinkerError::invalid_memory_subtype(import_name, expected_type, &found_type)
-                })?;
-                Ok(Extern::Memory(memory))
-            }
-            ExternType::Global(expected_type) => {
-                let global = resolved
-                    .as_extern()
-                    .copied()
-                    .and_then(Extern::into_global)
-                    .ok_or_else(invalid_type)?;
-                let found_type = global.ty(context);
-                if &found_type != expected_type {
-                    return Err(Error::from(LinkerError::global_type_mismatch(
-                        import_name,
-                        expected_type,
-                        &found_type,
-                    )));
+        resolved.resolve_import(context, &import)
+    }
+
+    /// Resolves the imports of `module` once for repeated instantiation.
+    ///
+    /// The returned [`InstancePre2`] captures the [`Linker`] definitions of all imports
+    /// of `module` so that [`InstancePre2::instantiate`] no longer needs to look up
+    /// imports by name. Later changes to the [`Linker`] do not affect the [`InstancePre2`].
+    ///
+    /// # Note
+    ///
+    /// - Host functions defined via [`Linker::func_wrap`] or [`Linker::func_new`] are
+    ///   type checked here and may be instantiated within any [`Store`] of the [`Engine`].
+    /// - Items defined via [`Linker::define`] belong to a single [`Store`] and are
+    ///   type checked upon [`InstancePre2::instantiate`] using that [`Store`].
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of the [`Linker`] and `module` are not the same.
+    ///
+    /// # Errors
+    ///
+    /// - If the linker does not define imports of the [`Module`].
+    /// - If an imported host function does not satisfy its type requirements.
+    ///
+    /// [`Store`]: crate::Store
+    pub fn instantiate_pre(&self, module: &Module) -> Result<InstancePre2<T>, Error> {
+        assert!(Engine::same(self.engine(), module.engine()));
+        let definitions = module
+            .imports()
+            .map(|import| {
+                let resolved = self
+                    .lookup_definition(import.module(), import.name())
+                    .ok_or_else(|| LinkerError::missing_definition(&import))?;
+                if let Definition::HostFunc(host_func) = resolved {
+                    let found_type = host_func.func_type();
+                    let Some(expected_type) = import.ty().func() else {
+                        let found_type = ExternType::Func(found_type.clone());
+                        return Err(Error::from(LinkerError::invalid_type_definition(
+                            &import,
+                            &found_type,
+                        )));
+                    };
+                    if found_type != expected_type {
+                        return Err(Error::from(LinkerError::func_type_mismatch(
+                            import.import_name(),
+                            expected_type,
+                            found_type,
+                        )));
+                    }
                 }
-                Ok(Extern::Global(global))
-            }
+                Ok(resolved.clone())
+            })
+            .collect::<Result<Arc<[_]>, Error>>()?;
+        Ok(InstancePre2 {
+            module: module.clone(),
+            definitions,
+        })
+    }
+}
+
+/// A [`Module`] with imports resolved by a [`Linker`] for repeated fast instantiation.
+///
+/// Create this type via [`Linker::instantiate_pre`].
+#[derive(Debug)]
+pub struct InstancePre2<T> {
+    /// The [`Module`] to instantiate.
+    module: Module,
+    /// The [`Linker`] definitions of all imports of `module` in order.
+    definitions: Arc<[Definition<T>]>,
+}
+
+impl<T> Clone for InstancePre2<T> {
+    fn clone(&self) -> Self {
+        Self {
+            module: self.module.clone(),
+            definitions: self.definitions.clone(),
         }
     }
 }
 
+impl<T> InstancePre2<T> {
+    /// Returns the [`Module`] of the [`InstancePre2`].
+    pub fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Instantiates the [`Module`] within `context` using the resolved imports.
+    ///
+    /// This only allocates and initializes the new [`Instance`] since all
+    /// imports have already been resolved by [`Linker::instantiate_pre`].
+    ///
+    /// # Panics
+    ///
+    /// If the [`Engine`] of the [`Module`] and `context` are not the same.
+    ///
+    /// # Errors
+    ///
+    /// - If an imported item defined via [`Linker::define`] does not belong to the
+    ///   [`Store`](crate::Store) of `context` or does not satisfy its type requirements.
+    /// - If the instantiation of the [`Module`] fails.
+    pub fn instantiate(
+        &self,
+        mut context: impl AsContextMut<Data = T>,
+    ) -> Result<InstancePre, Error> {
+        assert!(Engine::same(
+            self.module.engine(),
+            context.as_context().engine()
+        ));
+        let externals = self
+            .module
+            .imports()
+            .zip(self.definitions.iter())
+            .map(|(import, definition)| match definition {
+                Definition::HostFunc(_) => Ok(Extern::Func(
+                    definition
+                        .as_func(&mut context)
+                        .expect("host function definitions are always functions"),
+                )),
+                Definition::Extern(_) => definition.resolve_import(&mut context, &import),
+            })
+            .collect::<Result<Vec<Extern>, Error>>()?;
+        self.module.instantiate(context, externals)
+    }
+}
+
 /// Contains type states for the [`LinkerBuilder`] construction process.
 pub mod state {
     /// Signals that the [`LinkerBuilder`] is itself under construction.
@@ -719,7 +1268,8 @@ impl<T> LinkerBuilder<state::Ready, T> {
         Linker {
             engine: engine.clone(),
             shared: self.inner.clone().into(),
-            inner: <LinkerInner<T>>::default(),
+            parent: None,
+            inner: Arc::new(<LinkerInner<T>>::default()),
         }
     }
 }
@@ -800,6 +1350,63 @@ impl<T> LinkerBuilder<state::Constructing, T> {
         self.inner_mut().func_wrap(module, name, func)?;
         Ok(self)
     }
+
+    /// Creates a new named host [`Func`] from a `FnMut` closure for this [`Linker`].
+    ///
+    /// For more information see [`Linker::func_wrap_mut`].
+    ///
+    /// # Errors
+    ///
+    /// If there already is a definition under the same name for this [`Linker`].
+    ///
+    /// # Panics
+    ///
+    /// If the [`LinkerBuilder`] has already created a [`Linker`] using [`LinkerBuilder::finish`].
+    pub fn func_wrap_mut<Params, Results>(
+        &mut self,
+        module: &str,
+        name: &str,
+        func: impl FnMut(Caller<'_, T>, Params) -> Results + Send + 'static,
+    ) -> Result<&mut Self, LinkerError>
+    where
+        Params: WasmTyList,
+        Results: WasmRet,
+    {
+        self.inner_mut().func_wrap_mut(module, name, func)?;
+        Ok(self)
+    }
+}
+
+/// A guard that restores a [`Linker`] definition overridden by [`Linker::scoped_define`] when dropped.
+#[derive(Debug)]
+pub struct ScopedLinkerGuard<'a, T> {
+    /// The [`Linker`] with the overridden definition.
+    linker: &'a mut Linker<T>,
+    /// The import key of the overridden definition.
+    key: ImportKey,
+    /// The definition that was overridden if any.
+    previous: Option<Definition<T>>,
+}
+
+impl<T> Deref for ScopedLinkerGuard<'_, T> {
+    type Target = Linker<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.linker
+    }
+}
+
+impl<T> DerefMut for ScopedLinkerGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.linker
+    }
+}
+
+impl<T> Drop for ScopedLinkerGuard<'_, T> {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        self.linker.inner_mut().replace(self.key, previous);
+    }
 }
 
 /// Internal [`Linker`] implementation.
@@ -897,26 +1504,15 @@ impl<T> LinkerInner<T> {
         Ok(())
     }
 
-    /// Aliases one module's name as another.
+    /// Replaces the definition under the import key with `item`.
     ///
-    /// Read more about this method in [`Linker::alias_module`].
-    pub fn alias_module(&mut self, module: &str, as_module: &str) -> Result<(), Error> {
-        let module = self
-            .strings
-            .get_or_intern_with_hint(module, InternHint::LikelyExists);
-        let as_module = self
-            .strings
-            .get_or_intern_with_hint(as_module, InternHint::LikelyNew);
-        let items = self
-            .definitions
-            .iter()
-            .filter(|(key, _def)| key.module() == module)
-            .map(|(key, def)| (key.name(), def.clone()))
-            .collect::<Vec<_>>();
-        for (name, item) in items {
-            self.insert(ImportKey::new(as_module, name), item)?;
+    /// Removes the definition if `item` is `None`.
+    /// Returns the previous definition if any.
+    fn replace(&mut self, key: ImportKey, item: Option<Definition<T>>) -> Option<Definition<T>> {
+        match item {
+            Some(item) => self.definitions.insert(key, item),
+            None => self.definitions.remove(&key),
         }
-        Ok(())
     }
 
     /// Creates a new named [`Func::new`]-style host [`Func`] for this [`Linker`].
@@ -972,6 +1568,29 @@ impl<T> LinkerInner<T> {
         Ok(self)
     }
 
+    /// Creates a new named host [`Func`] from a `FnMut` closure for this [`Linker`].
+    ///
+    /// For more information see [`Linker::func_wrap_mut`].
+    ///
+    /// # Errors
+    ///
+    /// If there already is a definition under the same name for this [`Linker`].
+    pub fn func_wrap_mut<Params, Results>(
+        &mut self,
+        module: &str,
+        name: &str,
+        func: impl FnMut(Caller<'_, T>, Params) -> Results + Send + 'static,
+    ) -> Result<&mut Self, LinkerError>
+    where
+        Params: WasmTyList,
+        Results: WasmRet,
+    {
+        let func = HostFuncTrampolineEntity::wrap_mut(func);
+        let key = self.new_import_key(module, name);
+        self.insert(key, Definition::HostFunc(func))?;
+        Ok(self)
+    }
+
     /// Looks up a [`Definition`] by name in this [`Linker`].
     ///
     /// Returns `None` if this name was not previously defined in this [`Linker`].
@@ -984,6 +1603,16 @@ impl<T> LinkerInner<T> {
         self.definitions.get(&key)
     }
 
+    /// Returns an iterator over the module names, item names and [`Definition`]s of the [`LinkerInner`].
+    fn definitions(&self) -> impl Iterator<Item = (&str, &str, &Definition<T>)> {
+        self.definitions.iter().map(|(key, definition)| {
+            let (module, name) = self
+                .resolve_import_key(*key)
+                .unwrap_or_else(|| panic!("encountered missing import names for key {key:?}"));
+            (module, name, definition)
+        })
+    }
+
     /// Returns `true` if [`LinkerInner`] contains a [`Definition`] for `name` in `module`.
     fn has_definition(&self, module: &str, name: &str) -> bool {
         let Some(key) = self.get_import_key(module, name) else {
@@ -1220,4 +1849,691 @@ mod tests {
         linker.define("host", "hello", func).unwrap();
         linker.instantiate(&mut store, &module).unwrap();
     }
+
+    #[test]
+    fn cross_store_definition() {
+        use crate::{Engine, Linker, Memory, MemoryType, Module, Store};
+        let wasm = r#"
+            (module
+                (import "env" "memory" (memory $mem 1))
+            )"#;
+        let engine = Engine::default();
+        let mut linker = <Linker<()>>::new(&engine);
+        let mut store = Store::new(&engine, ());
+        let mut other = Store::new(&engine, ());
+        let memory = Memory::new(&mut other, MemoryType::new(1, None).unwrap()).unwrap();
+        let module = Module::new(&engine, wasm).unwrap();
+        linker.define("env", "memory", memory).unwrap();
+        let error = linker.instantiate(&mut store, &module).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            crate::errors::ErrorKind::Linker(LinkerError::CrossStore { .. })
+        ));
+    }
+
+    #[test]
+    fn scoped_define_restores_previous() {
+        use crate::{Engine, Global, Linker, Mutability, Store, Val};
+        let engine = Engine::default();
+        let mut linker = <Linker<()>>::new(&engine);
+        let mut store = Store::new(&engine, ());
+        let original = Global::new(&mut store, Val::I32(1), Mutability::Const);
+        let mock = Global::new(&mut store, Val::I32(2), Mutability::Const);
+        let get = |linker: &Linker<()>, store: &Store<()>, name: &str| {
+            linker
+                .get(store, "env", name)
+                .and_then(Extern::into_global)
+                .map(|global| global.get(store))
+        };
+        linker.define("env", "global", original).unwrap();
+        {
+            let guard = linker.scoped_define("env", "global", mock).unwrap();
+            assert!(matches!(get(&guard, &store, "global"), Some(Val::I32(2))));
+        }
+        assert!(matches!(get(&linker, &store, "global"), Some(Val::I32(1))));
+        // Scoped definitions without previous definition are removed when dropped.
+        {
+            let guard = linker.scoped_define("env", "other", mock).unwrap();
+            assert!(matches!(get(&guard, &store, "other"), Some(Val::I32(2))));
+        }
+        assert!(get(&linker, &store, "other").is_none());
+    }
+
+    #[test]
+    fn memory_subtype_errors_name_provided_type_first() {
+        use crate::{Engine, Linker, Memory, MemoryType, Module, Store};
+        use alloc::string::ToString;
+        let wasm = r#"
+            (module
+                (import "env" "memory" (memory $mem 1 4))
+            )"#;
+        let engine = Engine::default();
+        let mut store = Store::new(&engine, ());
+        let module = Module::new(&engine, wasm).unwrap();
+        // Providing `(memory 2 4)` for `(memory 1 4)` is valid.
+        let memory = Memory::new(&mut store, MemoryType::new(2, Some(4)).unwrap()).unwrap();
+        let mut linker = <Linker<()>>::new(&engine);
+        linker.define("env", "memory", memory).unwrap();
+        linker.instantiate(&mut store, &module).unwrap();
+        // Providing `(memory 2)` for `(memory 1 4)` is invalid since the maximum is missing.
+        let memory = Memory::new(&mut store, MemoryType::new(2, None).unwrap()).unwrap();
+        let mut linker = <Linker<()>>::new(&engine);
+        linker.define("env", "memory", memory).unwrap();
+        let error = linker.instantiate(&mut store, &module).unwrap_err();
+        let crate::errors::ErrorKind::Linker(LinkerError::InvalidMemorySubtype {
+            ty, other, ..
+        }) = error.kind()
+        else {
+            panic!("unexpected error: {error}")
+        };
+        assert_eq!(ty, &MemoryType::new(2, None).unwrap());
+        assert_eq!(other, &MemoryType::new(1, Some(4)).unwrap());
+        assert!(error.to_string().contains("missing maximum"));
+    }
+
+    #[test]
+    fn extend_merges_definitions() {
+        use crate::{Engine, Global, Linker, Module, Mutability, Store, Val};
+        let wasm = r#"
+            (module
+                (import "env" "base" (func $base (result i32)))
+                (import "env" "request" (global $request i32))
+                (func (export "run") (result i32)
+                    (i32.add (call $base) (global.get $request))
+                )
+            )"#;
+        let engine = Engine::default();
+        let mut base = <Linker<()>>::new(&engine);
+        base.func_wrap("env", "base", || 40_i32).unwrap();
+        let module = Module::new(&engine, wasm).unwrap();
+        for n in 0..3 {
+            let mut store = Store::new(&engine, ());
+            let request = Global::new(&mut store, Val::I32(n), Mutability::Const);
+            let mut linker = <Linker<()>>::new(&engine);
+            linker.define("env", "request", request).unwrap();
+            linker.extend(&base).unwrap();
+            let instance = linker
+                .instantiate(&mut store, &module)
+                .unwrap()
+                .start(&mut store)
+                .unwrap();
+            let run = instance.get_typed_func::<(), i32>(&store, "run").unwrap();
+            assert_eq!(run.call(&mut store, ()).unwrap(), 40 + n);
+        }
+    }
+
+    #[test]
+    fn extend_shadowing() {
+        use crate::{Engine, Global, Linker, Mutability, Store, Val};
+        let engine = Engine::default();
+        let mut store = Store::new(&engine, ());
+        let get = |linker: &Linker<()>, store: &Store<()>, name: &str| {
+            linker
+                .get(store, "env", name)
+                .and_then(Extern::into_global)
+                .map(|global| global.get(store))
+        };
+        let global = |store: &mut Store<()>, value: i32| {
+            Global::new(store, Val::I32(value), Mutability::Const)
+        };
+        let mut other = <Linker<()>>::new(&engine);
+        other.define("env", "a", global(&mut store, 1)).unwrap();
+        other.define("env", "b", global(&mut store, 2)).unwrap();
+        // Without shadowing conflicting definitions error and leave `linker` unchanged.
+        let mut linker = <Linker<()>>::new(&engine);
+        linker.define("env", "b", global(&mut store, 3)).unwrap();
+        assert!(matches!(
+            linker.extend(&other).unwrap_err(),
+            LinkerError::DuplicateDefinition { .. }
+        ));
+        assert!(get(&linker, &store, "a").is_none());
+        assert!(matches!(get(&linker, &store, "b"), Some(Val::I32(3))));
+        // With shadowing the definitions of `other` take precedence.
+        linker.allow_shadowing(true);
+        linker.extend(&other).unwrap();
+        assert!(matches!(get(&linker, &store, "a"), Some(Val::I32(1))));
+        assert!(matches!(get(&linker, &store, "b"), Some(Val::I32(2))));
+    }
+
+    #[test]
+    fn extend_no_shared_overwrite() {
+        use crate::{Engine, Linker};
+        let engine = Engine::default();
+        let mut builder = <Linker<()>>::build();
+        builder
+            .func_wrap("host", "func.0", |_caller: Caller<()>| ())
+            .unwrap();
+        let shared = builder.finish();
+        let mut linker = shared.create(&engine);
+        linker.allow_shadowing(true);
+        // Definitions shared by the builder cannot be shadowed even if allowed.
+        let other = shared.create(&engine);
+        assert!(matches!(
+            linker.extend(&other).unwrap_err(),
+            LinkerError::DuplicateDefinition { .. }
+        ));
+        // Shared definitions of `other` are merged into `linker`.
+        let mut linker = <Linker<()>>::new(&engine);
+        linker.extend(&other).unwrap();
+        let store = crate::Store::new(&engine, ());
+        assert!(linker.get_definition(&store, "host", "func.0").is_some());
+    }
+
+    #[test]
+    fn define_from_module_works() {
+        use crate::{Engine, Linker, Module, Store};
+        let engine = Engine::default();
+        let mut store = Store::new(&engine, ());
+        let prelude = Module::new(
+            &engine,
+            r#"
+            (module
+                (global $counter (export "counter") (mut i32) (i32.const 0))
+                (func (export "bump") (result i32)
+                    (global.set $counter (i32.add (global.get $counter) (i32.const 1)))
+                    (global.get $counter)
+                )
+            )
+            "#,
+        )
+        .unwrap();
+        let user = Module::new(
+            &engine,
+            r#"
+            (module
+                (import "prelude" "bump" (func $bump (result i32)))
+                (func (export "run") (result i32)
+                    (drop (call $bump))
+                    (call $bump)
+                )
+            )
+            "#,
+        )
+        .unwrap();
+        let mut linker = <Linker<()>>::new(&engine);
+        let prelude_instance = linker
+            .define_from_module(&mut store, "prelude", &prelude)
+            .unwrap();
+        assert!(linker.get(&store, "prelude", "counter").is_some());
+        let instance = linker
+            .instantiate(&mut store, &user)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let run = instance.get_typed_func::<(), i32>(&store, "run").unwrap();
+        assert_eq!(run.call(&mut store, ()).unwrap(), 2);
+        let counter = prelude_instance.get_global(&store, "counter").unwrap();
+        assert!(matches!(counter.get(&store), crate::Val::I32(2)));
+        // Defining the exports again fails since shadowing is not allowed.
+        let error = linker
+            .define_from_module(&mut store, "prelude", &prelude)
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            crate::errors::ErrorKind::Linker(LinkerError::DuplicateDefinition { .. })
+        ));
+    }
+
+    #[test]
+    fn module_and_alias_compose_modules() {
+        use crate::{Engine, Linker, Module, Store};
+        let engine = Engine::default();
+        let mut store = Store::new(&engine, ());
+        let a = Module::new(
+            &engine,
+            r#"
+            (module
+                (global (export "base") i32 (i32.const 10))
+                (func (export "inc") (param i32) (result i32)
+                    (i32.add (local.get 0) (i32.const 1))
+                )
+            )
+            "#,
+        )
+        .unwrap();
+        let b = Module::new(
+            &engine,
+            r#"
+            (module
+                (import "a" "inc" (func $inc (param i32) (result i32)))
+                (func (export "inc2") (param i32) (result i32)
+                    (call $inc (call $inc (local.get 0)))
+                )
+            )
+            "#,
+        )
+        .unwrap();
+        let c = Module::new(
+            &engine,
+            r#"
+            (module
+                (import "b" "inc2" (func $inc2 (param i32) (result i32)))
+                (import "b" "base" (global $base i32))
+                (func (export "run") (result i32)
+                    (call $inc2 (global.get $base))
+                )
+            )
+            "#,
+        )
+        .unwrap();
+        let mut linker = <Linker<()>>::new(&engine);
+        linker.module(&mut store, "a", &a).unwrap();
+        linker.module(&mut store, "b", &b).unwrap();
+        linker.alias("a", "base", "b", "base").unwrap();
+        linker.module(&mut store, "c", &c).unwrap();
+        let run = linker
+            .get(&store, "c", "run")
+            .and_then(Extern::into_func)
+            .unwrap()
+            .typed::<(), i32>(&store)
+            .unwrap();
+        assert_eq!(run.call(&mut store, ()).unwrap(), 12);
+        // Aliasing requires an existing definition and respects shadowing.
+        assert!(matches!(
+            linker.alias("a", "missing", "b", "missing"),
+            Err(LinkerError::UndefinedAlias { module, name })
+                if &*module == "a" && &*name == "missing"
+        ));
+        assert!(matches!(
+            linker.alias("a", "inc", "b", "inc2"),
+            Err(LinkerError::DuplicateDefinition { .. })
+        ));
+    }
+
+    #[test]
+    fn check_against_config_works() {
+        use crate::{Config, EnforcedLimits, Engine, ExternRef, Linker};
+        let engine = Engine::default();
+        let mut linker = <Linker<()>>::new(&engine);
+        linker
+            .func_wrap("host", "add", |lhs: i32, rhs: i32| lhs + rhs)
+            .unwrap();
+        assert!(linker.check_against_config(engine.config()).is_ok());
+        let mut config = Config::default();
+        config.wasm_reference_types(false);
+        assert!(linker.check_against_config(&config).is_ok());
+        linker
+            .func_wrap("host", "is_null", |value: ExternRef| {
+                i32::from(value.is_null())
+            })
+            .unwrap();
+        assert!(linker.check_against_config(engine.config()).is_ok());
+        assert!(matches!(
+            linker.check_against_config(&config),
+            Err(LinkerError::IncompatibleExtern { name, .. })
+                if name.module() == "host" && name.name() == "is_null"
+        ));
+        let mut config = Config::default();
+        config.deny_floating_point(true);
+        linker.func_wrap("host", "sqrt", f32::sqrt).unwrap();
+        assert!(linker.check_against_config(&config).is_err());
+        let mut config = Config::default();
+        config.floats(false);
+        assert!(linker.check_against_config(&config).is_err());
+        let mut config = Config::default();
+        config.wasm_multi_value(false);
+        linker.func_wrap("host", "pair", || (1_i32, 2_i32)).unwrap();
+        assert!(linker.check_against_config(&config).is_err());
+        // Child linkers also check the host functions of their parents.
+        let child = linker.with_parent();
+        assert!(child.check_against_config(&config).is_err());
+        let mut config = Config::default();
+        config.enforced_limits(EnforcedLimits::strict());
+        let mut linker = <Linker<()>>::new(&engine);
+        linker
+            .func_new(
+                "host",
+                "many_params",
+                FuncType::new([ValType::I32; 64], []),
+                |_caller, _params, _results| Ok(()),
+            )
+            .unwrap();
+        assert!(linker.check_against_config(engine.config()).is_ok());
+        assert!(linker.check_against_config(&config).is_err());
+    }
+
+    #[test]
+    fn instantiate_pre_works() {
+        use crate::{Engine, Linker, Module, Store};
+        let engine = Engine::default();
+        let module = Module::new(
+            &engine,
+            r#"
+            (module
+                (import "host" "value" (func $value (result i32)))
+                (func (export "run") (result i32)
+                    (call $value)
+                )
+            )
+            "#,
+        )
+        .unwrap();
+        let mut linker = <Linker<i32>>::new(&engine);
+        linker.allow_shadowing(true);
+        linker
+            .func_wrap("host", "value", |caller: Caller<i32>| *caller.data())
+            .unwrap();
+        let pre = linker.instantiate_pre(&module).unwrap();
+        // Later linker mutations do not affect the resolved imports.
+        linker.func_wrap("host", "value", || -1_i32).unwrap();
+        // Host functions can be instantiated within any store of the engine.
+        for data in [1, 2] {
+            let mut store = Store::new(&engine, data);
+            let instance = pre
+                .instantiate(&mut store)
+                .unwrap()
+                .start(&mut store)
+                .unwrap();
+            let run = instance.get_typed_func::<(), i32>(&store, "run").unwrap();
+            assert_eq!(run.call(&mut store, ()).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn instantiate_pre_errors() {
+        use crate::{errors::ErrorKind, Engine, Global, Linker, Module, Mutability, Store, Val};
+        let engine = Engine::default();
+        let module = Module::new(
+            &engine,
+            r#"
+            (module
+                (import "host" "func" (func (param i32)))
+                (import "host" "global" (global i32))
+            )
+            "#,
+        )
+        .unwrap();
+        let mut linker = <Linker<()>>::new(&engine);
+        let error = linker.instantiate_pre(&module).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorKind::Linker(LinkerError::MissingDefinition { .. })
+        ));
+        linker.func_wrap("host", "func", |_: i64| ()).unwrap();
+        let error = linker.instantiate_pre(&module).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorKind::Linker(LinkerError::FuncTypeMismatch { .. })
+        ));
+        let mut linker = <Linker<()>>::new(&engine);
+        linker.func_wrap("host", "func", |_: i32| ()).unwrap();
+        let mut store = Store::new(&engine, ());
+        let global = Global::new(&mut store, Val::I32(0), Mutability::Const);
+        linker.define("host", "global", global).unwrap();
+        let pre = linker.instantiate_pre(&module).unwrap();
+        pre.instantiate(&mut store).unwrap();
+        // Store owned definitions cannot be used with other stores.
+        let mut other = Store::new(&engine, ());
+        let error = pre.instantiate(&mut other).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorKind::Linker(LinkerError::CrossStore { .. })
+        ));
+    }
+
+    #[test]
+    fn func_wrap_mut_works() {
+        use crate::{Engine, Linker, Module, Store};
+        let engine = Engine::default();
+        let module = Module::new(
+            &engine,
+            r#"
+            (module
+                (import "host" "count" (func $count (param i32) (result i32)))
+                (func (export "run") (param i32) (result i32)
+                    (call $count (local.get 0))
+                )
+            )
+            "#,
+        )
+        .unwrap();
+        let mut linker = <Linker<()>>::new(&engine);
+        let mut counter = 0;
+        linker
+            .func_wrap_mut("host", "count", move |_: Caller<()>, delta: i32| {
+                counter += delta;
+                counter
+            })
+            .unwrap();
+        let mut store = Store::new(&engine, ());
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+        assert_eq!(run.call(&mut store, 1).unwrap(), 1);
+        assert_eq!(run.call(&mut store, 2).unwrap(), 3);
+        assert_eq!(run.call(&mut store, 3).unwrap(), 6);
+    }
+
+    #[test]
+    fn func_wrap_mut_waits_for_other_threads() {
+        use crate::{Engine, Linker, Module, Store};
+        use std::thread;
+        const THREADS: i32 = 2;
+        const CALLS: i32 = 1000;
+        let engine = Engine::default();
+        let module = Module::new(
+            &engine,
+            r#"
+            (module
+                (import "host" "count" (func $count (param i32) (result i32)))
+                (func (export "run") (param i32) (result i32)
+                    (call $count (local.get 0))
+                )
+            )
+            "#,
+        )
+        .unwrap();
+        let mut linker = <Linker<()>>::new(&engine);
+        let mut counter = 0;
+        linker
+            .func_wrap_mut("host", "count", move |_: Caller<()>, delta: i32| {
+                let old = counter;
+                // Give other threads the chance to contend for the host function.
+                thread::yield_now();
+                counter = old + delta;
+                counter
+            })
+            .unwrap();
+        let run_calls = |calls: i32| {
+            let mut store = Store::new(&engine, ());
+            let instance = linker
+                .instantiate(&mut store, &module)
+                .unwrap()
+                .start(&mut store)
+                .unwrap();
+            let run = instance.get_typed_func::<i32, i32>(&store, "run").unwrap();
+            (0..calls)
+                .map(|_| run.call(&mut store, 1).unwrap())
+                .last()
+                .unwrap()
+        };
+        thread::scope(|scope| {
+            for _ in 0..THREADS {
+                scope.spawn(|| run_calls(CALLS));
+            }
+        });
+        // No call was lost or failed due to contention between the threads.
+        assert_eq!(run_calls(1), THREADS * CALLS + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "called re-entrantly")]
+    fn func_wrap_mut_panics_on_reentrancy() {
+        use crate::{Engine, Linker, Module, Store};
+        let engine = Engine::default();
+        let module = Module::new(
+            &engine,
+            r#"
+            (module
+                (import "host" "reenter" (func $reenter (param i32)))
+                (func (export "run") (param i32)
+                    (call $reenter (local.get 0))
+                )
+            )
+            "#,
+        )
+        .unwrap();
+        let mut linker = <Linker<()>>::new(&engine);
+        linker
+            .func_wrap_mut("host", "reenter", |mut caller: Caller<()>, depth: i32| {
+                if depth == 0 {
+                    return Ok(());
+                }
+                let run = caller
+                    .instance()
+                    .unwrap()
+                    .get_typed_func::<i32, ()>(&caller, "run")
+                    .unwrap();
+                run.call(&mut caller, depth - 1)
+            })
+            .unwrap();
+        let mut store = Store::new(&engine, ());
+        let instance = linker
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let run = instance.get_typed_func::<i32, ()>(&store, "run").unwrap();
+        run.call(&mut store, 1).unwrap();
+    }
+
+    #[test]
+    fn with_parent_works() {
+        use crate::{Engine, Linker, Module, Store};
+        let engine = Engine::default();
+        let module = Module::new(
+            &engine,
+            r#"
+            (module
+                (import "host" "a" (func $a (result i32)))
+                (import "host" "b" (func $b (result i32)))
+                (func (export "run") (result i32)
+                    (i32.add (call $a) (call $b))
+                )
+            )
+            "#,
+        )
+        .unwrap();
+        let mut parent = <Linker<()>>::new(&engine);
+        parent
+            .func_wrap("host", "a", || 1_i32)
+            .unwrap()
+            .func_wrap("host", "b", || 10_i32)
+            .unwrap();
+        let mut child = parent.with_parent();
+        // The child shares the definitions of its parent.
+        assert!(Arc::ptr_eq(
+            &child.parent.as_ref().unwrap().inner,
+            &parent.inner
+        ));
+        assert_eq!(child.len(), 2);
+        // The child may override definitions of its parent.
+        child.func_wrap("host", "b", || 100_i32).unwrap();
+        child.func_wrap("host", "c", || 1000_i32).unwrap();
+        assert_eq!(parent.len(), 2);
+        assert_eq!(child.len(), 3);
+        // Duplicate definitions within the child respect its shadowing configuration.
+        assert!(child.func_wrap("host", "b", || 0_i32).is_err());
+        let run = |linker: &Linker<()>| {
+            let mut store = Store::new(&engine, ());
+            let instance = linker
+                .instantiate(&mut store, &module)
+                .unwrap()
+                .start(&mut store)
+                .unwrap();
+            instance
+                .get_typed_func::<(), i32>(&store, "run")
+                .unwrap()
+                .call(&mut store, ())
+                .unwrap()
+        };
+        assert_eq!(run(&parent), 11);
+        assert_eq!(run(&child), 101);
+        let pre = child.instantiate_pre(&module).unwrap();
+        let mut store = Store::new(&engine, ());
+        let instance = pre
+            .instantiate(&mut store)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let func = instance.get_typed_func::<(), i32>(&store, "run").unwrap();
+        assert_eq!(func.call(&mut store, ()).unwrap(), 101);
+        // Mutating the parent does not affect its existing children.
+        parent.func_wrap("host", "d", || 0_i32).unwrap();
+        assert_eq!(parent.len(), 3);
+        assert_eq!(child.len(), 3);
+        assert!(!Arc::ptr_eq(
+            &child.parent.as_ref().unwrap().inner,
+            &parent.inner
+        ));
+    }
+
+    #[test]
+    fn with_parent_get_traverses_chain() {
+        use crate::{Engine, Global, Linker, Mutability, Store, Val};
+        let engine = Engine::default();
+        let mut store = Store::new(&engine, ());
+        let global = |store: &mut Store<()>, value: i32| {
+            Global::new(&mut *store, Val::I32(value), Mutability::Const)
+        };
+        let g0 = global(&mut store, 0);
+        let g1 = global(&mut store, 1);
+        let g2 = global(&mut store, 2);
+        let mut root = <Linker<()>>::new(&engine);
+        root.define("env", "x", g0).unwrap();
+        root.define("env", "y", g0).unwrap();
+        let mut middle = root.with_parent();
+        middle.define("env", "y", g1).unwrap();
+        let mut leaf = middle.with_parent();
+        leaf.define("env", "z", g2).unwrap();
+        let get = |linker: &Linker<()>, name: &str| {
+            linker
+                .get(&store, "env", name)
+                .and_then(Extern::into_global)
+                .map(|global| global.get(&store).i32().unwrap())
+        };
+        assert_eq!(get(&leaf, "x"), Some(0));
+        assert_eq!(get(&leaf, "y"), Some(1));
+        assert_eq!(get(&leaf, "z"), Some(2));
+        assert_eq!(get(&middle, "z"), None);
+        assert_eq!(get(&root, "y"), Some(0));
+        assert_eq!(leaf.len(), 3);
+        assert!(!leaf.is_empty());
+        assert!(<Linker<()>>::new(&engine).with_parent().is_empty());
+    }
+
+    #[test]
+    fn alias_module_traverses_chain() {
+        use crate::{Engine, Global, Linker, Mutability, Store, Val};
+        let engine = Engine::default();
+        let mut store = Store::new(&engine, ());
+        let global = |store: &mut Store<()>, value: i32| {
+            Global::new(&mut *store, Val::I32(value), Mutability::Const)
+        };
+        let g0 = global(&mut store, 0);
+        let g1 = global(&mut store, 1);
+        let g2 = global(&mut store, 2);
+        let mut root = <Linker<()>>::new(&engine);
+        root.define("env", "x", g0).unwrap();
+        root.define("env", "y", g0).unwrap();
+        let mut middle = root.with_parent();
+        middle.define("env", "y", g1).unwrap();
+        let mut leaf = middle.with_parent();
+        leaf.define("env", "z", g2).unwrap();
+        leaf.alias_module("env", "alias").unwrap();
+        let get = |linker: &Linker<()>, name: &str| {
+            linker
+                .get(&store, "alias", name)
+                .and_then(Extern::into_global)
+                .map(|global| global.get(&store).i32().unwrap())
+        };
+        assert_eq!(get(&leaf, "x"), Some(0));
+        assert_eq!(get(&leaf, "y"), Some(1));
+        assert_eq!(get(&leaf, "z"), Some(2));
+        assert_eq!(get(&middle, "x"), None);
+        assert_eq!(leaf.len(), 6);
+    }
 }
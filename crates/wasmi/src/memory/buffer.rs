@@ -132,11 +132,68 @@ impl ByteBuffer {
         }
     }
 
+    /// Shrinks the byte buffer to the given `new_size`.
+    ///
+    /// # Panics
+    ///
+    /// If the current size of the [`ByteBuffer`] is smaller than `new_size`.
+    pub fn truncate(&mut self, new_size: usize) {
+        assert!(new_size <= self.len());
+        match self.get_vec() {
+            Some(mut vec) => {
+                // Case: the byte buffer is backed by a `Vec<u8>`.
+                vec.truncate(new_size);
+                let (ptr, len, capacity) = vec_into_raw_parts(vec);
+                self.ptr = ptr;
+                self.len = len;
+                self.capacity = capacity;
+            }
+            None => {
+                // Case: the byte buffer is backed by a `&'static [u8]`.
+                self.len = new_size;
+            }
+        }
+    }
+
+    /// Discards the bytes of `buffer[offset..offset+len]` so that they read as zero.
+    ///
+    /// # Note
+    ///
+    /// On Linux and Android with the `std` feature enabled the physical pages of a
+    /// `Vec<u8>` backed byte buffer that are entirely within the range are released
+    /// to the operating system via `madvise(MADV_DONTNEED)`. This relies on heap
+    /// allocations being private anonymous memory as is the case for the system allocator.
+    /// All other bytes of the range are zeroed explicitly.
+    ///
+    /// # Panics
+    ///
+    /// If `offset..offset+len` is out of bounds for the byte buffer.
+    pub fn discard(&mut self, offset: usize, len: usize) {
+        let is_static = self.is_static;
+        let bytes = &mut self.data_mut()[offset..offset + len];
+        let (head, tail) = match is_static {
+            true => (bytes, &mut [][..]),
+            false => release_pages(bytes),
+        };
+        head.fill(0x00_u8);
+        tail.fill(0x00_u8);
+    }
+
     /// Returns the length of the byte buffer in bytes.
     pub fn len(&self) -> usize {
         self.len
     }
 
+    /// Returns the number of bytes the byte buffer allocated on the heap.
+    ///
+    /// Returns `0` if the byte buffer is backed by a user provided static buffer.
+    pub fn allocated_bytes(&self) -> usize {
+        match self.is_static {
+            true => 0,
+            false => self.capacity,
+        }
+    }
+
     /// Returns a shared slice to the bytes underlying to the byte buffer.
     pub fn data(&self) -> &[u8] {
         // # Safety
@@ -174,6 +231,75 @@ impl ByteBuffer {
     }
 }
 
+/// Releases the physical pages entirely within `bytes` to the operating system.
+///
+/// Returns the bytes before and after the released pages which still need to be zeroed.
+#[cfg(all(
+    feature = "std",
+    any(target_os = "linux", target_os = "android"),
+    not(miri)
+))]
+fn release_pages(bytes: &mut [u8]) -> (&mut [u8], &mut [u8]) {
+    // Safety: `sysconf` has no preconditions.
+    let page_size = match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+        page_size @ 1.. => page_size as usize,
+        _ => return (bytes, &mut []),
+    };
+    let addr = bytes.as_ptr() as usize;
+    let head_len = addr.next_multiple_of(page_size) - addr;
+    if head_len >= bytes.len() {
+        return (bytes, &mut []);
+    }
+    let pages_len = (bytes.len() - head_len) / page_size * page_size;
+    if pages_len == 0 {
+        return (bytes, &mut []);
+    }
+    let (head, rest) = bytes.split_at_mut(head_len);
+    let (pages, tail) = rest.split_at_mut(pages_len);
+    // Safety: `pages` is a page aligned and exclusively borrowed range of private anonymous
+    //         memory which reads as zero after `MADV_DONTNEED` and stays accessible.
+    let result =
+        unsafe { libc::madvise(pages.as_mut_ptr().cast(), pages.len(), libc::MADV_DONTNEED) };
+    if result != 0 {
+        pages.fill(0x00_u8);
+    }
+    (head, tail)
+}
+
+/// Releases the physical pages entirely within `bytes` to the operating system.
+///
+/// Returns the bytes before and after the released pages which still need to be zeroed.
+///
+/// # Note
+///
+/// Pages cannot be released on this target so all `bytes` still need to be zeroed.
+#[cfg(not(all(
+    feature = "std",
+    any(target_os = "linux", target_os = "android"),
+    not(miri)
+)))]
+fn release_pages(bytes: &mut [u8]) -> (&mut [u8], &mut [u8]) {
+    (bytes, &mut [])
+}
+
+impl Clone for ByteBuffer {
+    /// Clones the [`ByteBuffer`] into a new `Vec<u8>` backed [`ByteBuffer`].
+    ///
+    /// # Note
+    ///
+    /// Static byte buffers are also cloned into `Vec<u8>` backed byte buffers
+    /// since their underlying `&'static mut [u8]` cannot be shared.
+    fn clone(&self) -> Self {
+        let (ptr, len, capacity) = vec_into_raw_parts(self.data().to_vec());
+        Self {
+            ptr,
+            len,
+            capacity,
+            is_static: false,
+        }
+    }
+}
+
 impl Drop for ByteBuffer {
     fn drop(&mut self) {
         self.get_vec();
@@ -217,6 +343,18 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_truncating_buffer() {
+        let mut buffer = ByteBuffer::new(5);
+        buffer.data_mut().fill(1);
+        buffer.grow(10);
+        buffer.truncate(3);
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.data(), &[1; 3]);
+        buffer.grow(5);
+        assert_eq!(buffer.data(), &[1, 1, 1, 0, 0]);
+    }
+
     #[test]
     fn test_growing_buffer() {
         let mut buffer = ByteBuffer::new(5);
@@ -248,4 +386,33 @@ mod test {
         let mut buffer = ByteBuffer::new_static(buf, 5);
         buffer.grow(10); // This should panic.
     }
+
+    #[test]
+    fn test_discard() {
+        let len = 1 << 20;
+        let mut buffer = ByteBuffer::new(len);
+        buffer.data_mut().fill(0xFF);
+        buffer.discard(100, len - 200);
+        let data = buffer.data();
+        assert!(data[..100].iter().all(|&byte| byte == 0xFF));
+        assert!(data[100..len - 100].iter().all(|&byte| byte == 0x00));
+        assert!(data[len - 100..].iter().all(|&byte| byte == 0xFF));
+        // Discarded bytes can be written again.
+        buffer.data_mut().fill(0xAA);
+        buffer.discard(0, len);
+        assert!(buffer.data().iter().all(|&byte| byte == 0x00));
+    }
+
+    #[test]
+    fn test_discard_static() {
+        static mut BUF: [u8; 10] = [7; 10];
+        let buf = unsafe { &mut *core::ptr::addr_of_mut!(BUF) };
+        let mut buffer = ByteBuffer::new_static(buf, 10);
+        buffer.data_mut().fill(0xFF);
+        buffer.discard(2, 5);
+        assert_eq!(
+            buffer.data(),
+            &[0xFF, 0xFF, 0, 0, 0, 0, 0, 0xFF, 0xFF, 0xFF]
+        );
+    }
 }
@@ -24,6 +24,8 @@ pub enum MemoryError {
     TooManyMemories,
     /// Tried to create memory with invalid static buffer size
     InvalidStaticBufferSize,
+    /// Tried to reset a linear memory that is not a linear memory of the given instance.
+    NotInstanceMemory,
 }
 
 #[cfg(feature = "std")]
@@ -45,7 +47,8 @@ impl Display for MemoryError {
                 write!(f, "tried to create an invalid virtual memory type")
             }
             Self::InvalidSubtype { ty, other } => {
-                write!(f, "memory type {ty:?} is not a subtype of {other:?}",)
+                write!(f, "memory type {ty:?} is not a subtype of {other:?}: ")?;
+                fmt_subtype_mismatch(ty, other, f)
             }
             Self::TooManyMemories => {
                 write!(f, "too many memories")
@@ -53,6 +56,43 @@ impl Display for MemoryError {
             Self::InvalidStaticBufferSize => {
                 write!(f, "tried to use too small static buffer")
             }
+            Self::NotInstanceMemory => {
+                write!(f, "linear memory is not a linear memory of the instance")
+            }
+        }
+    }
+}
+
+/// Writes which import subtyping rule `ty` violates with respect to `other`.
+fn fmt_subtype_mismatch(
+    ty: &MemoryType,
+    other: &MemoryType,
+    f: &mut fmt::Formatter,
+) -> fmt::Result {
+    let min = u32::from(ty.initial_pages());
+    let required_min = u32::from(other.initial_pages());
+    if min < required_min {
+        return write!(
+            f,
+            "minimum of {min} pages is less than the required minimum of {required_min} pages"
+        );
+    }
+    match (ty.maximum_pages(), other.maximum_pages()) {
+        (None, Some(required_max)) => {
+            let required_max = u32::from(required_max);
+            write!(
+                f,
+                "missing maximum while a maximum of at most {required_max} pages is required"
+            )
+        }
+        (Some(max), Some(required_max)) => {
+            let max = u32::from(max);
+            let required_max = u32::from(required_max);
+            write!(
+                f,
+                "maximum of {max} pages exceeds the required maximum of {required_max} pages"
+            )
         }
+        (_, None) => write!(f, "limits are unexpectedly compatible"),
     }
 }
@@ -15,8 +15,13 @@ use crate::{
     collections::arena::ArenaIndex,
     core::{Pages, TrapCode},
     error::EntityGrowError,
+    module::InitDataSegment,
     store::{Fuel, ResourceLimiterRef},
+    FuncRef,
+    Instance,
 };
+use alloc::vec::Vec;
+use core::{ptr, sync::atomic};
 
 /// A raw index to a linear memory entity.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -78,19 +83,39 @@ impl MemoryType {
         self.maximum_pages
     }
 
+    /// Returns `true` if the memory type is shared between threads.
+    ///
+    /// # Note
+    ///
+    /// Wasmi does not support the Wasm `threads` proposal and thus
+    /// linear memories are never shared.
+    pub fn is_shared(self) -> bool {
+        false
+    }
+
+    /// Returns the number of bytes per page of the memory type.
+    ///
+    /// # Note
+    ///
+    /// Wasmi does not support the Wasm `custom-page-sizes` proposal and thus
+    /// this always returns the standard page size of 65536 bytes.
+    pub fn page_size(self) -> u64 {
+        1 << 16
+    }
+
     /// Checks if `self` is a subtype of `other`.
     ///
     /// # Note
     ///
-    /// This implements the [subtyping rules] according to the WebAssembly spec.
+    /// This implements the [import subtyping] rules according to the WebAssembly spec.
     ///
     /// [import subtyping]:
     /// https://webassembly.github.io/spec/core/valid/types.html#import-subtyping
     ///
     /// # Errors
     ///
-    /// - If the `minimum` size of `self` is less than or equal to the `minimum` size of `other`.
-    /// - If the `maximum` size of `self` is greater than the `maximum` size of `other`.
+    /// - If the `minimum` size of `self` is less than the `minimum` size of `other`.
+    /// - If `other` has a `maximum` size and `self` has none or a greater one.
     pub(crate) fn is_subtype_or_err(&self, other: &MemoryType) -> Result<(), MemoryError> {
         match self.is_subtype_of(other) {
             true => Ok(()),
@@ -105,7 +130,7 @@ impl MemoryType {
     ///
     /// # Note
     ///
-    /// This implements the [subtyping rules] according to the WebAssembly spec.
+    /// This implements the [import subtyping] rules according to the WebAssembly spec.
     ///
     /// [import subtyping]:
     /// https://webassembly.github.io/spec/core/valid/types.html#import-subtyping
@@ -122,7 +147,7 @@ impl MemoryType {
 }
 
 /// A linear memory entity.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MemoryEntity {
     bytes: ByteBuffer,
     memory_type: MemoryType,
@@ -223,15 +248,45 @@ impl MemoryEntity {
     }
 
     /// Returns the amount of pages in use by the linear memory.
-    fn current_pages(&self) -> Pages {
+    pub(crate) fn current_pages(&self) -> Pages {
         self.current_pages
     }
 
+    /// Restores the [`MemoryEntity`] to `pages` and the contents of `data`.
+    ///
+    /// Truncates the linear memory if it has grown since `data` was captured.
+    ///
+    /// # Panics
+    ///
+    /// If `data` is larger than the linear memory.
+    pub(crate) fn restore(&mut self, pages: Pages, data: &[u8]) {
+        self.bytes.truncate(data.len());
+        self.bytes.data_mut().copy_from_slice(data);
+        self.current_pages = pages;
+    }
+
     /// Returns the size, in WebAssembly pages, of this Wasm linear memory.
     pub fn size(&self) -> u32 {
         self.current_pages.into()
     }
 
+    /// Returns the current number of pages of the linear memory.
+    pub fn pages(&self) -> u64 {
+        u64::from(u32::from(self.current_pages))
+    }
+
+    /// Returns the maximum number of pages of the linear memory if any.
+    pub fn max_pages(&self) -> Option<u64> {
+        self.memory_type
+            .maximum_pages()
+            .map(|pages| u64::from(u32::from(pages)))
+    }
+
+    /// Returns the current size of the linear memory in bytes.
+    pub fn byte_size(&self) -> u64 {
+        self.pages() * self.memory_type.page_size()
+    }
+
     /// Grows the linear memory by the given amount of new pages.
     ///
     /// Returns the amount of pages before the operation upon success.
@@ -332,6 +387,11 @@ impl MemoryEntity {
         self.bytes.len
     }
 
+    /// Returns the number of bytes this [`MemoryEntity`] allocated on the heap.
+    pub fn allocated_bytes(&self) -> usize {
+        self.bytes.allocated_bytes()
+    }
+
     /// Reads `n` bytes from `memory[offset..offset+n]` into `buffer`
     /// where `n` is the length of `buffer`.
     ///
@@ -363,6 +423,69 @@ impl MemoryEntity {
         slice.copy_from_slice(buffer);
         Ok(())
     }
+
+    /// Zeroes `len` bytes of `memory[offset..offset+len]`.
+    ///
+    /// # Errors
+    ///
+    /// If this operation accesses out of bounds linear memory.
+    pub fn zero(&mut self, offset: usize, len: usize) -> Result<(), MemoryError> {
+        let slice = offset
+            .checked_add(len)
+            .and_then(|end| self.data_mut().get_mut(offset..end))
+            .ok_or(MemoryError::OutOfBoundsAccess)?;
+        // Safety: `slice` is a valid and exclusive byte slice of `len` bytes.
+        unsafe { ptr::write_bytes(slice.as_mut_ptr(), 0x00, slice.len()) };
+        // The fence ensures that the zeroing is not elided as a dead store
+        // by the compiler even if the memory is never read again.
+        atomic::compiler_fence(atomic::Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Discards the `len_pages` pages starting at page `offset_pages`.
+    ///
+    /// Afterwards the discarded pages read as zero.
+    ///
+    /// # Errors
+    ///
+    /// If this operation accesses out of bounds linear memory.
+    pub fn discard(&mut self, offset_pages: u64, len_pages: u64) -> Result<(), MemoryError> {
+        let page_size = self.ty().page_size();
+        let (offset, len) = offset_pages
+            .checked_add(len_pages)
+            .filter(|&end| end <= u64::from(self.size()))
+            .and_then(|_| {
+                let offset = usize::try_from(offset_pages * page_size).ok()?;
+                let len = usize::try_from(len_pages * page_size).ok()?;
+                Some((offset, len))
+            })
+            .ok_or(MemoryError::OutOfBoundsAccess)?;
+        self.bytes.discard(offset, len);
+        Ok(())
+    }
+
+    /// Resets the contents of the linear memory to the active data `segments`.
+    ///
+    /// All bytes are discarded before the bytes of each segment are written at its offset.
+    ///
+    /// # Errors
+    ///
+    /// If an active data segment is out of bounds of the linear memory.
+    /// In this case the linear memory is left untouched.
+    pub fn reset_to_initial(&mut self, segments: &[(usize, &[u8])]) -> Result<(), MemoryError> {
+        // We check all data segments before altering any bytes.
+        for (offset, bytes) in segments {
+            offset
+                .checked_add(bytes.len())
+                .filter(|&end| end <= self.data_size())
+                .ok_or(MemoryError::OutOfBoundsAccess)?;
+        }
+        self.bytes.discard(0, self.data_size());
+        for (offset, bytes) in segments {
+            self.write(*offset, bytes)?;
+        }
+        Ok(())
+    }
 }
 
 /// A Wasm linear memory reference.
@@ -381,6 +504,14 @@ impl Memory {
         &self.0
     }
 
+    /// Returns `true` if this [`Memory`] originates from `store`.
+    ///
+    /// This allows to check if the [`Memory`] can be used with `store`
+    /// before calling methods that would otherwise panic.
+    pub fn is_from_store(&self, store: impl AsContext) -> bool {
+        store.as_context().store.inner.owns(self.as_inner())
+    }
+
     /// Creates a new linear memory to the store.
     ///
     /// # Errors
@@ -427,6 +558,19 @@ impl Memory {
         ctx.as_context().store.inner.resolve_memory(self).ty()
     }
 
+    /// Returns the memory type of the linear memory.
+    ///
+    /// This is an alias of [`Memory::ty`] that mirrors [`ExternType::memory`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    ///
+    /// [`ExternType::memory`]: crate::ExternType::memory
+    pub fn memory_type(&self, ctx: impl AsContext) -> MemoryType {
+        self.ty(ctx)
+    }
+
     /// Returns the dynamic [`MemoryType`] of the [`Memory`].
     ///
     /// # Note
@@ -454,6 +598,61 @@ impl Memory {
         ctx.as_context().store.inner.resolve_memory(self).size()
     }
 
+    /// Returns the current number of pages of the [`Memory`].
+    ///
+    /// # Note
+    ///
+    /// Unlike the initial pages of [`Memory::ty`] this respects
+    /// growth of the [`Memory`] since its creation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    pub fn pages(&self, ctx: impl AsContext) -> u64 {
+        ctx.as_context().store.inner.resolve_memory(self).pages()
+    }
+
+    /// Returns the maximum number of pages of the [`Memory`] if any.
+    ///
+    /// Returns `None` if the [`Memory`] has no maximum limit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    pub fn max_pages(&self, ctx: impl AsContext) -> Option<u64> {
+        ctx.as_context()
+            .store
+            .inner
+            .resolve_memory(self)
+            .max_pages()
+    }
+
+    /// Returns the current size of the [`Memory`] in bytes.
+    ///
+    /// This is the current number of pages multiplied with the page size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    pub fn byte_size(&self, ctx: impl AsContext) -> u64 {
+        ctx.as_context()
+            .store
+            .inner
+            .resolve_memory(self)
+            .byte_size()
+    }
+
+    /// Returns the current size of the [`Memory`] in bytes.
+    ///
+    /// This is an alias for [`Memory::byte_size`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    pub fn current_size_bytes(&self, ctx: impl AsContext) -> u64 {
+        self.byte_size(ctx)
+    }
+
     /// Grows the linear memory by the given amount of new pages.
     ///
     /// Returns the amount of pages before the operation upon success.
@@ -477,6 +676,41 @@ impl Memory {
             .map_err(|_| MemoryError::OutOfBoundsGrowth)
     }
 
+    /// Grows the linear memory to the given absolute amount of `target_pages`.
+    ///
+    /// Returns the amount of pages before the operation upon success.
+    /// Does nothing if the linear memory already has at least `target_pages` pages.
+    ///
+    /// # Errors
+    ///
+    /// - If `target_pages` exceeds the maximum limit of the linear memory.
+    /// - If the growth is denied by the [`ResourceLimiter`] of the store.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    ///
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    pub fn grow_to(
+        &self,
+        mut ctx: impl AsContextMut,
+        target_pages: u64,
+    ) -> Result<u64, MemoryError> {
+        let current = self.pages(&ctx);
+        if target_pages <= current {
+            return Ok(current);
+        }
+        if self
+            .max_pages(&ctx)
+            .is_some_and(|max_pages| target_pages > max_pages)
+        {
+            return Err(MemoryError::OutOfBoundsGrowth);
+        }
+        let additional =
+            u32::try_from(target_pages - current).map_err(|_| MemoryError::OutOfBoundsGrowth)?;
+        self.grow(ctx.as_context_mut(), additional).map(u64::from)
+    }
+
     /// Returns a shared slice to the bytes underlying the [`Memory`].
     ///
     /// # Panics
@@ -578,4 +812,139 @@ impl Memory {
             .resolve_memory_mut(self)
             .write(offset, buffer)
     }
+
+    /// Discards the `len_pages` pages starting at page `offset_pages` so that they read as zero.
+    ///
+    /// This is useful to reset parts of the [`Memory`] of long-running [`Store`]s
+    /// without dropping the [`Memory`].
+    /// The size of the [`Memory`] is unaffected and thus no [`ResourceLimiter`]
+    /// accounting takes place.
+    ///
+    /// # Note
+    ///
+    /// On Linux and Android with the `std` feature enabled the physical memory of
+    /// the discarded pages is released to the operating system via `madvise(MADV_DONTNEED)`
+    /// if the [`Memory`] is heap allocated. Otherwise, e.g. on other targets or for
+    /// [`Memory`] backed by a user provided static buffer, the bytes are zeroed explicitly.
+    ///
+    /// # Errors
+    ///
+    /// If this operation accesses out of bounds linear memory.
+    /// In this case no bytes are discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    ///
+    /// [`Store`]: crate::Store
+    /// [`ResourceLimiter`]: crate::ResourceLimiter
+    pub fn discard(
+        &self,
+        mut ctx: impl AsContextMut,
+        offset_pages: u64,
+        len_pages: u64,
+    ) -> Result<(), MemoryError> {
+        ctx.as_context_mut()
+            .store
+            .inner
+            .resolve_memory_mut(self)
+            .discard(offset_pages, len_pages)
+    }
+
+    /// Resets the contents of the [`Memory`] to its initial contents within `instance`.
+    ///
+    /// All bytes of the [`Memory`] are discarded as if by [`Memory::discard`] and then
+    /// the active data segments of the [`Module`] of `instance` that initialize the
+    /// [`Memory`] are written again. Their offsets are evaluated with the global
+    /// variables of `instance`.
+    ///
+    /// # Note
+    ///
+    /// - The [`Memory`] may be any linear memory of `instance`, including imported ones.
+    /// - The size of the [`Memory`] is unaffected, i.e. a grown [`Memory`] is not shrunk.
+    /// - Passive data segments and data segments dropped via `data.drop` are unaffected.
+    ///
+    /// # Errors
+    ///
+    /// - If the [`Memory`] is not a linear memory of `instance`.
+    /// - If an active data segment is out of bounds of the [`Memory`].
+    ///
+    /// In both cases the [`Memory`] is left untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`] or `instance`.
+    ///
+    /// [`Module`]: crate::Module
+    pub fn reset_to_initial(
+        &self,
+        mut ctx: impl AsContextMut,
+        instance: &Instance,
+    ) -> Result<(), MemoryError> {
+        let store = &ctx.as_context().store.inner;
+        let entity = store.resolve_instance(instance);
+        let memory_index = (0..)
+            .map_while(|index| entity.get_memory(index))
+            .position(|memory| memory.as_inner() == self.as_inner())
+            .ok_or(MemoryError::NotInstanceMemory)?;
+        let module = entity.module().clone();
+        let segments = module
+            .data_segments()
+            .into_iter()
+            .filter_map(|segment| match segment {
+                InitDataSegment::Active {
+                    memory_index: index,
+                    offset,
+                    bytes,
+                } if index.into_u32() as usize == memory_index => Some((offset, bytes)),
+                _ => None,
+            })
+            .map(|(offset, bytes)| {
+                let offset = offset
+                    .eval_with_context(
+                        |index| {
+                            let global = entity
+                                .get_global(index)
+                                .unwrap_or_else(|| panic!("missing global variable at {index}"));
+                            store.resolve_global(&global).get()
+                        },
+                        |index| FuncRef::new(entity.get_func(index)),
+                    )
+                    .expect("must evaluate to proper value");
+                (u32::from(offset) as usize, bytes)
+            })
+            .collect::<Vec<_>>();
+        ctx.as_context_mut()
+            .store
+            .inner
+            .resolve_memory_mut(self)
+            .reset_to_initial(&segments)
+    }
+
+    /// Zeroes `len` bytes of `memory[offset..offset+len]`.
+    ///
+    /// This is useful to scrub secrets from the linear memory after use.
+    /// The zeroing is guaranteed to not be optimized away and does not
+    /// touch any bytes or pages outside of the given range.
+    ///
+    /// # Errors
+    ///
+    /// If this operation accesses out of bounds linear memory.
+    /// In this case no bytes are zeroed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Memory`].
+    pub fn zero(
+        &self,
+        mut ctx: impl AsContextMut,
+        offset: usize,
+        len: usize,
+    ) -> Result<(), MemoryError> {
+        ctx.as_context_mut()
+            .store
+            .inner
+            .resolve_memory_mut(self)
+            .zero(offset, len)
+    }
 }
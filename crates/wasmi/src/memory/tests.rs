@@ -1,4 +1,6 @@
 use super::*;
+use crate::{Engine, Module, Store, StoreLimitsBuilder};
+use alloc::string::ToString;
 
 fn memory_type(minimum: u32, maximum: impl Into<Option<u32>>) -> MemoryType {
     MemoryType::new(minimum, maximum.into()).unwrap()
@@ -14,3 +16,302 @@ fn subtyping_works() {
     assert!(memory_type(0, 1).is_subtype_of(&memory_type(0, None)));
     assert!(!memory_type(0, None).is_subtype_of(&memory_type(0, 1)));
 }
+
+/// The minimum and maximum pages of a [`MemoryType`].
+type Limits = (u32, Option<u32>);
+
+#[test]
+fn subtyping_matrix() {
+    // (provided, required, expected error rule if any)
+    let matrix: &[(Limits, Limits, Option<&str>)] = &[
+        ((1, None), (1, None), None),
+        ((2, None), (1, None), None),
+        ((2, Some(4)), (1, None), None),
+        ((2, Some(4)), (1, Some(4)), None),
+        ((2, Some(3)), (1, Some(4)), None),
+        ((2, Some(2)), (2, Some(2)), None),
+        (
+            (0, None),
+            (1, None),
+            Some("minimum of 0 pages is less than"),
+        ),
+        (
+            (1, Some(4)),
+            (2, Some(4)),
+            Some("minimum of 1 pages is less than"),
+        ),
+        (
+            (1, Some(4)),
+            (2, None),
+            Some("minimum of 1 pages is less than"),
+        ),
+        ((2, None), (1, Some(4)), Some("missing maximum")),
+        (
+            (2, Some(5)),
+            (1, Some(4)),
+            Some("maximum of 5 pages exceeds"),
+        ),
+    ];
+    for &((min, max), (required_min, required_max), expected) in matrix {
+        let provided = memory_type(min, max);
+        let required = memory_type(required_min, required_max);
+        let result = provided.is_subtype_or_err(&required);
+        match expected {
+            None => assert!(
+                result.is_ok(),
+                "{provided:?} must be a subtype of {required:?}"
+            ),
+            Some(rule) => {
+                let message = result.unwrap_err().to_string();
+                assert!(message.contains(rule), "{message}");
+            }
+        }
+    }
+}
+
+#[test]
+fn zero_works() {
+    let mut store = Store::new(&Engine::default(), ());
+    let memory = Memory::new(&mut store, memory_type(1, None)).unwrap();
+    memory.data_mut(&mut store).fill(0xFF);
+    memory.zero(&mut store, 10, 20).unwrap();
+    let data = memory.data(&store);
+    assert!(data[..10].iter().all(|&byte| byte == 0xFF));
+    assert!(data[10..30].iter().all(|&byte| byte == 0x00));
+    assert!(data[30..].iter().all(|&byte| byte == 0xFF));
+    // Zeroing an empty range at the end of the memory is fine.
+    let size = memory.data_size(&store);
+    memory.zero(&mut store, size, 0).unwrap();
+}
+
+#[test]
+fn zero_out_of_bounds() {
+    let mut store = Store::new(&Engine::default(), ());
+    let memory = Memory::new(&mut store, memory_type(1, None)).unwrap();
+    memory.data_mut(&mut store).fill(0xFF);
+    let size = memory.data_size(&store);
+    for (offset, len) in [(size, 1), (size - 1, 2), (1, usize::MAX), (usize::MAX, 1)] {
+        assert!(matches!(
+            memory.zero(&mut store, offset, len),
+            Err(MemoryError::OutOfBoundsAccess)
+        ));
+    }
+    // Failed zeroing must not have altered any bytes.
+    assert!(memory.data(&store).iter().all(|&byte| byte == 0xFF));
+}
+
+#[test]
+fn memory_type_accessors() {
+    let mut store = Store::new(&Engine::default(), ());
+    for (minimum, maximum) in [(0, None), (1, Some(1)), (1, Some(10)), (3, None)] {
+        let ty = memory_type(minimum, maximum);
+        assert!(!ty.is_shared());
+        assert_eq!(ty.page_size(), 65536);
+        let memory = Memory::new(&mut store, ty).unwrap();
+        let ty = memory.ty(&store);
+        assert_eq!(ty.initial_pages(), Pages::new(minimum).unwrap());
+        assert_eq!(
+            ty.maximum_pages(),
+            maximum.map(|max| Pages::new(max).unwrap())
+        );
+        assert!(!ty.is_shared());
+        assert_eq!(ty.page_size(), 65536);
+        assert_eq!(
+            memory.data_size(&store) as u64,
+            u64::from(minimum) * ty.page_size()
+        );
+    }
+}
+
+#[test]
+fn size_accessors() {
+    let mut store = Store::new(&Engine::default(), ());
+    for (minimum, maximum) in [(0, None), (1, Some(1)), (1, Some(10)), (3, None)] {
+        let memory = Memory::new(&mut store, memory_type(minimum, maximum)).unwrap();
+        let ty = memory.ty(&store);
+        assert_eq!(
+            memory.pages(&store),
+            u64::from(u32::from(ty.initial_pages()))
+        );
+        assert_eq!(
+            memory.max_pages(&store),
+            ty.maximum_pages().map(|max| u64::from(u32::from(max)))
+        );
+        assert_eq!(
+            memory.byte_size(&store),
+            memory.pages(&store) * ty.page_size()
+        );
+        assert_eq!(memory.current_size_bytes(&store), memory.byte_size(&store));
+        assert_eq!(memory.byte_size(&store), memory.data_size(&store) as u64);
+        if maximum.map_or(true, |max| max > minimum) {
+            memory.grow(&mut store, 1).unwrap();
+            assert_eq!(memory.pages(&store), u64::from(minimum) + 1);
+            assert_eq!(memory.max_pages(&store), maximum.map(u64::from));
+            assert_eq!(memory.byte_size(&store), memory.data_size(&store) as u64);
+        }
+    }
+}
+
+#[test]
+fn grow_to_works() {
+    let mut store = Store::new(&Engine::default(), ());
+    let memory = Memory::new(&mut store, memory_type(1, 5)).unwrap();
+    assert_eq!(memory.grow_to(&mut store, 3).unwrap(), 1);
+    assert_eq!(memory.pages(&store), 3);
+    // Growing to less or equal pages than the current size does nothing.
+    assert_eq!(memory.grow_to(&mut store, 2).unwrap(), 3);
+    assert_eq!(memory.grow_to(&mut store, 3).unwrap(), 3);
+    assert_eq!(memory.pages(&store), 3);
+    // Growing beyond the maximum fails.
+    assert!(matches!(
+        memory.grow_to(&mut store, 6),
+        Err(MemoryError::OutOfBoundsGrowth)
+    ));
+    assert_eq!(memory.grow_to(&mut store, 5).unwrap(), 3);
+    assert_eq!(memory.pages(&store), 5);
+}
+
+#[test]
+fn grow_to_respects_resource_limiter() {
+    let limits = StoreLimitsBuilder::new().memory_size(2 * 65536).build();
+    let mut store = Store::new(&Engine::default(), limits);
+    store.limiter(|limits| limits);
+    let memory = Memory::new(&mut store, memory_type(1, None)).unwrap();
+    assert!(matches!(
+        memory.grow_to(&mut store, 3),
+        Err(MemoryError::OutOfBoundsGrowth)
+    ));
+    assert_eq!(memory.grow_to(&mut store, 2).unwrap(), 1);
+    assert_eq!(memory.pages(&store), 2);
+}
+
+#[test]
+fn discard_works() {
+    let mut store = Store::new(&Engine::default(), ());
+    let memory = Memory::new(&mut store, memory_type(4, None)).unwrap();
+    memory.data_mut(&mut store).fill(0xFF);
+    memory.discard(&mut store, 1, 2).unwrap();
+    let page_size = memory.ty(&store).page_size() as usize;
+    let data = memory.data(&store);
+    assert!(data[..page_size].iter().all(|&byte| byte == 0xFF));
+    assert!(data[page_size..3 * page_size]
+        .iter()
+        .all(|&byte| byte == 0x00));
+    assert!(data[3 * page_size..].iter().all(|&byte| byte == 0xFF));
+    // Discarding does not alter the size of the memory.
+    assert_eq!(memory.size(&store), 4);
+    // Discarding an empty range at the end of the memory is fine.
+    memory.discard(&mut store, 4, 0).unwrap();
+}
+
+#[test]
+fn discard_out_of_bounds() {
+    let mut store = Store::new(&Engine::default(), ());
+    let memory = Memory::new(&mut store, memory_type(2, None)).unwrap();
+    memory.data_mut(&mut store).fill(0xFF);
+    for (offset, len) in [(2, 1), (1, 2), (1, u64::MAX), (u64::MAX, 1)] {
+        assert!(matches!(
+            memory.discard(&mut store, offset, len),
+            Err(MemoryError::OutOfBoundsAccess)
+        ));
+    }
+    // Failed discarding must not have altered any bytes.
+    assert!(memory.data(&store).iter().all(|&byte| byte == 0xFF));
+}
+
+#[test]
+fn reset_to_initial_works() {
+    let engine = Engine::default();
+    let wasm = r#"
+        (module
+            (memory (export "memory") 1)
+            (data (i32.const 1) "abc")
+            (data (i32.add (i32.const 100) (i32.const 10)) "xyz")
+            (data "passive")
+        )
+    "#;
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = crate::Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let memory = instance.get_memory(&store, "memory").unwrap();
+    let initial = memory.data(&store).to_vec();
+    memory.data_mut(&mut store).fill(0xFF);
+    memory.grow(&mut store, 1).unwrap();
+    memory.reset_to_initial(&mut store, &instance).unwrap();
+    let data = memory.data(&store);
+    assert_eq!(&data[..initial.len()], &initial[..]);
+    assert_eq!(&data[1..4], b"abc");
+    assert_eq!(&data[110..113], b"xyz");
+    // The grown memory is not shrunk but its new pages are zeroed, too.
+    assert_eq!(memory.size(&store), 2);
+    assert!(data[initial.len()..].iter().all(|&byte| byte == 0x00));
+}
+
+#[test]
+fn reset_to_initial_multi_memory() {
+    let engine = Engine::default();
+    let wasm = r#"
+        (module
+            (import "env" "offset" (global $offset i32))
+            (import "env" "memory" (memory 1))
+            (memory (export "memory") 1)
+            (data (memory 0) (i32.const 0) "imported")
+            (data (memory 1) (global.get $offset) "defined")
+        )
+    "#;
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    let offset = crate::Global::new(&mut store, crate::Val::I32(10), crate::Mutability::Const);
+    let imported = Memory::new(&mut store, memory_type(1, None)).unwrap();
+    let mut linker = crate::Linker::new(&engine);
+    linker.define("env", "offset", offset).unwrap();
+    linker.define("env", "memory", imported).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let defined = instance.get_memory(&store, "memory").unwrap();
+    for memory in [imported, defined] {
+        memory.data_mut(&mut store).fill(0xFF);
+    }
+    // Only the data segments of the reset memory are written again.
+    defined.reset_to_initial(&mut store, &instance).unwrap();
+    assert_eq!(&defined.data(&store)[10..17], b"defined");
+    assert!(defined.data(&store)[..10].iter().all(|&byte| byte == 0x00));
+    assert!(imported.data(&store).iter().all(|&byte| byte == 0xFF));
+    // Imported memories are reset to the data segments of the instance, too.
+    imported.reset_to_initial(&mut store, &instance).unwrap();
+    assert_eq!(&imported.data(&store)[..8], b"imported");
+    assert!(imported.data(&store)[8..].iter().all(|&byte| byte == 0x00));
+}
+
+#[test]
+fn reset_to_initial_fails() {
+    let engine = Engine::default();
+    let wasm = r#"
+        (module
+            (memory 1)
+            (data (i32.const 0) "abc")
+        )
+    "#;
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = crate::Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let memory = Memory::new(&mut store, memory_type(1, None)).unwrap();
+    memory.data_mut(&mut store).fill(0xFF);
+    assert!(matches!(
+        memory.reset_to_initial(&mut store, &instance),
+        Err(MemoryError::NotInstanceMemory)
+    ));
+    // Failed resets must not have altered any bytes.
+    assert!(memory.data(&store).iter().all(|&byte| byte == 0xFF));
+}
@@ -20,7 +20,8 @@ use super::{
 };
 use crate::{
     collections::Map,
-    engine::{DedupFuncType, EngineFuncSpan},
+    engine::{DedupFuncType, EnforcedLimits, EngineFuncSpan},
+    store::FuelMultiplier,
     Engine,
     Error,
     FuncType,
@@ -42,6 +43,7 @@ pub struct ModuleBuilder {
 #[derive(Debug)]
 pub struct ModuleHeaderBuilder {
     engine: Engine,
+    limits: EnforcedLimits,
     pub func_types: Vec<DedupFuncType>,
     pub imports: ModuleImportsBuilder,
     pub funcs: Vec<DedupFuncType>,
@@ -50,16 +52,18 @@ pub struct ModuleHeaderBuilder {
     pub globals: Vec<GlobalType>,
     pub globals_init: Vec<ConstExpr>,
     pub exports: Map<Box<str>, ExternIdx>,
+    pub export_names: Box<[Box<str>]>,
     pub start: Option<FuncIdx>,
     pub engine_funcs: EngineFuncSpan,
     pub element_segments: Box<[ElementSegment]>,
 }
 
 impl ModuleHeaderBuilder {
-    /// Creates a new [`ModuleHeaderBuilder`] for the given [`Engine`].
-    pub fn new(engine: &Engine) -> Self {
+    /// Creates a new [`ModuleHeaderBuilder`] for the given [`Engine`] and [`EnforcedLimits`].
+    pub fn new(engine: &Engine, limits: EnforcedLimits) -> Self {
         Self {
             engine: engine.clone(),
+            limits,
             func_types: Vec::new(),
             imports: ModuleImportsBuilder::default(),
             funcs: Vec::new(),
@@ -68,6 +72,7 @@ impl ModuleHeaderBuilder {
             globals: Vec::new(),
             globals_init: Vec::new(),
             exports: Map::new(),
+            export_names: Box::from([]),
             start: None,
             engine_funcs: EngineFuncSpan::default(),
             element_segments: Box::from([]),
@@ -79,6 +84,7 @@ impl ModuleHeaderBuilder {
         ModuleHeader {
             inner: Arc::new(ModuleHeaderInner {
                 engine: self.engine.weak(),
+                limits: self.limits,
                 func_types: self.func_types.into(),
                 imports: self.imports.finish(),
                 funcs: self.funcs.into(),
@@ -87,6 +93,7 @@ impl ModuleHeaderBuilder {
                 globals: self.globals.into(),
                 globals_init: self.globals_init.into(),
                 exports: self.exports,
+                export_names: self.export_names,
                 start: self.start,
                 engine_funcs: self.engine_funcs,
                 element_segments: self.element_segments,
@@ -357,7 +364,9 @@ impl ModuleHeaderBuilder {
             self.exports.is_empty(),
             "tried to initialize module export declarations twice"
         );
-        self.exports = exports.into_iter().collect::<Result<Map<_, _>, _>>()?;
+        let exports = exports.into_iter().collect::<Result<Vec<_>, _>>()?;
+        self.export_names = exports.iter().map(|(name, _)| name.clone()).collect();
+        self.exports = exports.into_iter().collect::<Map<_, _>>();
         Ok(())
     }
 
@@ -416,6 +425,7 @@ impl ModuleBuilder {
                 data_segments: self.data_segments.finish(),
                 custom_sections: self.custom_sections.finish(),
             }),
+            fuel_multiplier: FuelMultiplier::default(),
         }
     }
 }
@@ -1,5 +1,5 @@
 use alloc::vec::Vec;
-use core::{slice, str};
+use core::{ops::Range, slice, str};
 
 /// Wasm custom sections.
 #[derive(Default, Debug)]
@@ -13,6 +13,14 @@ impl CustomSections {
     pub fn iter(&self) -> CustomSectionsIter {
         self.inner.iter()
     }
+
+    /// Returns the data of the first [`CustomSection`] with the given `name` if any.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&[u8]> {
+        self.iter()
+            .find(|section| section.name() == name)
+            .map(|section| section.data())
+    }
 }
 
 /// A builder for [`CustomSections`].
@@ -23,9 +31,11 @@ pub struct CustomSectionsBuilder {
 
 impl CustomSectionsBuilder {
     /// Pushes a new custom section segment to the [`CustomSectionsBuilder`].
+    ///
+    /// The `offset` is the byte offset of `data` within the original Wasm binary.
     #[inline]
-    pub fn push(&mut self, name: &str, data: &[u8]) {
-        self.inner.push(name, data);
+    pub fn push(&mut self, name: &str, offset: usize, data: &[u8]) {
+        self.inner.push(name, offset, data);
     }
 
     /// Finalize construction of the [`CustomSections`].
@@ -51,18 +61,21 @@ pub struct CustomSectionInner {
     len_name: usize,
     /// The length in bytes of the Wasm custom section data.
     len_data: usize,
+    /// The byte offset of the Wasm custom section data within the original Wasm binary.
+    offset: usize,
 }
 
 impl CustomSectionsInner {
     /// Pushes a new custom section segment to the [`CustomSectionsBuilder`].
     #[inline]
-    pub fn push(&mut self, name: &str, data: &[u8]) {
+    pub fn push(&mut self, name: &str, offset: usize, data: &[u8]) {
         let name_bytes = name.as_bytes();
         self.names_and_data.extend_from_slice(name_bytes);
         self.names_and_data.extend_from_slice(data);
         self.items.push(CustomSectionInner {
             len_name: name_bytes.len(),
             len_data: data.len(),
+            offset,
         })
     }
 
@@ -83,6 +96,8 @@ pub struct CustomSection<'a> {
     name: &'a str,
     /// The undecoded data of the custom section.
     data: &'a [u8],
+    /// The byte offset of `data` within the original Wasm binary.
+    offset: usize,
 }
 
 impl<'a> CustomSection<'a> {
@@ -97,6 +112,14 @@ impl<'a> CustomSection<'a> {
     pub fn data(&self) -> &'a [u8] {
         self.data
     }
+
+    /// Returns the byte range of the [`CustomSection`] data within the original Wasm binary.
+    ///
+    /// Indexing the original Wasm binary with this range yields [`CustomSection::data`].
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.data.len()
+    }
 }
 
 /// An iterator over the custom sections of a Wasm module.
@@ -123,7 +146,11 @@ impl<'a> Iterator for CustomSectionsIter<'a> {
         self.names_and_data = names_and_data;
         // Safety: We encoded this part of the data buffer from the bytes of a string previously.
         let name = unsafe { str::from_utf8_unchecked(name) };
-        Some(CustomSection { name, data })
+        Some(CustomSection {
+            name,
+            data,
+            offset: item.offset,
+        })
     }
 }
 
@@ -134,11 +161,11 @@ mod tests {
     #[test]
     fn it_works() {
         let mut builder = CustomSectionsBuilder::default();
-        builder.push("A", b"first");
-        builder.push("B", b"second");
-        builder.push("C", b"third");
-        builder.push("", b"fourth"); // empty name
-        builder.push("E", &[]); // empty data
+        builder.push("A", 0, b"first");
+        builder.push("B", 10, b"second");
+        builder.push("C", 20, b"third");
+        builder.push("", 30, b"fourth"); // empty name
+        builder.push("E", 40, &[]); // empty data
         let custom_sections = builder.finish();
         let mut iter = custom_sections.iter();
         assert_eq!(
@@ -163,4 +190,59 @@ mod tests {
         );
         assert_eq!(iter.next().map(|s| (s.name(), s.data())), None);
     }
+
+    #[test]
+    fn ranges_and_lookup() {
+        let mut builder = CustomSectionsBuilder::default();
+        builder.push("A", 8, b"first");
+        builder.push("B", 20, b"second");
+        builder.push("A", 40, b"third");
+        let custom_sections = builder.finish();
+        let ranges = custom_sections
+            .iter()
+            .map(|s| s.range())
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, [8..13, 20..26, 40..45]);
+        // Lookup yields the first custom section with a matching name.
+        assert_eq!(custom_sections.get("A"), Some(&b"first"[..]));
+        assert_eq!(custom_sections.get("B"), Some(&b"second"[..]));
+        assert_eq!(custom_sections.get("C"), None);
+    }
+
+    #[test]
+    fn module_custom_sections() {
+        use crate::{Engine, Module};
+        /// Appends a custom section with `name` and `data` to `wasm`.
+        fn push_custom(wasm: &mut Vec<u8>, name: &str, data: &[u8]) {
+            let len_payload = 1 + name.len() + data.len();
+            assert!(name.len() < 0x80 && len_payload < 0x80);
+            wasm.extend([0x00, len_payload as u8, name.len() as u8]);
+            wasm.extend(name.as_bytes());
+            wasm.extend(data);
+        }
+        let mut wasm = b"\0asm\x01\0\0\0".to_vec();
+        push_custom(&mut wasm, "producers", b"first");
+        push_custom(&mut wasm, "other", b"");
+        push_custom(&mut wasm, "producers", b"second");
+        let engine = Engine::default();
+        let module = Module::new(&engine, &wasm[..]).unwrap();
+        let sections = module
+            .custom_sections()
+            .map(|s| (s.name(), s.data()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sections,
+            [
+                ("producers", &b"first"[..]),
+                ("other", &b""[..]),
+                ("producers", &b"second"[..]),
+            ]
+        );
+        for section in module.custom_sections() {
+            assert_eq!(&wasm[section.range()], section.data());
+        }
+        assert_eq!(module.custom_section("producers"), Some(&b"first"[..]));
+        assert_eq!(module.custom_section("other"), Some(&b""[..]));
+        assert_eq!(module.custom_section("missing"), None);
+    }
 }
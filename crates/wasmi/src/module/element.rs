@@ -13,6 +13,8 @@ pub struct ElementSegment {
     ty: ValType,
     /// The items of the [`ElementSegment`].
     items: Box<[ConstExpr]>,
+    /// Is `true` if all items are `ref.func` or constant expressions.
+    is_lazy_compatible: bool,
 }
 
 /// The kind of a Wasm [`ElementSegment`].
@@ -97,7 +99,15 @@ impl From<wasmparser::Element<'_>> for ElementSegment {
                 (items, ty)
             }
         };
-        Self { kind, ty, items }
+        let is_lazy_compatible = items
+            .iter()
+            .all(|item| item.funcref().is_some() || item.eval_const().is_some());
+        Self {
+            kind,
+            ty,
+            items,
+            is_lazy_compatible,
+        }
     }
 }
 
@@ -116,4 +126,12 @@ impl ElementSegment {
     pub fn items(&self) -> &[ConstExpr] {
         &self.items[..]
     }
+
+    /// Returns `true` if the [`ElementSegment`] can lazily initialize a table.
+    ///
+    /// This is the case if all of its items are `ref.func` or constant expressions
+    /// which can be evaluated without access to global variables.
+    pub fn is_lazy_compatible(&self) -> bool {
+        self.is_lazy_compatible
+    }
 }
@@ -0,0 +1,167 @@
+use core::{fmt, fmt::Display};
+use wasmparser::{BlockType, ValType, VisitOperator};
+
+#[cfg(doc)]
+use crate::Config;
+
+/// An error that occurs when a Wasm module uses floating point types or instructions.
+///
+/// This is only ever raised if [`Config::deny_floating_point`] is enabled
+/// or if floating point is disabled via [`Config::floats`].
+/// The error refers to the first offending item of the Wasm module.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FloatingPointError {
+    /// A function type with floating point parameters or results.
+    FuncType {
+        /// The index of the offending function type.
+        type_index: u32,
+    },
+    /// A global variable of floating point type.
+    Global {
+        /// The index of the offending global variable.
+        global_index: u32,
+    },
+    /// A function with local variables of floating point type.
+    Local {
+        /// The index of the offending function.
+        func_index: u32,
+    },
+    /// A floating point instruction within a function body.
+    Instruction {
+        /// The index of the function containing the offending instruction.
+        func_index: u32,
+        /// The offset of the offending instruction within the Wasm binary.
+        offset: usize,
+        /// The name of the offending instruction.
+        name: &'static str,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FloatingPointError {}
+
+impl Display for FloatingPointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FuncType { type_index } => write!(
+                f,
+                "floating point is denied but the function type at index {type_index} uses floats"
+            ),
+            Self::Global { global_index } => write!(
+                f,
+                "floating point is denied but the global variable at index {global_index} is a float"
+            ),
+            Self::Local { func_index } => write!(
+                f,
+                "floating point is denied but the function at index {func_index} has float locals"
+            ),
+            Self::Instruction {
+                func_index,
+                offset,
+                name,
+            } => write!(
+                f,
+                "floating point is denied but the function at index {func_index} \
+                uses the `{name}` instruction at offset {offset}"
+            ),
+        }
+    }
+}
+
+/// Returns `true` if `ty` is a floating point value type.
+pub fn is_float(ty: ValType) -> bool {
+    matches!(ty, ValType::F32 | ValType::F64)
+}
+
+/// Finds floating point Wasm operators.
+///
+/// Visiting an operator returns its name if it is a floating point operator.
+#[derive(Debug, Default)]
+pub struct FloatOperatorFinder;
+
+/// Evaluates to `true` if a Wasm operator operates on floating point values.
+///
+/// An operator operates on floating point values if either
+///
+/// - its `wasmparser` annotation names a floating point value type, e.g. `(binary f32)`
+///   or `(conversion i32 f64)` where `v128f` denotes floating point SIMD lanes, or
+/// - it is one of the `relaxed-simd` floating point operators which `wasmparser`
+///   annotates as plain `v128` operators.
+macro_rules! is_float_operator {
+    ( $op:ident ( $( $ann:tt )* ) ) => {
+        is_float_operator!(@op $op) $( || is_float_operator!(@ty $ann) )*
+    };
+    ( @ty f32 ) => { true };
+    ( @ty f64 ) => { true };
+    ( @ty v128f ) => { true };
+    ( @ty $_other:tt ) => { false };
+    ( @op I32x4RelaxedTruncF32x4S ) => { true };
+    ( @op I32x4RelaxedTruncF32x4U ) => { true };
+    ( @op I32x4RelaxedTruncF64x2SZero ) => { true };
+    ( @op I32x4RelaxedTruncF64x2UZero ) => { true };
+    ( @op F32x4RelaxedMadd ) => { true };
+    ( @op F32x4RelaxedNmadd ) => { true };
+    ( @op F64x2RelaxedMadd ) => { true };
+    ( @op F64x2RelaxedNmadd ) => { true };
+    ( @op F32x4RelaxedMin ) => { true };
+    ( @op F32x4RelaxedMax ) => { true };
+    ( @op F64x2RelaxedMin ) => { true };
+    ( @op F64x2RelaxedMax ) => { true };
+    ( @op $_other:ident ) => { false };
+}
+
+impl FloatOperatorFinder {
+    /// Returns `Some(name)` if `is_float` is `true`.
+    fn check_operator(name: &'static str, is_float: bool) -> Option<&'static str> {
+        is_float.then_some(name)
+    }
+
+    /// Returns `Some(name)` if `block_type` has floating point results.
+    fn check_block_type(name: &'static str, block_type: BlockType) -> Option<&'static str> {
+        match block_type {
+            BlockType::Type(ty) if is_float(ty) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_visit_operator {
+    ( @mvp Block { $arg:ident: $argty:ty } => $visit:ident $_ann:tt $($rest:tt)* ) => {
+        impl_visit_operator!(@@block_type Block { $arg: $argty } => $visit $($rest)*);
+    };
+    ( @mvp Loop { $arg:ident: $argty:ty } => $visit:ident $_ann:tt $($rest:tt)* ) => {
+        impl_visit_operator!(@@block_type Loop { $arg: $argty } => $visit $($rest)*);
+    };
+    ( @mvp If { $arg:ident: $argty:ty } => $visit:ident $_ann:tt $($rest:tt)* ) => {
+        impl_visit_operator!(@@block_type If { $arg: $argty } => $visit $($rest)*);
+    };
+    ( @@block_type $op:ident { $arg:ident: $argty:ty } => $visit:ident $($rest:tt)* ) => {
+        fn $visit(&mut self, $arg: $argty) -> Self::Output {
+            Self::check_block_type(stringify!($op), $arg)
+        }
+        impl_visit_operator!($($rest)*);
+    };
+    ( @reference_types TypedSelect { $arg:ident: $argty:ty } => $visit:ident $_ann:tt $($rest:tt)* ) => {
+        fn $visit(&mut self, $arg: $argty) -> Self::Output {
+            if is_float($arg) {
+                return Some(stringify!(TypedSelect));
+            }
+            None
+        }
+        impl_visit_operator!($($rest)*);
+    };
+    ( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $ann:tt $($rest:tt)* ) => {
+        fn $visit(&mut self $($(, _: $argty)*)?) -> Self::Output {
+            Self::check_operator(stringify!($op), is_float_operator!($op $ann))
+        }
+        impl_visit_operator!($($rest)*);
+    };
+    () => {};
+}
+
+impl<'a> VisitOperator<'a> for FloatOperatorFinder {
+    type Output = Option<&'static str>;
+
+    wasmparser::for_each_visit_operator!(impl_visit_operator);
+}
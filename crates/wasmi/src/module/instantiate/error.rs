@@ -54,7 +54,14 @@ pub enum InstantiationError {
         /// The index of the found `start` function.
         index: u32,
     },
+    /// Caused when running the `start` function of an instance that has no pending `start` function.
+    NoPendingStartFn,
     TooManyInstances,
+    /// Caused when an external value for an import does not belong to the store used for instantiation.
+    CrossStore {
+        /// The external value from a different store.
+        actual: Extern,
+    },
 }
 
 #[cfg(feature = "std")]
@@ -88,10 +95,14 @@ impl Display for InstantiationError {
             Self::FoundStartFn { index } => {
                 write!(f, "found an unexpected start function with index {index}")
             }
+            Self::NoPendingStartFn => write!(f, "found no pending start function to run"),
             Self::Table(error) => Display::fmt(error, f),
             Self::Memory(error) => Display::fmt(error, f),
             Self::Global(error) => Display::fmt(error, f),
-            Self::TooManyInstances => write!(f, "too many instances")
+            Self::TooManyInstances => write!(f, "too many instances"),
+            Self::CrossStore { actual } => {
+                write!(f, "found {actual:?} external for import from a different store")
+            }
         }
     }
 }
@@ -7,9 +7,10 @@ mod tests;
 pub use self::{error::InstantiationError, pre::InstancePre};
 use super::{element::ElementSegmentKind, export, ConstExpr, InitDataSegment, Module};
 use crate::{
-    core::UntypedVal,
+    core::{UntypedVal, ValType},
     func::WasmFuncEntity,
     memory::{DataSegment, MemoryError},
+    table::LazySource,
     value::WithType,
     AsContext,
     AsContextMut,
@@ -26,6 +27,7 @@ use crate::{
     Table,
     Val,
 };
+use alloc::sync::Arc;
 
 impl Module {
     /// Instantiates a new [`Instance`] from the given compiled [`Module`].
@@ -57,6 +59,7 @@ impl Module {
             .as_context_mut()
             .store
             .check_new_instances_limit(1)?;
+        self.check_new_tables_and_memories_limits(&mut context)?;
         let handle = context.as_context_mut().store.inner.alloc_instance();
         let mut builder = InstanceEntity::build(self);
 
@@ -76,6 +79,25 @@ impl Module {
         Ok(InstancePre::new(handle, builder))
     }
 
+    /// Checks up front if the internal tables and linear memories of the [`Module`] fit into the [`Store`].
+    ///
+    /// This avoids partial instantiation for [`Module`]s exceeding the resource limits.
+    ///
+    /// [`Store`]: crate::Store
+    fn check_new_tables_and_memories_limits(
+        &self,
+        mut context: impl AsContextMut,
+    ) -> Result<(), Error> {
+        let context = context.as_context_mut();
+        context
+            .store
+            .check_new_tables_limit(self.len_internal_tables())?;
+        context
+            .store
+            .check_new_memories_limit(self.len_internal_memories())?;
+        Ok(())
+    }
+
     /// Extract the Wasm imports from the module and zips them with the given external values.
     ///
     /// This also stores imported references into the [`Instance`] under construction.
@@ -88,6 +110,7 @@ impl Module {
     ///   variable external value.
     /// - If the externally provided [`Table`], [`Memory`], [`Func`] or [`Global`] has a type
     ///   mismatch with the expected module import type.
+    /// - If any of the external values does not belong to `store`.
     ///
     /// [`Func`]: [`crate::Func`]
     fn extract_imports<I>(
@@ -108,6 +131,9 @@ impl Module {
             });
         }
         for (import, external) in imports.zip(externals) {
+            if !external.is_from_store(&store) {
+                return Err(InstantiationError::CrossStore { actual: external });
+            }
             match (import.ty(), external) {
                 (ExternType::Func(expected_signature), Extern::Func(func)) => {
                     let actual_signature = func.ty(&store);
@@ -178,10 +204,6 @@ impl Module {
         mut context: impl AsContextMut,
         builder: &mut InstanceEntityBuilder,
     ) -> Result<(), InstantiationError> {
-        context
-            .as_context_mut()
-            .store
-            .check_new_tables_limit(self.len_tables())?;
         for table_type in self.internal_tables().copied() {
             let init = Val::default(table_type.element());
             let table = Table::new(context.as_context_mut(), table_type, init)?;
@@ -200,10 +222,6 @@ impl Module {
         mut context: impl AsContextMut,
         builder: &mut InstanceEntityBuilder,
     ) -> Result<(), MemoryError> {
-        context
-            .as_context_mut()
-            .store
-            .check_new_memories_limit(self.len_memories())?;
         for memory_type in self.internal_memories().copied() {
             let memory = Memory::new(context.as_context_mut(), memory_type)?;
             builder.push_memory(memory);
@@ -245,8 +263,15 @@ impl Module {
     }
 
     /// Extracts the Wasm exports from the module and registers them into the [`Instance`].
+    ///
+    /// The exports are registered in the order of their declaration in the Wasm module.
     fn extract_exports(&self, builder: &mut InstanceEntityBuilder) {
-        for (field, idx) in &self.module_header().exports {
+        let header = self.module_header();
+        for field in &header.export_names[..] {
+            let idx = header
+                .exports
+                .get(field)
+                .unwrap_or_else(|| panic!("missing export declaration for name: {field}"));
             let external = match idx {
                 export::ExternIdx::Func(func_index) => {
                     let func_index = func_index.into_u32();
@@ -286,40 +311,66 @@ impl Module {
         mut context: impl AsContextMut,
         builder: &mut InstanceEntityBuilder,
     ) -> Result<(), Error> {
-        for segment in &self.module_header().element_segments[..] {
-            let get_global = |index| builder.get_global(index);
-            let get_func = |index| builder.get_func(index);
-            let element =
-                ElementSegment::new(context.as_context_mut(), segment, get_func, get_global);
-            if let ElementSegmentKind::Active(active) = segment.kind() {
-                let dst_index = u32::from(Self::eval_init_expr(
-                    context.as_context(),
-                    builder,
-                    active.offset(),
-                ));
-                let table = builder.get_table(active.table_index().into_u32());
-                // Note: This checks not only that the elements in the element segments properly
-                //       fit into the table at the given offset but also that the element segment
-                //       consists of at least 1 element member.
-                let len_table = table.size(&context);
-                let len_items = element.size(&context);
-                dst_index
-                    .checked_add(len_items)
-                    .filter(|&max_index| max_index <= len_table)
-                    .ok_or(InstantiationError::ElementSegmentDoesNotFit {
-                        table,
-                        offset: dst_index,
-                        amount: len_items,
-                    })?;
-                let (table, elem) = context
+        let lazy_table_init = context.as_context().engine().config().get_lazy_table_init();
+        let mut lazy_source: Option<Arc<LazySource>> = None;
+        for (index, segment) in self.module_header().element_segments.iter().enumerate() {
+            let ElementSegmentKind::Active(active) = segment.kind() else {
+                let get_global = |index| builder.get_global(index);
+                let get_func = |index| builder.get_func(index);
+                let element =
+                    ElementSegment::new(context.as_context_mut(), segment, get_func, get_global);
+                builder.push_element_segment(element);
+                continue;
+            };
+            let dst_index = u32::from(Self::eval_init_expr(
+                context.as_context(),
+                builder,
+                active.offset(),
+            ));
+            let table = builder.get_table(active.table_index().into_u32());
+            // Note: This checks not only that the elements in the element segments properly
+            //       fit into the table at the given offset but also that the element segment
+            //       consists of at least 1 element member.
+            let len_table = table.size(&context);
+            let len_items = segment.items().len() as u32;
+            dst_index
+                .checked_add(len_items)
+                .filter(|&max_index| max_index <= len_table)
+                .ok_or(InstantiationError::ElementSegmentDoesNotFit {
+                    table,
+                    offset: dst_index,
+                    amount: len_items,
+                })?;
+            if lazy_table_init && segment.ty() == ValType::FuncRef && segment.is_lazy_compatible() {
+                let source = lazy_source.get_or_insert_with(|| {
+                    Arc::new(LazySource::new(
+                        self.inner.header.clone(),
+                        builder.funcs().into(),
+                    ))
+                });
+                context
                     .as_context_mut()
                     .store
                     .inner
-                    .resolve_table_and_element_mut(&table, &element);
-                table.init(elem, dst_index, 0, len_items, None)?;
-                // Now drop the active element segment as commanded by the Wasm spec.
-                elem.drop_items();
+                    .resolve_table_mut(&table)
+                    .init_lazy(dst_index, len_items, index as u32, source.clone());
+                // Note: Lazily applied active element segments are dropped right away.
+                let element = ElementSegment::new_dropped(context.as_context_mut(), segment.ty());
+                builder.push_element_segment(element);
+                continue;
             }
+            let get_global = |index| builder.get_global(index);
+            let get_func = |index| builder.get_func(index);
+            let element =
+                ElementSegment::new(context.as_context_mut(), segment, get_func, get_global);
+            let (table, elem) = context
+                .as_context_mut()
+                .store
+                .inner
+                .resolve_table_and_element_mut(&table, &element);
+            table.init(elem, dst_index, 0, len_items, None)?;
+            // Now drop the active element segment as commanded by the Wasm spec.
+            elem.drop_items();
             builder.push_element_segment(element);
         }
         Ok(())
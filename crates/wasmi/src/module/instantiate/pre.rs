@@ -41,21 +41,32 @@ impl InstancePre {
     ///
     /// If the `start` function is invalid albeit successful validation.
     pub fn start(self, mut context: impl AsContextMut) -> Result<Instance, Error> {
-        let opt_start_index = self.start_fn();
+        let handle = self.instantiate_without_start(&mut context)?;
+        handle.run_pending_start(&mut context)?;
+        Ok(handle)
+    }
+
+    /// Finishes instantiation without running the `start` function and returns its handle.
+    ///
+    /// # Note
+    ///
+    /// - The `start` function, if any, remains pending and must be run via
+    ///   [`Instance::run_start`] for conformant module instantiation.
+    /// - This allows to inspect or snapshot the state of the [`Instance`] before
+    ///   its `start` function is run.
+    ///
+    /// # Errors
+    ///
+    /// This does not fail at the moment but might in the future.
+    pub fn instantiate_without_start(
+        self,
+        mut context: impl AsContextMut,
+    ) -> Result<Instance, Error> {
         context
             .as_context_mut()
             .store
             .inner
             .initialize_instance(self.handle, self.builder.finish());
-        if let Some(start_index) = opt_start_index {
-            let start_func = self
-                .handle
-                .get_func_by_index(&mut context, start_index)
-                .unwrap_or_else(|| {
-                    panic!("encountered invalid start function after validation: {start_index}")
-                });
-            start_func.call(context.as_context_mut(), &[], &mut [])?
-        }
         Ok(self.handle)
     }
 
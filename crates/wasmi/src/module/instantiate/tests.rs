@@ -121,3 +121,63 @@ fn test_internal_table() {
     assert!(resolve_instance(&store, &instance).get_table(0).is_some());
     assert_no_duplicates(&store, instance);
 }
+
+#[test]
+fn module_item_counts() {
+    let wasm = r#"
+        (module
+            (import "env" "memory" (memory 4))
+            (import "env" "table" (table 4 funcref))
+            (import "env" "f" (func $f))
+            (import "env" "g" (global i32))
+            (table 1 funcref)
+            (global (mut i64) (i64.const 0))
+            (global f32 (f32.const 0))
+            (func (export "a"))
+            (func (export "b"))
+            (export "f" (func $f))
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+    assert_eq!(module.num_imports(), 4);
+    assert_eq!(module.num_exports(), 3);
+    assert_eq!(module.num_functions(), 3);
+    assert_eq!(module.num_tables(), 2);
+    assert_eq!(module.num_memories(), 1);
+    assert_eq!(module.num_globals(), 3);
+    assert_eq!(module.num_imports(), module.imports().len());
+    assert_eq!(module.num_exports(), module.exports().count());
+}
+
+#[test]
+fn module_memory_pages() {
+    let engine = Engine::default();
+    let memory_pages = |wasm: &str| {
+        let module = Module::new(&engine, wasm).unwrap();
+        (module.min_memory_pages(), module.max_memory_pages())
+    };
+    assert_eq!(memory_pages("(module)"), (None, None));
+    assert_eq!(
+        memory_pages(r#"(module (import "env" "memory" (memory 4 8)))"#),
+        (None, None)
+    );
+    assert_eq!(memory_pages("(module (memory 2))"), (Some(2), None));
+    assert_eq!(memory_pages("(module (memory 2 10))"), (Some(2), Some(10)));
+    assert_eq!(
+        memory_pages(
+            r#"
+            (module
+                (import "env" "memory" (memory 4 8))
+                (memory 1 3)
+                (memory 2 5)
+            )
+            "#
+        ),
+        (Some(3), Some(8))
+    );
+    assert_eq!(
+        memory_pages("(module (memory 1 3) (memory 2))"),
+        (Some(3), None)
+    );
+}
@@ -0,0 +1,276 @@
+use super::{ExportType, ImportType};
+use crate::ExternType;
+use alloc::boxed::Box;
+
+#[cfg(doc)]
+use crate::Module;
+
+/// An owned description of the imports and exports of a [`Module`].
+///
+/// This is primarily accessed from the [`Module::interface`] method.
+///
+/// # Note
+///
+/// - Unlike [`Module::imports`] and [`Module::exports`] this does not borrow the [`Module`].
+/// - With the `serde` crate feature enabled this can be serialized and deserialized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModuleInterface {
+    /// The imports of the [`Module`] in the order of [`Module::imports`].
+    imports: Box<[ImportInterface]>,
+    /// The exports of the [`Module`] sorted by their names.
+    exports: Box<[ExportInterface]>,
+}
+
+impl ModuleInterface {
+    /// Creates a new [`ModuleInterface`] from the given `imports` and `exports`.
+    pub(crate) fn new(
+        imports: Box<[ImportInterface]>,
+        mut exports: Box<[ExportInterface]>,
+    ) -> Self {
+        exports.sort_unstable_by(|lhs, rhs| lhs.name.cmp(&rhs.name));
+        Self { imports, exports }
+    }
+
+    /// Returns the imports of the [`ModuleInterface`] in the order of [`Module::imports`].
+    pub fn imports(&self) -> &[ImportInterface] {
+        &self.imports
+    }
+
+    /// Returns the exports of the [`ModuleInterface`] sorted by their names.
+    pub fn exports(&self) -> &[ExportInterface] {
+        &self.exports
+    }
+}
+
+/// An owned description of an import of a [`Module`].
+///
+/// This is the owned counterpart of an [`ImportType`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImportInterface {
+    /// The module import name.
+    module: Box<str>,
+    /// The name of the imported item.
+    name: Box<str>,
+    /// The type of the imported item.
+    #[cfg_attr(feature = "serde", serde(with = "repr"))]
+    ty: ExternType,
+}
+
+impl<'module> From<ImportType<'module>> for ImportInterface {
+    fn from(import: ImportType<'module>) -> Self {
+        Self {
+            module: import.module().into(),
+            name: import.name().into(),
+            ty: import.ty().clone(),
+        }
+    }
+}
+
+impl ImportInterface {
+    /// Returns the module import name.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Returns the name of the imported item.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type of the imported item.
+    pub fn ty(&self) -> &ExternType {
+        &self.ty
+    }
+}
+
+/// An owned description of an export of a [`Module`].
+///
+/// This is the owned counterpart of an [`ExportType`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExportInterface {
+    /// The name by which the export is known.
+    name: Box<str>,
+    /// The type of the exported item.
+    #[cfg_attr(feature = "serde", serde(with = "repr"))]
+    ty: ExternType,
+}
+
+impl<'module> From<ExportType<'module>> for ExportInterface {
+    fn from(export: ExportType<'module>) -> Self {
+        Self {
+            name: export.name().into(),
+            ty: export.ty().clone(),
+        }
+    }
+}
+
+impl ExportInterface {
+    /// Returns the name by which the export is known.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type of the exported item.
+    pub fn ty(&self) -> &ExternType {
+        &self.ty
+    }
+}
+
+/// Serializable representation of [`ExternType`].
+#[cfg(feature = "serde")]
+mod repr {
+    use crate::{
+        core::ValType,
+        ExternType,
+        FuncType,
+        GlobalType,
+        MemoryType,
+        Mutability,
+        TableType,
+    };
+    use alloc::vec::Vec;
+    use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializable representation of [`ValType`].
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum ValTypeRepr {
+        I32,
+        I64,
+        F32,
+        F64,
+        FuncRef,
+        ExternRef,
+    }
+
+    impl From<ValType> for ValTypeRepr {
+        fn from(ty: ValType) -> Self {
+            match ty {
+                ValType::I32 => Self::I32,
+                ValType::I64 => Self::I64,
+                ValType::F32 => Self::F32,
+                ValType::F64 => Self::F64,
+                ValType::FuncRef => Self::FuncRef,
+                ValType::ExternRef => Self::ExternRef,
+            }
+        }
+    }
+
+    impl From<ValTypeRepr> for ValType {
+        fn from(ty: ValTypeRepr) -> Self {
+            match ty {
+                ValTypeRepr::I32 => Self::I32,
+                ValTypeRepr::I64 => Self::I64,
+                ValTypeRepr::F32 => Self::F32,
+                ValTypeRepr::F64 => Self::F64,
+                ValTypeRepr::FuncRef => Self::FuncRef,
+                ValTypeRepr::ExternRef => Self::ExternRef,
+            }
+        }
+    }
+
+    /// Serializable representation of [`ExternType`].
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "kind", rename_all = "lowercase")]
+    enum ExternTypeRepr {
+        Func {
+            params: Vec<ValTypeRepr>,
+            results: Vec<ValTypeRepr>,
+        },
+        Global {
+            content: ValTypeRepr,
+            mutable: bool,
+        },
+        Memory {
+            minimum: u32,
+            maximum: Option<u32>,
+        },
+        Table {
+            element: ValTypeRepr,
+            minimum: u32,
+            maximum: Option<u32>,
+        },
+    }
+
+    pub fn serialize<S>(ty: &ExternType, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let repr = match ty {
+            ExternType::Func(ty) => ExternTypeRepr::Func {
+                params: ty.params().iter().copied().map(ValTypeRepr::from).collect(),
+                results: ty
+                    .results()
+                    .iter()
+                    .copied()
+                    .map(ValTypeRepr::from)
+                    .collect(),
+            },
+            ExternType::Global(ty) => ExternTypeRepr::Global {
+                content: ty.content().into(),
+                mutable: ty.mutability().is_mut(),
+            },
+            ExternType::Memory(ty) => ExternTypeRepr::Memory {
+                minimum: ty.initial_pages().into(),
+                maximum: ty.maximum_pages().map(u32::from),
+            },
+            ExternType::Table(ty) => ExternTypeRepr::Table {
+                element: ty.element().into(),
+                minimum: ty.minimum(),
+                maximum: ty.maximum(),
+            },
+        };
+        repr.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<ExternType, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ty = match ExternTypeRepr::deserialize(deserializer)? {
+            ExternTypeRepr::Func { params, results } => {
+                if params.len() > FuncType::MAX_LEN_PARAMS
+                    || results.len() > FuncType::MAX_LEN_RESULTS
+                {
+                    return Err(D::Error::custom("too many function parameters or results"));
+                }
+                let params = params.into_iter().map(ValType::from);
+                let results = results.into_iter().map(ValType::from);
+                ExternType::Func(FuncType::new(params, results))
+            }
+            ExternTypeRepr::Global { content, mutable } => {
+                let mutability = match mutable {
+                    true => Mutability::Var,
+                    false => Mutability::Const,
+                };
+                ExternType::Global(GlobalType::new(content.into(), mutability))
+            }
+            ExternTypeRepr::Memory { minimum, maximum } => {
+                let ty = MemoryType::new(minimum, maximum).map_err(D::Error::custom)?;
+                ExternType::Memory(ty)
+            }
+            ExternTypeRepr::Table {
+                element,
+                minimum,
+                maximum,
+            } => {
+                let element = ValType::from(element);
+                if !element.is_ref() {
+                    return Err(D::Error::custom(
+                        "table element type must be a reference type",
+                    ));
+                }
+                if maximum.is_some_and(|maximum| minimum > maximum) {
+                    return Err(D::Error::custom(
+                        "table minimum size exceeds its maximum size",
+                    ));
+                }
+                ExternType::Table(TableType::new(element, minimum, maximum))
+            }
+        };
+        Ok(ty)
+    }
+}
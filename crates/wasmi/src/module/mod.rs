@@ -3,12 +3,16 @@ mod custom_section;
 mod data;
 mod element;
 mod export;
+mod floats;
 mod global;
 mod import;
 mod init_expr;
 mod instantiate;
+mod interface;
+mod options;
 mod parser;
 mod read;
+mod resources;
 pub(crate) mod utils;
 
 use self::{
@@ -22,10 +26,14 @@ use self::{
 pub use self::{
     custom_section::{CustomSection, CustomSectionsIter},
     export::{ExportType, FuncIdx, MemoryIdx, ModuleExportsIter, TableIdx},
+    floats::FloatingPointError,
     global::GlobalIdx,
     import::{FuncTypeIdx, ImportName},
     instantiate::{InstancePre, InstantiationError},
+    interface::{ExportInterface, ImportInterface, ModuleInterface},
+    options::{ModuleOptions, ModuleOptionsError},
     read::{Read, ReadError},
+    resources::ModuleResources,
 };
 pub(crate) use self::{
     data::{DataSegment, DataSegments, InitDataSegment, PassiveDataSegmentBytes},
@@ -35,7 +43,15 @@ pub(crate) use self::{
 };
 use crate::{
     collections::Map,
-    engine::{DedupFuncType, EngineFunc, EngineFuncSpan, EngineFuncSpanIter, EngineWeak},
+    engine::{
+        DedupFuncType,
+        EnforcedLimits,
+        EngineFunc,
+        EngineFuncSpan,
+        EngineFuncSpanIter,
+        EngineWeak,
+    },
+    store::FuelMultiplier,
     Engine,
     Error,
     ExternType,
@@ -52,6 +68,8 @@ use wasmparser::{FuncValidatorAllocations, Parser, ValidPayload, Validator};
 #[derive(Debug, Clone)]
 pub struct Module {
     inner: Arc<ModuleInner>,
+    /// Multiplies the fuel consumed by the functions of instances created from the [`Module`].
+    fuel_multiplier: FuelMultiplier,
 }
 
 /// The internal data of a [`Module`].
@@ -72,6 +90,8 @@ pub struct ModuleHeader {
 #[derive(Debug)]
 struct ModuleHeaderInner {
     engine: EngineWeak,
+    /// The [`EnforcedLimits`] the [`Module`] has been compiled with.
+    limits: EnforcedLimits,
     func_types: Arc<[DedupFuncType]>,
     imports: ModuleImports,
     funcs: Box<[DedupFuncType]>,
@@ -80,6 +100,8 @@ struct ModuleHeaderInner {
     globals: Box<[GlobalType]>,
     globals_init: Box<[ConstExpr]>,
     exports: Map<Box<str>, ExternIdx>,
+    /// The names of the exports in the order of their declaration.
+    export_names: Box<[Box<str>]>,
     start: Option<FuncIdx>,
     engine_funcs: EngineFuncSpan,
     element_segments: Box<[ElementSegment]>,
@@ -91,6 +113,11 @@ impl ModuleHeader {
         &self.inner.engine
     }
 
+    /// Returns the [`EnforcedLimits`] the [`ModuleHeader`] has been compiled with.
+    pub fn enforced_limits(&self) -> &EnforcedLimits {
+        &self.inner.limits
+    }
+
     /// Returns the [`FuncType`] at the given index.
     pub fn get_func_type(&self, func_type_idx: FuncTypeIdx) -> &DedupFuncType {
         &self.inner.func_types[func_type_idx.into_u32() as usize]
@@ -101,6 +128,16 @@ impl ModuleHeader {
         &self.inner.funcs[func_idx.into_u32() as usize]
     }
 
+    /// Returns the [`TableType`] of the indexed table.
+    pub fn get_type_of_table(&self, table_idx: TableIdx) -> &TableType {
+        &self.inner.tables[table_idx.into_u32() as usize]
+    }
+
+    /// Returns the [`ElementSegment`] at the given index.
+    pub fn get_element_segment(&self, index: u32) -> &ElementSegment {
+        &self.inner.element_segments[index as usize]
+    }
+
     /// Returns the [`GlobalType`] the the indexed global variable.
     pub fn get_type_of_global(&self, global_idx: GlobalIdx) -> &GlobalType {
         &self.inner.globals[global_idx.into_u32() as usize]
@@ -220,6 +257,37 @@ impl Module {
         ModuleParser::new(engine).parse_buffered(wasm)
     }
 
+    /// Creates a new Wasm [`Module`] from the given Wasm bytecode buffer with [`ModuleOptions`].
+    ///
+    /// # Note
+    ///
+    /// - This is the same as [`Module::new`] but uses the [`CompilationMode`] and
+    ///   [`EnforcedLimits`] of `options` instead of the ones of the `engine` if set.
+    /// - This allows to compile some Wasm modules eagerly and others lazily
+    ///   using the same `engine`.
+    ///
+    /// # Errors
+    ///
+    /// - If `options` loosen the [`Config`] used by the `engine`.
+    /// - If the Wasm bytecode is malformed or fails to validate.
+    /// - If the Wasm bytecode violates restrictions
+    ///   set in the [`Config`] used by the `engine` or in `options`.
+    /// - If Wasmi cannot translate the Wasm bytecode.
+    ///
+    /// [`Config`]: crate::Config
+    /// [`CompilationMode`]: crate::CompilationMode
+    pub fn new_with(
+        engine: &Engine,
+        wasm: impl AsRef<[u8]>,
+        options: ModuleOptions,
+    ) -> Result<Self, Error> {
+        let parser = ModuleParser::with_options(engine, &options)?;
+        let wasm = wasm.as_ref();
+        #[cfg(feature = "wat")]
+        let wasm = &wat::parse_bytes(wasm)?[..];
+        parser.parse_buffered(wasm)
+    }
+
     /// Creates a new Wasm [`Module`] from the given Wasm bytecode stream.
     ///
     /// # Note
@@ -296,6 +364,39 @@ impl Module {
         &self.inner.engine
     }
 
+    /// Returns `true` if both [`Module`] references `a` and `b` refer to the same [`Module`].
+    pub fn same(a: &Module, b: &Module) -> bool {
+        Arc::ptr_eq(&a.inner, &b.inner)
+    }
+
+    /// Sets the `factor` by which the fuel consumed by the functions of the [`Module`] is multiplied.
+    ///
+    /// # Note
+    ///
+    /// - The multiplier applies to the fuel consumed by the Wasm instructions of the
+    ///   functions of all [`Instance`]s that are created from the [`Module`] afterwards.
+    ///   Already existing [`Instance`]s keep the multiplier they were created with.
+    /// - The costs of the consumed fuel are rounded up to the next integer.
+    /// - The fuel consumed by host functions or by bulk memory and table operations
+    ///   proportional to the number of processed bytes or elements is not affected.
+    /// - This has no effect if fuel metering is disabled.
+    ///
+    /// Default value: `1.0`
+    ///
+    /// # Panics
+    ///
+    /// If `factor` is not a finite number greater than zero.
+    ///
+    /// [`Instance`]: crate::Instance
+    pub fn set_fuel_multiplier(&mut self, factor: f64) {
+        self.fuel_multiplier = FuelMultiplier::new(factor);
+    }
+
+    /// Returns the [`FuelMultiplier`] of the [`Module`].
+    pub(crate) fn fuel_multiplier(&self) -> FuelMultiplier {
+        self.fuel_multiplier
+    }
+
     /// Returns a shared reference to the [`ModuleHeaderInner`].
     fn module_header(&self) -> &ModuleHeaderInner {
         &self.inner.header.inner
@@ -334,23 +435,111 @@ impl Module {
         Ok(())
     }
 
-    /// Returns the number of non-imported functions of the [`Module`].
-    pub(crate) fn len_funcs(&self) -> usize {
+    /// Returns `true` if the [`Module`] has a `start` function.
+    ///
+    /// # Note
+    ///
+    /// The `start` function is run upon [`InstancePre::start`] or [`Instance::run_start`].
+    ///
+    /// [`Instance::run_start`]: crate::Instance::run_start
+    pub fn has_start(&self) -> bool {
+        self.module_header().start.is_some()
+    }
+
+    /// Returns the number of imports of the [`Module`].
+    pub fn num_imports(&self) -> usize {
+        self.module_header().imports.items.len()
+    }
+
+    /// Returns the number of exports of the [`Module`].
+    pub fn num_exports(&self) -> usize {
+        self.module_header().export_names.len()
+    }
+
+    /// Returns the number of functions of the [`Module`] including imported functions.
+    pub fn num_functions(&self) -> usize {
         self.module_header().funcs.len()
     }
-    /// Returns the number of non-imported tables of the [`Module`].
-    pub(crate) fn len_tables(&self) -> usize {
+
+    /// Returns the number of tables of the [`Module`] including imported tables.
+    pub fn num_tables(&self) -> usize {
         self.module_header().tables.len()
     }
-    /// Returns the number of non-imported linear memories of the [`Module`].
-    pub(crate) fn len_memories(&self) -> usize {
+
+    /// Returns the number of linear memories of the [`Module`] including imported linear memories.
+    pub fn num_memories(&self) -> usize {
         self.module_header().memories.len()
     }
-    /// Returns the number of non-imported global variables of the [`Module`].
-    pub(crate) fn len_globals(&self) -> usize {
+
+    /// Returns the number of global variables of the [`Module`] including imported global variables.
+    pub fn num_globals(&self) -> usize {
         self.module_header().globals.len()
     }
 
+    /// Returns the total number of initial pages of the linear memories defined by the [`Module`].
+    ///
+    /// This allows to reject a [`Module`] whose initial memory size exceeds
+    /// some policy before instantiating it.
+    ///
+    /// # Note
+    ///
+    /// - Imported linear memories are not taken into account.
+    /// - Returns `None` if the [`Module`] does not define any linear memory.
+    /// - If the [`Module`] defines multiple linear memories via the `multi-memory`
+    ///   Wasm proposal their initial pages are summed up.
+    pub fn min_memory_pages(&self) -> Option<u32> {
+        let mut memories = self.internal_memories().peekable();
+        memories.peek()?;
+        let pages = memories
+            .map(|memory| u32::from(memory.initial_pages()))
+            .fold(0_u32, u32::saturating_add);
+        Some(pages)
+    }
+
+    /// Returns the total number of maximum pages of the linear memories defined by the [`Module`].
+    ///
+    /// # Note
+    ///
+    /// - Imported linear memories are not taken into account.
+    /// - Returns `None` if the [`Module`] does not define any linear memory or
+    ///   if any of its defined linear memories has no maximum size.
+    /// - If the [`Module`] defines multiple linear memories via the `multi-memory`
+    ///   Wasm proposal their maximum pages are summed up.
+    pub fn max_memory_pages(&self) -> Option<u32> {
+        let mut memories = self.internal_memories().peekable();
+        memories.peek()?;
+        memories
+            .map(|memory| memory.maximum_pages().map(u32::from))
+            .try_fold(0_u32, |acc, pages| Some(acc.saturating_add(pages?)))
+    }
+
+    /// Returns the index of the function of the [`Module`] that is compiled to `func`.
+    ///
+    /// Returns `None` if the [`Module`] does not define `func`.
+    pub(crate) fn get_func_index(&self, func: EngineFunc) -> Option<u32> {
+        self.inner
+            .header
+            .get_func_index(func)
+            .map(FuncIdx::into_u32)
+    }
+
+    /// Returns the data segments of the [`Module`].
+    pub(crate) fn data_segments(&self) -> &DataSegments {
+        &self.inner.data_segments
+    }
+
+    /// Returns the number of non-imported tables of the [`Module`].
+    pub(crate) fn len_internal_tables(&self) -> usize {
+        let header = self.module_header();
+        header.tables.len() - header.imports.len_tables
+    }
+
+    /// Returns the number of non-imported linear memories of the [`Module`].
+    pub(crate) fn len_internal_memories(&self) -> usize {
+        let header = self.module_header();
+        header.memories.len() - header.imports.len_memories
+    }
+
     /// Returns a slice to the function types of the [`Module`].
     ///
     /// # Note
@@ -433,6 +622,69 @@ impl Module {
         ModuleExportsIter::new(self)
     }
 
+    /// Returns an iterator over the names and types of the exported functions of the [`Module`].
+    ///
+    /// This yields the subset of [`Module::exports`] that are functions.
+    pub fn func_exports(&self) -> impl Iterator<Item = (&str, FuncType)> {
+        self.exports().filter_map(|export| {
+            let ty = export.ty().func()?.clone();
+            Some((export.name(), ty))
+        })
+    }
+
+    /// Returns an iterator over the names and types of the exported tables of the [`Module`].
+    ///
+    /// This yields the subset of [`Module::exports`] that are tables.
+    pub fn table_exports(&self) -> impl Iterator<Item = (&str, TableType)> {
+        self.exports().filter_map(|export| {
+            let ty = *export.ty().table()?;
+            Some((export.name(), ty))
+        })
+    }
+
+    /// Returns an iterator over the names and types of the exported memories of the [`Module`].
+    ///
+    /// This yields the subset of [`Module::exports`] that are linear memories.
+    pub fn memory_exports(&self) -> impl Iterator<Item = (&str, MemoryType)> {
+        self.exports().filter_map(|export| {
+            let ty = *export.ty().memory()?;
+            Some((export.name(), ty))
+        })
+    }
+
+    /// Returns an iterator over the names and types of the exported global variables of the [`Module`].
+    ///
+    /// This yields the subset of [`Module::exports`] that are global variables.
+    pub fn global_exports(&self) -> impl Iterator<Item = (&str, GlobalType)> {
+        self.exports().filter_map(|export| {
+            let ty = *export.ty().global()?;
+            Some((export.name(), ty))
+        })
+    }
+
+    /// Returns an owned description of the imports and exports of the [`Module`].
+    ///
+    /// # Note
+    ///
+    /// - Imports are ordered as yielded by [`Module::imports`] and exports are sorted by their names.
+    /// - With the `serde` crate feature enabled the returned [`ModuleInterface`]
+    ///   can be serialized, for example for bindings generation.
+    pub fn interface(&self) -> ModuleInterface {
+        let imports = self.imports().map(ImportInterface::from).collect();
+        let exports = self.exports().map(ExportInterface::from).collect();
+        ModuleInterface::new(imports, exports)
+    }
+
+    /// Returns a summary of the resources requested by the [`Module`] upon instantiation.
+    ///
+    /// This can be used to reject untrusted Wasm modules or to configure
+    /// [`StoreLimits`] before instantiating them.
+    ///
+    /// [`StoreLimits`]: crate::StoreLimits
+    pub fn resources(&self) -> ModuleResources {
+        ModuleResources::new(self)
+    }
+
     /// Looks up an export in this [`Module`] by its `name`.
     ///
     /// Returns `None` if no export with the name was found.
@@ -487,6 +739,18 @@ impl Module {
     pub fn custom_sections(&self) -> CustomSectionsIter {
         self.inner.custom_sections.iter()
     }
+
+    /// Returns the data of the first custom section named `name` of the Wasm [`Module`] if any.
+    ///
+    /// # Note
+    ///
+    /// Always returns `None` if [`Config::ignore_custom_sections`] is set to `true`.
+    ///
+    /// [`Config::ignore_custom_sections`]: crate::Config::ignore_custom_sections
+    #[inline]
+    pub fn custom_section(&self, name: &str) -> Option<&[u8]> {
+        self.inner.custom_sections.get(name)
+    }
 }
 
 /// An iterator over the imports of a [`Module`].
@@ -0,0 +1,125 @@
+use crate::{
+    engine::{EnforcedLimitKind, EnforcedLimits},
+    CompilationMode,
+    Config,
+};
+use core::fmt::{self, Display};
+
+/// Per-[`Module`] overrides of the [`Config`] of the [`Engine`].
+///
+/// This is used by [`Module::new_with`].
+///
+/// # Note
+///
+/// Overrides may only tighten but never loosen the [`Config`] of the [`Engine`].
+///
+/// [`Engine`]: crate::Engine
+/// [`Module`]: crate::Module
+/// [`Module::new_with`]: crate::Module::new_with
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ModuleOptions {
+    /// Overrides the [`CompilationMode`] of the [`Engine`] if `Some`.
+    ///
+    /// # Note
+    ///
+    /// The [`CompilationMode`] must not be lazier than the one of the [`Engine`].
+    /// The order from eager to lazy is:
+    ///
+    /// 1. [`CompilationMode::Eager`]
+    /// 2. [`CompilationMode::LazyTranslation`]
+    /// 3. [`CompilationMode::Lazy`]
+    ///
+    /// [`Engine`]: crate::Engine
+    pub compilation_mode: Option<CompilationMode>,
+    /// Overrides the [`EnforcedLimits`] of the [`Engine`] if `Some`.
+    ///
+    /// # Note
+    ///
+    /// Every limit enforced by the [`Engine`] must also be enforced
+    /// by the overriding [`EnforcedLimits`] and must not be exceeded.
+    ///
+    /// [`Engine`]: crate::Engine
+    pub enforced_limits: Option<EnforcedLimits>,
+}
+
+impl ModuleOptions {
+    /// Returns the effective [`CompilationMode`] and [`EnforcedLimits`] for `config`.
+    ///
+    /// # Errors
+    ///
+    /// If the [`ModuleOptions`] loosen the `config`.
+    pub(crate) fn resolve(
+        &self,
+        config: &Config,
+    ) -> Result<(CompilationMode, EnforcedLimits), ModuleOptionsError> {
+        let engine_mode = config.get_compilation_mode();
+        let compilation_mode = match self.compilation_mode {
+            Some(mode) if laziness(mode) > laziness(engine_mode) => {
+                return Err(ModuleOptionsError::LazierCompilationMode {
+                    engine: engine_mode,
+                    module: mode,
+                })
+            }
+            Some(mode) => mode,
+            None => engine_mode,
+        };
+        let engine_limits = config.get_enforced_limits();
+        let enforced_limits = match self.enforced_limits {
+            Some(limits) => {
+                if let Some(kind) = engine_limits.loosened_by(&limits) {
+                    return Err(ModuleOptionsError::LooserLimit { kind });
+                }
+                limits
+            }
+            None => *engine_limits,
+        };
+        Ok((compilation_mode, enforced_limits))
+    }
+}
+
+/// Returns the laziness of `mode` where eager compilation is the least lazy.
+fn laziness(mode: CompilationMode) -> u8 {
+    match mode {
+        CompilationMode::Eager => 0,
+        CompilationMode::LazyTranslation => 1,
+        CompilationMode::Lazy => 2,
+    }
+}
+
+/// An error that can occur upon [`Module::new_with`] if [`ModuleOptions`] loosen the [`Config`].
+///
+/// [`Module::new_with`]: crate::Module::new_with
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ModuleOptionsError {
+    /// The [`CompilationMode`] of the [`Module`] is lazier than the one of the [`Engine`].
+    ///
+    /// [`Engine`]: crate::Engine
+    /// [`Module`]: crate::Module
+    LazierCompilationMode {
+        engine: CompilationMode,
+        module: CompilationMode,
+    },
+    /// A limit of the [`EnforcedLimits`] of the [`Engine`] is loosened or not enforced.
+    ///
+    /// [`Engine`]: crate::Engine
+    LooserLimit { kind: EnforcedLimitKind },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ModuleOptionsError {}
+
+impl Display for ModuleOptionsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::LazierCompilationMode { engine, module } => write!(
+                f,
+                "module compilation mode ({module:?}) must not be lazier than \
+                the engine compilation mode ({engine:?})"
+            ),
+            Self::LooserLimit { kind } => write!(
+                f,
+                "module limits must not loosen the engine limits: {kind:?}"
+            ),
+        }
+    }
+}
@@ -1,26 +1,32 @@
 use super::{
     builder::ModuleHeaderBuilder,
     export::ExternIdx,
+    floats::{is_float, FloatOperatorFinder},
     global::Global,
     import::{FuncTypeIdx, Import},
     CustomSectionsBuilder,
     ElementSegment,
+    FloatingPointError,
     FuncIdx,
     ModuleBuilder,
     ModuleHeader,
+    ModuleOptions,
 };
 use crate::{
-    engine::{EnforcedLimitsError, EngineFunc},
+    engine::{EnforcedLimits, EnforcedLimitsError, EngineFunc, TranslationError},
+    CompilationMode,
     Engine,
     Error,
     FuncType,
     MemoryType,
     TableType,
+    WasmProposal,
 };
 use alloc::boxed::Box;
 use core::ops::Range;
 use wasmparser::{
     CustomSectionReader,
+    DataKind,
     DataSectionReader,
     ElementSectionReader,
     Encoding,
@@ -33,6 +39,7 @@ use wasmparser::{
     Parser as WasmParser,
     Payload,
     TableSectionReader,
+    TypeRef,
     TypeSectionReader,
     Validator,
 };
@@ -47,6 +54,10 @@ mod streaming;
 pub struct ModuleParser {
     /// The engine used for translation.
     engine: Engine,
+    /// The [`CompilationMode`] used for the parsed Wasm module.
+    compilation_mode: CompilationMode,
+    /// The [`EnforcedLimits`] enforced on the parsed Wasm module.
+    limits: EnforcedLimits,
     /// The Wasm validator used throughout stream parsing.
     validator: Option<Validator>,
     /// The underlying Wasm parser.
@@ -60,9 +71,35 @@ pub struct ModuleParser {
 impl ModuleParser {
     /// Creates a new [`ModuleParser`] for the given [`Engine`].
     pub fn new(engine: &Engine) -> Self {
+        let config = engine.config();
+        Self::with_config(
+            engine,
+            config.get_compilation_mode(),
+            *config.get_enforced_limits(),
+        )
+    }
+
+    /// Creates a new [`ModuleParser`] for the given [`Engine`] with [`ModuleOptions`].
+    ///
+    /// # Errors
+    ///
+    /// If `options` loosen the [`Config`](crate::Config) of the `engine`.
+    pub fn with_options(engine: &Engine, options: &ModuleOptions) -> Result<Self, Error> {
+        let (compilation_mode, limits) = options.resolve(engine.config())?;
+        Ok(Self::with_config(engine, compilation_mode, limits))
+    }
+
+    /// Creates a new [`ModuleParser`] with the given [`CompilationMode`] and [`EnforcedLimits`].
+    fn with_config(
+        engine: &Engine,
+        compilation_mode: CompilationMode,
+        limits: EnforcedLimits,
+    ) -> Self {
         let parser = WasmParser::new(0);
         Self {
             engine: engine.clone(),
+            compilation_mode,
+            limits,
             validator: None,
             parser,
             engine_funcs: 0,
@@ -111,20 +148,36 @@ impl ModuleParser {
         if let Some(validator) = &mut self.validator {
             validator.type_section(&section)?;
         }
-        let limits = self.engine.config().get_enforced_limits();
-        let func_types = section.into_iter().map(|result| {
+        let limits = self.limits;
+        let deny_floats = self.engine.config().floats_denied();
+        let func_types = section.into_iter().enumerate().map(|(type_index, result)| {
             let ty = result?.into_types().next().unwrap();
             let func_ty = ty.unwrap_func();
+            if deny_floats
+                && func_ty
+                    .params()
+                    .iter()
+                    .chain(func_ty.results())
+                    .any(|ty| is_float(*ty))
+            {
+                return Err(Error::from(FloatingPointError::FuncType {
+                    type_index: type_index as u32,
+                }));
+            }
             if let Some(limit) = limits.max_params {
                 if func_ty.params().len() > limit {
                     return Err(Error::from(EnforcedLimitsError::TooManyParameters {
                         limit,
+                        value: func_ty.params().len(),
                     }));
                 }
             }
             if let Some(limit) = limits.max_results {
                 if func_ty.results().len() > limit {
-                    return Err(Error::from(EnforcedLimitsError::TooManyResults { limit }));
+                    return Err(Error::from(EnforcedLimitsError::TooManyResults {
+                        limit,
+                        value: func_ty.results().len(),
+                    }));
                 }
             }
             Ok(FuncType::from_wasmparser(func_ty))
@@ -151,9 +204,18 @@ impl ModuleParser {
         if let Some(validator) = &mut self.validator {
             validator.import_section(&section)?;
         }
-        let imports = section
-            .into_iter()
-            .map(|import| import.map(Import::from).map_err(Error::from));
+        let deny_floats = self.engine.config().floats_denied();
+        let mut global_index = 0;
+        let imports = section.into_iter().map(|import| {
+            let import = import?;
+            if let TypeRef::Global(global_type) = import.ty {
+                if deny_floats && is_float(global_type.content_type) {
+                    return Err(Error::from(FloatingPointError::Global { global_index }));
+                }
+                global_index += 1;
+            }
+            Ok(Import::from(import))
+        });
         header.push_imports(imports)?;
         Ok(())
     }
@@ -172,9 +234,12 @@ impl ModuleParser {
         section: FunctionSectionReader,
         header: &mut ModuleHeaderBuilder,
     ) -> Result<(), Error> {
-        if let Some(limit) = self.engine.config().get_enforced_limits().max_functions {
+        if let Some(limit) = self.limits.max_functions {
             if section.count() > limit {
-                return Err(Error::from(EnforcedLimitsError::TooManyFunctions { limit }));
+                return Err(Error::from(EnforcedLimitsError::TooManyFunctions {
+                    limit,
+                    value: section.count(),
+                }));
             }
         }
         if let Some(validator) = &mut self.validator {
@@ -201,9 +266,12 @@ impl ModuleParser {
         section: TableSectionReader,
         header: &mut ModuleHeaderBuilder,
     ) -> Result<(), Error> {
-        if let Some(limit) = self.engine.config().get_enforced_limits().max_tables {
+        if let Some(limit) = self.limits.max_tables {
             if section.count() > limit {
-                return Err(Error::from(EnforcedLimitsError::TooManyTables { limit }));
+                return Err(Error::from(EnforcedLimitsError::TooManyTables {
+                    limit,
+                    value: section.count(),
+                }));
             }
         }
         if let Some(validator) = &mut self.validator {
@@ -234,17 +302,30 @@ impl ModuleParser {
         section: MemorySectionReader,
         header: &mut ModuleHeaderBuilder,
     ) -> Result<(), Error> {
-        if let Some(limit) = self.engine.config().get_enforced_limits().max_memories {
+        if let Some(limit) = self.limits.max_memories {
             if section.count() > limit {
-                return Err(Error::from(EnforcedLimitsError::TooManyMemories { limit }));
+                return Err(Error::from(EnforcedLimitsError::TooManyMemories {
+                    limit,
+                    value: section.count(),
+                }));
             }
         }
         if let Some(validator) = &mut self.validator {
             validator.memory_section(&section)?;
         }
-        let memories = section
-            .into_iter()
-            .map(|memory| memory.map(MemoryType::from_wasmparser).map_err(Error::from));
+        let max_memory_pages = self.limits.max_memory_pages;
+        let memories = section.into_iter().map(|memory| {
+            let memory = memory?;
+            if let Some(limit) = max_memory_pages {
+                if memory.initial > u64::from(limit) {
+                    return Err(Error::from(EnforcedLimitsError::TooManyMemoryPages {
+                        limit,
+                        value: memory.initial,
+                    }));
+                }
+            }
+            Ok(MemoryType::from_wasmparser(memory))
+        });
         header.push_memories(memories)?;
         Ok(())
     }
@@ -263,17 +344,27 @@ impl ModuleParser {
         section: GlobalSectionReader,
         header: &mut ModuleHeaderBuilder,
     ) -> Result<(), Error> {
-        if let Some(limit) = self.engine.config().get_enforced_limits().max_globals {
+        if let Some(limit) = self.limits.max_globals {
             if section.count() > limit {
-                return Err(Error::from(EnforcedLimitsError::TooManyGlobals { limit }));
+                return Err(Error::from(EnforcedLimitsError::TooManyGlobals {
+                    limit,
+                    value: section.count(),
+                }));
             }
         }
         if let Some(validator) = &mut self.validator {
             validator.global_section(&section)?;
         }
-        let globals = section
-            .into_iter()
-            .map(|global| global.map(Global::from).map_err(Error::from));
+        let deny_floats = self.engine.config().floats_denied();
+        let len_imported_globals = header.imports.globals.len() as u32;
+        let globals = section.into_iter().enumerate().map(|(index, global)| {
+            let global = global?;
+            if deny_floats && is_float(global.ty.content_type) {
+                let global_index = len_imported_globals + index as u32;
+                return Err(Error::from(FloatingPointError::Global { global_index }));
+            }
+            Ok(Global::from(global))
+        });
         header.push_globals(globals)?;
         Ok(())
     }
@@ -341,15 +432,11 @@ impl ModuleParser {
         section: ElementSectionReader,
         header: &mut ModuleHeaderBuilder,
     ) -> Result<(), Error> {
-        if let Some(limit) = self
-            .engine
-            .config()
-            .get_enforced_limits()
-            .max_element_segments
-        {
+        if let Some(limit) = self.limits.max_element_segments {
             if section.count() > limit {
                 return Err(Error::from(EnforcedLimitsError::TooManyElementSegments {
                     limit,
+                    value: section.count(),
                 }));
             }
         }
@@ -370,10 +457,11 @@ impl ModuleParser {
     /// This is part of the bulk memory operations Wasm proposal and not yet supported
     /// by Wasmi.
     fn process_data_count(&mut self, count: u32, range: Range<usize>) -> Result<(), Error> {
-        if let Some(limit) = self.engine.config().get_enforced_limits().max_data_segments {
+        if let Some(limit) = self.limits.max_data_segments {
             if count > limit {
                 return Err(Error::from(EnforcedLimitsError::TooManyDataSegments {
                     limit,
+                    value: count,
                 }));
             }
         }
@@ -397,10 +485,11 @@ impl ModuleParser {
         section: DataSectionReader,
         builder: &mut ModuleBuilder,
     ) -> Result<(), Error> {
-        if let Some(limit) = self.engine.config().get_enforced_limits().max_data_segments {
+        if let Some(limit) = self.limits.max_data_segments {
             if section.count() > limit {
                 return Err(Error::from(EnforcedLimitsError::TooManyDataSegments {
                     limit,
+                    value: section.count(),
                 }));
             }
         }
@@ -410,9 +499,19 @@ impl ModuleParser {
             // Also benchmarks show that validation of the data section can be very costly.
             validator.data_section(&section)?;
         }
+        // Note: `wasmparser` does not check that passive data segments
+        //       require the `bulk-memory` Wasm proposal so we do it ourselves.
+        let deny_passive =
+            self.validator.is_some() && !self.engine.config().is_enabled(WasmProposal::BulkMemory);
         builder.reserve_data_segments(section.count() as usize);
         for segment in section {
-            builder.push_data_segment(segment?)?;
+            let segment = segment?;
+            if deny_passive && matches!(segment.kind, DataKind::Passive) {
+                return Err(Error::from(TranslationError::PassiveDataSegment {
+                    offset: segment.range.start,
+                }));
+            }
+            builder.push_data_segment(segment)?;
         }
         Ok(())
     }
@@ -434,10 +533,13 @@ impl ModuleParser {
         range: Range<usize>,
         size: u32,
     ) -> Result<(), Error> {
-        let enforced_limits = self.engine.config().get_enforced_limits();
+        let enforced_limits = self.limits;
         if let Some(limit) = enforced_limits.max_functions {
             if count > limit {
-                return Err(Error::from(EnforcedLimitsError::TooManyFunctions { limit }));
+                return Err(Error::from(EnforcedLimitsError::TooManyFunctions {
+                    limit,
+                    value: count,
+                }));
             }
         }
         if let Some(limit) = enforced_limits.min_avg_bytes_per_function {
@@ -489,14 +591,68 @@ impl ModuleParser {
         header: &ModuleHeader,
     ) -> Result<(), Error> {
         let (func, engine_func) = self.next_func(header);
+        if let Some(limit) = self.limits.max_locals_per_function {
+            let mut len_locals = 0_u64;
+            for local in func_body.get_locals_reader()? {
+                let (amount, _ty) = local?;
+                len_locals += u64::from(amount);
+            }
+            if len_locals > u64::from(limit) {
+                return Err(Error::from(EnforcedLimitsError::TooManyLocals {
+                    limit,
+                    value: len_locals,
+                    func_index: func.into_u32(),
+                }));
+            }
+        }
+        if self.engine.config().floats_denied() {
+            Self::deny_floats_in_body(func, &func_body)?;
+        }
         let module = header.clone();
         let offset = func_body.get_binary_reader().original_position();
         let func_to_validate = match &mut self.validator {
             Some(validator) => Some(validator.code_section_entry(&func_body)?),
             None => None,
         };
-        self.engine
-            .translate_func(func, engine_func, offset, bytes, module, func_to_validate)?;
+        self.engine.translate_func(
+            self.compilation_mode,
+            func,
+            engine_func,
+            offset,
+            bytes,
+            module,
+            func_to_validate,
+        )?;
+        Ok(())
+    }
+
+    /// Returns an error if `func_body` uses floating point locals or instructions.
+    ///
+    /// # Note
+    ///
+    /// This is an extra pass over the function body before its translation
+    /// so that floating point is denied the same for all [`CompilationMode`]s.
+    ///
+    /// [`CompilationMode`]: crate::CompilationMode
+    fn deny_floats_in_body(func: FuncIdx, func_body: &FunctionBody) -> Result<(), Error> {
+        let func_index = func.into_u32();
+        for local in func_body.get_locals_reader()? {
+            let (_amount, ty) = local?;
+            if is_float(ty) {
+                return Err(Error::from(FloatingPointError::Local { func_index }));
+            }
+        }
+        let mut reader = func_body.get_operators_reader()?;
+        while !reader.eof() {
+            let offset = reader.original_position();
+            if let Some(name) = reader.visit_operator(&mut FloatOperatorFinder)? {
+                return Err(Error::from(FloatingPointError::Instruction {
+                    func_index,
+                    offset,
+                    name,
+                }));
+            }
+        }
         Ok(())
     }
 
@@ -509,7 +665,7 @@ impl ModuleParser {
         if self.engine.config().get_ignore_custom_sections() {
             return Ok(());
         }
-        custom_sections.push(reader.name(), reader.data());
+        custom_sections.push(reader.name(), reader.data_offset(), reader.data());
         Ok(())
     }
 
@@ -101,7 +101,7 @@ impl ModuleParser {
         buffer: &mut &[u8],
         custom_sections: &mut CustomSectionsBuilder,
     ) -> Result<ModuleHeader, Error> {
-        let mut header = ModuleHeaderBuilder::new(&self.engine);
+        let mut header = ModuleHeaderBuilder::new(&self.engine, self.limits);
         loop {
             let (consumed, payload) = self.next_payload(buffer)?;
             match payload {
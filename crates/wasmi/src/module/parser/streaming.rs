@@ -147,7 +147,7 @@ impl ModuleParser {
         buffer: &mut ParseBuffer,
         custom_sections: &mut CustomSectionsBuilder,
     ) -> Result<ModuleHeader, Error> {
-        let mut header = ModuleHeaderBuilder::new(&self.engine);
+        let mut header = ModuleHeaderBuilder::new(&self.engine, self.limits);
         loop {
             match self.parser.parse(&buffer[..], self.eof)? {
                 Chunk::NeedMoreData(hint) => {
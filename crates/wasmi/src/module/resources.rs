@@ -0,0 +1,145 @@
+use super::{InitDataSegment, Module};
+use crate::{core::UntypedVal, MemoryType, TableType};
+use alloc::boxed::Box;
+use core::mem;
+
+/// A summary of the resources requested by a [`Module`] upon instantiation.
+///
+/// This is primarily accessed from the [`Module::resources`] method.
+///
+/// # Note
+///
+/// This can be used to configure [`StoreLimits`] or to reject untrusted
+/// Wasm modules before instantiating them.
+///
+/// [`StoreLimits`]: crate::StoreLimits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleResources {
+    /// The types of the linear memories defined by the [`Module`].
+    memories: Box<[MemoryType]>,
+    /// The types of the tables defined by the [`Module`].
+    tables: Box<[TableType]>,
+    /// The number of global variables defined by the [`Module`].
+    len_globals: usize,
+    /// The number of functions defined by the [`Module`].
+    len_funcs: usize,
+    /// The number of linear memories imported by the [`Module`].
+    len_imported_memories: usize,
+    /// The number of tables imported by the [`Module`].
+    len_imported_tables: usize,
+    /// The number of global variables imported by the [`Module`].
+    len_imported_globals: usize,
+    /// The number of functions imported by the [`Module`].
+    len_imported_funcs: usize,
+    /// The total number of bytes of all data segments of the [`Module`].
+    data_bytes: u64,
+    /// The total number of items of all element segments of the [`Module`].
+    element_items: u64,
+}
+
+impl ModuleResources {
+    /// Creates a new [`ModuleResources`] summarizing the resources of `module`.
+    pub(super) fn new(module: &Module) -> Self {
+        let header = module.module_header();
+        let imports = &header.imports;
+        let data_bytes = module
+            .data_segments()
+            .into_iter()
+            .map(|segment| match segment {
+                InitDataSegment::Active { bytes, .. } => bytes.len() as u64,
+                InitDataSegment::Passive { bytes } => bytes.as_ref().len() as u64,
+            })
+            .sum();
+        let element_items = header
+            .element_segments
+            .iter()
+            .map(|segment| segment.items().len() as u64)
+            .sum();
+        Self {
+            memories: module.internal_memories().copied().collect(),
+            tables: module.internal_tables().copied().collect(),
+            len_globals: header.globals.len() - imports.len_globals,
+            len_funcs: header.funcs.len() - imports.len_funcs,
+            len_imported_memories: imports.len_memories,
+            len_imported_tables: imports.len_tables,
+            len_imported_globals: imports.len_globals,
+            len_imported_funcs: imports.len_funcs,
+            data_bytes,
+            element_items,
+        }
+    }
+
+    /// Returns the types of the linear memories defined by the [`Module`].
+    ///
+    /// Imported linear memories are not included.
+    pub fn memories(&self) -> &[MemoryType] {
+        &self.memories
+    }
+
+    /// Returns the types of the tables defined by the [`Module`].
+    ///
+    /// Imported tables are not included.
+    pub fn tables(&self) -> &[TableType] {
+        &self.tables
+    }
+
+    /// Returns the number of global variables defined by the [`Module`].
+    pub fn len_globals(&self) -> usize {
+        self.len_globals
+    }
+
+    /// Returns the number of functions defined by the [`Module`].
+    pub fn len_funcs(&self) -> usize {
+        self.len_funcs
+    }
+
+    /// Returns the number of linear memories imported by the [`Module`].
+    pub fn len_imported_memories(&self) -> usize {
+        self.len_imported_memories
+    }
+
+    /// Returns the number of tables imported by the [`Module`].
+    pub fn len_imported_tables(&self) -> usize {
+        self.len_imported_tables
+    }
+
+    /// Returns the number of global variables imported by the [`Module`].
+    pub fn len_imported_globals(&self) -> usize {
+        self.len_imported_globals
+    }
+
+    /// Returns the number of functions imported by the [`Module`].
+    pub fn len_imported_funcs(&self) -> usize {
+        self.len_imported_funcs
+    }
+
+    /// Returns the total number of bytes of all active and passive data segments.
+    pub fn data_bytes(&self) -> u64 {
+        self.data_bytes
+    }
+
+    /// Returns the total number of items of all active, passive and declared element segments.
+    pub fn element_items(&self) -> u64 {
+        self.element_items
+    }
+
+    /// Returns the estimated number of bytes allocated upon instantiation of the [`Module`].
+    ///
+    /// This is the sum of the initial sizes of all defined linear memories and tables.
+    ///
+    /// # Note
+    ///
+    /// Imported entities and the bookkeeping of Wasmi itself are not included.
+    pub fn estimated_instantiation_bytes(&self) -> u64 {
+        let memory_bytes = self.memories.iter().fold(0_u64, |bytes, ty| {
+            let pages = u64::from(u32::from(ty.initial_pages()));
+            bytes.saturating_add(pages.saturating_mul(ty.page_size()))
+        });
+        let elem_size = mem::size_of::<UntypedVal>() as u64;
+        let table_bytes = self.tables.iter().fold(0_u64, |bytes, ty| {
+            let len = u64::from(ty.minimum());
+            bytes.saturating_add(len.saturating_mul(elem_size))
+        });
+        memory_bytes.saturating_add(table_bytes)
+    }
+}
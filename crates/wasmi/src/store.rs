@@ -1,9 +1,9 @@
 use crate::{
     collections::arena::{Arena, ArenaIndex, GuardedEntity},
-    core::TrapCode,
+    core::{Pages, TrapCode, UntypedVal},
     engine::{DedupFuncType, FuelCosts},
     externref::{ExternObject, ExternObjectEntity, ExternObjectIdx},
-    func::{Trampoline, TrampolineEntity, TrampolineIdx},
+    func::{HostFuncEntity, Trampoline, TrampolineEntity, TrampolineIdx, TypedFuncCache},
     memory::{DataSegment, MemoryError},
     module::InstantiationError,
     table::TableError,
@@ -15,9 +15,11 @@ use crate::{
     ElementSegmentIdx,
     Engine,
     Error,
+    Extern,
     Func,
     FuncEntity,
     FuncIdx,
+    FuncRef,
     FuncType,
     Global,
     GlobalEntity,
@@ -32,10 +34,12 @@ use crate::{
     Table,
     TableEntity,
     TableIdx,
+    Val,
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, sync::Arc};
 use core::{
     fmt::{self, Debug},
+    mem,
     sync::atomic::{AtomicU32, Ordering},
 };
 
@@ -73,6 +77,90 @@ impl StoreIdx {
 /// A stored entity.
 pub type Stored<Idx> = GuardedEntity<StoreIdx, Idx>;
 
+/// Rebinds entity references of a [`Store`] to its copy created by [`Store::try_clone`].
+///
+/// # Note
+///
+/// References that do not originate from the original [`Store`] are kept as is.
+#[derive(Debug, Copy, Clone)]
+pub struct StoreRebind {
+    /// The index of the original [`Store`].
+    from: StoreIdx,
+    /// The index of the copy of the original [`Store`].
+    to: StoreIdx,
+}
+
+impl StoreRebind {
+    /// Rebinds the [`Stored<Idx>`] reference to the copied [`Store`].
+    fn stored<Idx>(&self, stored: &Stored<Idx>) -> Stored<Idx>
+    where
+        Idx: ArenaIndex,
+    {
+        match stored.entity_index(self.from) {
+            Some(index) => Stored::new(self.to, index),
+            None => *stored,
+        }
+    }
+
+    /// Rebinds the [`Func`] to the copied [`Store`].
+    pub fn func(&self, func: &Func) -> Func {
+        Func::from_inner(self.stored(func.as_inner()))
+    }
+
+    /// Rebinds the [`Trampoline`] to the copied [`Store`].
+    pub fn trampoline(&self, trampoline: &Trampoline) -> Trampoline {
+        Trampoline::from_inner(self.stored(trampoline.as_inner()))
+    }
+
+    /// Rebinds the [`Instance`] to the copied [`Store`].
+    pub fn instance(&self, instance: &Instance) -> Instance {
+        Instance::from_inner(self.stored(instance.as_inner()))
+    }
+
+    /// Rebinds the [`Table`] to the copied [`Store`].
+    pub fn table(&self, table: &Table) -> Table {
+        Table::from_inner(self.stored(table.as_inner()))
+    }
+
+    /// Rebinds the [`Memory`] to the copied [`Store`].
+    pub fn memory(&self, memory: &Memory) -> Memory {
+        Memory::from_inner(self.stored(memory.as_inner()))
+    }
+
+    /// Rebinds the [`Global`] to the copied [`Store`].
+    pub fn global(&self, global: &Global) -> Global {
+        Global::from_inner(self.stored(global.as_inner()))
+    }
+
+    /// Rebinds the [`DataSegment`] to the copied [`Store`].
+    pub fn data_segment(&self, segment: &DataSegment) -> DataSegment {
+        DataSegment::from_inner(self.stored(segment.as_inner()))
+    }
+
+    /// Rebinds the [`ElementSegment`] to the copied [`Store`].
+    pub fn element_segment(&self, segment: &ElementSegment) -> ElementSegment {
+        ElementSegment::from_inner(self.stored(segment.as_inner()))
+    }
+
+    /// Rebinds the [`Extern`] to the copied [`Store`].
+    pub fn external(&self, external: &Extern) -> Extern {
+        match external {
+            Extern::Global(global) => Extern::Global(self.global(global)),
+            Extern::Table(table) => Extern::Table(self.table(table)),
+            Extern::Memory(memory) => Extern::Memory(self.memory(memory)),
+            Extern::Func(func) => Extern::Func(self.func(func)),
+        }
+    }
+
+    /// Rebinds the untyped `funcref` `value` to the copied [`Store`].
+    pub fn funcref(&self, value: UntypedVal) -> UntypedVal {
+        match FuncRef::from(value).func() {
+            Some(func) => UntypedVal::from(FuncRef::new(self.func(func))),
+            None => value,
+        }
+    }
+}
+
 /// A wrapper around an optional `&mut dyn` [`ResourceLimiter`], that exists
 /// both to make types a little easier to read and to provide a `Debug` impl so
 /// that `#[derive(Debug)]` works on structs that contain it.
@@ -169,6 +257,27 @@ pub struct StoreInner {
     engine: Engine,
     /// The fuel of the [`Store`].
     fuel: Fuel,
+    /// The cached signature checks of [`TypedFunc`] construction.
+    ///
+    /// [`TypedFunc`]: crate::TypedFunc
+    typed_funcs: TypedFuncCache,
+    /// The fuel charged for calls from Wasm to host functions.
+    host_func_cost: HostFuncFuelCost,
+    /// The maximum value stack height observed by executions using the [`Store`].
+    max_observed_value_stack: usize,
+    /// The maximum call depth observed by executions using the [`Store`].
+    max_observed_call_depth: usize,
+    /// The [`Store`] this [`Store`] was copied from via [`Store::try_clone`] if any.
+    origin: Option<StoreOrigin>,
+}
+
+/// The [`Store`] a [`Store`] was copied from via [`Store::try_clone`].
+#[derive(Debug, Copy, Clone)]
+struct StoreOrigin {
+    /// Rebinds entity references of the original [`Store`] to its copy.
+    rebind: StoreRebind,
+    /// The number of instances of the original [`Store`] at the time it was copied.
+    len_instances: usize,
 }
 
 #[test]
@@ -240,6 +349,130 @@ impl FuelError {
     }
 }
 
+/// An error that may occur upon restoring a [`StoreSnapshot`].
+#[derive(Debug, Clone)]
+pub enum SnapshotError {
+    /// Raised when restoring a [`StoreSnapshot`] that was taken from a different [`Store`].
+    ForeignSnapshot,
+    /// Raised when memories, tables or global variables were added to the [`Store`]
+    /// after the [`StoreSnapshot`] was taken.
+    EntitiesAdded,
+    /// Raised when cloning a [`Store`] that owns [`ExternRef`] objects which cannot be cloned.
+    ///
+    /// [`ExternRef`]: crate::ExternRef
+    ExternObjects,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SnapshotError {}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ForeignSnapshot => write!(f, "snapshot was taken from a different store"),
+            Self::EntitiesAdded => {
+                write!(
+                    f,
+                    "entities were added to the store after the snapshot was taken"
+                )
+            }
+            Self::ExternObjects => {
+                write!(f, "cannot clone a store that owns `externref` objects")
+            }
+        }
+    }
+}
+
+/// A snapshot of the execution state of a [`Store`].
+///
+/// Captures the contents of all linear memories, tables and global variables
+/// as well as the remaining fuel of the [`Store`].
+///
+/// Created by [`Store::snapshot`] and restored by [`Store::restore`].
+#[derive(Debug, Clone)]
+pub struct StoreSnapshot {
+    /// The index of the [`Store`] the snapshot was taken from.
+    store_idx: StoreIdx,
+    /// The size and contents of all linear memories.
+    memories: Box<[(Pages, Box<[u8]>)]>,
+    /// The elements of all tables.
+    tables: Box<[Box<[UntypedVal]>]>,
+    /// The values of all global variables.
+    globals: Box<[UntypedVal]>,
+    /// The remaining fuel.
+    fuel: u64,
+}
+
+/// A snapshot of the remaining fuel of a [`Store`].
+///
+/// Created by [`Store::fuel_checkpoint`] and consumed by [`Store::fuel_restore`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FuelCheckpoint {
+    /// The remaining fuel at the time the checkpoint was created.
+    remaining: u64,
+}
+
+impl FuelCheckpoint {
+    /// Returns the remaining fuel captured by the [`FuelCheckpoint`].
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+}
+
+/// A multiplier for the fuel consumed by the functions of a [`Module`].
+///
+/// # Note
+///
+/// The multiplier is stored as fixed-point number with 16 fractional bits
+/// so that applying it does not require floating point arithmetic.
+///
+/// [`Module`]: crate::Module
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FuelMultiplier {
+    /// The multiplier scaled by [`FuelMultiplier::ONE`].
+    scaled: u64,
+}
+
+impl Default for FuelMultiplier {
+    fn default() -> Self {
+        Self { scaled: Self::ONE }
+    }
+}
+
+impl FuelMultiplier {
+    /// The fixed-point representation of a multiplier of `1.0`.
+    const ONE: u64 = 1 << 16;
+
+    /// Creates a new [`FuelMultiplier`] from `factor`.
+    ///
+    /// Factors too small to be represented are rounded up to the smallest representable factor.
+    ///
+    /// # Panics
+    ///
+    /// If `factor` is not a finite number greater than zero.
+    pub fn new(factor: f64) -> Self {
+        assert!(
+            factor.is_finite() && factor > 0.0,
+            "fuel multiplier must be a finite number greater than zero but found: {factor}"
+        );
+        let scaled = ((factor * Self::ONE as f64) as u64).max(1);
+        Self { scaled }
+    }
+
+    /// Returns `fuel` multiplied by `self` rounded up.
+    ///
+    /// Saturates at `u64::MAX`.
+    #[inline]
+    pub fn apply(self, fuel: u64) -> u64 {
+        if self.scaled == Self::ONE {
+            return fuel;
+        }
+        let product = u128::from(fuel) * u128::from(self.scaled);
+        let scaled = product.div_ceil(u128::from(Self::ONE));
+        u64::try_from(scaled).unwrap_or(u64::MAX)
+    }
+}
+
 /// The remaining and consumed fuel counters.
 #[derive(Debug, Copy, Clone)]
 pub struct Fuel {
@@ -305,6 +538,19 @@ impl Fuel {
         Ok(self.remaining)
     }
 
+    /// Adds `delta` fuel to the remaining fuel.
+    ///
+    /// Returns the remaining fuel after this operation saturating at `u64::MAX`.
+    ///
+    /// # Errors
+    ///
+    /// If fuel metering is disabled.
+    pub fn add_fuel(&mut self, delta: u64) -> Result<u64, FuelError> {
+        self.check_fuel_metering_enabled()?;
+        self.remaining = self.remaining.saturating_add(delta);
+        Ok(self.remaining)
+    }
+
     /// Synthetically consumes an amount of [`Fuel`] from the [`Store`].
     ///
     /// Returns the remaining amount of [`Fuel`] after this operation.
@@ -365,6 +611,35 @@ impl Fuel {
     }
 }
 
+/// A user provided hook to compute the fuel costs of host function calls.
+type HostFuncCostHook = Arc<dyn Fn(&FuncType) -> u64 + Send + Sync>;
+
+/// The fuel charged for calls from Wasm to host functions.
+#[derive(Clone, Default)]
+enum HostFuncFuelCost {
+    /// Host function calls consume no fuel.
+    #[default]
+    Free,
+    /// Host function calls consume `base + len_params * per_param` fuel.
+    Linear { base: u64, per_param: u64 },
+    /// Host function calls consume the fuel computed by the hook for the host function type.
+    Hook(HostFuncCostHook),
+}
+
+impl Debug for HostFuncFuelCost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Free => write!(f, "Free"),
+            Self::Linear { base, per_param } => f
+                .debug_struct("Linear")
+                .field("base", base)
+                .field("per_param", per_param)
+                .finish(),
+            Self::Hook(_) => write!(f, "Hook(...)"),
+        }
+    }
+}
+
 impl StoreInner {
     /// Creates a new [`StoreInner`] for the given [`Engine`].
     pub fn new(engine: &Engine) -> Self {
@@ -381,7 +656,87 @@ impl StoreInner {
             elems: Arena::new(),
             extern_objects: Arena::new(),
             fuel,
+            typed_funcs: TypedFuncCache::default(),
+            host_func_cost: HostFuncFuelCost::default(),
+            max_observed_value_stack: 0,
+            max_observed_call_depth: 0,
+            origin: None,
+        }
+    }
+
+    /// Updates the observed stack high-water marks of the [`StoreInner`].
+    ///
+    /// The high-water marks are only ever raised by this operation.
+    pub fn observe_stack_usage(&mut self, value_stack: usize, call_depth: usize) {
+        self.max_observed_value_stack = self.max_observed_value_stack.max(value_stack);
+        self.max_observed_call_depth = self.max_observed_call_depth.max(call_depth);
+    }
+
+    /// Returns the number of bytes the [`StoreInner`] allocated on the heap.
+    ///
+    /// See [`Store::allocated_bytes`] for more information.
+    pub fn allocated_bytes(&self) -> usize {
+        fn arena_bytes<Idx, T>(arena: &Arena<Idx, T>) -> usize {
+            arena.capacity() * mem::size_of::<T>()
+        }
+        let entities = arena_bytes(&self.funcs)
+            + arena_bytes(&self.memories)
+            + arena_bytes(&self.tables)
+            + arena_bytes(&self.globals)
+            + arena_bytes(&self.instances)
+            + arena_bytes(&self.datas)
+            + arena_bytes(&self.elems)
+            + arena_bytes(&self.extern_objects);
+        let memories: usize = self
+            .memories
+            .iter()
+            .map(|(_, memory)| memory.allocated_bytes())
+            .sum();
+        let tables: usize = self
+            .tables
+            .iter()
+            .map(|(_, table)| table.allocated_bytes())
+            .sum();
+        entities + memories + tables
+    }
+
+    /// Consumes the fuel for calling `host_func` from Wasm if fuel metering is enabled.
+    ///
+    /// # Errors
+    ///
+    /// If out of fuel.
+    #[inline]
+    pub fn consume_host_func_fuel(&mut self, host_func: &HostFuncEntity) -> Result<(), TrapCode> {
+        if !self.fuel.is_fuel_metering_enabled() {
+            return Ok(());
         }
+        let delta = match &self.host_func_cost {
+            HostFuncFuelCost::Free => return Ok(()),
+            HostFuncFuelCost::Linear { base, per_param } => {
+                let len_params = u64::from(host_func.len_params());
+                base.saturating_add(len_params.saturating_mul(*per_param))
+            }
+            HostFuncFuelCost::Hook(hook) => {
+                // Note: The hook must not be called while the function type registry is locked
+                //       since it is user code that may access the same `Engine` again.
+                let func_type = self
+                    .engine
+                    .resolve_func_type(host_func.ty_dedup(), FuncType::clone);
+                hook(&func_type)
+            }
+        };
+        self.fuel.consume_fuel_unchecked(delta)?;
+        Ok(())
+    }
+
+    /// Returns the [`TypedFuncCache`] of the [`StoreInner`].
+    pub fn typed_func_cache(&self) -> &TypedFuncCache {
+        &self.typed_funcs
+    }
+
+    /// Returns the unique index of the [`StoreInner`].
+    pub fn store_idx(&self) -> StoreIdx {
+        self.store_idx
     }
 
     /// Returns the [`Engine`] that this store is associated with.
@@ -404,6 +759,177 @@ impl StoreInner {
         Stored::new(self.store_idx, entity_idx)
     }
 
+    /// Takes a [`StoreSnapshot`] of the execution state of the [`StoreInner`].
+    ///
+    /// Read more about this method in [`Store::snapshot`].
+    pub fn snapshot(&self) -> StoreSnapshot {
+        let memories = self
+            .memories
+            .iter()
+            .map(|(_, memory)| (memory.current_pages(), memory.data().into()))
+            .collect();
+        let tables = self
+            .tables
+            .iter()
+            .map(|(_, table)| table.elements())
+            .collect();
+        let globals = self
+            .globals
+            .iter()
+            .map(|(_, global)| global.get_untyped())
+            .collect();
+        StoreSnapshot {
+            store_idx: self.store_idx,
+            memories,
+            tables,
+            globals,
+            fuel: self.fuel.remaining,
+        }
+    }
+
+    /// Restores the execution state of the [`StoreInner`] captured by `snapshot`.
+    ///
+    /// Read more about this method in [`Store::restore`].
+    ///
+    /// # Errors
+    ///
+    /// - If `snapshot` was taken from a different [`Store`].
+    /// - If entities were added after `snapshot` was taken.
+    pub fn restore(&mut self, snapshot: &StoreSnapshot) -> Result<(), SnapshotError> {
+        if snapshot.store_idx != self.store_idx {
+            return Err(SnapshotError::ForeignSnapshot);
+        }
+        if self.memories.len() != snapshot.memories.len()
+            || self.tables.len() != snapshot.tables.len()
+            || self.globals.len() != snapshot.globals.len()
+        {
+            return Err(SnapshotError::EntitiesAdded);
+        }
+        for ((_, memory), (pages, data)) in self.memories.iter_mut().zip(&snapshot.memories) {
+            memory.restore(*pages, data);
+        }
+        for ((_, table), elements) in self.tables.iter_mut().zip(&snapshot.tables) {
+            table.restore(elements);
+        }
+        for ((_, global), value) in self.globals.iter_mut().zip(&snapshot.globals) {
+            global.set_untyped(*value);
+        }
+        self.fuel.remaining = snapshot.fuel;
+        Ok(())
+    }
+
+    /// Returns a copy of the [`StoreInner`] with its own store index.
+    ///
+    /// Read more about this method in [`Store::try_clone`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`StoreInner`] owns [`ExternObjectEntity`]s which cannot be cloned.
+    pub fn try_clone(&self) -> Result<Self, SnapshotError> {
+        fn clone_arena<Idx: ArenaIndex, T: Clone>(
+            arena: &Arena<Idx, T>,
+            rebind: impl Fn(&mut T),
+        ) -> Arena<Idx, T> {
+            arena
+                .iter()
+                .map(|(_, entity)| {
+                    let mut entity = entity.clone();
+                    rebind(&mut entity);
+                    entity
+                })
+                .collect()
+        }
+        if !self.extern_objects.is_empty() {
+            return Err(SnapshotError::ExternObjects);
+        }
+        let rebind = StoreRebind {
+            from: self.store_idx,
+            to: StoreIdx::new(),
+        };
+        Ok(Self {
+            store_idx: rebind.to,
+            funcs: clone_arena(&self.funcs, |func| func.rebind(&rebind)),
+            memories: clone_arena(&self.memories, |_| ()),
+            tables: clone_arena(&self.tables, |table| table.rebind(&rebind)),
+            globals: clone_arena(&self.globals, |global| global.rebind(&rebind)),
+            instances: clone_arena(&self.instances, |instance| instance.rebind(&rebind)),
+            datas: clone_arena(&self.datas, |_| ()),
+            elems: clone_arena(&self.elems, |segment| segment.rebind(&rebind)),
+            extern_objects: Arena::new(),
+            engine: self.engine.clone(),
+            fuel: self.fuel,
+            typed_funcs: self.typed_funcs.clone(),
+            host_func_cost: self.host_func_cost.clone(),
+            max_observed_value_stack: self.max_observed_value_stack,
+            max_observed_call_depth: self.max_observed_call_depth,
+            origin: Some(StoreOrigin {
+                rebind,
+                len_instances: self.instances.len(),
+            }),
+        })
+    }
+
+    /// Returns the copy of the original `instance` if the [`StoreInner`] was copied from its [`Store`].
+    ///
+    /// Read more about this method in [`Store::cloned_instance`].
+    pub fn cloned_instance(&self, instance: &Instance) -> Option<Instance> {
+        let origin = self.origin?;
+        let index = instance.as_inner().entity_index(origin.rebind.from)?;
+        if index.into_usize() >= origin.len_instances {
+            return None;
+        }
+        Some(origin.rebind.instance(instance))
+    }
+
+    /// Returns `true` if the [`Stored<Idx>`] reference originates from this [`Store`].
+    pub fn owns<Idx>(&self, stored: &Stored<Idx>) -> bool
+    where
+        Idx: ArenaIndex,
+    {
+        stored.is_guarded_by(self.store_idx)
+    }
+
+    /// Returns `true` if all entities referenced by `value` originate from this [`Store`].
+    ///
+    /// # Note
+    ///
+    /// Non-reference values and `null` references never refer to any entity.
+    pub fn owns_val(&self, value: &Val) -> bool {
+        match value {
+            Val::FuncRef(funcref) => funcref
+                .func()
+                .map_or(true, |func| self.owns(func.as_inner())),
+            Val::ExternRef(externref) => externref
+                .as_object()
+                .map_or(true, |object| self.owns(object.as_inner())),
+            _ => true,
+        }
+    }
+
+    /// Returns `true` if all entities referenced by `value` are alive in this [`Store`].
+    ///
+    /// # Note
+    ///
+    /// In contrast to [`StoreInner::owns_val`] this also checks that the
+    /// referenced entities exist which is required for references that
+    /// were reconstructed from raw bits.
+    pub fn contains_val(&self, value: &Val) -> bool {
+        match value {
+            Val::FuncRef(funcref) => funcref.func().map_or(true, |func| {
+                func.as_inner()
+                    .entity_index(self.store_idx)
+                    .is_some_and(|index| self.funcs.get(index).is_some())
+            }),
+            Val::ExternRef(externref) => externref.as_object().map_or(true, |object| {
+                object
+                    .as_inner()
+                    .entity_index(self.store_idx)
+                    .is_some_and(|index| self.extern_objects.get(index).is_some())
+            }),
+            _ => true,
+        }
+    }
+
     /// Unwraps the given [`Stored<Idx>`] reference and returns the `Idx`.
     ///
     /// # Panics
@@ -697,16 +1223,6 @@ impl StoreInner {
         (mem, elem, fuel)
     }
 
-    /// Returns a shared reference to the [`ElementSegmentEntity`] associated to the given [`ElementSegment`].
-    ///
-    /// # Panics
-    ///
-    /// - If the [`ElementSegment`] does not originate from this [`Store`].
-    /// - If the [`ElementSegment`] cannot be resolved to its entity.
-    pub fn resolve_element_segment(&self, segment: &ElementSegment) -> &ElementSegmentEntity {
-        self.resolve(segment.as_inner(), &self.elems)
-    }
-
     /// Returns an exclusive reference to the [`ElementSegmentEntity`] associated to the given [`ElementSegment`].
     ///
     /// # Panics
@@ -829,6 +1345,17 @@ impl StoreInner {
         self.resolve(instance.as_inner(), &self.instances)
     }
 
+    /// Returns an exclusive reference to the [`InstanceEntity`] associated to the given [`Instance`].
+    ///
+    /// # Panics
+    ///
+    /// - If the [`Instance`] does not originate from this [`Store`].
+    /// - If the [`Instance`] cannot be resolved to its entity.
+    pub fn resolve_instance_mut(&mut self, instance: &Instance) -> &mut InstanceEntity {
+        let idx = self.unwrap_stored(instance.as_inner());
+        Self::resolve_mut(idx, &mut self.instances)
+    }
+
     /// Returns a shared reference to the [`ExternObjectEntity`] associated to the given [`ExternObject`].
     ///
     /// # Panics
@@ -877,6 +1404,11 @@ where
 
 impl<T> Store<T> {
     /// Creates a new store.
+    ///
+    /// # Note
+    ///
+    /// Creating a [`Store`] does not allocate on the heap.
+    /// Storage for Wasm entities is allocated lazily once they are created.
     pub fn new(engine: &Engine, data: T) -> Self {
         Self {
             inner: StoreInner::new(engine),
@@ -903,6 +1435,11 @@ impl<T> Store<T> {
     }
 
     /// Consumes `self` and returns its user provided data.
+    ///
+    /// # Note
+    ///
+    /// All Wasm entities owned by the [`Store`] are dropped.
+    /// This is useful to recover host state that accumulated results during execution.
     pub fn into_data(self) -> T {
         self.data
     }
@@ -992,6 +1529,185 @@ impl<T> Store<T> {
         self.inner.fuel.set_fuel(fuel).map_err(Into::into)
     }
 
+    /// Adds `delta` fuel to the remaining fuel of the [`Store`] if fuel metering is enabled.
+    ///
+    /// Returns the remaining fuel after this operation.
+    ///
+    /// # Note
+    ///
+    /// - The remaining fuel saturates at `u64::MAX`.
+    /// - Unlike a [`Store::get_fuel`] and [`Store::set_fuel`] pair this
+    ///   cannot lose fuel that has been granted in between both calls.
+    ///
+    /// # Errors
+    ///
+    /// If fuel metering is disabled.
+    pub fn add_fuel(&mut self, delta: u64) -> Result<u64, Error> {
+        self.inner.fuel.add_fuel(delta).map_err(Into::into)
+    }
+
+    /// Consumes `delta` fuel from the remaining fuel of the [`Store`] if fuel metering is enabled.
+    ///
+    /// Returns the remaining fuel after this operation.
+    ///
+    /// # Note
+    ///
+    /// This is useful for host functions to charge fuel for their work.
+    ///
+    /// # Errors
+    ///
+    /// - If fuel metering is disabled.
+    /// - If the remaining fuel is less than `delta`.
+    ///   In this case the remaining fuel is left unchanged.
+    pub fn consume_fuel(&mut self, delta: u64) -> Result<u64, Error> {
+        self.inner.fuel.consume_fuel(|_| delta).map_err(Into::into)
+    }
+
+    /// Returns the maximum value stack height observed by Wasm executions using the [`Store`].
+    ///
+    /// # Note
+    ///
+    /// - The unit is the same as the one of [`StackLimits::maximum_value_stack_height`].
+    /// - This is a high-water mark that is updated whenever a call into Wasm returns,
+    ///   traps or is suspended. Compare it to the configured [`StackLimits`] in order
+    ///   to tune them for a workload.
+    ///
+    /// [`StackLimits`]: crate::StackLimits
+    /// [`StackLimits::maximum_value_stack_height`]: crate::StackLimits::maximum_value_stack_height
+    pub fn max_observed_value_stack(&self) -> usize {
+        self.inner.max_observed_value_stack
+    }
+
+    /// Returns the maximum call depth observed by Wasm executions using the [`Store`].
+    ///
+    /// # Note
+    ///
+    /// This is a high-water mark that is updated whenever a call into Wasm returns,
+    /// traps or is suspended. Compare it to [`StackLimits::maximum_recursion_depth`]
+    /// in order to tune the [`StackLimits`] for a workload.
+    ///
+    /// [`StackLimits`]: crate::StackLimits
+    /// [`StackLimits::maximum_recursion_depth`]: crate::StackLimits::maximum_recursion_depth
+    pub fn max_observed_call_depth(&self) -> usize {
+        self.inner.max_observed_call_depth
+    }
+
+    /// Returns the number of bytes the [`Store`] allocated on the heap.
+    ///
+    /// # Note
+    ///
+    /// - This includes the storage of all Wasm entities as well as the bytes
+    ///   of all linear memories and the elements of all tables.
+    /// - This is an estimate that excludes the user provided data `T`, memory
+    ///   owned by individual entities such as host function closures or instance
+    ///   exports, as well as memory owned by the [`Engine`].
+    pub fn allocated_bytes(&self) -> usize {
+        self.inner.allocated_bytes()
+            + self.trampolines.capacity() * mem::size_of::<TrampolineEntity<T>>()
+    }
+
+    /// Captures the remaining fuel of the [`Store`] as a [`FuelCheckpoint`].
+    ///
+    /// The [`FuelCheckpoint`] can later be passed to [`Store::fuel_restore`]
+    /// in order to refund all fuel consumed since its creation.
+    ///
+    /// # Errors
+    ///
+    /// If fuel metering is disabled.
+    pub fn fuel_checkpoint(&self) -> Result<FuelCheckpoint, Error> {
+        let remaining = self.inner.fuel.get_fuel()?;
+        Ok(FuelCheckpoint { remaining })
+    }
+
+    /// Takes a [`StoreSnapshot`] of the current execution state of the [`Store`].
+    ///
+    /// The snapshot contains full copies of the contents of all linear memories,
+    /// tables and global variables as well as the remaining fuel.
+    pub fn snapshot(&self) -> StoreSnapshot {
+        self.inner.snapshot()
+    }
+
+    /// Restores the execution state of the [`Store`] captured by `snapshot`.
+    ///
+    /// Linear memories and tables that have grown since the `snapshot` was taken
+    /// are truncated back to their captured sizes.
+    ///
+    /// # Note
+    ///
+    /// This does not restore dropped data or element segments.
+    ///
+    /// # Errors
+    ///
+    /// - If `snapshot` was taken from a different [`Store`].
+    /// - If linear memories, tables or global variables were added to the [`Store`]
+    ///   after `snapshot` was taken.
+    pub fn restore(&mut self, snapshot: &StoreSnapshot) -> Result<(), Error> {
+        self.inner.restore(snapshot).map_err(Into::into)
+    }
+
+    /// Creates a copy of the [`Store`] with the given `data` and an independent execution state.
+    ///
+    /// The contents of all linear memories, tables and global variables as well as
+    /// the remaining fuel are copied. The copy shares the same [`Engine`] and thus
+    /// reuses the compiled Wasm functions, and shares the host functions.
+    ///
+    /// # Note
+    ///
+    /// - The returned [`Store`] has its own identity. Entity references such as
+    ///   [`Instance`], [`Func`] or [`Memory`] of `self` are not valid for the returned
+    ///   [`Store`] and vice versa. Use [`Store::cloned_instance`] to get the copy of an
+    ///   [`Instance`] and access the copies of its exports through it.
+    /// - The [`ResourceLimiter`] and call hook are not copied.
+    /// - This operation is linear in the total size of all linear memories, tables and
+    ///   global variables.
+    ///
+    /// # Errors
+    ///
+    /// If `self` owns [`ExternRef`] objects since those cannot be cloned.
+    ///
+    /// [`Func`]: crate::Func
+    /// [`ExternRef`]: crate::ExternRef
+    pub fn try_clone(&self, data: T) -> Result<Self, Error> {
+        let inner = self.inner.try_clone()?;
+        let trampolines = self
+            .trampolines
+            .iter()
+            .map(|(_, trampoline)| trampoline.clone())
+            .collect();
+        Ok(Self {
+            inner,
+            trampolines,
+            data,
+            limiter: None,
+            call_hook: None,
+        })
+    }
+
+    /// Returns the copy of `instance` if `self` was created from its [`Store`] by [`Store::try_clone`].
+    ///
+    /// Returns `None` if `instance` does not originate from the [`Store`] that `self` was
+    /// copied from or if `instance` was created after `self` was copied.
+    pub fn cloned_instance(&self, instance: &Instance) -> Option<Instance> {
+        self.inner.cloned_instance(instance)
+    }
+
+    /// Restores the remaining fuel of the [`Store`] to the value captured by `checkpoint`.
+    ///
+    /// # Note
+    ///
+    /// This is useful for transactional execution where fuel consumed by an
+    /// aborted sub-computation should be given back.
+    ///
+    /// # Errors
+    ///
+    /// If fuel metering is disabled.
+    pub fn fuel_restore(&mut self, checkpoint: FuelCheckpoint) -> Result<(), Error> {
+        self.inner
+            .fuel
+            .set_fuel(checkpoint.remaining)
+            .map_err(Into::into)
+    }
+
     /// Allocates a new [`TrampolineEntity`] and returns a [`Trampoline`] reference to it.
     pub(super) fn alloc_trampoline(&mut self, func: TrampolineEntity<T>) -> Trampoline {
         let idx = self.trampolines.alloc(func);
@@ -1030,6 +1746,47 @@ impl<T> Store<T> {
             .unwrap_or_else(|| panic!("failed to resolve stored host function: {entity_index:?}"))
     }
 
+    /// Charges `base_cost + num_params * per_param_cost` fuel for every call from Wasm to a host function.
+    ///
+    /// The fuel is consumed in the context of the calling Wasm function
+    /// before the host function is entered. A call that exceeds the remaining
+    /// fuel traps with [`TrapCode::OutOfFuel`] and the host function is not called.
+    ///
+    /// # Note
+    ///
+    /// - This replaces any hook set via [`Store::set_host_func_cost_hook`].
+    /// - Host functions that are called directly from the host consume no fuel.
+    /// - This has no effect unless fuel metering is enabled via
+    ///   [`Config::consume_fuel`](crate::Config::consume_fuel).
+    ///
+    /// By default host function calls consume no fuel.
+    pub fn set_host_func_fuel_cost(&mut self, base_cost: u64, per_param_cost: u64) {
+        self.inner.host_func_cost = match (base_cost, per_param_cost) {
+            (0, 0) => HostFuncFuelCost::Free,
+            (base, per_param) => HostFuncFuelCost::Linear { base, per_param },
+        };
+    }
+
+    /// Charges the fuel returned by `hook` for every call from Wasm to a host function.
+    ///
+    /// The `hook` is given the [`FuncType`] of the called host function and
+    /// runs in the context of the calling Wasm function before the host
+    /// function is entered. A call that exceeds the remaining fuel traps with
+    /// [`TrapCode::OutOfFuel`] and the host function is not called.
+    ///
+    /// # Note
+    ///
+    /// - This replaces any cost set via [`Store::set_host_func_fuel_cost`].
+    /// - Host functions that are called directly from the host consume no fuel.
+    /// - This has no effect unless fuel metering is enabled via
+    ///   [`Config::consume_fuel`](crate::Config::consume_fuel).
+    pub fn set_host_func_cost_hook(
+        &mut self,
+        hook: impl Fn(&FuncType) -> u64 + Send + Sync + 'static,
+    ) {
+        self.inner.host_func_cost = HostFuncFuelCost::Hook(Arc::new(hook));
+    }
+
     /// Sets a callback function that is executed whenever a WebAssembly
     /// function is called from the host or a host function is called from
     /// WebAssembly, or these functions return.
@@ -1201,6 +1958,29 @@ impl<T> StoreContextMut<'_, T> {
     pub fn set_fuel(&mut self, fuel: u64) -> Result<(), Error> {
         self.store.set_fuel(fuel)
     }
+
+    /// Adds `delta` fuel to the remaining fuel of the [`Store`] if fuel metering is enabled.
+    ///
+    /// For more information see [`Store::add_fuel`](crate::Store::add_fuel).
+    ///
+    /// # Errors
+    ///
+    /// If fuel metering is disabled.
+    pub fn add_fuel(&mut self, delta: u64) -> Result<u64, Error> {
+        self.store.add_fuel(delta)
+    }
+
+    /// Consumes `delta` fuel from the remaining fuel of the [`Store`] if fuel metering is enabled.
+    ///
+    /// For more information see [`Store::consume_fuel`](crate::Store::consume_fuel).
+    ///
+    /// # Errors
+    ///
+    /// - If fuel metering is disabled.
+    /// - If the remaining fuel is less than `delta`.
+    pub fn consume_fuel(&mut self, delta: u64) -> Result<u64, Error> {
+        self.store.consume_fuel(delta)
+    }
 }
 
 impl<T> AsContext for &'_ T
@@ -2,8 +2,7 @@ use crate::{
     collections::arena::ArenaIndex,
     core::{UntypedVal, ValType},
     module,
-    store::Stored,
-    AsContext,
+    store::{StoreRebind, Stored},
     AsContextMut,
     Func,
     FuncRef,
@@ -65,13 +64,16 @@ impl ElementSegment {
             .alloc_element_segment(entity)
     }
 
-    /// Returns the number of items in the [`ElementSegment`].
-    pub fn size(&self, ctx: impl AsContext) -> u32 {
-        ctx.as_context()
+    /// Allocates a new dropped [`ElementSegment`] with element type `ty` on the store.
+    ///
+    /// # Note
+    ///
+    /// This represents active element segments that have been applied lazily.
+    pub fn new_dropped(mut ctx: impl AsContextMut, ty: ValType) -> Self {
+        ctx.as_context_mut()
             .store
             .inner
-            .resolve_element_segment(self)
-            .size()
+            .alloc_element_segment(ElementSegmentEntity::empty(ty))
     }
 }
 
@@ -82,7 +84,7 @@ impl ElementSegment {
 /// With the `bulk-memory` Wasm proposal it is possible to interact
 /// with element segments at runtime. Therefore Wasm instances now have
 /// a need to have an instantiated representation of data segments.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ElementSegmentEntity {
     /// The [`ValType`] of elements of this [`ElementSegmentEntity`].
     ty: ValType,
@@ -126,11 +128,6 @@ impl ElementSegmentEntity {
         self.ty
     }
 
-    /// Returns the number of items in the [`ElementSegment`].
-    pub fn size(&self) -> u32 {
-        self.items().len() as u32
-    }
-
     /// Returns the items of the [`ElementSegmentEntity`].
     pub fn items(&self) -> &[UntypedVal] {
         &self.items[..]
@@ -140,4 +137,16 @@ impl ElementSegmentEntity {
     pub fn drop_items(&mut self) {
         self.items = [].into();
     }
+
+    /// Rebinds the function references of the [`ElementSegmentEntity`] to the copied [`Store`].
+    ///
+    /// [`Store`]: crate::Store
+    pub(crate) fn rebind(&mut self, rebind: &StoreRebind) {
+        if !matches!(self.ty, ValType::FuncRef) {
+            return;
+        }
+        for item in &mut self.items[..] {
+            *item = rebind.funcref(*item);
+        }
+    }
 }
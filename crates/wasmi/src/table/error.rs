@@ -39,6 +39,8 @@ pub enum TableError {
         other: TableType,
     },
     TooManyTables,
+    /// Occurs when a value refers to an entity of a different store than the [`Table`](crate::Table).
+    CrossStore,
 }
 
 #[cfg(feature = "std")]
@@ -72,11 +74,46 @@ impl Display for TableError {
                 write!(f, "out of bounds access of table elements while copying")
             }
             Self::InvalidSubtype { ty, other } => {
-                write!(f, "table type {ty:?} is not a subtype of {other:?}",)
+                write!(f, "table type {ty:?} is not a subtype of {other:?}: ")?;
+                fmt_subtype_mismatch(ty, other, f)
             }
             Self::TooManyTables => {
                 write!(f, "too many tables")
             }
+            Self::CrossStore => {
+                write!(f, "encountered table value from a different store")
+            }
         }
     }
 }
+
+/// Writes which import subtyping rule `ty` violates with respect to `other`.
+fn fmt_subtype_mismatch(ty: &TableType, other: &TableType, f: &mut fmt::Formatter) -> fmt::Result {
+    let element = ty.element();
+    let required_element = other.element();
+    if element != required_element {
+        return write!(
+            f,
+            "element type {element:?} does not match the required element type {required_element:?}"
+        );
+    }
+    let min = ty.minimum();
+    let required_min = other.minimum();
+    if min < required_min {
+        return write!(
+            f,
+            "minimum of {min} elements is less than the required minimum of {required_min} elements"
+        );
+    }
+    match (ty.maximum(), other.maximum()) {
+        (None, Some(required_max)) => write!(
+            f,
+            "missing maximum while a maximum of at most {required_max} elements is required"
+        ),
+        (Some(max), Some(required_max)) => write!(
+            f,
+            "maximum of {max} elements exceeds the required maximum of {required_max} elements"
+        ),
+        (_, None) => write!(f, "limits are unexpectedly compatible"),
+    }
+}
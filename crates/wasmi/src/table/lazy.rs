@@ -0,0 +1,193 @@
+use crate::{core::UntypedVal, module::ModuleHeader, Func, FuncRef};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::ops::Range;
+
+/// The maximum number of [`LazyRegion`]s of a single table.
+///
+/// # Note
+///
+/// Once a table would exceed this number of [`LazyRegion`]s, for example
+/// due to many fragmenting `table.set` operations, all of its lazily
+/// initialized elements are materialized to keep element accesses fast.
+const MAX_LAZY_REGIONS: usize = 32;
+
+/// The source of lazily initialized table elements of a single instantiation.
+#[derive(Debug)]
+pub struct LazySource {
+    /// The header of the instantiated module holding the element segments.
+    header: ModuleHeader,
+    /// The functions of the instance used to resolve `ref.func` items.
+    funcs: Box<[Func]>,
+}
+
+impl LazySource {
+    /// Creates a new [`LazySource`] for the instance with `funcs` of the module with `header`.
+    pub fn new(header: ModuleHeader, funcs: Box<[Func]>) -> Self {
+        Self { header, funcs }
+    }
+
+    /// Evaluates the `index`-th item of the `segment`-th element segment.
+    fn eval(&self, segment: u32, index: u32) -> UntypedVal {
+        let item = &self.header.get_element_segment(segment).items()[index as usize];
+        if let Some(func_index) = item.funcref() {
+            let func = self.funcs[func_index.into_u32() as usize];
+            return FuncRef::new(func).into();
+        }
+        item.eval_const().unwrap_or_else(|| {
+            panic!("lazily initialized element items must be `ref.func` or constant: {item:?}")
+        })
+    }
+}
+
+/// A region of table elements that is lazily initialized by an active element segment.
+#[derive(Debug, Clone)]
+struct LazyRegion {
+    /// The index of the first table element of the region.
+    dst: u32,
+    /// The number of table elements of the region.
+    len: u32,
+    /// The index of the element segment within the module.
+    segment: u32,
+    /// The index of the element segment item that initializes the table element at `dst`.
+    src: u32,
+    /// The source used to evaluate the element segment items.
+    source: Arc<LazySource>,
+}
+
+impl LazyRegion {
+    /// Returns the range of table elements covered by the [`LazyRegion`].
+    fn range(&self) -> Range<u32> {
+        self.dst..(self.dst + self.len)
+    }
+
+    /// Evaluates the table element at `index` which must be covered by the [`LazyRegion`].
+    fn eval(&self, index: u32) -> UntypedVal {
+        debug_assert!(self.range().contains(&index));
+        self.source
+            .eval(self.segment, self.src + (index - self.dst))
+    }
+
+    /// Returns the part of the [`LazyRegion`] that is covered by `range` if any.
+    fn sub_region(&self, range: Range<u32>) -> Option<Self> {
+        let start = self.dst.max(range.start);
+        let end = (self.dst + self.len).min(range.end);
+        if start >= end {
+            return None;
+        }
+        Some(Self {
+            dst: start,
+            len: end - start,
+            src: self.src + (start - self.dst),
+            ..self.clone()
+        })
+    }
+}
+
+/// The lazily initialized elements of a table.
+///
+/// # Note
+///
+/// All [`LazyRegion`]s are disjoint. Table elements covered by a
+/// [`LazyRegion`] are evaluated from their element segment item upon
+/// access instead of being read from the table's elements.
+#[derive(Debug, Default, Clone)]
+pub struct LazyElements {
+    regions: Vec<LazyRegion>,
+}
+
+impl LazyElements {
+    /// Returns `true` if there are no lazily initialized elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Returns the lazily initialized element at `index` if any.
+    pub fn get(&self, index: u32) -> Option<UntypedVal> {
+        self.regions
+            .iter()
+            .find(|region| region.range().contains(&index))
+            .map(|region| region.eval(index))
+    }
+
+    /// Lazily initializes `len` elements starting at `dst` with the `segment` items of `source`.
+    ///
+    /// Returns `false` if the table must be materialized since it has too many regions.
+    pub fn init(&mut self, dst: u32, len: u32, segment: u32, source: Arc<LazySource>) -> bool {
+        self.remove(dst..(dst + len));
+        if len == 0 {
+            return true;
+        }
+        self.regions.push(LazyRegion {
+            dst,
+            len,
+            segment,
+            src: 0,
+            source,
+        });
+        self.regions.len() <= MAX_LAZY_REGIONS
+    }
+
+    /// Removes all lazily initialized elements within `range`.
+    ///
+    /// This must be called before elements within `range` are overwritten.
+    ///
+    /// Returns `false` if the table must be materialized since it has too many regions.
+    pub fn remove(&mut self, range: Range<u32>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let mut index = 0;
+        while index < self.regions.len() {
+            let region = &self.regions[index];
+            if region.sub_region(range.clone()).is_none() {
+                index += 1;
+                continue;
+            }
+            let region = self.regions.swap_remove(index);
+            let before = region.sub_region(region.dst..range.start);
+            let after = region.sub_region(range.end..(region.dst + region.len));
+            for part in [before, after].into_iter().flatten() {
+                self.regions.push(part);
+            }
+            // Note: newly pushed parts are disjoint from `range` and thus are skipped.
+        }
+        self.regions.len() <= MAX_LAZY_REGIONS
+    }
+
+    /// Writes all lazily initialized elements within `range` into `elements`.
+    ///
+    /// The `elements` represent the table elements starting at `range.start`.
+    pub fn write_into(&self, range: Range<u32>, elements: &mut [UntypedVal]) {
+        for region in &self.regions {
+            let Some(part) = region.sub_region(range.clone()) else {
+                continue;
+            };
+            for index in part.range() {
+                elements[(index - range.start) as usize] = part.eval(index);
+            }
+        }
+    }
+
+    /// Materializes all lazily initialized elements within `range` into `elements`.
+    ///
+    /// The `elements` represent all table elements.
+    ///
+    /// Returns `false` if the table must be materialized since it has too many regions.
+    pub fn materialize(&mut self, range: Range<u32>, elements: &mut [UntypedVal]) -> bool {
+        let dst = &mut elements[range.start as usize..range.end as usize];
+        self.write_into(range.clone(), dst);
+        self.remove(range)
+    }
+
+    /// Materializes all lazily initialized elements into `elements`.
+    ///
+    /// The `elements` represent all table elements.
+    pub fn materialize_all(&mut self, elements: &mut [UntypedVal]) {
+        for region in self.regions.drain(..) {
+            for index in region.range() {
+                elements[index as usize] = region.eval(index);
+            }
+        }
+    }
+}
@@ -1,21 +1,28 @@
+pub(crate) use self::lazy::LazySource;
 pub use self::{
     element::{ElementSegment, ElementSegmentEntity, ElementSegmentIdx},
     error::TableError,
 };
+use self::{lazy::LazyElements, signatures::SignatureCache};
 use super::{AsContext, AsContextMut, Stored};
 use crate::{
     collections::arena::ArenaIndex,
     core::{TrapCode, UntypedVal, ValType},
+    engine::DedupFuncType,
     error::EntityGrowError,
-    store::{Fuel, FuelError, ResourceLimiterRef},
+    store::{Fuel, FuelError, ResourceLimiterRef, StoreRebind},
     value::WithType,
+    ExternRef,
+    FuncRef,
     Val,
 };
-use alloc::{vec, vec::Vec};
-use core::cmp::max;
+use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
+use core::{cmp::max, mem, ops::Range};
 
 mod element;
 mod error;
+mod lazy;
+mod signatures;
 
 #[cfg(test)]
 mod tests;
@@ -94,7 +101,7 @@ impl TableType {
     ///
     /// # Note
     ///
-    /// This implements the [subtyping rules] according to the WebAssembly spec.
+    /// This implements the [import subtyping] rules according to the WebAssembly spec.
     ///
     /// [import subtyping]:
     /// https://webassembly.github.io/spec/core/valid/types.html#import-subtyping
@@ -102,8 +109,8 @@ impl TableType {
     /// # Errors
     ///
     /// - If the `element` type of `self` does not match the `element` type of `other`.
-    /// - If the `minimum` size of `self` is less than or equal to the `minimum` size of `other`.
-    /// - If the `maximum` size of `self` is greater than the `maximum` size of `other`.
+    /// - If the `minimum` size of `self` is less than the `minimum` size of `other`.
+    /// - If `other` has a `maximum` size and `self` has none or a greater one.
     pub(crate) fn is_subtype_or_err(&self, other: &TableType) -> Result<(), TableError> {
         match self.is_subtype_of(other) {
             true => Ok(()),
@@ -118,7 +125,7 @@ impl TableType {
     ///
     /// # Note
     ///
-    /// This implements the [subtyping rules] according to the WebAssembly spec.
+    /// This implements the [import subtyping] rules according to the WebAssembly spec.
     ///
     /// [import subtyping]:
     /// https://webassembly.github.io/spec/core/valid/types.html#import-subtyping
@@ -138,10 +145,14 @@ impl TableType {
 }
 
 /// A Wasm table entity.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TableEntity {
     ty: TableType,
     elements: Vec<UntypedVal>,
+    /// The lazily initialized elements which take precedence over `elements`.
+    lazy: LazyElements,
+    /// The last matched `call_indirect` signatures of the elements.
+    signatures: SignatureCache,
 }
 
 impl TableEntity {
@@ -171,7 +182,12 @@ impl TableEntity {
         }
 
         let elements = vec![init.into(); ty.minimum() as usize];
-        Ok(Self { ty, elements })
+        Ok(Self {
+            ty,
+            elements,
+            lazy: LazyElements::default(),
+            signatures: SignatureCache::default(),
+        })
     }
 
     /// Returns the resizable limits of the table.
@@ -194,6 +210,131 @@ impl TableEntity {
         self.elements.len() as u32
     }
 
+    /// Returns the number of bytes the elements of this [`TableEntity`] allocated on the heap.
+    pub fn allocated_bytes(&self) -> usize {
+        self.elements.capacity() * mem::size_of::<UntypedVal>() + self.signatures.allocated_bytes()
+    }
+
+    /// Returns `true` if `signature` was the last matched signature of the element at `index`.
+    ///
+    /// # Note
+    ///
+    /// Used by `call_indirect` to skip signature checks of repeatedly called elements.
+    #[inline]
+    pub(crate) fn has_cached_signature(&self, index: u32, signature: &DedupFuncType) -> bool {
+        self.signatures.matches(index, signature)
+    }
+
+    /// Caches `signature` as the last matched signature of the element at `index`.
+    ///
+    /// The cached signature is invalidated once the element at `index` is overwritten.
+    pub(crate) fn cache_signature(&mut self, index: u32, signature: DedupFuncType) {
+        self.signatures.insert(index, signature);
+    }
+
+    /// Returns the untyped elements of the [`TableEntity`].
+    ///
+    /// # Note
+    ///
+    /// This includes all lazily initialized elements.
+    pub(crate) fn elements(&self) -> Box<[UntypedVal]> {
+        let mut elements: Box<[UntypedVal]> = self.elements[..].into();
+        self.lazy.write_into(0..self.size(), &mut elements);
+        elements
+    }
+
+    /// Lazily initializes `len` elements starting at `dst_index` with the items of an element segment.
+    ///
+    /// The items are the `segment`-th element segment items of the module of `source`.
+    ///
+    /// # Note
+    ///
+    /// Lazily initialized elements are evaluated from their element segment
+    /// items upon access and are indistinguishable from eagerly initialized ones.
+    ///
+    /// # Panics
+    ///
+    /// If `dst_index..(dst_index + len)` is out of bounds for the [`TableEntity`].
+    pub(crate) fn init_lazy(
+        &mut self,
+        dst_index: u32,
+        len: u32,
+        segment: u32,
+        source: Arc<LazySource>,
+    ) {
+        assert!(dst_index
+            .checked_add(len)
+            .is_some_and(|end| end <= self.size()));
+        self.signatures.invalidate(dst_index..(dst_index + len));
+        if !self.lazy.init(dst_index, len, segment, source) {
+            self.lazy.materialize_all(&mut self.elements);
+        }
+    }
+
+    /// Removes all lazily initialized elements and cached signatures within `range`.
+    ///
+    /// This must be called before elements within `range` are overwritten.
+    fn forget_range(&mut self, range: Range<u32>) {
+        self.signatures.invalidate(range.clone());
+        if self.lazy.is_empty() {
+            return;
+        }
+        if !self.lazy.remove(range) {
+            self.lazy.materialize_all(&mut self.elements);
+        }
+    }
+
+    /// Returns the range `index..(index + len)` if it is in bounds of the [`TableEntity`].
+    ///
+    /// # Errors
+    ///
+    /// If the range is out of bounds.
+    fn checked_range(&self, index: u32, len: u32) -> Result<Range<u32>, TrapCode> {
+        index
+            .checked_add(len)
+            .filter(|&end| end <= self.size())
+            .map(|end| index..end)
+            .ok_or(TrapCode::TableOutOfBounds)
+    }
+
+    /// Materializes all lazily initialized elements within `range`.
+    fn materialize_lazy(&mut self, range: Range<u32>) {
+        if self.lazy.is_empty() {
+            return;
+        }
+        if !self.lazy.materialize(range, &mut self.elements) {
+            self.lazy.materialize_all(&mut self.elements);
+        }
+    }
+
+    /// Restores the [`TableEntity`] to the untyped `elements`.
+    ///
+    /// Truncates the table if it has grown since `elements` were captured.
+    ///
+    /// # Panics
+    ///
+    /// If `elements` is larger than the table.
+    pub(crate) fn restore(&mut self, elements: &[UntypedVal]) {
+        assert!(elements.len() <= self.elements.len());
+        self.lazy = LazyElements::default();
+        self.signatures.clear();
+        self.elements.truncate(elements.len());
+        self.elements.copy_from_slice(elements);
+    }
+
+    /// Rebinds the function references held by the [`TableEntity`] to the copied [`Store`].
+    ///
+    /// [`Store`]: crate::Store
+    pub(crate) fn rebind(&mut self, rebind: &StoreRebind) {
+        if !matches!(self.ty().element(), ValType::FuncRef) {
+            return;
+        }
+        self.lazy.materialize_all(&mut self.elements);
+        for element in &mut self.elements {
+            *element = rebind.funcref(*element);
+        }
+    }
+
     /// Grows the table by the given amount of elements.
     ///
     /// Returns the old size of the [`Table`] upon success.
@@ -302,7 +443,26 @@ impl TableEntity {
     /// This is a more efficient version of [`Table::get`] for
     /// internal use only.
     pub fn get_untyped(&self, index: u32) -> Option<UntypedVal> {
-        self.elements.get(index as usize).copied()
+        let value = self.elements.get(index as usize).copied()?;
+        if self.lazy.is_empty() {
+            return Some(value);
+        }
+        Some(self.lazy.get(index).unwrap_or(value))
+    }
+
+    /// Returns the untyped [`Table`] element value at `index` without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `index` is in bounds of the [`Table`].
+    pub unsafe fn get_untyped_unchecked(&self, index: u32) -> UntypedVal {
+        debug_assert!(index < self.size());
+        // Safety: the caller ensures that `index` is in bounds.
+        let value = unsafe { *self.elements.get_unchecked(index as usize) };
+        if self.lazy.is_empty() {
+            return value;
+        }
+        self.lazy.get(index).unwrap_or(value)
     }
 
     /// Sets the [`Val`] of this [`Table`] at `index`.
@@ -323,6 +483,9 @@ impl TableEntity {
     /// If `index` is out of bounds.
     pub fn set_untyped(&mut self, index: u32, value: UntypedVal) -> Result<(), TableError> {
         let current = self.size();
+        if index < current {
+            self.forget_range(index..index + 1);
+        }
         let untyped =
             self.elements
                 .get_mut(index as usize)
@@ -334,6 +497,18 @@ impl TableEntity {
         Ok(())
     }
 
+    /// Sets the [`UntypedVal`] of the [`Table`] at `index` without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `index` is in bounds of the [`Table`].
+    pub unsafe fn set_untyped_unchecked(&mut self, index: u32, value: UntypedVal) {
+        debug_assert!(index < self.size());
+        self.forget_range(index..index + 1);
+        // Safety: the caller ensures that `index` is in bounds.
+        unsafe { *self.elements.get_unchecked_mut(index as usize) = value };
+    }
+
     /// Initialize `len` elements from `src_element[src_index..]` into `self[dst_index..]`.
     ///
     /// # Errors
@@ -360,16 +535,12 @@ impl TableEntity {
         table_type
             .matches_element_type(element.ty())
             .map_err(|_| TrapCode::BadSignature)?;
+        // Perform bounds check before anything else.
+        let dst_range = self.checked_range(dst_index, len)?;
         // Convert parameters to indices.
         let dst_index = dst_index as usize;
         let src_index = src_index as usize;
         let len = len as usize;
-        // Perform bounds check before anything else.
-        let dst_items = self
-            .elements
-            .get_mut(dst_index..)
-            .and_then(|items| items.get_mut(..len))
-            .ok_or(TrapCode::TableOutOfBounds)?;
         let src_items = element
             .items()
             .get(src_index..)
@@ -385,7 +556,8 @@ impl TableEntity {
             fuel.consume_fuel_if(|costs| costs.fuel_for_copies(len as u64))?;
         }
         // Perform the actual table initialization.
-        dst_items.copy_from_slice(src_items);
+        self.forget_range(dst_range);
+        self.elements[dst_index..][..len].copy_from_slice(src_items);
         Ok(())
     }
 
@@ -404,26 +576,18 @@ impl TableEntity {
         len: u32,
         fuel: Option<&mut Fuel>,
     ) -> Result<(), TrapCode> {
-        // Turn parameters into proper slice indices.
-        let src_index = src_index as usize;
-        let dst_index = dst_index as usize;
-        let len = len as usize;
         // Perform bounds check before anything else.
-        let dst_items = dst_table
-            .elements
-            .get_mut(dst_index..)
-            .and_then(|items| items.get_mut(..len))
-            .ok_or(TrapCode::TableOutOfBounds)?;
-        let src_items = src_table
-            .elements
-            .get(src_index..)
-            .and_then(|items| items.get(..len))
-            .ok_or(TrapCode::TableOutOfBounds)?;
+        let dst_range = dst_table.checked_range(dst_index, len)?;
+        let src_range = src_table.checked_range(src_index, len)?;
         if let Some(fuel) = fuel {
-            fuel.consume_fuel_if(|costs| costs.fuel_for_copies(len as u64))?;
+            fuel.consume_fuel_if(|costs| costs.fuel_for_copies(u64::from(len)))?;
         }
         // Finally, copy elements in-place for the table.
+        dst_table.forget_range(dst_range.clone());
+        let dst_items = &mut dst_table.elements[dst_range.start as usize..dst_range.end as usize];
+        let src_items = &src_table.elements[src_range.start as usize..src_range.end as usize];
         dst_items.copy_from_slice(src_items);
+        src_table.lazy.write_into(src_range, dst_items);
         Ok(())
     }
 
@@ -445,13 +609,15 @@ impl TableEntity {
             .checked_add(len)
             .filter(|&offset| offset <= self.size())
             .ok_or(TrapCode::TableOutOfBounds)?;
+        if let Some(fuel) = fuel {
+            fuel.consume_fuel_if(|costs| costs.fuel_for_copies(u64::from(len)))?;
+        }
+        self.materialize_lazy(src_index..(src_index + len));
+        self.forget_range(dst_index..(dst_index + len));
         // Turn parameters into proper indices.
         let src_index = src_index as usize;
         let dst_index = dst_index as usize;
         let len = len as usize;
-        if let Some(fuel) = fuel {
-            fuel.consume_fuel_if(|costs| costs.fuel_for_copies(len as u64))?;
-        }
         // Finally, copy elements in-place for the table.
         self.elements
             .copy_within(src_index..src_index.wrapping_add(len), dst_index);
@@ -506,17 +672,12 @@ impl TableEntity {
         len: u32,
         fuel: Option<&mut Fuel>,
     ) -> Result<(), TrapCode> {
-        let dst_index = dst as usize;
-        let len = len as usize;
-        let dst = self
-            .elements
-            .get_mut(dst_index..)
-            .and_then(|elements| elements.get_mut(..len))
-            .ok_or(TrapCode::TableOutOfBounds)?;
+        let dst_range = self.checked_range(dst, len)?;
         if let Some(fuel) = fuel {
-            fuel.consume_fuel_if(|costs| costs.fuel_for_copies(len as u64))?;
+            fuel.consume_fuel_if(|costs| costs.fuel_for_copies(u64::from(len)))?;
         }
-        dst.fill(val);
+        self.forget_range(dst_range.clone());
+        self.elements[dst_range.start as usize..dst_range.end as usize].fill(val);
         Ok(())
     }
 }
@@ -537,16 +698,28 @@ impl Table {
         &self.0
     }
 
+    /// Returns `true` if this [`Table`] originates from `store`.
+    ///
+    /// This allows to check if the [`Table`] can be used with `store`
+    /// before calling methods that would otherwise panic.
+    pub fn is_from_store(&self, store: impl AsContext) -> bool {
+        store.as_context().store.inner.owns(self.as_inner())
+    }
+
     /// Creates a new table to the store.
     ///
     /// # Errors
     ///
-    /// If `init` does not match the [`TableType`] element type.
+    /// - If `init` does not match the [`TableType`] element type.
+    /// - If `init` refers to an entity that does not belong to `ctx`.
     pub fn new(mut ctx: impl AsContextMut, ty: TableType, init: Val) -> Result<Self, TableError> {
         let (inner, mut resource_limiter) = ctx
             .as_context_mut()
             .store
             .store_inner_and_resource_limiter_ref();
+        if !inner.owns_val(&init) {
+            return Err(TableError::CrossStore);
+        }
         let entity = TableEntity::new(ty, init, &mut resource_limiter)?;
         let table = inner.alloc_table(entity);
         Ok(table)
@@ -561,6 +734,19 @@ impl Table {
         ctx.as_context().store.inner.resolve_table(self).ty()
     }
 
+    /// Returns the type and limits of the table.
+    ///
+    /// This is an alias of [`Table::ty`] that mirrors [`ExternType::table`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Table`].
+    ///
+    /// [`ExternType::table`]: crate::ExternType::table
+    pub fn table_type(&self, ctx: impl AsContext) -> TableType {
+        self.ty(ctx)
+    }
+
     /// Returns the dynamic [`TableType`] of the [`Table`].
     ///
     /// # Note
@@ -588,6 +774,45 @@ impl Table {
         ctx.as_context().store.inner.resolve_table(self).size()
     }
 
+    /// Returns the maximum size of the [`Table`] if any.
+    ///
+    /// Returns `None` if the [`Table`] has no maximum limit.
+    ///
+    /// # Note
+    ///
+    /// Checking whether the [`Table`] can hold another element is as simple as:
+    /// `table.size(&store) < table.max_size(&store).unwrap_or(u32::MAX)`
+    ///
+    /// # Panics
+    ///
+    /// If `ctx` does not own this [`Table`].
+    pub fn max_size(&self, ctx: impl AsContext) -> Option<u32> {
+        ctx.as_context()
+            .store
+            .inner
+            .resolve_table(self)
+            .ty()
+            .maximum()
+    }
+
+    /// Returns the [`ValType`] of the elements stored in the [`Table`].
+    ///
+    /// # Note
+    ///
+    /// This is useful to construct the right `null` value of the [`Table`] elements.
+    ///
+    /// # Panics
+    ///
+    /// If `ctx` does not own this [`Table`].
+    pub fn element_type(&self, ctx: impl AsContext) -> ValType {
+        ctx.as_context()
+            .store
+            .inner
+            .resolve_table(self)
+            .ty()
+            .element()
+    }
+
     /// Grows the table by the given amount of elements.
     ///
     /// Returns the old size of the [`Table`] upon success.
@@ -600,6 +825,7 @@ impl Table {
     ///
     /// - If the table is grown beyond its maximum limits.
     /// - If `value` does not match the [`Table`] element type.
+    /// - If `init` refers to an entity that does not belong to `ctx`.
     ///
     /// # Panics
     ///
@@ -614,6 +840,9 @@ impl Table {
             .as_context_mut()
             .store
             .store_inner_and_resource_limiter_ref();
+        if !inner.owns_val(&init) {
+            return Err(TableError::CrossStore);
+        }
         let table = inner.resolve_table_mut(self);
         let current = table.size();
         let maximum = table.ty().maximum().unwrap_or(u32::MAX);
@@ -637,12 +866,43 @@ impl Table {
         ctx.as_context().store.inner.resolve_table(self).get(index)
     }
 
+    /// Returns the [`FuncRef`] element of this `funcref` [`Table`] at `index`.
+    ///
+    /// Returns `None` if `index` is out of bounds or if the [`Table`]
+    /// element type is not [`ValType::FuncRef`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Table`].
+    pub fn get_func(&self, ctx: impl AsContext, index: u32) -> Option<FuncRef> {
+        match self.get(ctx, index)? {
+            Val::FuncRef(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`ExternRef`] element of this `externref` [`Table`] at `index`.
+    ///
+    /// Returns `None` if `index` is out of bounds or if the [`Table`]
+    /// element type is not [`ValType::ExternRef`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ctx` does not own this [`Table`].
+    pub fn get_extern(&self, ctx: impl AsContext, index: u32) -> Option<ExternRef> {
+        match self.get(ctx, index)? {
+            Val::ExternRef(value) => Some(value),
+            _ => None,
+        }
+    }
+
     /// Sets the [`Val`] of this [`Table`] at `index`.
     ///
     /// # Errors
     ///
     /// - If `index` is out of bounds.
     /// - If `value` does not match the [`Table`] element type.
+    /// - If `value` refers to an entity that does not belong to `ctx`.
     ///
     /// # Panics
     ///
@@ -653,11 +913,11 @@ impl Table {
         index: u32,
         value: Val,
     ) -> Result<(), TableError> {
-        ctx.as_context_mut()
-            .store
-            .inner
-            .resolve_table_mut(self)
-            .set(index, value)
+        let inner = &mut ctx.as_context_mut().store.inner;
+        if !inner.owns_val(&value) {
+            return Err(TableError::CrossStore);
+        }
+        inner.resolve_table_mut(self).set(index, value)
     }
 
     /// Returns `true` if `lhs` and `rhs` [`Table`] refer to the same entity.
@@ -717,6 +977,33 @@ impl Table {
         }
     }
 
+    /// Copy `len` elements from `self[src_index..]` into `self[dst_index..]`.
+    ///
+    /// The source and destination ranges may overlap.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either range is out of bounds of the [`Table`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `store` does not own `self`.
+    pub fn copy_within(
+        &self,
+        mut store: impl AsContextMut,
+        dst_index: u32,
+        src_index: u32,
+        len: u32,
+    ) -> Result<(), TableError> {
+        store
+            .as_context_mut()
+            .store
+            .inner
+            .resolve_table_mut(self)
+            .copy_within(dst_index, src_index, len, None)
+            .map_err(|_| TableError::CopyOutOfBounds)
+    }
+
     /// Fill `table[dst..(dst + len)]` with the given value.
     ///
     /// # Errors
@@ -0,0 +1,58 @@
+use crate::engine::DedupFuncType;
+use alloc::vec::Vec;
+use core::{mem, ops::Range};
+
+/// The last successfully matched `call_indirect` signature per table element.
+///
+/// # Note
+///
+/// - Indirect calls through a cached table element skip resolving and comparing the
+///   signature of the called function if the expected signature is the cached one.
+/// - Cached signatures must be invalidated whenever table elements are overwritten.
+/// - Signatures are deduplicated per [`Engine`] and thus a cache is valid for all
+///   instances sharing the table within the same [`Store`].
+///
+/// [`Engine`]: crate::Engine
+/// [`Store`]: crate::Store
+#[derive(Debug, Default, Clone)]
+pub struct SignatureCache {
+    /// The cached signatures indexed by table element.
+    ///
+    /// Only grows up to the highest cached table element index.
+    signatures: Vec<Option<DedupFuncType>>,
+}
+
+impl SignatureCache {
+    /// Returns `true` if `signature` is cached for the table element at `index`.
+    #[inline]
+    pub fn matches(&self, index: u32, signature: &DedupFuncType) -> bool {
+        matches!(self.signatures.get(index as usize), Some(Some(cached)) if cached == signature)
+    }
+
+    /// Caches the matched `signature` for the table element at `index`.
+    pub fn insert(&mut self, index: u32, signature: DedupFuncType) {
+        let index = index as usize;
+        if index >= self.signatures.len() {
+            self.signatures.resize(index + 1, None);
+        }
+        self.signatures[index] = Some(signature);
+    }
+
+    /// Invalidates the cached signatures of all table elements within `range`.
+    pub fn invalidate(&mut self, range: Range<u32>) {
+        let len = self.signatures.len();
+        let start = (range.start as usize).min(len);
+        let end = (range.end as usize).min(len);
+        self.signatures[start..end].fill(None);
+    }
+
+    /// Invalidates the cached signatures of all table elements.
+    pub fn clear(&mut self) {
+        self.signatures = Vec::new();
+    }
+
+    /// Returns the number of bytes the [`SignatureCache`] allocated on the heap.
+    pub fn allocated_bytes(&self) -> usize {
+        self.signatures.capacity() * mem::size_of::<Option<DedupFuncType>>()
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use alloc::string::ToString;
 
 fn table_type(element: ValType, minimum: u32, maximum: impl Into<Option<u32>>) -> TableType {
     TableType::new(element, minimum, maximum.into())
@@ -17,3 +18,124 @@ fn subtyping_works() {
     assert!(table_type(I32, 0, 1).is_subtype_of(&table_type(I32, 0, None)));
     assert!(!table_type(I32, 0, None).is_subtype_of(&table_type(I32, 0, 1)));
 }
+
+/// The element type, minimum and maximum elements of a [`TableType`].
+type Limits = (ValType, u32, Option<u32>);
+
+#[test]
+fn subtyping_matrix() {
+    // (provided, required, expected error rule if any)
+    let matrix: &[(Limits, Limits, Option<&str>)] = &[
+        ((I32, 1, None), (I32, 1, None), None),
+        ((I32, 2, None), (I32, 1, None), None),
+        ((I32, 2, Some(4)), (I32, 1, None), None),
+        ((I32, 2, Some(4)), (I32, 1, Some(4)), None),
+        ((I32, 2, Some(3)), (I32, 1, Some(4)), None),
+        ((I32, 2, Some(2)), (I32, 2, Some(2)), None),
+        (
+            (F64, 1, None),
+            (I32, 1, None),
+            Some("element type F64 does not match"),
+        ),
+        (
+            (I32, 0, None),
+            (I32, 1, None),
+            Some("minimum of 0 elements is less than"),
+        ),
+        (
+            (I32, 1, Some(4)),
+            (I32, 2, Some(4)),
+            Some("minimum of 1 elements is less than"),
+        ),
+        (
+            (I32, 1, Some(4)),
+            (I32, 2, None),
+            Some("minimum of 1 elements is less than"),
+        ),
+        ((I32, 2, None), (I32, 1, Some(4)), Some("missing maximum")),
+        (
+            (I32, 2, Some(5)),
+            (I32, 1, Some(4)),
+            Some("maximum of 5 elements exceeds"),
+        ),
+    ];
+    for &((ty, min, max), (required_ty, required_min, required_max), expected) in matrix {
+        let provided = table_type(ty, min, max);
+        let required = table_type(required_ty, required_min, required_max);
+        let result = provided.is_subtype_or_err(&required);
+        match expected {
+            None => assert!(
+                result.is_ok(),
+                "{provided:?} must be a subtype of {required:?}"
+            ),
+            Some(rule) => {
+                let message = result.unwrap_err().to_string();
+                assert!(message.contains(rule), "{message}");
+            }
+        }
+    }
+}
+
+#[test]
+fn get_func_and_get_extern() {
+    use crate::{Engine, Func, Store};
+    let mut store = Store::new(&Engine::default(), ());
+    let func = Func::wrap(&mut store, || 42_i32);
+    let funcs = Table::new(
+        &mut store,
+        table_type(ValType::FuncRef, 2, None),
+        Val::FuncRef(FuncRef::null()),
+    )
+    .unwrap();
+    funcs.set(&mut store, 1, Val::FuncRef(func.into())).unwrap();
+    let externs = Table::new(
+        &mut store,
+        table_type(ValType::ExternRef, 2, None),
+        Val::ExternRef(ExternRef::null()),
+    )
+    .unwrap();
+    let object = ExternRef::new(&mut store, 42_i32);
+    externs.set(&mut store, 1, Val::ExternRef(object)).unwrap();
+    // `funcref` tables
+    assert!(funcs.get_func(&store, 0).unwrap().is_null());
+    let found = *funcs.get_func(&store, 1).unwrap().func().unwrap();
+    let found = found.typed::<(), i32>(&store).unwrap();
+    assert_eq!(found.call(&mut store, ()).unwrap(), 42);
+    assert!(funcs.get_func(&store, 2).is_none());
+    assert!(funcs.get_extern(&store, 1).is_none());
+    // `externref` tables
+    assert!(externs.get_extern(&store, 0).unwrap().is_null());
+    let found = externs.get_extern(&store, 1).unwrap();
+    let data = found.data(&store).unwrap().downcast_ref::<i32>();
+    assert_eq!(data, Some(&42));
+    assert!(externs.get_extern(&store, 2).is_none());
+    assert!(externs.get_func(&store, 1).is_none());
+}
+
+#[test]
+fn size_accessors() {
+    use crate::{Engine, Store};
+    let mut store = Store::new(&Engine::default(), ());
+    for (element, minimum, maximum) in [
+        (ValType::FuncRef, 0, None),
+        (ValType::FuncRef, 1, Some(2)),
+        (ValType::ExternRef, 2, Some(2)),
+    ] {
+        let table = Table::new(
+            &mut store,
+            table_type(element, minimum, maximum),
+            Val::default(element),
+        )
+        .unwrap();
+        let ty = table.ty(&store);
+        assert_eq!(table.size(&store), ty.minimum());
+        assert_eq!(table.max_size(&store), ty.maximum());
+        assert_eq!(table.element_type(&store), ty.element());
+        let has_room = table.size(&store) < table.max_size(&store).unwrap_or(u32::MAX);
+        assert_eq!(
+            has_room,
+            table.grow(&mut store, 1, Val::default(element)).is_ok()
+        );
+        assert_eq!(table.max_size(&store), maximum);
+    }
+}
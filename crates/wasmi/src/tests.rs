@@ -77,8 +77,8 @@ where
     /// - If the call fails or traps.
     pub fn call<Args, Results>(&mut self, func_name: &str, args: Args) -> Result<Results, Error>
     where
-        Args: WasmParams,
-        Results: WasmResults,
+        Args: WasmParams + 'static,
+        Results: WasmResults + 'static,
     {
         let Some(module) = &self.module else {
             panic!("need Wasm before calling")
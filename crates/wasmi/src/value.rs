@@ -1,9 +1,11 @@
 use crate::{
     core::{UntypedVal, ValType, F32, F64},
+    AsContext,
     ExternRef,
     Func,
     FuncRef,
 };
+use core::cmp::Ordering;
 
 /// Untyped instances that allow to be typed.
 pub trait WithType {
@@ -79,6 +81,49 @@ impl Val {
         }
     }
 
+    /// Creates a [`Val`] of type `ty` from its raw `untyped` representation.
+    ///
+    /// # Note
+    ///
+    /// Numeric values are always converted losslessly.
+    /// Reference values are reconstructed from raw bits without a [`Store`]
+    /// and therefore are not validated: the resulting [`FuncRef`] or [`ExternRef`]
+    /// might refer to an entity of another [`Store`] or to no entity at all and
+    /// thus panic upon use. Use [`Val::from_untyped_checked`] to validate them.
+    ///
+    /// [`Store`]: crate::Store
+    #[inline]
+    pub fn from_untyped(untyped: UntypedVal, ty: ValType) -> Self {
+        untyped.with_type(ty)
+    }
+
+    /// Creates a [`Val`] of type `ty` from its raw `untyped` representation.
+    ///
+    /// Returns `None` if the resulting `funcref` or `externref` does not refer
+    /// to an entity that is alive in `ctx`. Null references are always valid.
+    pub fn from_untyped_checked(
+        ctx: impl AsContext,
+        untyped: UntypedVal,
+        ty: ValType,
+    ) -> Option<Self> {
+        let value = Self::from_untyped(untyped, ty);
+        if !ctx.as_context().store.inner.contains_val(&value) {
+            return None;
+        }
+        Some(value)
+    }
+
+    /// Returns the raw [`UntypedVal`] representation of `self`.
+    ///
+    /// # Note
+    ///
+    /// The [`ValType`] of `self` is not part of the [`UntypedVal`] and must be
+    /// kept separately in order to convert it back via [`Val::from_untyped`].
+    #[inline]
+    pub fn to_untyped(&self) -> UntypedVal {
+        UntypedVal::from(self.clone())
+    }
+
     /// Get variable type for this value.
     #[inline]
     pub fn ty(&self) -> ValType {
@@ -92,6 +137,18 @@ impl Val {
         }
     }
 
+    /// Returns `true` if `self` is a valid value of type `ty`.
+    ///
+    /// # Note
+    ///
+    /// Null references only match the reference type they were created for,
+    /// i.e. a `null` [`FuncRef`] does not match [`ValType::ExternRef`] and vice versa,
+    /// since Wasm `funcref` and `externref` are disjoint types.
+    #[inline]
+    pub fn matches_type(&self, ty: ValType) -> bool {
+        self.ty() == ty
+    }
+
     /// Returns the underlying `i32` if the type matches otherwise returns `None`.
     pub fn i32(&self) -> Option<i32> {
         match self {
@@ -139,6 +196,28 @@ impl Val {
             _ => None,
         }
     }
+
+    /// Compares `self` and `other` according to the semantics of the Wasm comparison instructions.
+    ///
+    /// Returns `None` if `self` and `other` are unordered:
+    ///
+    /// - If `self` and `other` are of different types.
+    /// - If either `self` or `other` is a reference type.
+    /// - If either `self` or `other` is a NaN float value.
+    ///
+    /// # Note
+    ///
+    /// - Integers are compared as signed integers, i.e. like `i32.lt_s` et al.
+    /// - Floats are compared like `f32.lt`, `f64.ge` et al. which treats `-0.0` and `+0.0` as equal.
+    pub fn partial_cmp_wasm(&self, other: &Val) -> Option<Ordering> {
+        match (self, other) {
+            (Self::I32(lhs), Self::I32(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::I64(lhs), Self::I64(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::F32(lhs), Self::F32(rhs)) => f32::from(*lhs).partial_cmp(&f32::from(*rhs)),
+            (Self::F64(lhs), Self::F64(rhs)) => f64::from(*lhs).partial_cmp(&f64::from(*rhs)),
+            _ => None,
+        }
+    }
 }
 
 impl From<i32> for Val {
@@ -189,3 +268,209 @@ impl From<ExternRef> for Val {
         Self::ExternRef(externref)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Engine, Instance, Linker, Module, Store};
+
+    const NAN32: f32 = f32::NAN;
+    const NAN64: f64 = f64::NAN;
+
+    fn f32s() -> [f32; 8] {
+        [
+            NAN32,
+            -NAN32,
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+        ]
+    }
+
+    fn f64s() -> [f64; 8] {
+        [
+            NAN64,
+            -NAN64,
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ]
+    }
+
+    #[test]
+    fn partial_cmp_wasm_nan_and_zero() {
+        let nan = Val::F32(NAN32.into());
+        for value in f32s() {
+            assert_eq!(nan.partial_cmp_wasm(&Val::F32(value.into())), None);
+            assert_eq!(Val::F32(value.into()).partial_cmp_wasm(&nan), None);
+        }
+        let nan = Val::F64(NAN64.into());
+        for value in f64s() {
+            assert_eq!(nan.partial_cmp_wasm(&Val::F64(value.into())), None);
+            assert_eq!(Val::F64(value.into()).partial_cmp_wasm(&nan), None);
+        }
+        assert_eq!(
+            Val::F32(0.0.into()).partial_cmp_wasm(&Val::F32((-0.0).into())),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            Val::F64((-0.0).into()).partial_cmp_wasm(&Val::F64(0.0.into())),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn partial_cmp_wasm_mismatching_types() {
+        assert_eq!(Val::I32(0).partial_cmp_wasm(&Val::I64(0)), None);
+        assert_eq!(
+            Val::F32(0.0.into()).partial_cmp_wasm(&Val::F64(0.0.into())),
+            None
+        );
+        let null = Val::FuncRef(FuncRef::null());
+        assert_eq!(null.partial_cmp_wasm(&null), None);
+        assert_eq!(
+            Val::I32(-1).partial_cmp_wasm(&Val::I32(1)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Val::I64(1).partial_cmp_wasm(&Val::I64(-1)),
+            Some(Ordering::Greater)
+        );
+    }
+
+    #[test]
+    fn matches_type_works() {
+        let tys = [
+            ValType::I32,
+            ValType::I64,
+            ValType::F32,
+            ValType::F64,
+            ValType::FuncRef,
+            ValType::ExternRef,
+        ];
+        for ty in tys {
+            let value = Val::default(ty);
+            assert_eq!(value.ty(), ty);
+            for other in tys {
+                assert_eq!(value.matches_type(other), ty == other);
+            }
+        }
+        let mut store = Store::new(&Engine::default(), ());
+        let func = crate::Func::wrap(&mut store, || ());
+        let funcref = Val::FuncRef(FuncRef::new(func));
+        assert!(funcref.matches_type(ValType::FuncRef));
+        assert!(!funcref.matches_type(ValType::ExternRef));
+        let externref = Val::ExternRef(ExternRef::new(&mut store, 42_i32));
+        assert!(externref.matches_type(ValType::ExternRef));
+        assert!(!externref.matches_type(ValType::FuncRef));
+    }
+
+    #[test]
+    fn untyped_round_trip() {
+        let mut store = Store::new(&Engine::default(), ());
+        let func = crate::Func::wrap(&mut store, || ());
+        let values = [
+            Val::I32(-1),
+            Val::I64(i64::MIN),
+            Val::F32(1.5.into()),
+            Val::F64(f64::NEG_INFINITY.into()),
+            Val::FuncRef(FuncRef::null()),
+            Val::FuncRef(FuncRef::new(func)),
+            Val::ExternRef(ExternRef::null()),
+            Val::ExternRef(ExternRef::new(&mut store, 42_i32)),
+        ];
+        for value in values {
+            let untyped = value.to_untyped();
+            let ty = value.ty();
+            let restored = Val::from_untyped(untyped, ty);
+            assert_eq!(restored.ty(), ty);
+            assert_eq!(restored.to_untyped(), untyped);
+            let checked = Val::from_untyped_checked(&store, untyped, ty).unwrap();
+            assert_eq!(checked.to_untyped(), untyped);
+        }
+    }
+
+    #[test]
+    fn from_untyped_checked_rejects_foreign_refs() {
+        let engine = Engine::default();
+        let mut store = Store::new(&engine, ());
+        let other = Store::new(&engine, ());
+        let func = crate::Func::wrap(&mut store, || ());
+        let externref = ExternRef::new(&mut store, 42_i32);
+        let untyped = Val::from(func).to_untyped();
+        assert!(Val::from_untyped_checked(&other, untyped, ValType::FuncRef).is_none());
+        let untyped = Val::from(externref).to_untyped();
+        assert!(Val::from_untyped_checked(&other, untyped, ValType::ExternRef).is_none());
+        assert!(Val::from_untyped_checked(&other, UntypedVal::from(0), ValType::FuncRef).is_some());
+    }
+
+    /// Returns `(lt, eq, gt)` as computed by the Wasm comparison instructions.
+    fn wasm_cmp(
+        store: &mut Store<()>,
+        instance: Instance,
+        name: &str,
+        lhs: Val,
+        rhs: Val,
+    ) -> (bool, bool, bool) {
+        let func = instance.get_func(&*store, name).unwrap();
+        let mut results = [Val::I32(0), Val::I32(0), Val::I32(0)];
+        func.call(&mut *store, &[lhs, rhs], &mut results).unwrap();
+        let [lt, eq, gt] = results.map(|result| result.i32().unwrap() != 0);
+        (lt, eq, gt)
+    }
+
+    #[test]
+    fn partial_cmp_wasm_agrees_with_executor() {
+        let wasm = r#"
+            (module
+                (func (export "f32") (param f32 f32) (result i32 i32 i32)
+                    (f32.lt (local.get 0) (local.get 1))
+                    (f32.eq (local.get 0) (local.get 1))
+                    (f32.gt (local.get 0) (local.get 1))
+                )
+                (func (export "f64") (param f64 f64) (result i32 i32 i32)
+                    (f64.lt (local.get 0) (local.get 1))
+                    (f64.eq (local.get 0) (local.get 1))
+                    (f64.gt (local.get 0) (local.get 1))
+                )
+            )
+        "#;
+        let engine = Engine::default();
+        let mut store = Store::new(&engine, ());
+        let module = Module::new(&engine, wasm).unwrap();
+        let instance = Linker::new(&engine)
+            .instantiate(&mut store, &module)
+            .unwrap()
+            .start(&mut store)
+            .unwrap();
+        let expected = |ordering: Option<Ordering>| {
+            (
+                ordering == Some(Ordering::Less),
+                ordering == Some(Ordering::Equal),
+                ordering == Some(Ordering::Greater),
+            )
+        };
+        for lhs in f32s() {
+            for rhs in f32s() {
+                let (lhs, rhs) = (Val::F32(lhs.into()), Val::F32(rhs.into()));
+                let ordering = lhs.partial_cmp_wasm(&rhs);
+                let results = wasm_cmp(&mut store, instance, "f32", lhs, rhs);
+                assert_eq!(results, expected(ordering));
+            }
+        }
+        for lhs in f64s() {
+            for rhs in f64s() {
+                let (lhs, rhs) = (Val::F64(lhs.into()), Val::F64(rhs.into()));
+                let ordering = lhs.partial_cmp_wasm(&rhs);
+                let results = wasm_cmp(&mut store, instance, "f64", lhs, rhs);
+                assert_eq!(results, expected(ordering));
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Tests to check that `Config::wasm_bulk_memory` gates the `bulk-memory` Wasm proposal.
+
+use wasmi::{CompilationMode, Config, Engine, Error, Module};
+
+/// Parses the Wasm module `wasm` with `bulk-memory` enabled or disabled using `mode`.
+fn parse_with(wasm: &str, bulk_memory: bool, mode: CompilationMode) -> Result<Module, Error> {
+    let mut config = Config::default();
+    config
+        .wasm_bulk_memory(bulk_memory)
+        .wasm_reference_types(false)
+        .compilation_mode(mode);
+    let engine = Engine::new(&config);
+    Module::new(&engine, wasm)
+}
+
+/// Asserts that `wasm` is accepted with `bulk-memory` enabled and rejected if disabled.
+///
+/// The error message of the rejection must mention the `bulk memory` proposal.
+fn assert_gated(wasm: &str) {
+    for mode in [CompilationMode::Eager, CompilationMode::LazyTranslation] {
+        parse_with(wasm, true, mode).unwrap();
+        let error = parse_with(wasm, false, mode).unwrap_err();
+        assert!(
+            error.to_string().contains("bulk memory"),
+            "unexpected error for {mode:?}: {error}"
+        );
+    }
+}
+
+#[test]
+fn mvp_module_is_accepted() {
+    let wasm = r#"
+        (module
+            (memory 1)
+            (table 1 funcref)
+            (data (i32.const 0) "abc")
+            (elem (i32.const 0) $f)
+            (func $f (param i32) (result i32)
+                (i32.load (local.get 0))
+            )
+        )
+    "#;
+    parse_with(wasm, false, CompilationMode::Eager).unwrap();
+}
+
+#[test]
+fn memory_fill_is_gated() {
+    assert_gated(
+        r#"
+        (module
+            (memory 1)
+            (func (param i32 i32 i32)
+                (memory.fill (local.get 0) (local.get 1) (local.get 2))
+            )
+        )
+    "#,
+    );
+}
+
+#[test]
+fn memory_copy_is_gated() {
+    assert_gated(
+        r#"
+        (module
+            (memory 1)
+            (func (param i32 i32 i32)
+                (memory.copy (local.get 0) (local.get 1) (local.get 2))
+            )
+        )
+    "#,
+    );
+}
+
+#[test]
+fn table_copy_is_gated() {
+    assert_gated(
+        r#"
+        (module
+            (table 1 funcref)
+            (func (param i32 i32 i32)
+                (table.copy (local.get 0) (local.get 1) (local.get 2))
+            )
+        )
+    "#,
+    );
+}
+
+#[test]
+fn passive_data_segment_is_gated() {
+    assert_gated(
+        r#"
+        (module
+            (memory 1)
+            (data "abc")
+        )
+    "#,
+    );
+}
+
+#[test]
+fn passive_element_segment_is_gated() {
+    assert_gated(
+        r#"
+        (module
+            (func $f)
+            (elem func $f)
+        )
+    "#,
+    );
+}
@@ -0,0 +1,47 @@
+//! Tests to check if `Config::max_call_depth` works as intended.
+
+use wasmi::{core::TrapCode, Config, Engine, Linker, Module, Store, TypedFunc};
+
+/// Instantiates a module with a recursive function using `max_call_depth`.
+///
+/// The returned function calls itself `n` times which yields `n + 1` call frames.
+fn setup(max_call_depth: usize) -> (Store<()>, TypedFunc<i32, ()>) {
+    let wasm = r#"
+        (module
+            (func $rec (export "rec") (param $n i32)
+                (if (local.get $n)
+                    (then
+                        (call $rec (i32.sub (local.get $n) (i32.const 1)))
+                    )
+                )
+            )
+        )
+    "#;
+    let mut config = Config::default();
+    config.max_call_depth(max_call_depth);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, wasm).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_typed_func::<i32, ()>(&store, "rec").unwrap();
+    (store, func)
+}
+
+#[test]
+fn max_call_depth_traps_exactly() {
+    for depth in [1, 2, 10, 100] {
+        let (mut store, func) = setup(depth);
+        let n = depth as i32;
+        // A total of `depth` call frames is allowed.
+        func.call(&mut store, n - 1).unwrap();
+        // More than `depth` call frames trap.
+        let error = func.call(&mut store, n).unwrap_err();
+        assert_eq!(error.as_trap_code(), Some(TrapCode::StackOverflow));
+        // The store remains usable after the trap.
+        func.call(&mut store, n - 1).unwrap();
+    }
+}
@@ -0,0 +1,165 @@
+//! Tests to check that cached `call_indirect` signatures are invalidated upon table mutation.
+
+use wasmi::{core::TrapCode, Engine, Error, Instance, Linker, Module, Store, Val};
+
+/// The Wasm module used by the tests.
+///
+/// - `$t[0]` and `$t[1]` hold functions of type `$i32` and `$i64` respectively.
+/// - `call` indirectly calls `$t[index]` expecting type `$i32`.
+/// - The other exports mutate `$t[0]` in various ways.
+const WASM: &str = r#"
+    (module
+        (type $i32 (func (result i32)))
+        (type $i64 (func (result i64)))
+        (table $t (export "table") 2 10 funcref)
+        (func $f32 (type $i32) (i32.const 42))
+        (func $f64 (type $i64) (i64.const 42))
+        (elem (table $t) (i32.const 0) func $f32 $f64)
+        (elem $wrong func $f64)
+        (func (export "call") (param $index i32) (result i32)
+            (call_indirect $t (type $i32) (local.get $index))
+        )
+        (func (export "set")
+            (table.set $t (i32.const 0) (ref.func $f64))
+        )
+        (func (export "copy")
+            (table.copy $t $t (i32.const 0) (i32.const 1) (i32.const 1))
+        )
+        (func (export "fill")
+            (table.fill $t (i32.const 0) (ref.func $f64) (i32.const 1))
+        )
+        (func (export "init")
+            (table.init $t $wrong (i32.const 0) (i32.const 0) (i32.const 1))
+        )
+        (func (export "grow") (result i32)
+            (table.grow $t (ref.null func) (i32.const 1))
+        )
+    )
+"#;
+
+/// Instantiates [`WASM`].
+fn setup() -> (Store<()>, Instance) {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, WASM).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Indirectly calls `$t[index]` of `instance` expecting type `$i32`.
+fn call(store: &mut Store<()>, instance: Instance, index: i32) -> Result<i32, Error> {
+    instance
+        .get_typed_func::<i32, i32>(&*store, "call")
+        .unwrap()
+        .call(store, index)
+}
+
+/// Calls the exported table mutating function `name` of `instance`.
+fn mutate(store: &mut Store<()>, instance: Instance, name: &str) {
+    instance
+        .get_func(&*store, name)
+        .unwrap()
+        .call(store, &[], &mut [])
+        .unwrap();
+}
+
+/// Asserts that indirectly calling `$t[0]` hits the cache and then traps after `mutate`.
+fn assert_invalidated_by(mutate: impl FnOnce(&mut Store<()>, Instance)) {
+    let (mut store, instance) = setup();
+    for _ in 0..3 {
+        assert_eq!(call(&mut store, instance, 0).unwrap(), 42);
+    }
+    mutate(&mut store, instance);
+    let error = call(&mut store, instance, 0).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::BadSignature));
+}
+
+#[test]
+fn wrong_signature_traps() {
+    let (mut store, instance) = setup();
+    for _ in 0..3 {
+        let error = call(&mut store, instance, 1).unwrap_err();
+        assert_eq!(error.as_trap_code(), Some(TrapCode::BadSignature));
+    }
+}
+
+#[test]
+fn table_set_invalidates() {
+    assert_invalidated_by(|store, instance| mutate(store, instance, "set"));
+}
+
+#[test]
+fn table_copy_invalidates() {
+    assert_invalidated_by(|store, instance| mutate(store, instance, "copy"));
+}
+
+#[test]
+fn table_fill_invalidates() {
+    assert_invalidated_by(|store, instance| mutate(store, instance, "fill"));
+}
+
+#[test]
+fn table_init_invalidates() {
+    assert_invalidated_by(|store, instance| mutate(store, instance, "init"));
+}
+
+#[test]
+fn host_table_set_invalidates() {
+    assert_invalidated_by(|store, instance| {
+        let table = instance.get_table(&*store, "table").unwrap();
+        let wrong = table.get(&*store, 1).unwrap();
+        table.set(store, 0, wrong).unwrap();
+    });
+}
+
+#[test]
+fn table_grow_keeps_cache_valid() {
+    let (mut store, instance) = setup();
+    assert_eq!(call(&mut store, instance, 0).unwrap(), 42);
+    let mut result = [Val::I32(0)];
+    instance
+        .get_func(&store, "grow")
+        .unwrap()
+        .call(&mut store, &[], &mut result)
+        .unwrap();
+    assert_eq!(result[0].i32(), Some(2));
+    assert_eq!(call(&mut store, instance, 0).unwrap(), 42);
+    let error = call(&mut store, instance, 2).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::IndirectCallToNull));
+}
+
+#[test]
+fn shared_table_across_instances() {
+    let (mut store, instance) = setup();
+    let table = instance.get_table(&store, "table").unwrap();
+    let wasm = r#"
+        (module
+            (type $i64 (func (result i64)))
+            (import "env" "table" (table $t 2 funcref))
+            (func (export "call") (param $index i32) (result i64)
+                (call_indirect $t (type $i64) (local.get $index))
+            )
+        )
+    "#;
+    let engine = store.engine().clone();
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut linker = <Linker<()>>::new(&engine);
+    linker.define("env", "table", table).unwrap();
+    let other = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let other_call = other.get_typed_func::<i32, i64>(&store, "call").unwrap();
+    // Both instances call `$t[0]` alternatingly expecting different signatures.
+    for _ in 0..3 {
+        assert_eq!(call(&mut store, instance, 0).unwrap(), 42);
+        let error = other_call.call(&mut store, 0).unwrap_err();
+        assert_eq!(error.as_trap_code(), Some(TrapCode::BadSignature));
+        assert_eq!(other_call.call(&mut store, 1).unwrap(), 42);
+    }
+}
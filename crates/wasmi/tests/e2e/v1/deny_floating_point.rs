@@ -0,0 +1,179 @@
+//! Tests to check that `Config::deny_floating_point` rejects Wasm modules using floats.
+
+use wasmi::{
+    errors::{ErrorKind, FloatingPointError},
+    CompilationMode,
+    Config,
+    Engine,
+    Error,
+    Module,
+};
+
+/// Parses the Wasm module `wasm` with floating point denied using `mode`.
+fn parse_with(wasm: &str, mode: CompilationMode) -> Result<Module, Error> {
+    let mut config = Config::default();
+    config.deny_floating_point(true).compilation_mode(mode);
+    let engine = Engine::new(&config);
+    Module::new(&engine, wasm)
+}
+
+/// Asserts that parsing `wasm` fails with `expected` for all [`CompilationMode`]s.
+fn assert_denied(wasm: &str, expected: FloatingPointError) {
+    for mode in [
+        CompilationMode::Eager,
+        CompilationMode::LazyTranslation,
+        CompilationMode::Lazy,
+    ] {
+        let error = parse_with(wasm, mode).unwrap_err();
+        match error.kind() {
+            ErrorKind::FloatingPoint(error) => assert_eq!(*error, expected),
+            _ => panic!("unexpected error for {mode:?}: {error}"),
+        }
+    }
+}
+
+#[test]
+fn integer_module_is_accepted() {
+    let wasm = r#"
+        (module
+            (global $g (mut i64) (i64.const 0))
+            (func (export "add") (param i32 i32) (result i32)
+                (local i64)
+                (i32.add (local.get 0) (local.get 1))
+            )
+        )
+    "#;
+    parse_with(wasm, CompilationMode::Eager).unwrap();
+}
+
+#[test]
+fn float_module_is_accepted_by_default() {
+    let wasm = r#"
+        (module
+            (func (param f32) (result f64)
+                (f64.promote_f32 (local.get 0))
+            )
+        )
+    "#;
+    let engine = Engine::default();
+    Module::new(&engine, wasm).unwrap();
+}
+
+#[test]
+fn deny_arith_op() {
+    let wasm = r#"
+        (module
+            (func (export "f") (param i32) (result i32)
+                (i32.const 1)
+                (f32.const 1.0)
+                (f32.const 2.0)
+                (f32.add)
+                (drop)
+            )
+        )
+    "#;
+    let error = parse_with(wasm, CompilationMode::Eager).unwrap_err();
+    let ErrorKind::FloatingPoint(FloatingPointError::Instruction {
+        func_index, name, ..
+    }) = error.kind()
+    else {
+        panic!("unexpected error: {error}")
+    };
+    assert_eq!(*func_index, 0);
+    assert_eq!(*name, "F32Const");
+    assert!(error.to_string().contains("F32Const"));
+    for mode in [CompilationMode::LazyTranslation, CompilationMode::Lazy] {
+        assert!(matches!(
+            parse_with(wasm, mode).unwrap_err().kind(),
+            ErrorKind::FloatingPoint(FloatingPointError::Instruction { .. })
+        ));
+    }
+}
+
+#[test]
+fn deny_param_type() {
+    let wasm = r#"
+        (module
+            (type (func (param i32)))
+            (type (func (param i32 f32)))
+        )
+    "#;
+    assert_denied(wasm, FloatingPointError::FuncType { type_index: 1 });
+}
+
+#[test]
+fn deny_result_type() {
+    let wasm = r#"
+        (module
+            (func (result f64)
+                (unreachable)
+            )
+        )
+    "#;
+    assert_denied(wasm, FloatingPointError::FuncType { type_index: 0 });
+}
+
+#[test]
+fn deny_global() {
+    let wasm = r#"
+        (module
+            (import "env" "g" (global i32))
+            (global i64 (i64.const 0))
+            (global f64 (f64.const 0))
+        )
+    "#;
+    assert_denied(wasm, FloatingPointError::Global { global_index: 2 });
+}
+
+#[test]
+fn deny_local() {
+    let wasm = r#"
+        (module
+            (func)
+            (func (local i32 f32))
+        )
+    "#;
+    assert_denied(wasm, FloatingPointError::Local { func_index: 1 });
+}
+
+#[test]
+fn deny_conversion_op() {
+    let wasm = r#"
+        (module
+            (func (param i32) (result i32)
+                (i32.reinterpret_f32 (f32.reinterpret_i32 (local.get 0)))
+            )
+        )
+    "#;
+    assert_denied(
+        wasm,
+        FloatingPointError::Instruction {
+            func_index: 0,
+            offset: 0x1b,
+            name: "F32ReinterpretI32",
+        },
+    );
+}
+
+#[test]
+fn disabled_floats_are_denied() {
+    let wasm = r#"
+        (module
+            (func (result i32)
+                (i32.trunc_f32_s (f32.const 1.0))
+            )
+        )
+    "#;
+    let mut config = Config::default();
+    config.floats(false);
+    let engine = Engine::new(&config);
+    let error = Module::new(&engine, wasm).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::FloatingPoint(FloatingPointError::Instruction {
+            func_index: 0,
+            name: "F32Const",
+            ..
+        })
+    ));
+}
@@ -0,0 +1,100 @@
+//! Tests to check that the `Config` of an `Engine` reflects its effective settings.
+
+use wasmi::{
+    AvgBytesPerFunctionLimit,
+    CompilationMode,
+    Config,
+    EnforcedLimits,
+    Engine,
+    StackLimits,
+    WasmProposal,
+};
+
+#[test]
+fn default_config() {
+    let engine = Engine::default();
+    let config = engine.config();
+    assert!(!config.get_consume_fuel());
+    assert!(!config.get_ignore_custom_sections());
+    assert!(!config.get_lazy_table_init());
+    assert!(!config.get_deny_floating_point());
+    assert_eq!(config.get_compilation_mode(), CompilationMode::Eager);
+    assert_eq!(config.get_enforced_limits().get_max_functions(), None);
+    for proposal in [
+        WasmProposal::MutableGlobal,
+        WasmProposal::SignExtension,
+        WasmProposal::SaturatingFloatToInt,
+        WasmProposal::MultiValue,
+        WasmProposal::MultiMemory,
+        WasmProposal::BulkMemory,
+        WasmProposal::ReferenceTypes,
+        WasmProposal::TailCall,
+        WasmProposal::ExtendedConst,
+    ] {
+        assert!(config.is_enabled(proposal), "{proposal:?}");
+    }
+    assert!(!config.is_enabled(WasmProposal::Simd));
+}
+
+#[test]
+fn custom_config() {
+    let stack_limits = StackLimits::new(16, 1024, 64).unwrap();
+    let avg_bytes = AvgBytesPerFunctionLimit {
+        req_funcs_bytes: 100,
+        min_avg_bytes_per_function: 10,
+    };
+    let mut limits = EnforcedLimits::default();
+    limits
+        .max_globals(Some(1))
+        .max_functions(Some(2))
+        .max_tables(Some(3))
+        .max_element_segments(Some(4))
+        .max_memories(Some(5))
+        .max_data_segments(Some(6))
+        .max_memory_pages(Some(7))
+        .max_params(Some(8))
+        .max_results(Some(9))
+        .max_locals_per_function(Some(10))
+        .max_br_table_targets(Some(11))
+        .min_avg_bytes_per_function(Some(avg_bytes));
+    let mut config = Config::default();
+    config
+        .set_stack_limits(stack_limits)
+        .set_cached_stacks(5)
+        .wasm_tail_call(false)
+        .wasm_bulk_memory(false)
+        .consume_fuel(true)
+        .ignore_custom_sections(true)
+        .compilation_mode(CompilationMode::Lazy)
+        .enforced_limits(limits)
+        .lazy_table_init(true)
+        .deny_floating_point(true);
+    let engine = Engine::new(&config);
+    let config = engine.config();
+    assert_eq!(config.stack_limits().initial_value_stack_height(), 16);
+    assert_eq!(config.stack_limits().maximum_value_stack_height(), 1024);
+    assert_eq!(config.stack_limits().maximum_recursion_depth(), 64);
+    assert_eq!(config.cached_stacks(), 5);
+    assert!(!config.is_enabled(WasmProposal::TailCall));
+    assert!(!config.is_enabled(WasmProposal::BulkMemory));
+    assert!(config.is_enabled(WasmProposal::MultiValue));
+    assert!(config.get_consume_fuel());
+    assert!(config.fuel_costs().base() > 0);
+    assert!(config.get_ignore_custom_sections());
+    assert_eq!(config.get_compilation_mode(), CompilationMode::Lazy);
+    assert!(config.get_lazy_table_init());
+    assert!(config.get_deny_floating_point());
+    let limits = config.get_enforced_limits();
+    assert_eq!(limits.get_max_globals(), Some(1));
+    assert_eq!(limits.get_max_functions(), Some(2));
+    assert_eq!(limits.get_max_tables(), Some(3));
+    assert_eq!(limits.get_max_element_segments(), Some(4));
+    assert_eq!(limits.get_max_memories(), Some(5));
+    assert_eq!(limits.get_max_data_segments(), Some(6));
+    assert_eq!(limits.get_max_memory_pages(), Some(7));
+    assert_eq!(limits.get_max_params(), Some(8));
+    assert_eq!(limits.get_max_results(), Some(9));
+    assert_eq!(limits.get_max_locals_per_function(), Some(10));
+    assert_eq!(limits.get_max_br_table_targets(), Some(11));
+    assert_eq!(limits.get_min_avg_bytes_per_function(), Some(avg_bytes));
+}
@@ -1,7 +1,20 @@
 //! Tests to check if wasmi's fuel metering works as intended.
 
 use std::fmt::Debug;
-use wasmi::{core::TrapCode, Config, Engine, Error, Func, Linker, Module, Store};
+use wasmi::{
+    core::{TrapCode, ValType},
+    errors::{ErrorKind, FuelError},
+    Caller,
+    Config,
+    Engine,
+    Error,
+    Func,
+    Linker,
+    Module,
+    Store,
+    TypedResumableCall,
+    Val,
+};
 
 /// Setup [`Engine`] and [`Store`] for fuel metering.
 fn test_setup() -> (Store<()>, Linker<()>) {
@@ -85,3 +98,576 @@ fn metered_i32_add() {
     assert_success(func.call(&mut store, (1, 2)));
     assert_eq!(store.get_fuel().ok(), Some(7));
 }
+
+#[test]
+fn fuel_checkpoint_restore() {
+    let wasm = r#"
+        (module
+            (func (export "test") (param $a i32) (param $b i32) (result i32)
+                (i32.div_s
+                    (local.get $a)
+                    (local.get $b)
+                )
+            )
+        )
+    "#;
+    let (mut store, func) = default_test_setup(wasm.as_bytes());
+    let func = func.typed::<(i32, i32), i32>(&store).unwrap();
+    store.set_fuel(10).unwrap();
+    let checkpoint = store.fuel_checkpoint().unwrap();
+    assert_eq!(checkpoint.remaining(), 10);
+    // The failing sub-computation consumes some fuel before it traps.
+    let error = func.call(&mut store, (1, 0)).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::IntegerDivisionByZero));
+    let consumed = 10 - store.get_fuel().unwrap();
+    assert!(consumed > 0);
+    // Restoring the checkpoint refunds exactly the consumed fuel.
+    store.fuel_restore(checkpoint).unwrap();
+    assert_eq!(store.get_fuel().ok(), Some(10));
+    // Subsequent executions proceed normally.
+    assert_eq!(func.call(&mut store, (6, 2)).unwrap(), 3);
+    assert_eq!(store.get_fuel().ok(), Some(10 - consumed));
+}
+
+#[test]
+fn fuel_checkpoint_requires_fuel_metering() {
+    let mut store = Store::new(&Engine::default(), ());
+    assert!(store.fuel_checkpoint().is_err());
+    let (fuel_store, _) = test_setup();
+    let checkpoint = fuel_store.fuel_checkpoint().unwrap();
+    assert!(store.fuel_restore(checkpoint).is_err());
+}
+
+/// Returns the fuel consumed by calling `"test"` of an instance of `module` that loops `n` times.
+fn consumed_fuel_of_loop(module: &Module, n: i32) -> u64 {
+    let mut store = Store::new(module.engine(), ());
+    let linker = <Linker<()>>::new(module.engine());
+    let instance = linker
+        .instantiate(&mut store, module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_typed_func::<i32, ()>(&store, "test").unwrap();
+    store.set_fuel(10_000).unwrap();
+    func.call(&mut store, n).unwrap();
+    10_000 - store.get_fuel().unwrap()
+}
+
+/// A Wasm module that loops `$n` times.
+const LOOP: &str = r#"
+    (module
+        (func (export "test") (param $n i32)
+            (loop $continue
+                (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+                (br_if $continue)
+            )
+        )
+    )
+"#;
+
+#[test]
+fn fuel_multiplier_scales_consumption() {
+    let (store, _linker) = test_setup();
+    let module = create_module(&store, LOOP.as_bytes());
+    let base = consumed_fuel_of_loop(&module, 10);
+    let mut tripled = module.clone();
+    tripled.set_fuel_multiplier(3.0);
+    assert!(Module::same(&module, &tripled));
+    assert_eq!(consumed_fuel_of_loop(&tripled, 10), 3 * base);
+    let mut halved = module.clone();
+    halved.set_fuel_multiplier(0.5);
+    let halved_fuel = consumed_fuel_of_loop(&halved, 10);
+    assert!(halved_fuel < base);
+    assert!(halved_fuel >= base / 2);
+    // The multiplier of `module` itself remains untouched.
+    assert_eq!(consumed_fuel_of_loop(&module, 10), base);
+}
+
+#[test]
+fn fuel_multiplier_applies_per_instance() {
+    let (mut store, mut linker) = test_setup();
+    let callee = create_module(&store, LOOP.as_bytes());
+    let callee_base = consumed_fuel_of_loop(&callee, 10);
+    let mut discounted = callee.clone();
+    discounted.set_fuel_multiplier(0.25);
+    let callee_instance = linker
+        .instantiate(&mut store, &discounted)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    linker
+        .instance(&mut store, "callee", callee_instance)
+        .unwrap();
+    let caller = r#"
+        (module
+            (import "callee" "test" (func $loop (param i32)))
+            (func (export "test") (param $n i32)
+                (call $loop (local.get $n))
+            )
+        )
+    "#;
+    let caller = create_module(&store, caller.as_bytes());
+    let caller_instance = linker
+        .instantiate(&mut store, &caller)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = caller_instance
+        .get_typed_func::<i32, ()>(&store, "test")
+        .unwrap();
+    store.set_fuel(10_000).unwrap();
+    func.call(&mut store, 10).unwrap();
+    let consumed = 10_000 - store.get_fuel().unwrap();
+    // The calling function is charged normally whereas the looping function is discounted.
+    assert!(consumed < callee_base);
+    assert!(consumed > callee_base / 4);
+}
+
+#[test]
+#[should_panic]
+fn fuel_multiplier_must_be_positive() {
+    let (store, _linker) = test_setup();
+    let mut module = create_module(&store, LOOP.as_bytes());
+    module.set_fuel_multiplier(0.0);
+}
+
+/// Setup [`Store`] and [`Func`] where `"test"` calls the host function `"env" "fuel"`
+/// with its parameter before it loops `n` times.
+///
+/// The host function adds its parameter to the remaining fuel if it is positive
+/// and otherwise sets the remaining fuel to zero.
+fn host_fuel_test_setup() -> (Store<()>, Func) {
+    let (mut store, mut linker) = test_setup();
+    linker
+        .func_wrap(
+            "env",
+            "fuel",
+            |mut caller: Caller<()>, delta: i64| -> Result<(), Error> {
+                let fuel = match delta {
+                    delta if delta > 0 => caller.get_fuel()? + delta as u64,
+                    _ => 0,
+                };
+                caller.set_fuel(fuel)
+            },
+        )
+        .unwrap();
+    let wasm = r#"
+        (module
+            (import "env" "fuel" (func $fuel (param i64)))
+            (func (export "test") (param $delta i64) (param $n i32)
+                (call $fuel (local.get $delta))
+                (loop $continue
+                    (br_if $continue
+                        (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+                    )
+                )
+            )
+        )
+    "#;
+    let module = create_module(&store, wasm.as_bytes());
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_func(&store, "test").unwrap();
+    (store, func)
+}
+
+#[test]
+fn host_func_tops_up_fuel() {
+    let (mut store, func) = host_fuel_test_setup();
+    let func = func.typed::<(i64, i32), ()>(&store).unwrap();
+    // Without bonus fuel the loop runs out of fuel.
+    store.set_fuel(100).unwrap();
+    assert_out_of_fuel(func.call(&mut store, (0, 1_000)));
+    // The bonus fuel granted by the host function is used by the rest of the execution.
+    store.set_fuel(100).unwrap();
+    assert_success(func.call(&mut store, (100_000, 1_000)));
+    let remaining = store.get_fuel().unwrap();
+    assert!(remaining > 100);
+    assert!(remaining < 100_000);
+}
+
+#[test]
+fn host_func_withdraws_fuel() {
+    let (mut store, func) = host_fuel_test_setup();
+    let func = func.typed::<(i64, i32), ()>(&store).unwrap();
+    store.set_fuel(100_000).unwrap();
+    // The host function sets the remaining fuel to zero so the loop runs out of fuel.
+    assert_out_of_fuel(func.call(&mut store, (0, 1)));
+    assert_eq!(store.get_fuel().ok(), Some(0));
+}
+
+/// Setup [`Store`] and [`Func`] where `"test"` calls the host functions
+/// `"env" "nop0"` and `"env" "nop2"` each `n` times.
+fn host_call_cost_test_setup() -> (Store<()>, Func) {
+    let (mut store, mut linker) = test_setup();
+    linker.func_wrap("env", "nop0", || ()).unwrap();
+    linker
+        .func_wrap("env", "nop2", |_: i32, _: i64| ())
+        .unwrap();
+    let wasm = r#"
+        (module
+            (import "env" "nop0" (func $nop0))
+            (import "env" "nop2" (func $nop2 (param i32 i64)))
+            (func (export "test") (param $n i32)
+                (loop $continue
+                    (call $nop0)
+                    (call $nop2 (i32.const 0) (i64.const 0))
+                    (br_if $continue
+                        (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+                    )
+                )
+            )
+        )
+    "#;
+    let module = create_module(&store, wasm.as_bytes());
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_func(&store, "test").unwrap();
+    (store, func)
+}
+
+/// Returns the fuel consumed by calling `func` with `n` using `store`.
+fn consumed_fuel_of_host_calls(store: &mut Store<()>, func: Func, n: i32) -> u64 {
+    let func = func.typed::<i32, ()>(&*store).unwrap();
+    store.set_fuel(1_000_000).unwrap();
+    func.call(&mut *store, n).unwrap();
+    1_000_000 - store.get_fuel().unwrap()
+}
+
+#[test]
+fn host_func_fuel_cost_works() {
+    let (mut store, func) = host_call_cost_test_setup();
+    let free = consumed_fuel_of_host_calls(&mut store, func, 10);
+    store.set_host_func_fuel_cost(100, 10);
+    let charged = consumed_fuel_of_host_calls(&mut store, func, 10);
+    // Each iteration calls `nop0` for 100 fuel and `nop2` for 100 + 2 * 10 fuel.
+    assert_eq!(charged - free, 10 * (100 + 120));
+    // Resetting the costs makes host function calls free again.
+    store.set_host_func_fuel_cost(0, 0);
+    assert_eq!(consumed_fuel_of_host_calls(&mut store, func, 10), free);
+}
+
+#[test]
+fn host_func_cost_hook_works() {
+    let (mut store, func) = host_call_cost_test_setup();
+    let free = consumed_fuel_of_host_calls(&mut store, func, 10);
+    store.set_host_func_cost_hook(|func_type| match func_type.params() {
+        [] => 1,
+        [ValType::I32, ValType::I64] => 1_000,
+        _ => panic!("unexpected host function type: {func_type:?}"),
+    });
+    let charged = consumed_fuel_of_host_calls(&mut store, func, 10);
+    assert_eq!(charged - free, 10 * (1 + 1_000));
+}
+
+#[test]
+fn host_func_cost_hook_may_use_engine() {
+    let (mut store, func) = host_call_cost_test_setup();
+    let engine = store.engine().clone();
+    // Compiling a module registers new function types with the `Engine`
+    // which must not deadlock with the charging of host function calls.
+    store.set_host_func_cost_hook(move |func_type| {
+        let wasm = "(module (type (func (param i64 i64 i64) (result f32 f64))))";
+        Module::new(&engine, wasm).unwrap();
+        func_type.params().len() as u64
+    });
+    let free = {
+        let (mut store, func) = host_call_cost_test_setup();
+        consumed_fuel_of_host_calls(&mut store, func, 10)
+    };
+    let charged = consumed_fuel_of_host_calls(&mut store, func, 10);
+    assert_eq!(charged - free, 10 * 2);
+}
+
+#[test]
+fn host_func_fuel_cost_runs_out_of_fuel() {
+    let (mut store, func) = host_call_cost_test_setup();
+    let func = func.typed::<i32, ()>(&store).unwrap();
+    // Without host function costs the fuel suffices.
+    store.set_fuel(10_000).unwrap();
+    assert_success(func.call(&mut store, 100));
+    // With host function costs the same amount of fuel is exceeded.
+    store.set_host_func_fuel_cost(100, 0);
+    store.set_fuel(10_000).unwrap();
+    assert_out_of_fuel(func.call(&mut store, 100));
+}
+
+#[test]
+fn host_func_fuel_cost_skips_host_func_when_out_of_fuel() {
+    let (mut store, mut linker) = test_setup();
+    linker
+        .func_wrap("env", "host", |_: Caller<()>| -> Result<(), Error> {
+            panic!("must not call host function without enough fuel")
+        })
+        .unwrap();
+    let wasm = r#"
+        (module
+            (import "env" "host" (func $host))
+            (func (export "test")
+                (call $host)
+            )
+        )
+    "#;
+    let module = create_module(&store, wasm.as_bytes());
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_typed_func::<(), ()>(&store, "test").unwrap();
+    store.set_host_func_fuel_cost(1_000, 0);
+    store.set_fuel(100).unwrap();
+    assert_out_of_fuel(func.call(&mut store, ()));
+}
+
+#[test]
+fn add_fuel_works() {
+    let (mut store, _linker) = test_setup();
+    assert_eq!(store.add_fuel(10).unwrap(), 10);
+    assert_eq!(store.add_fuel(5).unwrap(), 15);
+    assert_eq!(store.get_fuel().unwrap(), 15);
+    // Adding fuel saturates instead of overflowing.
+    assert_eq!(store.add_fuel(u64::MAX).unwrap(), u64::MAX);
+    assert_eq!(store.add_fuel(1).unwrap(), u64::MAX);
+}
+
+#[test]
+fn consume_fuel_works() {
+    let (mut store, _linker) = test_setup();
+    store.set_fuel(100).unwrap();
+    assert_eq!(store.consume_fuel(30).unwrap(), 70);
+    assert_eq!(store.consume_fuel(70).unwrap(), 0);
+    // Consuming more fuel than remaining fails and leaves the fuel unchanged.
+    store.set_fuel(10).unwrap();
+    assert!(matches!(
+        store.consume_fuel(11).unwrap_err().kind(),
+        ErrorKind::Fuel(FuelError::OutOfFuel),
+    ));
+    assert_eq!(store.get_fuel().unwrap(), 10);
+}
+
+#[test]
+fn add_and_consume_fuel_fail_when_disabled() {
+    let mut store = <Store<()>>::new(&Engine::default(), ());
+    for error in [
+        store.add_fuel(1).unwrap_err(),
+        store.consume_fuel(1).unwrap_err(),
+    ] {
+        assert!(matches!(
+            error.kind(),
+            ErrorKind::Fuel(FuelError::FuelMeteringDisabled),
+        ));
+    }
+}
+
+#[test]
+fn host_func_consume_fuel_works() {
+    let (mut store, mut linker) = test_setup();
+    linker
+        .func_wrap("env", "charge", |mut caller: Caller<()>, delta: i64| {
+            caller.consume_fuel(delta as u64).map(|_| ())
+        })
+        .unwrap();
+    let wasm = r#"
+        (module
+            (import "env" "charge" (func $charge (param i64)))
+            (func (export "test") (param i64)
+                (call $charge (local.get 0))
+            )
+        )
+    "#;
+    let module = create_module(&store, wasm.as_bytes());
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_typed_func::<i64, ()>(&store, "test").unwrap();
+    store.set_fuel(1_000).unwrap();
+    func.call(&mut store, 0).unwrap();
+    let base = 1_000 - store.get_fuel().unwrap();
+    store.set_fuel(1_000).unwrap();
+    func.call(&mut store, 500).unwrap();
+    assert_eq!(store.get_fuel().unwrap(), 1_000 - base - 500);
+    store.set_fuel(1_000).unwrap();
+    let error = func.call(&mut store, 1_000).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Fuel(FuelError::OutOfFuel)
+    ));
+}
+
+#[test]
+fn add_fuel_while_suspended() {
+    let (mut store, mut linker) = test_setup();
+    linker
+        .func_wrap("env", "suspend", || -> Result<(), Error> {
+            Err(Error::new("suspend"))
+        })
+        .unwrap();
+    let wasm = r#"
+        (module
+            (import "env" "suspend" (func $suspend))
+            (func (export "test") (param $n i32)
+                (call $suspend)
+                (loop $continue
+                    (br_if $continue
+                        (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+                    )
+                )
+            )
+        )
+    "#;
+    let module = create_module(&store, wasm.as_bytes());
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_typed_func::<i32, ()>(&store, "test").unwrap();
+    store.set_fuel(100).unwrap();
+    let TypedResumableCall::Resumable(invocation) = func.call_resumable(&mut store, 1_000).unwrap()
+    else {
+        panic!("expected the call to be suspended")
+    };
+    // The remaining fuel is not enough to finish the loop.
+    let remaining = store.get_fuel().unwrap();
+    assert!(remaining < 100);
+    assert_eq!(store.add_fuel(10_000).unwrap(), remaining + 10_000);
+    let result = invocation.resume(&mut store, &[]).unwrap();
+    assert!(matches!(result, TypedResumableCall::Finished(())));
+}
+
+/// A Wasm module that sums up all numbers from `$n` down to 1.
+const SUM: &str = r#"
+    (module
+        (func (export "test") (param $n i64) (result i64)
+            (local $sum i64)
+            (block $exit
+                (loop $continue
+                    (br_if $exit (i64.eqz (local.get $n)))
+                    (local.set $sum (i64.add (local.get $sum) (local.get $n)))
+                    (local.set $n (i64.sub (local.get $n) (i64.const 1)))
+                    (br $continue)
+                )
+            )
+            (local.get $sum)
+        )
+    )
+"#;
+
+#[test]
+fn call_with_fuel_budget_in_installments() {
+    let (mut store, func) = default_test_setup(SUM.as_bytes());
+    let func = func.typed::<i64, i64>(&store).unwrap();
+    let n = 1_000;
+    store.set_fuel(1_000_000).unwrap();
+    assert_eq!(func.call(&mut store, n).unwrap(), n * (n + 1) / 2);
+    let required = 1_000_000 - store.get_fuel().unwrap();
+    let installment = required.div_ceil(3);
+    store.set_fuel(0).unwrap();
+    let mut installments = 0;
+    let result = func.call_with_fuel_budget(&mut store, n, |required_fuel| {
+        assert!(required_fuel > 0);
+        installments += 1;
+        Some(installment)
+    });
+    assert_eq!(result.unwrap(), n * (n + 1) / 2);
+    assert_eq!(installments, 3);
+    assert_eq!(store.get_fuel().unwrap(), 3 * installment - required);
+}
+
+#[test]
+fn call_with_fuel_budget_stops_early() {
+    let (mut store, func) = default_test_setup(SUM.as_bytes());
+    store.set_fuel(0).unwrap();
+    let mut installments = 0;
+    let mut outputs = [Val::I64(0)];
+    let result = func.call_with_fuel_budget(&mut store, &[Val::I64(1_000)], &mut outputs, |_| {
+        installments += 1;
+        (installments == 1).then_some(100)
+    });
+    assert_out_of_fuel(result);
+    assert_eq!(installments, 2);
+    // The fuel of the first installment has been consumed partially.
+    assert!(store.get_fuel().unwrap() < 100);
+}
+
+#[test]
+fn call_with_fuel_budget_stops_without_fuel() {
+    let (mut store, func) = default_test_setup(SUM.as_bytes());
+    store.set_fuel(0).unwrap();
+    let mut installments = 0;
+    let mut outputs = [Val::I64(0)];
+    let result = func.call_with_fuel_budget(&mut store, &[Val::I64(1_000)], &mut outputs, |_| {
+        installments += 1;
+        Some(0)
+    });
+    assert_out_of_fuel(result);
+    assert_eq!(installments, 1);
+}
+
+#[test]
+fn call_with_fuel_budget_returns_host_errors() {
+    let (mut store, mut linker) = test_setup();
+    linker
+        .func_wrap("env", "fail", || -> Result<(), Error> {
+            Err(Error::new("host error"))
+        })
+        .unwrap();
+    let wasm = r#"
+        (module
+            (import "env" "fail" (func $fail))
+            (func (export "test") (param $n i32)
+                (loop $continue
+                    (br_if $continue
+                        (local.tee $n (i32.sub (local.get $n) (i32.const 1)))
+                    )
+                )
+                (call $fail)
+            )
+        )
+    "#;
+    let module = create_module(&store, wasm.as_bytes());
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_typed_func::<i32, ()>(&store, "test").unwrap();
+    store.set_fuel(10).unwrap();
+    let mut installments = 0;
+    let error = func
+        .call_with_fuel_budget(&mut store, 100, |_| {
+            installments += 1;
+            Some(100)
+        })
+        .unwrap_err();
+    assert!(installments > 0);
+    assert!(error.as_trap_code().is_none());
+    assert_eq!(error.to_string(), "host error");
+}
+
+#[test]
+fn out_of_fuel_without_budget_is_a_trap() {
+    let (mut store, func) = default_test_setup(SUM.as_bytes());
+    let func = func.typed::<i64, i64>(&store).unwrap();
+    store.set_fuel(100).unwrap();
+    let error = func.call(&mut store, 1_000).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::OutOfFuel));
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::TrapCode(TrapCode::OutOfFuel)
+    ));
+    let error = func.call_resumable(&mut store, 1_000).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::TrapCode(TrapCode::OutOfFuel)
+    ));
+}
@@ -5,6 +5,7 @@ use core::slice;
 use assert_matches::assert_matches;
 use wasmi::{
     errors::{ErrorKind, FuncError},
+    Caller,
     Engine,
     Func,
     FuncType,
@@ -314,6 +315,31 @@ fn dynamic_many_results_works() {
     )
 }
 
+#[test]
+fn call_boxed_works() {
+    let (mut store, func) = setup_many_results();
+    let results = func.call_boxed(&mut store, &[]).unwrap();
+    assert_eq!(results.len(), 16);
+    for (i, result) in results.iter().enumerate() {
+        assert_eq!(result.i32(), Some(i as i32));
+    }
+    let (mut store, add2, add2_dyn) = setup_add2();
+    let params = [Val::I32(1), Val::I32(2)];
+    for func in [add2, add2_dyn] {
+        let results = func.call_boxed(&mut store, &params).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].i32(), Some(3));
+    }
+    // Type mismatch of the inputs is reported as error.
+    let error = add2
+        .call_boxed(&mut store, &[Val::I64(1), Val::I32(2)])
+        .unwrap_err();
+    assert_matches!(
+        error.kind(),
+        ErrorKind::Func(FuncError::MismatchingParameterType)
+    );
+}
+
 #[test]
 fn static_many_results_works() {
     let (mut store, func) = setup_many_results();
@@ -520,3 +546,145 @@ fn static_type_check_works() {
         ErrorKind::Func(FuncError::MismatchingResultType)
     );
 }
+
+#[test]
+fn downcast_host_fn_works() {
+    /// Downcasts the closure of `func` to the type of `_closure`.
+    fn downcast<'a, F: 'static>(store: &'a Store<()>, func: &Func, _closure: &F) -> Option<&'a F> {
+        func.downcast_host_fn::<(), F>(store)
+    }
+    let mut store = test_setup();
+    let offset = 10;
+    let add = move |lhs: i32, rhs: i32| lhs + rhs + offset;
+    let add_func = Func::wrap(&mut store, add);
+    let with_caller = |_: Caller<()>, input: i32| input;
+    let with_caller_func = Func::wrap(&mut store, with_caller);
+    // Case: the closure type matches exactly.
+    let host_fn = downcast(&store, &add_func, &add).unwrap();
+    assert_eq!(host_fn(1, 2), 13);
+    let host_fn = downcast(&store, &with_caller_func, &with_caller);
+    assert!(host_fn.is_some());
+    // Case: the closure type does not match.
+    assert!(downcast(&store, &add_func, &with_caller).is_none());
+    assert!(downcast(&store, &with_caller_func, &add).is_none());
+    assert!(add_func
+        .downcast_host_fn::<(), fn(i32, i32) -> i32>(&store)
+        .is_none());
+    // Case: host functions created via `Func::new` have no typed closure.
+    let (store, _, add2_dyn) = setup_add2();
+    assert!(add2_dyn
+        .downcast_host_fn::<(), fn(i32, i32) -> i32>(&store)
+        .is_none());
+}
+
+#[test]
+fn downcast_host_fn_ptr_works() {
+    fn add(lhs: i32, rhs: i32) -> i32 {
+        lhs + rhs
+    }
+    let mut store = test_setup();
+    let func = Func::wrap(&mut store, add as fn(i32, i32) -> i32);
+    let host_fn = func
+        .downcast_host_fn::<(), fn(i32, i32) -> i32>(&store)
+        .unwrap();
+    assert_eq!(host_fn(1, 2), 3);
+    assert!(func
+        .downcast_host_fn::<(), fn(i64, i64) -> i64>(&store)
+        .is_none());
+}
+
+#[test]
+fn repeated_typed_works() {
+    let (mut store, add2, add2_dyn) = setup_add2();
+    for _ in 0..3 {
+        let typed = add2.typed::<(i32, i32), i32>(&store).unwrap();
+        assert_eq!(typed.call(&mut store, (1, 2)).unwrap(), 3);
+    }
+    // Different functions of the same type share the cached signature check.
+    let typed = add2_dyn.typed::<(i32, i32), i32>(&store).unwrap();
+    assert_eq!(typed.call(&mut store, (3, 4)).unwrap(), 7);
+}
+
+#[test]
+fn typed_mismatch_after_cached_typed_fails() {
+    let (store, add2, _) = setup_add2();
+    assert!(add2.typed::<(i32, i32), i32>(&store).is_ok());
+    assert!(add2.typed::<(i32, i64), i32>(&store).is_err());
+    assert!(add2.typed::<(i32, i32), i64>(&store).is_err());
+    assert!(add2.typed::<(i32, i32), ()>(&store).is_err());
+    assert!(add2.typed::<(i32, i32), i32>(&store).is_ok());
+    // Functions of a different type must not use the cached check.
+    let mut store = store;
+    let add2_i64 = Func::wrap(&mut store, |lhs: i64, rhs: i64| lhs + rhs);
+    assert!(add2_i64.typed::<(i32, i32), i32>(&store).is_err());
+    assert!(add2_i64.typed::<(i64, i64), i64>(&store).is_ok());
+}
+
+#[test]
+fn store_into_data_works() {
+    let engine = Engine::default();
+    let mut store = <Store<Vec<i32>>>::new(&engine, Vec::new());
+    let push = Func::wrap(&mut store, |mut caller: Caller<Vec<i32>>, value: i32| {
+        caller.data_mut().push(value);
+    });
+    let push = push.typed::<i32, ()>(&store).unwrap();
+    for value in [1, 2, 3] {
+        push.call(&mut store, value).unwrap();
+    }
+    assert_eq!(store.into_data(), [1, 2, 3]);
+}
+
+#[test]
+fn new_boxed_works() {
+    type BoxedHostFn =
+        Box<dyn Fn(Caller<'_, ()>, &[Val], &mut [Val]) -> Result<(), wasmi::Error> + Send + Sync>;
+    let mut store = test_setup();
+    // Host functions of different signatures and closure types stored side by side.
+    let host_fns: Vec<(FuncType, BoxedHostFn)> = vec![
+        (
+            FuncType::new([ValType::I32, ValType::I32], [ValType::I32]),
+            Box::new(|_caller, params, results| {
+                results[0] = Val::I32(params[0].i32().unwrap() + params[1].i32().unwrap());
+                Ok(())
+            }),
+        ),
+        (
+            FuncType::new([ValType::F64], [ValType::F64]),
+            Box::new(|_caller, params, results| {
+                results[0] = Val::F64((-f64::from(params[0].f64().unwrap())).into());
+                Ok(())
+            }),
+        ),
+    ];
+    let funcs = host_fns
+        .into_iter()
+        .map(|(ty, func)| Func::new_boxed(&mut store, ty, func))
+        .collect::<Vec<_>>();
+    let mut results = [Val::I32(0)];
+    funcs[0]
+        .call(&mut store, &[Val::I32(1), Val::I32(2)], &mut results)
+        .unwrap();
+    assert_eq!(results[0].i32(), Some(3));
+    let mut results = [Val::F64(0.0.into())];
+    funcs[1]
+        .call(&mut store, &[Val::F64(1.5.into())], &mut results)
+        .unwrap();
+    assert_eq!(results[0].f64(), Some(F64::from(-1.5)));
+}
+
+#[test]
+fn caller_data_reflects_store_data() {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, 0_i32);
+    let func = Func::wrap(&mut store, |mut caller: Caller<i32>, delta: i32| {
+        *caller.data_mut() += delta;
+        let data = *caller.data();
+        let ctx = caller.into_store_context_mut();
+        assert_eq!(*ctx.data(), data);
+        data
+    });
+    let func = func.typed::<i32, i32>(&store).unwrap();
+    *store.data_mut() = 40;
+    assert_eq!(func.call(&mut store, 2).unwrap(), 42);
+    assert_eq!(*store.data(), 42);
+}
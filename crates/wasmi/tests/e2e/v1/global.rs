@@ -0,0 +1,203 @@
+//! Tests to check if host created [`Global`] variables of reference types work as intended.
+
+use wasmi::{
+    core::ValType,
+    errors::GlobalError,
+    ExternRef,
+    FuncRef,
+    Global,
+    Instance,
+    Linker,
+    Module,
+    Mutability,
+    Store,
+    Val,
+};
+
+/// The Wasm module used by the tests.
+///
+/// Imports a mutable `funcref` and a mutable `externref` global variable
+/// and exports functions to read and write them from within Wasm.
+const WASM: &str = r#"
+    (module
+        (import "env" "funcref" (global $funcref (mut funcref)))
+        (import "env" "externref" (global $externref (mut externref)))
+        (func $f (export "f") (result i32) (i32.const 42))
+        (elem declare func $f)
+        (func (export "get_funcref") (result funcref)
+            (global.get $funcref)
+        )
+        (func (export "set_funcref")
+            (global.set $funcref (ref.func $f))
+        )
+        (func (export "clear_funcref")
+            (global.set $funcref (ref.null func))
+        )
+        (func (export "funcref_is_null") (result i32)
+            (ref.is_null (global.get $funcref))
+        )
+        (func (export "get_externref") (result externref)
+            (global.get $externref)
+        )
+        (func (export "set_externref") (param externref)
+            (global.set $externref (local.get 0))
+        )
+        (func (export "externref_is_null") (result i32)
+            (ref.is_null (global.get $externref))
+        )
+    )
+"#;
+
+/// Instantiates [`WASM`] with host created `funcref` and `externref` global variables.
+fn setup() -> (Store<()>, Instance, Global, Global) {
+    let mut store = <Store<()>>::default();
+    let module = Module::new(store.engine(), WASM).unwrap();
+    let funcref = Global::new(&mut store, Val::FuncRef(FuncRef::null()), Mutability::Var);
+    let externref = Global::new(
+        &mut store,
+        Val::ExternRef(ExternRef::null()),
+        Mutability::Var,
+    );
+    let mut linker = <Linker<()>>::new(store.engine());
+    linker.define("env", "funcref", funcref).unwrap();
+    linker.define("env", "externref", externref).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance, funcref, externref)
+}
+
+#[test]
+fn funcref_global_works() {
+    let (mut store, instance, global, _) = setup();
+    let set = instance
+        .get_typed_func::<(), ()>(&store, "set_funcref")
+        .unwrap();
+    let clear = instance
+        .get_typed_func::<(), ()>(&store, "clear_funcref")
+        .unwrap();
+    let is_null = instance
+        .get_typed_func::<(), i32>(&store, "funcref_is_null")
+        .unwrap();
+    let get = instance
+        .get_typed_func::<(), FuncRef>(&store, "get_funcref")
+        .unwrap();
+    assert_eq!(is_null.call(&mut store, ()).unwrap(), 1);
+    assert!(global.get(&store).funcref().unwrap().is_null());
+    // The host observes the function reference written by Wasm.
+    set.call(&mut store, ()).unwrap();
+    let func = *global.get(&store).funcref().unwrap().func().unwrap();
+    let result = func.typed::<(), i32>(&store).unwrap().call(&mut store, ());
+    assert_eq!(result.unwrap(), 42);
+    // Wasm observes the `null` function reference written by the host.
+    global
+        .set(&mut store, Val::FuncRef(FuncRef::null()))
+        .unwrap();
+    assert_eq!(is_null.call(&mut store, ()).unwrap(), 1);
+    assert!(get.call(&mut store, ()).unwrap().is_null());
+    // Wasm observes the function reference written by the host.
+    let f = instance.get_func(&store, "f").unwrap();
+    global
+        .set(&mut store, Val::FuncRef(FuncRef::new(f)))
+        .unwrap();
+    assert_eq!(is_null.call(&mut store, ()).unwrap(), 0);
+    assert!(!get.call(&mut store, ()).unwrap().is_null());
+    // The host observes the `null` function reference written by Wasm.
+    clear.call(&mut store, ()).unwrap();
+    assert!(global.get(&store).funcref().unwrap().is_null());
+}
+
+#[test]
+fn externref_global_works() {
+    let (mut store, instance, _, global) = setup();
+    let set = instance
+        .get_typed_func::<ExternRef, ()>(&store, "set_externref")
+        .unwrap();
+    let get = instance
+        .get_typed_func::<(), ExternRef>(&store, "get_externref")
+        .unwrap();
+    let is_null = instance
+        .get_typed_func::<(), i32>(&store, "externref_is_null")
+        .unwrap();
+    assert_eq!(is_null.call(&mut store, ()).unwrap(), 1);
+    // Wasm observes the external reference written by the host.
+    let object = ExternRef::new(&mut store, 42_i32);
+    global.set(&mut store, Val::ExternRef(object)).unwrap();
+    assert_eq!(is_null.call(&mut store, ()).unwrap(), 0);
+    let value = get.call(&mut store, ()).unwrap();
+    assert_eq!(value.data(&store).unwrap().downcast_ref::<i32>(), Some(&42));
+    // The host observes the `null` external reference written by Wasm.
+    set.call(&mut store, ExternRef::null()).unwrap();
+    assert!(global.get(&store).externref().unwrap().is_null());
+    // The host observes the external reference written by Wasm.
+    let object = ExternRef::new(&mut store, "hello");
+    set.call(&mut store, object).unwrap();
+    let value = *global.get(&store).externref().unwrap();
+    assert_eq!(
+        value.data(&store).unwrap().downcast_ref::<&str>(),
+        Some(&"hello")
+    );
+    // Wasm observes the `null` external reference written by the host.
+    global
+        .set(&mut store, Val::ExternRef(ExternRef::null()))
+        .unwrap();
+    assert_eq!(is_null.call(&mut store, ()).unwrap(), 1);
+}
+
+#[test]
+fn ref_global_type_mismatch() {
+    let (mut store, _, funcref, externref) = setup();
+    // Null references only match the reference type they were created for.
+    assert!(matches!(
+        funcref.set(&mut store, Val::ExternRef(ExternRef::null())),
+        Err(GlobalError::TypeMismatch {
+            expected: ValType::FuncRef,
+            encountered: ValType::ExternRef,
+        })
+    ));
+    assert!(matches!(
+        externref.set(&mut store, Val::FuncRef(FuncRef::null())),
+        Err(GlobalError::TypeMismatch {
+            expected: ValType::ExternRef,
+            encountered: ValType::FuncRef,
+        })
+    ));
+    assert!(matches!(
+        funcref.set(&mut store, Val::I32(0)),
+        Err(GlobalError::TypeMismatch { .. })
+    ));
+    // Failed writes leave the global variables untouched.
+    assert!(funcref.get(&store).funcref().unwrap().is_null());
+    assert!(externref.get(&store).externref().unwrap().is_null());
+}
+
+#[test]
+fn immutable_ref_global_works() {
+    let mut store = <Store<()>>::default();
+    let object = ExternRef::new(&mut store, 1_u8);
+    let global = Global::new(&mut store, Val::ExternRef(object), Mutability::Const);
+    assert_eq!(global.ty(&store).content(), ValType::ExternRef);
+    assert!(matches!(
+        global.set(&mut store, Val::ExternRef(ExternRef::null())),
+        Err(GlobalError::ImmutableWrite)
+    ));
+    let value = *global.get(&store).externref().unwrap();
+    assert_eq!(value.data(&store).unwrap().downcast_ref::<u8>(), Some(&1));
+}
+
+#[test]
+fn cross_store_ref_global_set_fails() {
+    let (mut store, instance, funcref, _) = setup();
+    let (mut other, ..) = setup();
+    let f = instance.get_func(&store, "f").unwrap();
+    let other_global = Global::new(&mut other, Val::FuncRef(FuncRef::null()), Mutability::Var);
+    assert!(matches!(
+        other_global.set(&mut other, Val::FuncRef(FuncRef::new(f))),
+        Err(GlobalError::CrossStore)
+    ));
+    assert!(funcref
+        .set(&mut store, Val::FuncRef(FuncRef::new(f)))
+        .is_ok());
+}
@@ -1,7 +1,7 @@
 //! Test to assert that host functions that call back into
 //! Wasm works correctly.
 
-use wasmi::{Caller, Engine, Extern, Func, Linker, Module, Store};
+use wasmi::{Caller, Engine, Extern, Func, Instance, Linker, Module, Store};
 
 fn test_setup() -> (Store<()>, Linker<()>) {
     let engine = Engine::default();
@@ -54,3 +54,68 @@ fn host_calls_wasm() {
     let result = wasm_fn.call(&mut store, input).unwrap();
     assert_eq!(result, expected);
 }
+
+#[test]
+fn host_calls_wasm_via_caller_instance() {
+    let (mut store, mut linker) = test_setup();
+    let host_fn = Func::wrap(&mut store, |mut caller: Caller<()>, input: i32| -> i32 {
+        let instance = caller.instance().unwrap();
+        let add = instance
+            .get_typed_func::<(i32, i32), i32>(&caller, "add")
+            .unwrap();
+        let square = instance
+            .get_typed_func::<i32, i32>(&caller, "square")
+            .unwrap();
+        let sum = add.call(&mut caller, (input, input)).unwrap();
+        square.call(&mut caller, sum).unwrap()
+    });
+    linker.define("env", "host_fn", host_fn).unwrap();
+    let wasm = r#"
+        (module
+            (import "env" "host_fn" (func $host_fn (param i32) (result i32)))
+            (func (export "wasm_fn") (param i32) (result i32)
+                (call $host_fn (local.get 0))
+            )
+            (func (export "add") (param i32 i32) (result i32)
+                (i32.add
+                    (local.get 0)
+                    (local.get 1)
+                )
+            )
+            (func (export "square") (param i32) (result i32)
+                (i32.mul
+                    (local.get 0)
+                    (local.get 0)
+                )
+            )
+        )
+        "#;
+    let module = Module::new(store.engine(), wasm).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let wasm_fn = instance
+        .get_typed_func::<i32, i32>(&store, "wasm_fn")
+        .unwrap();
+    let input = 5;
+    let expected = (input + input) * (input + input);
+    let result = wasm_fn.call(&mut store, input).unwrap();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn caller_instance_is_none_for_host_calls() {
+    let (mut store, _linker) = test_setup();
+    let host_fn = Func::wrap(&mut store, |caller: Caller<()>| -> i32 {
+        let instance: Option<Instance> = caller.instance();
+        i32::from(instance.is_some())
+    });
+    let result = host_fn
+        .typed::<(), i32>(&store)
+        .unwrap()
+        .call(&mut store, ())
+        .unwrap();
+    assert_eq!(result, 0);
+}
@@ -0,0 +1,276 @@
+//! Tests to check if `Config::lazy_table_init` is indistinguishable from eager table initialization.
+
+use wasmi::{
+    errors::{ErrorKind, InstantiationError},
+    Config,
+    Engine,
+    Func,
+    Instance,
+    Linker,
+    Module,
+    Store,
+    Table,
+    Val,
+};
+
+/// The Wasm module used by the tests.
+///
+/// - The `$t` table is initialized by two overlapping active element segments.
+/// - The `$e` passive element segment is used by `table.init`.
+const WASM: &str = r#"
+    (module
+        (type $f (func (result i32)))
+        (table $t (export "table") 12 funcref)
+        (func $f0 (result i32) (i32.const 0))
+        (func $f1 (result i32) (i32.const 1))
+        (func $f2 (result i32) (i32.const 2))
+        (func $f3 (result i32) (i32.const 3))
+        (func $f4 (result i32) (i32.const 4))
+        (func $f5 (result i32) (i32.const 5))
+        (elem (table $t) (i32.const 1) func $f0 $f1 $f2 $f3 $f4)
+        (elem (table $t) (i32.const 4) funcref (ref.func $f5) (ref.null func) (ref.func $f5))
+        (elem $e func $f3 $f2 $f1)
+        ;; Returns the result of the function at `index` or -1 for `null` elements.
+        (func (export "call") (param $index i32) (result i32)
+            (if (result i32) (ref.is_null (table.get $t (local.get $index)))
+                (then (i32.const -1))
+                (else (call_indirect $t (type $f) (local.get $index)))
+            )
+        )
+        (func (export "set") (param $dst i32) (param $src i32)
+            (table.set $t (local.get $dst) (table.get $t (local.get $src)))
+        )
+        (func (export "copy") (param $dst i32) (param $src i32) (param $len i32)
+            (table.copy $t $t (local.get $dst) (local.get $src) (local.get $len))
+        )
+        (func (export "fill_null") (param $dst i32) (param $len i32)
+            (table.fill $t (local.get $dst) (ref.null func) (local.get $len))
+        )
+        (func (export "init") (param $dst i32) (param $src i32) (param $len i32)
+            (table.init $t $e (local.get $dst) (local.get $src) (local.get $len))
+        )
+        (func (export "grow") (param $len i32) (result i32)
+            (table.grow $t (ref.null func) (local.get $len))
+        )
+    )
+"#;
+
+/// Instantiates [`WASM`] with lazy table initialization enabled or disabled.
+fn setup(lazy: bool) -> (Store<()>, Instance) {
+    let mut config = Config::default();
+    config.lazy_table_init(lazy);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, WASM).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Returns the results of calling each table element via `call_indirect`.
+fn call_all(store: &mut Store<()>, instance: Instance) -> Vec<i32> {
+    let call = instance
+        .get_typed_func::<i32, i32>(&*store, "call")
+        .unwrap();
+    let table = instance.get_table(&*store, "table").unwrap();
+    (0..table.size(&*store))
+        .map(|index| call.call(&mut *store, index as i32).unwrap())
+        .collect()
+}
+
+/// Returns the results of calling each table element via [`Table::get_func`].
+fn host_call_all(store: &mut Store<()>, table: Table) -> Vec<i32> {
+    (0..table.size(&*store))
+        .map(|index| {
+            let funcref = table.get_func(&*store, index).unwrap();
+            match funcref.func() {
+                Some(func) => func
+                    .typed::<(), i32>(&*store)
+                    .unwrap()
+                    .call(&mut *store, ())
+                    .unwrap(),
+                None => -1,
+            }
+        })
+        .collect()
+}
+
+/// An operation on the table of the [`WASM`] instance.
+#[derive(Debug, Copy, Clone)]
+enum Op {
+    Call,
+    Set(i32, i32),
+    Copy(i32, i32, i32),
+    FillNull(i32, i32),
+    Init(i32, i32, i32),
+    Grow(i32),
+    HostSet(u32, Option<u32>),
+    HostCopy(u32, u32, u32),
+}
+
+/// Applies `op` and returns a log of its observable effects.
+fn apply(store: &mut Store<()>, instance: Instance, op: Op) -> String {
+    let table = instance.get_table(&*store, "table").unwrap();
+    let result = match op {
+        Op::Call => return format!("{:?}", call_all(store, instance)),
+        Op::Set(dst, src) => instance
+            .get_typed_func::<(i32, i32), ()>(&*store, "set")
+            .unwrap()
+            .call(&mut *store, (dst, src))
+            .map_err(|error| error.to_string()),
+        Op::Copy(dst, src, len) => instance
+            .get_typed_func::<(i32, i32, i32), ()>(&*store, "copy")
+            .unwrap()
+            .call(&mut *store, (dst, src, len))
+            .map_err(|error| error.to_string()),
+        Op::FillNull(dst, len) => instance
+            .get_typed_func::<(i32, i32), ()>(&*store, "fill_null")
+            .unwrap()
+            .call(&mut *store, (dst, len))
+            .map_err(|error| error.to_string()),
+        Op::Init(dst, src, len) => instance
+            .get_typed_func::<(i32, i32, i32), ()>(&*store, "init")
+            .unwrap()
+            .call(&mut *store, (dst, src, len))
+            .map_err(|error| error.to_string()),
+        Op::Grow(len) => instance
+            .get_typed_func::<i32, i32>(&*store, "grow")
+            .unwrap()
+            .call(&mut *store, len)
+            .map(|_| ())
+            .map_err(|error| error.to_string()),
+        Op::HostSet(dst, src) => {
+            let value = match src {
+                Some(src) => table.get(&*store, src).unwrap(),
+                None => Val::FuncRef(Func::wrap(&mut *store, || 42_i32).into()),
+            };
+            table
+                .set(&mut *store, dst, value)
+                .map_err(|error| error.to_string())
+        }
+        Op::HostCopy(dst, src, len) => Table::copy(&mut *store, &table, dst, &table, src, len)
+            .map_err(|error| error.to_string()),
+    };
+    format!("{result:?} {:?}", host_call_all(store, table))
+}
+
+/// Applies all `ops` with lazy and eager table initialization and asserts identical effects.
+fn assert_indistinguishable(ops: &[Op]) {
+    let (mut lazy_store, lazy) = setup(true);
+    let (mut eager_store, eager) = setup(false);
+    assert_eq!(
+        call_all(&mut lazy_store, lazy),
+        [-1, 0, 1, 2, 5, -1, 5, -1, -1, -1, -1, -1]
+    );
+    for op in ops {
+        assert_eq!(
+            apply(&mut lazy_store, lazy, *op),
+            apply(&mut eager_store, eager, *op),
+            "mismatch for {op:?}",
+        );
+    }
+}
+
+#[test]
+fn lazy_init_reads() {
+    assert_indistinguishable(&[Op::Call]);
+}
+
+#[test]
+fn lazy_init_set() {
+    assert_indistinguishable(&[
+        Op::Set(0, 2),
+        Op::Set(2, 0),
+        Op::Set(5, 4),
+        Op::Set(12, 0),
+        Op::HostSet(3, None),
+        Op::HostSet(6, Some(1)),
+        Op::HostSet(12, Some(1)),
+        Op::Call,
+    ]);
+}
+
+#[test]
+fn lazy_init_copy() {
+    assert_indistinguishable(&[
+        Op::Copy(0, 1, 3),
+        Op::Copy(3, 1, 4),
+        Op::Copy(8, 2, 4),
+        Op::Copy(10, 0, 3),
+        Op::HostCopy(7, 4, 3),
+        Op::HostCopy(11, 0, 2),
+        Op::Call,
+    ]);
+}
+
+#[test]
+fn lazy_init_fill_init_grow() {
+    assert_indistinguishable(&[
+        Op::FillNull(2, 2),
+        Op::FillNull(11, 2),
+        Op::Init(5, 0, 3),
+        Op::Init(10, 0, 3),
+        Op::Grow(3),
+        Op::Copy(12, 1, 3),
+        Op::Call,
+    ]);
+}
+
+#[test]
+fn lazy_init_many_fragments() {
+    // Fragmenting the lazily initialized elements many times eventually
+    // materializes them which still must be indistinguishable.
+    let mut ops = Vec::new();
+    for _ in 0..20 {
+        for index in [1, 3, 5] {
+            ops.push(Op::Set(index, index + 1));
+            ops.push(Op::Copy(index + 1, index, 1));
+        }
+    }
+    ops.push(Op::Call);
+    assert_indistinguishable(&ops);
+}
+
+#[test]
+fn lazy_init_snapshot() {
+    let (mut store, instance) = setup(true);
+    let snapshot = store.snapshot();
+    apply(&mut store, instance, Op::FillNull(0, 12));
+    assert!(call_all(&mut store, instance).iter().all(|&n| n == -1));
+    store.restore(&snapshot).unwrap();
+    assert_eq!(
+        call_all(&mut store, instance),
+        [-1, 0, 1, 2, 5, -1, 5, -1, -1, -1, -1, -1]
+    );
+}
+
+#[test]
+fn lazy_init_out_of_bounds_segment() {
+    let wasm = r#"
+        (module
+            (table 4 funcref)
+            (func $f)
+            (elem (i32.const 0) func $f)
+            (elem (i32.const 2) func $f $f $f)
+        )
+    "#;
+    let mut config = Config::default();
+    config.lazy_table_init(true);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, wasm).unwrap();
+    let error = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Instantiation(InstantiationError::ElementSegmentDoesNotFit {
+            offset: 2,
+            amount: 3,
+            ..
+        })
+    ));
+}
@@ -1,9 +1,28 @@
+mod bulk_memory;
+mod call_depth;
 mod call_hook;
+mod call_indirect_cache;
+mod deny_floating_point;
+mod engine_config;
 mod fuel_consumption;
 mod fuel_metering;
 mod func;
+mod global;
 mod host_call_compilation;
 mod host_call_instantiation;
 mod host_calls_wasm;
+mod lazy_table_init;
+mod module_exports;
+mod module_interface;
+mod module_options;
+mod module_resources;
+#[cfg(feature = "profiling")]
+mod profiling;
 mod resource_limiter;
 mod resumable_call;
+mod stack_overflow;
+mod stack_recycler;
+mod store_clone;
+mod store_snapshot;
+mod table;
+mod trap_codes;
@@ -0,0 +1,134 @@
+//! Tests to check that the filtered export iterators of `Module` yield the right exports.
+
+use wasmi::{
+    core::ValType,
+    Engine,
+    FuncType,
+    GlobalType,
+    Linker,
+    MemoryType,
+    Module,
+    Mutability,
+    Store,
+    TableType,
+};
+
+/// Returns the [`Module`] used by the tests exporting items of all kinds.
+fn module() -> Module {
+    let wasm = r#"
+        (module
+            (import "env" "imported" (func $imported (param i32)))
+            (func $add (export "add") (param i32 i32) (result i32)
+                (i32.add (local.get 0) (local.get 1))
+            )
+            (func $nop (export "nop"))
+            (memory $mem (export "mem") 1 2)
+            (table $funcs (export "funcs") 1 funcref)
+            (table $externs (export "externs") 2 10 externref)
+            (global $c (export "c") i64 (i64.const 0))
+            (global $m (export "m") (mut f32) (f32.const 0))
+            (export "reexported" (func $imported))
+        )
+    "#;
+    let engine = Engine::default();
+    Module::new(&engine, wasm).unwrap()
+}
+
+/// Returns the items of `iter` sorted by their names.
+fn sorted<'a, T>(iter: impl Iterator<Item = (&'a str, T)>) -> Vec<(&'a str, T)> {
+    let mut items = iter.collect::<Vec<_>>();
+    items.sort_by(|lhs, rhs| lhs.0.cmp(rhs.0));
+    items
+}
+
+#[test]
+fn func_exports() {
+    let module = module();
+    assert_eq!(
+        sorted(module.func_exports()),
+        [
+            (
+                "add",
+                FuncType::new([ValType::I32, ValType::I32], [ValType::I32])
+            ),
+            ("nop", FuncType::new([], [])),
+            ("reexported", FuncType::new([ValType::I32], [])),
+        ]
+    );
+}
+
+#[test]
+fn table_exports() {
+    let module = module();
+    assert_eq!(
+        sorted(module.table_exports()),
+        [
+            ("externs", TableType::new(ValType::ExternRef, 2, Some(10))),
+            ("funcs", TableType::new(ValType::FuncRef, 1, None)),
+        ]
+    );
+}
+
+#[test]
+fn memory_exports() {
+    let module = module();
+    assert_eq!(
+        sorted(module.memory_exports()),
+        [("mem", MemoryType::new(1, Some(2)).unwrap())]
+    );
+}
+
+#[test]
+fn global_exports() {
+    let module = module();
+    assert_eq!(
+        sorted(module.global_exports()),
+        [
+            ("c", GlobalType::new(ValType::I64, Mutability::Const)),
+            ("m", GlobalType::new(ValType::F32, Mutability::Var)),
+        ]
+    );
+}
+
+#[test]
+fn filtered_exports_cover_all_exports() {
+    let module = module();
+    let len_filtered = module.func_exports().count()
+        + module.table_exports().count()
+        + module.memory_exports().count()
+        + module.global_exports().count();
+    assert_eq!(len_filtered, module.exports().count());
+}
+
+#[test]
+fn instance_exports_have_direct_types() {
+    let module = module();
+    let mut store = Store::new(module.engine(), ());
+    let mut linker = <Linker<()>>::new(module.engine());
+    linker.func_wrap("env", "imported", |_: i32| {}).unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let add = instance.get_func(&store, "add").unwrap();
+    assert_eq!(
+        add.func_type(&store),
+        FuncType::new([ValType::I32, ValType::I32], [ValType::I32])
+    );
+    let mem = instance.get_memory(&store, "mem").unwrap();
+    assert_eq!(
+        mem.memory_type(&store),
+        MemoryType::new(1, Some(2)).unwrap()
+    );
+    let externs = instance.get_table(&store, "externs").unwrap();
+    assert_eq!(
+        externs.table_type(&store),
+        TableType::new(ValType::ExternRef, 2, Some(10))
+    );
+    let m = instance.get_global(&store, "m").unwrap();
+    assert_eq!(
+        m.global_type(&store),
+        GlobalType::new(ValType::F32, Mutability::Var)
+    );
+}
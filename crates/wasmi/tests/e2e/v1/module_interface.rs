@@ -0,0 +1,147 @@
+//! Tests to check that `Module::interface` describes imports and exports of a Wasm module.
+
+use wasmi::{
+    core::ValType,
+    Engine,
+    ExternType,
+    FuncType,
+    GlobalType,
+    MemoryType,
+    Module,
+    ModuleInterface,
+    Mutability,
+    TableType,
+};
+
+/// Returns the [`ModuleInterface`] of the Wasm module used by the tests.
+///
+/// # Note
+///
+/// Imports are grouped by their kind: functions, tables, memories and globals.
+fn interface() -> ModuleInterface {
+    let wasm = r#"
+        (module
+            (import "env" "log" (func $log (param i32 i64)))
+            (import "env" "counter" (global $counter (mut i32)))
+            (import "env" "mem" (memory 1 2))
+            (import "host" "table" (table 2 funcref))
+            (global $g f64 (f64.const 0))
+            (table $t 1 10 externref)
+            (func $run (export "run") (param f32) (result i32 f64)
+                (i32.const 0)
+                (f64.const 0)
+            )
+            (export "mem" (memory 0))
+            (export "g" (global $g))
+            (export "t" (table $t))
+        )
+    "#;
+    let engine = Engine::default();
+    Module::new(&engine, wasm).unwrap().interface()
+}
+
+#[test]
+fn imports_and_exports() {
+    let interface = interface();
+    let imports = interface
+        .imports()
+        .iter()
+        .map(|import| (import.module(), import.name(), import.ty().clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        imports,
+        [
+            (
+                "env",
+                "log",
+                ExternType::Func(FuncType::new([ValType::I32, ValType::I64], []))
+            ),
+            (
+                "host",
+                "table",
+                ExternType::Table(TableType::new(ValType::FuncRef, 2, None))
+            ),
+            (
+                "env",
+                "mem",
+                ExternType::Memory(MemoryType::new(1, Some(2)).unwrap())
+            ),
+            (
+                "env",
+                "counter",
+                ExternType::Global(GlobalType::new(ValType::I32, Mutability::Var))
+            ),
+        ]
+    );
+    let exports = interface
+        .exports()
+        .iter()
+        .map(|export| (export.name(), export.ty().clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        exports,
+        [
+            (
+                "g",
+                ExternType::Global(GlobalType::new(ValType::F64, Mutability::Const))
+            ),
+            (
+                "mem",
+                ExternType::Memory(MemoryType::new(1, Some(2)).unwrap())
+            ),
+            (
+                "run",
+                ExternType::Func(FuncType::new([ValType::F32], [ValType::I32, ValType::F64]))
+            ),
+            (
+                "t",
+                ExternType::Table(TableType::new(ValType::ExternRef, 1, Some(10)))
+            ),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_roundtrip() {
+    let interface = interface();
+    let json = serde_json::to_value(&interface).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "imports": [
+                { "module": "env", "name": "log", "ty": { "kind": "func", "params": ["i32", "i64"], "results": [] } },
+                { "module": "host", "name": "table", "ty": { "kind": "table", "element": "funcref", "minimum": 2, "maximum": null } },
+                { "module": "env", "name": "mem", "ty": { "kind": "memory", "minimum": 1, "maximum": 2 } },
+                { "module": "env", "name": "counter", "ty": { "kind": "global", "content": "i32", "mutable": true } },
+            ],
+            "exports": [
+                { "name": "g", "ty": { "kind": "global", "content": "f64", "mutable": false } },
+                { "name": "mem", "ty": { "kind": "memory", "minimum": 1, "maximum": 2 } },
+                { "name": "run", "ty": { "kind": "func", "params": ["f32"], "results": ["i32", "f64"] } },
+                { "name": "t", "ty": { "kind": "table", "element": "externref", "minimum": 1, "maximum": 10 } },
+            ],
+        })
+    );
+    let decoded: ModuleInterface = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded, interface);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_rejects_invalid_types() {
+    let invalid_table = serde_json::json!({
+        "imports": [],
+        "exports": [
+            { "name": "t", "ty": { "kind": "table", "element": "i32", "minimum": 0, "maximum": null } },
+        ],
+    });
+    assert!(serde_json::from_value::<ModuleInterface>(invalid_table).is_err());
+    let invalid_limits = serde_json::json!({
+        "imports": [],
+        "exports": [
+            { "name": "t", "ty": { "kind": "table", "element": "funcref", "minimum": 2, "maximum": 1 } },
+        ],
+    });
+    assert!(serde_json::from_value::<ModuleInterface>(invalid_limits).is_err());
+}
@@ -0,0 +1,162 @@
+//! Tests for per-module overrides of the `Config` via `Module::new_with`.
+
+use wasmi::{
+    errors::{EnforcedLimitKind, EnforcedLimitsError, ErrorKind, ModuleOptionsError},
+    CompilationMode,
+    Config,
+    EnforcedLimits,
+    Engine,
+    Linker,
+    Module,
+    ModuleOptions,
+    Store,
+};
+
+/// A Wasm module with an invalid function body that is never called.
+const INVALID_FUNC_BODY: &str = r#"
+    (module
+        (func (export "valid") (result i32)
+            (i32.const 1)
+        )
+        (func (export "invalid") (result i32)
+            (i64.const 1)
+        )
+    )
+"#;
+
+/// A Wasm module with three global variables.
+const THREE_GLOBALS: &str = r#"
+    (module
+        (global i32 (i32.const 1))
+        (global i32 (i32.const 2))
+        (global i32 (i32.const 3))
+    )
+"#;
+
+/// Creates an [`Engine`] with the given [`CompilationMode`] and [`EnforcedLimits`].
+fn engine_with(mode: CompilationMode, limits: EnforcedLimits) -> Engine {
+    let mut config = Config::default();
+    config.compilation_mode(mode);
+    config.enforced_limits(limits);
+    Engine::new(&config)
+}
+
+/// Returns [`EnforcedLimits`] with the given maximum number of global variables.
+fn max_globals(limit: Option<u32>) -> EnforcedLimits {
+    let mut limits = EnforcedLimits::default();
+    limits.max_globals(limit);
+    limits
+}
+
+#[test]
+fn lazy_engine_eager_module() {
+    let engine = engine_with(CompilationMode::Lazy, EnforcedLimits::default());
+    // The lazy engine defers validation of function bodies until their first use.
+    Module::new(&engine, INVALID_FUNC_BODY).unwrap();
+    // The eager module validates all function bodies upon creation.
+    let options = ModuleOptions {
+        compilation_mode: Some(CompilationMode::Eager),
+        ..ModuleOptions::default()
+    };
+    let error = Module::new_with(&engine, INVALID_FUNC_BODY, options).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::Wasm(_)));
+    let options = ModuleOptions {
+        compilation_mode: Some(CompilationMode::LazyTranslation),
+        ..ModuleOptions::default()
+    };
+    let error = Module::new_with(&engine, INVALID_FUNC_BODY, options).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::Wasm(_)));
+}
+
+#[test]
+fn lazier_compilation_mode_is_rejected() {
+    let wasm = "(module (func))";
+    let engine = engine_with(CompilationMode::LazyTranslation, EnforcedLimits::default());
+    let options = ModuleOptions {
+        compilation_mode: Some(CompilationMode::Lazy),
+        ..ModuleOptions::default()
+    };
+    let error = Module::new_with(&engine, wasm, options).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::ModuleOptions(ModuleOptionsError::LazierCompilationMode {
+            engine: CompilationMode::LazyTranslation,
+            module: CompilationMode::Lazy,
+        })
+    ));
+    for mode in [CompilationMode::LazyTranslation, CompilationMode::Eager] {
+        let options = ModuleOptions {
+            compilation_mode: Some(mode),
+            ..ModuleOptions::default()
+        };
+        Module::new_with(&engine, wasm, options).unwrap();
+    }
+}
+
+#[test]
+fn tighter_limits_are_enforced() {
+    let engine = engine_with(CompilationMode::Eager, max_globals(Some(3)));
+    Module::new(&engine, THREE_GLOBALS).unwrap();
+    let options = ModuleOptions {
+        enforced_limits: Some(max_globals(Some(2))),
+        ..ModuleOptions::default()
+    };
+    let error = Module::new_with(&engine, THREE_GLOBALS, options).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyGlobals { limit: 2, value: 3 })
+    ));
+}
+
+#[test]
+fn looser_limits_are_rejected() {
+    let engine = engine_with(CompilationMode::Eager, max_globals(Some(2)));
+    for limits in [max_globals(Some(3)), max_globals(None)] {
+        let options = ModuleOptions {
+            enforced_limits: Some(limits),
+            ..ModuleOptions::default()
+        };
+        let error = Module::new_with(&engine, THREE_GLOBALS, options).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorKind::ModuleOptions(ModuleOptionsError::LooserLimit {
+                kind: EnforcedLimitKind::Globals,
+            })
+        ));
+    }
+}
+
+#[test]
+fn tighter_br_table_limit_with_lazy_translation() {
+    let wasm = r#"
+        (module
+            (func (export "br_table") (param i32)
+                (block (block (block
+                    (br_table 0 1 2 (local.get 0))
+                )))
+            )
+        )
+    "#;
+    let engine = engine_with(CompilationMode::Lazy, EnforcedLimits::default());
+    let mut limits = EnforcedLimits::default();
+    limits.max_br_table_targets(Some(1));
+    let options = ModuleOptions {
+        compilation_mode: Some(CompilationMode::Lazy),
+        enforced_limits: Some(limits),
+    };
+    // The `br_table` limit is enforced during translation which is deferred until the first call.
+    let module = Module::new_with(&engine, wasm, options).unwrap();
+    let mut store = <Store<()>>::new(&engine, ());
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .and_then(|pre| pre.start(&mut store))
+        .unwrap();
+    let func = instance
+        .get_typed_func::<i32, ()>(&store, "br_table")
+        .unwrap();
+    let error = func.call(&mut store, 0).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Limits(EnforcedLimitsError::TooManyBrTableTargets { limit: 1, value: 2 })
+    ));
+}
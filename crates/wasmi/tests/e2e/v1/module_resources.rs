@@ -0,0 +1,69 @@
+//! Tests to check that `Module::resources` summarizes the resources of a Wasm module.
+
+use wasmi::{core::ValType, Engine, MemoryType, Module, TableType};
+
+#[test]
+fn resources_of_fixture() {
+    let wasm = r#"
+        (module
+            (import "env" "f0" (func))
+            (import "env" "f1" (func (param i32)))
+            (import "env" "mem" (memory 1))
+            (import "env" "table" (table 1 funcref))
+            (import "env" "g" (global i32))
+            (memory 2 10)
+            (table $t 3 funcref)
+            (table 5 20 externref)
+            (global i32 (i32.const 0))
+            (global (mut i64) (i64.const 0))
+            (global f32 (f32.const 0))
+            (func $a)
+            (func $b (param i32))
+            (data (memory 1) (i32.const 0) "hello")
+            (data "passive")
+            (elem (table $t) (i32.const 0) func $a $b)
+            (elem funcref (ref.func $a))
+            (elem declare func $b)
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+    let resources = module.resources();
+    assert_eq!(
+        resources.memories(),
+        [MemoryType::new(2, Some(10)).unwrap()]
+    );
+    assert_eq!(
+        resources.tables(),
+        [
+            TableType::new(ValType::FuncRef, 3, None),
+            TableType::new(ValType::ExternRef, 5, Some(20)),
+        ]
+    );
+    assert_eq!(resources.len_funcs(), 2);
+    assert_eq!(resources.len_globals(), 3);
+    assert_eq!(resources.len_imported_funcs(), 2);
+    assert_eq!(resources.len_imported_memories(), 1);
+    assert_eq!(resources.len_imported_tables(), 1);
+    assert_eq!(resources.len_imported_globals(), 1);
+    assert_eq!(resources.data_bytes(), 5 + 7);
+    assert_eq!(resources.element_items(), 2 + 1 + 1);
+    assert_eq!(
+        resources.estimated_instantiation_bytes(),
+        2 * 65536 + (3 + 5) * 8
+    );
+}
+
+#[test]
+fn resources_of_empty_module() {
+    let engine = Engine::default();
+    let module = Module::new(&engine, "(module)").unwrap();
+    let resources = module.resources();
+    assert!(resources.memories().is_empty());
+    assert!(resources.tables().is_empty());
+    assert_eq!(resources.len_funcs(), 0);
+    assert_eq!(resources.len_globals(), 0);
+    assert_eq!(resources.data_bytes(), 0);
+    assert_eq!(resources.element_items(), 0);
+    assert_eq!(resources.estimated_instantiation_bytes(), 0);
+}
@@ -0,0 +1,95 @@
+//! Tests for the instruction profiling of the Wasmi `Engine`.
+
+use wasmi::{Engine, Linker, Module, Store, TypedFunc};
+
+/// Returns the `count` function of a module that counts down from its input to zero.
+fn setup(engine: &Engine) -> (Store<()>, TypedFunc<i32, ()>) {
+    let wasm = r#"
+        (module
+            (func (export "count") (param $n i32)
+                (block $exit
+                    (loop $continue
+                        (br_if $exit (i32.eqz (local.get $n)))
+                        (local.set $n (i32.sub (local.get $n) (i32.const 1)))
+                        (br $continue)
+                    )
+                )
+            )
+        )
+    "#;
+    let module = Module::new(engine, wasm).unwrap();
+    let mut store = Store::new(engine, ());
+    let instance = Linker::new(engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let count = instance.get_typed_func::<i32, ()>(&store, "count").unwrap();
+    (store, count)
+}
+
+#[test]
+fn profiling_is_disabled_by_default() {
+    let engine = Engine::default();
+    let (mut store, count) = setup(&engine);
+    count.call(&mut store, 10).unwrap();
+    assert!(engine.take_profile().is_none());
+}
+
+#[test]
+fn profiling_counts_executed_instructions() {
+    let engine = Engine::default();
+    let (mut store, count) = setup(&engine);
+    engine.start_profiling();
+    count.call(&mut store, 10).unwrap();
+    count.call(&mut store, 10).unwrap();
+    engine.stop_profiling();
+    count.call(&mut store, 10).unwrap();
+    let report = engine.take_profile().unwrap();
+    let mut instrs = report.instrs().iter();
+    let hottest = instrs.next().unwrap();
+    assert!(instrs.all(|instr| instr.count() <= hottest.count()));
+    assert_eq!(
+        report.total(),
+        report
+            .instrs()
+            .iter()
+            .map(|instr| instr.count())
+            .sum::<u64>()
+    );
+    assert_eq!(report.count("Return"), 2);
+    assert_eq!(report.count("NonExistingInstruction"), 0);
+    // Taking the profile discards the collected instructions.
+    assert!(engine.take_profile().is_none());
+}
+
+#[test]
+fn reset_profiling_discards_instructions() {
+    let engine = Engine::default();
+    let (mut store, count) = setup(&engine);
+    engine.start_profiling();
+    count.call(&mut store, 10).unwrap();
+    let first = engine.take_profile().unwrap();
+    count.call(&mut store, 10).unwrap();
+    engine.reset_profiling();
+    assert!(engine.take_profile().is_none());
+    count.call(&mut store, 10).unwrap();
+    assert_eq!(engine.take_profile().unwrap(), first);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn profile_report_serializes_to_json() {
+    let engine = Engine::default();
+    let (mut store, count) = setup(&engine);
+    engine.start_profiling();
+    count.call(&mut store, 0).unwrap();
+    let report = engine.take_profile().unwrap();
+    let json = serde_json::to_value(&report).unwrap();
+    let instrs = json["instrs"].as_array().unwrap();
+    assert_eq!(instrs.len(), report.instrs().len());
+    for (json, instr) in instrs.iter().zip(report.instrs()) {
+        assert_eq!(json["name"], instr.name());
+        assert_eq!(json["count"], instr.count());
+    }
+}
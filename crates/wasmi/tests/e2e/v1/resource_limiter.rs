@@ -1,15 +1,20 @@
 //! Tests to check if wasmi's ResourceLimiter works as intended.
 use wasmi::{
-    core::TrapCode,
+    core::{TrapCode, ValType},
     Config,
     Engine,
     Error,
     Linker,
+    Memory,
+    MemoryType,
     Module,
     Store,
     StoreLimits,
     StoreLimitsBuilder,
+    Table,
+    TableType,
     TypedFunc,
+    Val,
 };
 
 /// Setup [`Engine`] and [`Store`] for resource limiting.
@@ -202,3 +207,33 @@ fn test_table_traps_on_limited_growth() -> Result<(), Error> {
     assert_eq!(test.table_size.call(&mut test.store, ())?, 100);
     Ok(())
 }
+
+#[test]
+fn test_imported_tables_and_memories_are_not_limited() -> Result<(), Error> {
+    let wasm = r#"
+        (module
+            (import "env" "memory" (memory 1))
+            (import "env" "table" (table 1 funcref))
+        )
+    "#;
+    let limits = StoreLimitsBuilder::new().memories(1).tables(1).build();
+    let (mut store, mut linker) = test_setup(limits);
+    let memory = Memory::new(&mut store, MemoryType::new(1, None)?)?;
+    let table_type = TableType::new(ValType::FuncRef, 1, None);
+    let table = Table::new(&mut store, table_type, Val::default(ValType::FuncRef))?;
+    linker.define("env", "memory", memory)?;
+    linker.define("env", "table", table)?;
+    let module = create_module(&store, wasm.as_bytes())?;
+    linker.instantiate(&mut store, &module)?.start(&mut store)?;
+    Ok(())
+}
+
+#[test]
+fn test_too_many_tables_fails_to_instantiate() -> Result<(), Error> {
+    let wasm = r#"(module (table 1 funcref) (table 1 funcref))"#;
+    let limits = StoreLimitsBuilder::new().tables(1).build();
+    let (mut store, linker) = test_setup(limits);
+    let module = create_module(&store, wasm.as_bytes())?;
+    assert!(linker.instantiate(&mut store, &module).is_err());
+    Ok(())
+}
@@ -77,10 +77,9 @@ impl<Results> UnwrapResumable for Result<TypedResumableCall<Results>, Error> {
     type Results = Results;
 
     fn unwrap_resumable(self) -> TypedResumableInvocation<Self::Results> {
-        match self.unwrap() {
-            TypedResumableCall::Resumable(invocation) => invocation,
-            TypedResumableCall::Finished(_) => panic!("expected TypedResumableCall::Resumable"),
-        }
+        self.unwrap()
+            .into_host_trap()
+            .expect("expected TypedResumableCall::Resumable")
     }
 }
 
@@ -103,6 +102,28 @@ fn resumable_call_smoldot_01() {
     }
 }
 
+#[test]
+fn resumable_call_predicates() {
+    let (mut store, wasm_fn) = resumable_call_smoldot_common(
+        r#"
+        (module
+            (import "env" "host_fn" (func $host_fn (result i32)))
+            (func (export "test") (result i32)
+                (call $host_fn)
+            )
+        )
+        "#,
+    );
+    let call = wasm_fn.call_resumable(&mut store, ()).unwrap();
+    assert!(call.is_host_trap());
+    assert!(!call.is_finished());
+    let invocation = call.into_host_trap().unwrap();
+    let call = invocation.resume(&mut store, &[Val::I32(42)]).unwrap();
+    assert!(call.is_finished());
+    assert!(!call.is_host_trap());
+    assert!(call.into_host_trap().is_none());
+}
+
 #[test]
 fn resumable_call_smoldot_tail_01() {
     let (mut store, wasm_fn) = resumable_call_smoldot_common(
@@ -0,0 +1,129 @@
+//! Tests to check that stack overflow traps report which stack limit has been exhausted.
+
+use wasmi::{
+    core::TrapCode,
+    errors::{ErrorKind, StackOverflowError, StackOverflowKind},
+    Config,
+    Engine,
+    Error,
+    Instance,
+    Linker,
+    Module,
+    StackLimits,
+    Store,
+};
+
+/// The maximum value stack height used by the tests.
+const MAX_VALUE_STACK: usize = 1_000;
+
+/// The maximum recursion depth used by the tests.
+const MAX_CALL_DEPTH: usize = 50;
+
+/// Instantiates `wasm` with the stack limits used by the tests.
+fn setup(wasm: &str) -> (Store<()>, Instance) {
+    let mut config = Config::default();
+    config.set_stack_limits(StackLimits::new(256, MAX_VALUE_STACK, MAX_CALL_DEPTH).unwrap());
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, wasm).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Returns the [`StackOverflowError`] of `error` or panics.
+fn unwrap_stack_overflow(error: &Error) -> &StackOverflowError {
+    assert_eq!(error.as_trap_code(), Some(TrapCode::StackOverflow));
+    match error.kind() {
+        ErrorKind::StackOverflow(error) => error,
+        _ => panic!("expected a stack overflow error but found: {error}"),
+    }
+}
+
+/// Returns a Wasm module with a recursive function at index 1.
+fn recursive_wasm() -> &'static str {
+    r#"
+        (module
+            (func $dummy)
+            (func $rec (export "rec") (param $n i32)
+                (if (local.get $n)
+                    (then
+                        (call $rec (i32.sub (local.get $n) (i32.const 1)))
+                    )
+                )
+            )
+        )
+    "#
+}
+
+#[test]
+fn stack_limits_getters() {
+    let limits = StackLimits::new(256, MAX_VALUE_STACK, MAX_CALL_DEPTH).unwrap();
+    assert_eq!(limits.initial_value_stack_height(), 256);
+    assert_eq!(limits.maximum_value_stack_height(), MAX_VALUE_STACK);
+    assert_eq!(limits.maximum_recursion_depth(), MAX_CALL_DEPTH);
+}
+
+#[test]
+fn call_stack_exhaustion() {
+    let (mut store, instance) = setup(recursive_wasm());
+    let rec = instance.get_typed_func::<i32, ()>(&store, "rec").unwrap();
+    let error = rec.call(&mut store, MAX_CALL_DEPTH as i32).unwrap_err();
+    let overflow = unwrap_stack_overflow(&error);
+    assert_eq!(overflow.kind(), StackOverflowKind::CallStack);
+    assert_eq!(overflow.limit(), MAX_CALL_DEPTH);
+    assert_eq!(overflow.func_index(), Some(1));
+    assert!(error.to_string().contains("recursion depth"));
+}
+
+#[test]
+fn value_stack_exhaustion() {
+    // A single call frame of `$wide` requires more cells than the value stack allows.
+    let locals = " i64".repeat(MAX_VALUE_STACK);
+    let wasm = format!(
+        r#"
+        (module
+            (func $wide (local{locals}))
+            (func (export "run")
+                (call $wide)
+            )
+        )
+    "#
+    );
+    let (mut store, instance) = setup(&wasm);
+    let run = instance.get_typed_func::<(), ()>(&store, "run").unwrap();
+    let error = run.call(&mut store, ()).unwrap_err();
+    let overflow = unwrap_stack_overflow(&error);
+    assert_eq!(overflow.kind(), StackOverflowKind::ValueStack);
+    assert_eq!(overflow.limit(), MAX_VALUE_STACK);
+    assert_eq!(overflow.func_index(), Some(0));
+    assert!(error.to_string().contains("value stack"));
+}
+
+#[test]
+fn max_observed_stack_usage() {
+    let (mut store, instance) = setup(recursive_wasm());
+    assert_eq!(store.max_observed_call_depth(), 0);
+    assert_eq!(store.max_observed_value_stack(), 0);
+    let rec = instance.get_typed_func::<i32, ()>(&store, "rec").unwrap();
+    // Calling `rec` with `n` yields `n + 1` call frames.
+    rec.call(&mut store, 9).unwrap();
+    assert_eq!(store.max_observed_call_depth(), 10);
+    let value_stack = store.max_observed_value_stack();
+    assert!(value_stack > 0);
+    // Shallower calls do not lower the high-water marks.
+    rec.call(&mut store, 2).unwrap();
+    assert_eq!(store.max_observed_call_depth(), 10);
+    assert_eq!(store.max_observed_value_stack(), value_stack);
+    // Deeper calls raise the high-water marks.
+    rec.call(&mut store, 19).unwrap();
+    assert_eq!(store.max_observed_call_depth(), 20);
+    assert!(store.max_observed_value_stack() > value_stack);
+    // Exhausting the call stack reports the limit as high-water mark.
+    rec.call(&mut store, MAX_CALL_DEPTH as i32).unwrap_err();
+    assert_eq!(store.max_observed_call_depth(), MAX_CALL_DEPTH);
+    assert!(store.max_observed_value_stack() <= MAX_VALUE_STACK);
+}
@@ -0,0 +1,111 @@
+//! Tests for calling functions with a user owned [`StackRecycler`].
+
+use wasmi::{
+    core::TrapCode,
+    Config,
+    Engine,
+    Instance,
+    Linker,
+    Module,
+    StackLimits,
+    StackRecycler,
+    Store,
+    Val,
+};
+
+/// A recursive function computing the sum of `0..=n`.
+const WASM: &str = r#"
+    (module
+        (func $sum (export "sum") (param $n i32) (result i32)
+            (if (result i32) (i32.eqz (local.get $n))
+                (then (i32.const 0))
+                (else
+                    (i32.add
+                        (local.get $n)
+                        (call $sum (i32.sub (local.get $n) (i32.const 1)))
+                    )
+                )
+            )
+        )
+    )
+"#;
+
+/// Instantiates [`WASM`] using an [`Engine`] with the given `config`.
+fn setup(config: &Config) -> (Store<()>, Instance) {
+    let engine = Engine::new(config);
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, WASM).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+#[test]
+fn call_with_stack_works() {
+    let (mut store, instance) = setup(&Config::default());
+    let sum = instance.get_typed_func::<i32, i32>(&store, "sum").unwrap();
+    let mut recycler = StackRecycler::new(store.engine());
+    for n in 0..100 {
+        let result = sum.call_with_stack(&mut store, n, &mut recycler).unwrap();
+        assert_eq!(result, n * (n + 1) / 2);
+    }
+    let func = instance.get_func(&store, "sum").unwrap();
+    let mut results = [Val::I32(0)];
+    func.call_with_stack(&mut store, &[Val::I32(10)], &mut results, &mut recycler)
+        .unwrap();
+    assert_eq!(results[0].i32(), Some(55));
+}
+
+#[test]
+fn default_recycler_works() {
+    let (mut store, instance) = setup(&Config::default());
+    let sum = instance.get_typed_func::<i32, i32>(&store, "sum").unwrap();
+    let mut recycler = StackRecycler::default();
+    for _ in 0..3 {
+        let result = sum.call_with_stack(&mut store, 10, &mut recycler).unwrap();
+        assert_eq!(result, 55);
+    }
+}
+
+#[test]
+fn recycler_survives_stack_overflow() {
+    let mut config = Config::default();
+    config.set_stack_limits(StackLimits::new(256, 1_000, 50).unwrap());
+    let (mut store, instance) = setup(&config);
+    let sum = instance.get_typed_func::<i32, i32>(&store, "sum").unwrap();
+    let mut recycler = StackRecycler::new(store.engine());
+    let error = sum
+        .call_with_stack(&mut store, 1_000, &mut recycler)
+        .unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::StackOverflow));
+    let result = sum.call_with_stack(&mut store, 10, &mut recycler).unwrap();
+    assert_eq!(result, 55);
+}
+
+#[test]
+fn recycler_adapts_to_engine() {
+    let mut config = Config::default();
+    config.set_stack_limits(StackLimits::new(256, 1_000, 50).unwrap());
+    let (mut store_a, instance_a) = setup(&config);
+    let (mut store_b, instance_b) = setup(&Config::default());
+    let sum_a = instance_a
+        .get_typed_func::<i32, i32>(&store_a, "sum")
+        .unwrap();
+    let sum_b = instance_b
+        .get_typed_func::<i32, i32>(&store_b, "sum")
+        .unwrap();
+    let mut recycler = StackRecycler::new(store_a.engine());
+    // The recycled stack of `store_a` is too small for this call but
+    // is replaced by a stack using the limits of the engine of `store_b`.
+    let result = sum_b
+        .call_with_stack(&mut store_b, 100, &mut recycler)
+        .unwrap();
+    assert_eq!(result, 5050);
+    let error = sum_a
+        .call_with_stack(&mut store_a, 100, &mut recycler)
+        .unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::StackOverflow));
+}
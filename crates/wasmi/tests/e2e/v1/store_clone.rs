@@ -0,0 +1,173 @@
+//! Tests to check if `Store::try_clone` works as intended.
+
+use wasmi::{
+    errors::{ErrorKind, FuncError, SnapshotError},
+    Caller,
+    Config,
+    Engine,
+    ExternRef,
+    Func,
+    Global,
+    Instance,
+    Linker,
+    Module,
+    Mutability,
+    Store,
+    Val,
+};
+
+/// Instantiates a simulation that advances its state stored in memory, table and global.
+fn setup() -> (Store<u32>, Instance) {
+    let wasm = r#"
+        (module
+            (import "host" "tick" (func $tick))
+            (memory (export "memory") 1)
+            (table (export "table") 1 funcref)
+            (global $steps (export "steps") (mut i32) (i32.const 0))
+            (elem declare func $step)
+            (func $step (export "step")
+                (call $tick)
+                (global.set $steps (i32.add (global.get $steps) (i32.const 1)))
+                (i32.store (i32.const 0)
+                    (i32.add (i32.load (i32.const 0)) (i32.const 10))
+                )
+                (drop (table.grow (ref.func $step) (i32.const 1)))
+            )
+        )
+    "#;
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, 0);
+    store.set_fuel(1_000_000).unwrap();
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut linker = <Linker<u32>>::new(&engine);
+    linker
+        .func_wrap("host", "tick", |mut caller: Caller<u32>| {
+            *caller.data_mut() += 1;
+        })
+        .unwrap();
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+/// Returns the observable state of the simulation `instance`.
+fn state(store: &Store<u32>, instance: Instance) -> (u32, i32, [u8; 4], u32, u64) {
+    let memory = instance.get_memory(store, "memory").unwrap();
+    let table = instance.get_table(store, "table").unwrap();
+    let steps = instance.get_global(store, "steps").unwrap();
+    let bytes = memory.data(store)[0..4].try_into().unwrap();
+    (
+        *store.data(),
+        steps.get(store).i32().unwrap(),
+        bytes,
+        table.size(store),
+        store.get_fuel().unwrap(),
+    )
+}
+
+/// Runs `n` steps of the simulation `instance`.
+fn run(store: &mut Store<u32>, instance: Instance, n: usize) {
+    let step = instance.get_typed_func::<(), ()>(&*store, "step").unwrap();
+    for _ in 0..n {
+        step.call(&mut *store, ()).unwrap();
+    }
+}
+
+#[test]
+fn try_clone_works() {
+    let (mut store, instance) = setup();
+    run(&mut store, instance, 3);
+    let mut clone = store.try_clone(*store.data()).unwrap();
+    let cloned = clone.cloned_instance(&instance).unwrap();
+    let cloned_state = state(&clone, cloned);
+    assert_eq!(cloned_state, state(&store, instance));
+    assert_eq!(cloned_state.0, 3);
+    assert_eq!(cloned_state.3, 4);
+    // Mutating the original store must not affect the clone.
+    run(&mut store, instance, 5);
+    assert_eq!(state(&store, instance).1, 8);
+    assert_eq!(state(&clone, cloned), cloned_state);
+    // The clone can be executed independently and behaves the same.
+    run(&mut clone, cloned, 5);
+    assert_eq!(state(&clone, cloned), state(&store, instance));
+}
+
+#[test]
+fn try_clone_with_different_data() {
+    let (mut store, instance) = setup();
+    run(&mut store, instance, 2);
+    let mut clone = store.try_clone(100).unwrap();
+    let cloned = clone.cloned_instance(&instance).unwrap();
+    run(&mut clone, cloned, 1);
+    assert_eq!(*clone.data(), 101);
+    assert_eq!(*store.data(), 2);
+}
+
+#[test]
+fn try_clone_fails_with_externrefs() {
+    let (mut store, _instance) = setup();
+    let _ = ExternRef::new(&mut store, 42_i32);
+    let error = store.try_clone(0).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Snapshot(SnapshotError::ExternObjects)
+    ));
+}
+
+#[test]
+fn try_clone_has_own_identity() {
+    let (mut store, instance) = setup();
+    let mut clone = store.try_clone(0).unwrap();
+    let cloned = clone.cloned_instance(&instance).unwrap();
+    // Entities allocated after cloning share their indices in both stores.
+    let original_func = Func::wrap(&mut store, || ());
+    let cloned_func = Func::wrap(&mut clone, || ());
+    let original_global = Global::new(&mut store, Val::I32(1), Mutability::Var);
+    let cloned_global = Global::new(&mut clone, Val::I32(2), Mutability::Var);
+    // Using entities of one store with the other store must be rejected.
+    for (func, store) in [(original_func, &mut clone), (cloned_func, &mut store)] {
+        let error = func.call(store, &[], &mut []).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorKind::Func(FuncError::CrossStore)
+        ));
+    }
+    let step = instance.get_func(&store, "step").unwrap();
+    let error = step.call(&mut clone, &[], &mut []).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Func(FuncError::CrossStore)
+    ));
+    let step = cloned.get_func(&clone, "step").unwrap();
+    let error = step.call(&mut store, &[], &mut []).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Func(FuncError::CrossStore)
+    ));
+    assert!(!original_global.is_from_store(&clone));
+    assert!(!cloned_global.is_from_store(&store));
+    assert!(cloned_global.is_from_store(&clone));
+    // Instances are only translated from the original store into its clone.
+    assert!(store.cloned_instance(&cloned).is_none());
+    assert!(clone.cloned_instance(&cloned).is_none());
+}
+
+#[test]
+fn try_clone_rebinds_funcrefs() {
+    let (mut store, instance) = setup();
+    run(&mut store, instance, 1);
+    let mut clone = store.try_clone(0).unwrap();
+    let cloned = clone.cloned_instance(&instance).unwrap();
+    // The `funcref` stored in the table by the original store refers to the cloned function.
+    let table = cloned.get_table(&clone, "table").unwrap();
+    let funcref = table.get(&clone, 1).unwrap();
+    let func = *funcref.funcref().unwrap().func().unwrap();
+    func.call(&mut clone, &[], &mut []).unwrap();
+    assert_eq!(*clone.data(), 1);
+    assert_eq!(*store.data(), 1);
+}
@@ -0,0 +1,96 @@
+//! Tests to check if `Store::snapshot` and `Store::restore` work as intended.
+
+use wasmi::{
+    errors::{ErrorKind, SnapshotError},
+    Config,
+    Engine,
+    Global,
+    Instance,
+    Linker,
+    Module,
+    Mutability,
+    Store,
+    Val,
+};
+
+/// Instantiates a module that mutates its exported memory, table and global.
+fn setup() -> (Store<()>, Instance) {
+    let wasm = r#"
+        (module
+            (memory (export "memory") 1)
+            (table (export "table") 1 externref)
+            (global (export "global") (mut i32) (i32.const 0))
+            (func (export "mutate") (param externref)
+                (i32.store8 (i32.const 10) (i32.const 42))
+                (global.set 0 (i32.const 1))
+                (table.set (i32.const 0) (local.get 0))
+                (drop (memory.grow (i32.const 2)))
+                (drop (table.grow (ref.null extern) (i32.const 3)))
+                (i32.store8 (i32.const 70000) (i32.const 1))
+            )
+        )
+    "#;
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    let engine = Engine::new(&config);
+    let mut store = Store::new(&engine, ());
+    store.set_fuel(1_000_000).unwrap();
+    let module = Module::new(&engine, wasm).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    (store, instance)
+}
+
+#[test]
+fn snapshot_restore_works() {
+    let (mut store, instance) = setup();
+    let memory = instance.get_memory(&store, "memory").unwrap();
+    let table = instance.get_table(&store, "table").unwrap();
+    let global = instance.get_global(&store, "global").unwrap();
+    let mutate = instance
+        .get_typed_func::<wasmi::ExternRef, ()>(&store, "mutate")
+        .unwrap();
+    let snapshot = store.snapshot();
+    let memory_before = memory.data(&store).to_vec();
+    let fuel_before = store.get_fuel().unwrap();
+    let value = wasmi::ExternRef::new::<i32>(&mut store, 5);
+    mutate.call(&mut store, value).unwrap();
+    assert_eq!(memory.size(&store), 3);
+    assert_eq!(table.size(&store), 4);
+    assert_eq!(global.get(&store).i32(), Some(1));
+    assert_ne!(store.get_fuel().unwrap(), fuel_before);
+    store.restore(&snapshot).unwrap();
+    // All state is restored including truncating grown memories and tables.
+    assert_eq!(memory.size(&store), 1);
+    assert_eq!(memory.data(&store), &memory_before[..]);
+    assert_eq!(table.size(&store), 1);
+    assert!(table.get(&store, 0).unwrap().externref().unwrap().is_null());
+    assert_eq!(global.get(&store).i32(), Some(0));
+    assert_eq!(store.get_fuel().unwrap(), fuel_before);
+    // The store keeps working normally after restoring.
+    mutate.call(&mut store, value).unwrap();
+    assert_eq!(memory.size(&store), 3);
+    assert_eq!(memory.data(&store)[10], 42);
+}
+
+#[test]
+fn snapshot_restore_errors() {
+    let (mut store, _instance) = setup();
+    let (other, _instance) = setup();
+    let foreign = other.snapshot();
+    let error = store.restore(&foreign).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Snapshot(SnapshotError::ForeignSnapshot)
+    ));
+    let snapshot = store.snapshot();
+    Global::new(&mut store, Val::I32(0), Mutability::Var);
+    let error = store.restore(&snapshot).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::Snapshot(SnapshotError::EntitiesAdded)
+    ));
+}
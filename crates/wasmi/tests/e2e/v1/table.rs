@@ -0,0 +1,220 @@
+//! Tests to check if Wasm `table.get` and `table.set` with constant indices work as intended
+//! as well as the `Table` host API.
+
+use wasmi::{
+    core::{TrapCode, ValType},
+    errors::TableError,
+    Engine,
+    ExternRef,
+    Func,
+    FuncRef,
+    Linker,
+    Module,
+    Store,
+    Table,
+    TableType,
+    TypedFunc,
+    Val,
+};
+
+#[test]
+fn table_get_set_const_index() {
+    // The table has a minimum size of 2 and is grown to a size of 4 before
+    // accessing indices in bounds of its minimum size, in bounds of its
+    // current size and out of bounds of its current size.
+    let wasm = r#"
+        (module
+            (table $t 2 externref)
+            (func (export "grow") (result i32)
+                (table.grow $t (ref.null extern) (i32.const 2))
+            )
+            (func (export "set_1") (param externref)
+                (table.set $t (i32.const 1) (local.get 0))
+            )
+            (func (export "get_1") (result externref)
+                (table.get $t (i32.const 1))
+            )
+            (func (export "set_3") (param externref)
+                (table.set $t (i32.const 3) (local.get 0))
+            )
+            (func (export "get_3") (result externref)
+                (table.get $t (i32.const 3))
+            )
+            (func (export "set_4") (param externref)
+                (table.set $t (i32.const 4) (local.get 0))
+            )
+            (func (export "get_4") (result externref)
+                (table.get $t (i32.const 4))
+            )
+        )
+    "#;
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, ());
+    let module = Module::new(&engine, wasm).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let get = |store: &Store<()>, name: &str| -> TypedFunc<(), wasmi::ExternRef> {
+        instance.get_typed_func(store, name).unwrap()
+    };
+    let set = |store: &Store<()>, name: &str| -> TypedFunc<wasmi::ExternRef, ()> {
+        instance.get_typed_func(store, name).unwrap()
+    };
+    let value = wasmi::ExternRef::new::<i32>(&mut store, 42);
+    let assert_oob = |error: wasmi::Error| {
+        assert_eq!(error.as_trap_code(), Some(TrapCode::TableOutOfBounds));
+    };
+    // Before growing: only indices below the minimum size are in bounds.
+    set(&store, "set_1").call(&mut store, value).unwrap();
+    let result = get(&store, "get_1").call(&mut store, ()).unwrap();
+    assert_eq!(
+        result.data(&store).unwrap().downcast_ref::<i32>(),
+        Some(&42)
+    );
+    assert_oob(set(&store, "set_3").call(&mut store, value).unwrap_err());
+    assert_oob(get(&store, "get_3").call(&mut store, ()).unwrap_err());
+    // After growing: indices below the current size are in bounds.
+    let grow = instance.get_typed_func::<(), i32>(&store, "grow").unwrap();
+    assert_eq!(grow.call(&mut store, ()).unwrap(), 2);
+    set(&store, "set_3").call(&mut store, value).unwrap();
+    let result = get(&store, "get_3").call(&mut store, ()).unwrap();
+    assert_eq!(
+        result.data(&store).unwrap().downcast_ref::<i32>(),
+        Some(&42)
+    );
+    assert_oob(set(&store, "set_4").call(&mut store, value).unwrap_err());
+    assert_oob(get(&store, "get_4").call(&mut store, ()).unwrap_err());
+}
+
+#[test]
+fn func_identity_of_table_elements() {
+    let wasm = r#"
+        (module
+            (table (export "table") 2 funcref)
+            (elem (i32.const 0) $f)
+            (func $f (export "f"))
+            (func (export "g"))
+        )
+    "#;
+    let engine = Engine::default();
+    let mut store = <Store<()>>::new(&engine, ());
+    let module = Module::new(&engine, wasm).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let table = instance.get_table(&store, "table").unwrap();
+    let f = instance.get_func(&store, "f").unwrap();
+    let g = instance.get_func(&store, "g").unwrap();
+    let elem = |store: &Store<()>, index: u32| -> Option<Func> {
+        table
+            .get(store, index)
+            .unwrap()
+            .funcref()
+            .unwrap()
+            .func()
+            .copied()
+    };
+    let elem0 = elem(&store, 0).unwrap();
+    assert!(Func::same(&elem0, &f));
+    assert!(!Func::same(&elem0, &g));
+    assert!(elem(&store, 1).is_none());
+    table
+        .set(&mut store, 1, Val::from(FuncRef::new(g)))
+        .unwrap();
+    assert!(Func::same(&elem(&store, 1).unwrap(), &g));
+}
+
+/// Creates a `funcref` [`Table`] holding `funcs` followed by `len_null` null elements.
+fn funcref_table(store: &mut Store<()>, funcs: &[Func], len_null: u32) -> Table {
+    let size = funcs.len() as u32 + len_null;
+    let ty = TableType::new(ValType::FuncRef, size, None);
+    let table = Table::new(&mut *store, ty, Val::from(FuncRef::null())).unwrap();
+    for (index, func) in funcs.iter().enumerate() {
+        table
+            .set(&mut *store, index as u32, Val::from(FuncRef::new(*func)))
+            .unwrap();
+    }
+    table
+}
+
+/// Returns the [`Func`] at `index` of `table` or `None` if it is null.
+fn table_func(store: &Store<()>, table: &Table, index: u32) -> Option<Func> {
+    table.get(store, index)?.funcref()?.func().copied()
+}
+
+/// Asserts that `table` holds exactly the `expected` elements.
+fn assert_table_funcs(store: &Store<()>, table: &Table, expected: &[Option<Func>]) {
+    assert_eq!(table.size(store), expected.len() as u32);
+    for (index, expected) in expected.iter().enumerate() {
+        let actual = table_func(store, table, index as u32);
+        match (actual, expected) {
+            (Some(actual), Some(expected)) => assert!(Func::same(&actual, expected)),
+            (None, None) => {}
+            _ => panic!("mismatch at index {index}: {actual:?} != {expected:?}"),
+        }
+    }
+}
+
+#[test]
+fn table_copy_works() {
+    let engine = Engine::default();
+    let mut store = <Store<()>>::new(&engine, ());
+    let f = Func::wrap(&mut store, || {});
+    let g = Func::wrap(&mut store, || {});
+    let src = funcref_table(&mut store, &[f, g], 0);
+    let dst = funcref_table(&mut store, &[], 4);
+    Table::copy(&mut store, &dst, 1, &src, 0, 2).unwrap();
+    assert_table_funcs(&store, &dst, &[None, Some(f), Some(g), None]);
+    // Zero length copies at the end of the tables are in bounds.
+    Table::copy(&mut store, &dst, 4, &src, 2, 0).unwrap();
+    // Out of bounds copies are rejected and leave the tables unchanged.
+    for (dst_index, src_index, len) in [(3, 0, 2), (0, 1, 2), (5, 0, 0), (0, 3, 0)] {
+        let error = Table::copy(&mut store, &dst, dst_index, &src, src_index, len).unwrap_err();
+        assert!(matches!(error, TableError::CopyOutOfBounds));
+    }
+    assert_table_funcs(&store, &dst, &[None, Some(f), Some(g), None]);
+}
+
+#[test]
+fn table_copy_element_type_mismatch() {
+    let engine = Engine::default();
+    let mut store = <Store<()>>::new(&engine, ());
+    let src = funcref_table(&mut store, &[], 2);
+    let ty = TableType::new(ValType::ExternRef, 2, None);
+    let dst = Table::new(&mut store, ty, Val::from(ExternRef::null())).unwrap();
+    let error = Table::copy(&mut store, &dst, 0, &src, 0, 1).unwrap_err();
+    assert!(matches!(error, TableError::ElementTypeMismatch { .. }));
+    let error = Table::copy(&mut store, &src, 0, &dst, 0, 1).unwrap_err();
+    assert!(matches!(error, TableError::ElementTypeMismatch { .. }));
+}
+
+#[test]
+fn table_copy_within_works() {
+    let engine = Engine::default();
+    let mut store = <Store<()>>::new(&engine, ());
+    let f = Func::wrap(&mut store, || {});
+    let g = Func::wrap(&mut store, || {});
+    let h = Func::wrap(&mut store, || {});
+    let table = funcref_table(&mut store, &[f, g, h], 2);
+    // Overlapping copy towards higher indices.
+    table.copy_within(&mut store, 1, 0, 3).unwrap();
+    assert_table_funcs(&store, &table, &[Some(f), Some(f), Some(g), Some(h), None]);
+    // Overlapping copy towards lower indices.
+    table.copy_within(&mut store, 0, 2, 3).unwrap();
+    assert_table_funcs(&store, &table, &[Some(g), Some(h), None, Some(h), None]);
+    // `Table::copy` with the same source and destination table copies within the table.
+    Table::copy(&mut store, &table, 2, &table, 0, 2).unwrap();
+    assert_table_funcs(&store, &table, &[Some(g), Some(h), Some(g), Some(h), None]);
+    // Out of bounds copies are rejected and leave the table unchanged.
+    for (dst_index, src_index, len) in [(4, 0, 2), (0, 4, 2), (6, 0, 0), (0, u32::MAX, 1)] {
+        let error = table
+            .copy_within(&mut store, dst_index, src_index, len)
+            .unwrap_err();
+        assert!(matches!(error, TableError::CopyOutOfBounds));
+    }
+    assert_table_funcs(&store, &table, &[Some(g), Some(h), Some(g), Some(h), None]);
+}
@@ -0,0 +1,343 @@
+//! Tests to check that all guest-visible failures are reported as [`TrapCode`].
+//!
+//! Failures of lazy compilation are the exception and keep their original error.
+
+use wasmi::{
+    core::{TrapCode, ValType},
+    errors::ErrorKind,
+    CompilationMode,
+    Config,
+    Engine,
+    Error,
+    FuncType,
+    Linker,
+    Module,
+    Store,
+    StoreLimits,
+    StoreLimitsBuilder,
+};
+
+/// Instantiates `wasm` using `config` and calls its `run` function.
+///
+/// Returns the error of the `run` function call.
+///
+/// # Panics
+///
+/// - If compilation or instantiation of `wasm` failed.
+/// - If the `run` function call did not fail.
+fn run_with(config: &Config, limits: StoreLimits, wasm: &str) -> Error {
+    let engine = Engine::new(config);
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, limits);
+    store.limiter(|limits| limits);
+    let linker = <Linker<StoreLimits>>::new(&engine);
+    let instance = linker
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_func(&store, "run").unwrap();
+    run.call(&mut store, &[], &mut []).unwrap_err()
+}
+
+/// Asserts that calling `run` of `wasm` traps with `expected`.
+fn assert_trap(wasm: &str, expected: TrapCode) {
+    assert_trap_with(&Config::default(), StoreLimits::default(), wasm, expected)
+}
+
+/// Asserts that calling `run` of `wasm` using `config` and `limits` traps with `expected`.
+fn assert_trap_with(config: &Config, limits: StoreLimits, wasm: &str, expected: TrapCode) {
+    let error = run_with(config, limits, wasm);
+    assert_eq!(error.as_trap_code(), Some(expected), "{error}");
+}
+
+/// Asserts that `error` is the Wasm validation error of a lazily compiled function.
+fn assert_lazy_validation_error(error: &Error) {
+    assert!(error.as_trap_code().is_none(), "{error}");
+    assert!(matches!(error.kind(), ErrorKind::Wasm(_)), "{error}");
+}
+
+#[test]
+fn unreachable_code_reached() {
+    let wasm = r#"
+        (module
+            (func (export "run") (unreachable))
+        )
+    "#;
+    assert_trap(wasm, TrapCode::UnreachableCodeReached);
+}
+
+#[test]
+fn memory_out_of_bounds() {
+    let wasm = r#"
+        (module
+            (memory 1)
+            (func (export "run")
+                (drop (i32.load (i32.const 65536)))
+            )
+        )
+    "#;
+    assert_trap(wasm, TrapCode::MemoryOutOfBounds);
+}
+
+#[test]
+fn table_out_of_bounds() {
+    let wasm = r#"
+        (module
+            (table 1 funcref)
+            (func (export "run")
+                (drop (table.get (i32.const 1)))
+            )
+        )
+    "#;
+    assert_trap(wasm, TrapCode::TableOutOfBounds);
+}
+
+#[test]
+fn indirect_call_to_null() {
+    let wasm = r#"
+        (module
+            (table 1 funcref)
+            (func (export "run")
+                (call_indirect (i32.const 0))
+            )
+        )
+    "#;
+    assert_trap(wasm, TrapCode::IndirectCallToNull);
+}
+
+#[test]
+fn integer_division_by_zero() {
+    let wasm = r#"
+        (module
+            (global $zero (mut i32) (i32.const 0))
+            (func (export "run")
+                (drop (i32.div_u (i32.const 1) (global.get $zero)))
+            )
+        )
+    "#;
+    assert_trap(wasm, TrapCode::IntegerDivisionByZero);
+}
+
+#[test]
+fn integer_overflow() {
+    let wasm = r#"
+        (module
+            (global $minus_one (mut i32) (i32.const -1))
+            (func (export "run")
+                (drop (i32.div_s (i32.const 0x8000_0000) (global.get $minus_one)))
+            )
+        )
+    "#;
+    assert_trap(wasm, TrapCode::IntegerOverflow);
+}
+
+#[test]
+fn bad_conversion_to_integer() {
+    let wasm = r#"
+        (module
+            (global $nan (mut f32) (f32.const nan))
+            (func (export "run")
+                (drop (i32.trunc_f32_s (global.get $nan)))
+            )
+        )
+    "#;
+    assert_trap(wasm, TrapCode::BadConversionToInteger);
+}
+
+#[test]
+fn stack_overflow() {
+    let wasm = r#"
+        (module
+            (func $run (export "run")
+                (call $run)
+            )
+        )
+    "#;
+    assert_trap(wasm, TrapCode::StackOverflow);
+}
+
+#[test]
+fn bad_signature() {
+    let wasm = r#"
+        (module
+            (type $i32_to_void (func (param i32)))
+            (table 1 funcref)
+            (elem (i32.const 0) $f)
+            (func $f)
+            (func (export "run")
+                (call_indirect (type $i32_to_void) (i32.const 1) (i32.const 0))
+            )
+        )
+    "#;
+    assert_trap(wasm, TrapCode::BadSignature);
+}
+
+#[test]
+fn out_of_fuel() {
+    let wasm = r#"
+        (module
+            (func (export "run")
+                (loop $continue (br $continue))
+            )
+        )
+    "#;
+    let mut config = Config::default();
+    config.consume_fuel(true);
+    let engine = Engine::new(&config);
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    store.set_fuel(1_000).unwrap();
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_func(&store, "run").unwrap();
+    let error = run.call(&mut store, &[], &mut []).unwrap_err();
+    assert_eq!(error.as_trap_code(), Some(TrapCode::OutOfFuel), "{error}");
+}
+
+#[test]
+fn growth_operation_limited() {
+    let wasm = r#"
+        (module
+            (memory 1)
+            (func (export "run")
+                (drop (memory.grow (i32.const 1)))
+            )
+        )
+    "#;
+    let limits = StoreLimitsBuilder::new()
+        .memory_size(65536)
+        .trap_on_grow_failure(true)
+        .build();
+    assert_trap_with(
+        &Config::default(),
+        limits,
+        wasm,
+        TrapCode::GrowthOperationLimited,
+    );
+}
+
+/// Returns a [`Config`] that compiles and validates Wasm functions lazily.
+fn lazy_config() -> Config {
+    let mut config = Config::default();
+    config.compilation_mode(CompilationMode::Lazy);
+    config
+}
+
+#[test]
+fn lazy_compilation_failed() {
+    let wasm = r#"
+        (module
+            (func (export "run")
+                (i64.const 0)
+            )
+        )
+    "#;
+    let error = run_with(&lazy_config(), StoreLimits::default(), wasm);
+    assert_lazy_validation_error(&error);
+}
+
+#[test]
+fn lazy_compilation_failed_nested() {
+    let wasm = r#"
+        (module
+            (func $invalid
+                (i64.const 0)
+            )
+            (func (export "run")
+                (call $invalid)
+            )
+        )
+    "#;
+    let error = run_with(&lazy_config(), StoreLimits::default(), wasm);
+    assert_lazy_validation_error(&error);
+}
+
+#[test]
+fn lazy_compilation_failed_twice() {
+    let wasm = r#"
+        (module
+            (func $invalid
+                (i64.const 0)
+            )
+            (func (export "run")
+                (call $invalid)
+            )
+        )
+    "#;
+    let engine = Engine::new(&lazy_config());
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let run = instance.get_func(&store, "run").unwrap();
+    let error = run.call(&mut store, &[], &mut []).unwrap_err();
+    assert_lazy_validation_error(&error);
+    // Subsequent calls report that the function failed to compile before.
+    let error = run.call(&mut store, &[], &mut []).unwrap_err();
+    assert!(error.as_trap_code().is_none(), "{error}");
+    assert!(matches!(error.kind(), ErrorKind::Translation(_)), "{error}");
+}
+
+#[test]
+fn indirect_call_to_null_message() {
+    let wasm = r#"
+        (module
+            (type $void (func))
+            (table 1 funcref)
+            (table 4 funcref)
+            (func (export "run")
+                (call_indirect 1 (type $void) (i32.const 3))
+            )
+        )
+    "#;
+    let error = run_with(&Config::default(), StoreLimits::default(), wasm);
+    assert_eq!(error.as_trap_code(), Some(TrapCode::IndirectCallToNull));
+    let ErrorKind::IndirectCall(error) = error.kind() else {
+        panic!("expected an indirect call error but found: {error}")
+    };
+    assert_eq!(error.table_index(), 1);
+    assert_eq!(error.element_index(), 3);
+    assert_eq!(error.expected_type_index(), 0);
+    assert!(error.actual_type().is_none());
+    assert!(error
+        .to_string()
+        .ends_with("call_indirect of element 3 of table 1 expected type 0 but found null"));
+}
+
+#[test]
+fn bad_signature_message() {
+    let wasm = r#"
+        (module
+            (type $void (func))
+            (type $i32_to_void (func (param i32)))
+            (table 2 funcref)
+            (elem (i32.const 1) $f)
+            (func $f (result i32) (i32.const 0))
+            (func (export "run")
+                (call_indirect (type $i32_to_void) (i32.const 0) (i32.const 1))
+            )
+        )
+    "#;
+    let error = run_with(&Config::default(), StoreLimits::default(), wasm);
+    assert_eq!(error.as_trap_code(), Some(TrapCode::BadSignature));
+    let ErrorKind::IndirectCall(error) = error.kind() else {
+        panic!("expected an indirect call error but found: {error}")
+    };
+    assert_eq!(error.table_index(), 0);
+    assert_eq!(error.element_index(), 1);
+    assert_eq!(error.expected_type_index(), 1);
+    assert_eq!(
+        error.actual_type(),
+        Some(&FuncType::new([], [ValType::I32]))
+    );
+    assert!(error
+        .to_string()
+        .contains("call_indirect of element 1 of table 0 expected type 1 but found FuncType"));
+}
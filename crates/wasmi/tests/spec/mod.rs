@@ -1,5 +1,5 @@
 use wasmi::Config;
-use wasmi_wast::{ParsingMode, RunnerConfig, WastRunner};
+use wasmi_wast::{OnUnsupported, ParsingMode, RunnerConfig, WastRunner};
 
 /// Runs the Wasm test spec identified by the given name.
 fn process_wast(path: &'static str, wast: &'static str, config: RunnerConfig) {
@@ -88,6 +88,7 @@ fn test_config(consume_fuel: bool, parsing_mode: ParsingMode) -> RunnerConfig {
     RunnerConfig {
         config,
         parsing_mode,
+        on_unsupported: OnUnsupported::Fail,
     }
 }
 
@@ -299,6 +300,7 @@ mod multi_memory {
         RunnerConfig {
             config,
             parsing_mode,
+            on_unsupported: OnUnsupported::Fail,
         }
     }
 
@@ -331,3 +333,193 @@ mod streaming {
         let runner = process_wast;
     }
 }
+
+mod report {
+    use super::*;
+    use wasmi_wast::DirectiveOutcome;
+
+    #[test]
+    fn process_directives_report() {
+        let wast = r#"
+            (module
+                (func (export "answer") (result i32) (i32.const 42))
+            )
+            (assert_return (invoke "answer") (i32.const 42))
+            (assert_return (invoke "answer") (i32.const 0))
+            (assert_malformed (module quote "(func") "unexpected end")
+        "#;
+        let mut runner = WastRunner::new(test_config(false, ParsingMode::Buffered));
+        let report = runner
+            .process_directives_report("report.wast", wast)
+            .unwrap();
+        let outcomes = report
+            .directives
+            .iter()
+            .map(|directive| (directive.kind, directive.line, &directive.outcome))
+            .collect::<Vec<_>>();
+        assert!(matches!(
+            &outcomes[..],
+            [
+                ("module", 2, DirectiveOutcome::Pass),
+                ("assert_return", 5, DirectiveOutcome::Pass),
+                ("assert_return", 6, DirectiveOutcome::Fail { .. }),
+                ("assert_malformed", 7, DirectiveOutcome::Skip { .. }),
+            ]
+        ));
+        assert_eq!(
+            (report.passed(), report.failed(), report.skipped()),
+            (2, 1, 1)
+        );
+        assert!(!report.is_success());
+        let json = report.to_json();
+        assert!(json.starts_with(
+            r#"{"file":"report.wast","passed":2,"failed":1,"skipped":1,"directives":[{"kind":"module","line":2,"col":13,"outcome":"pass"},"#
+        ));
+        assert!(json.contains(
+            r#""line":6,"col":13,"outcome":"fail","message":"failed directive on report.wast:6:13"#
+        ));
+        assert!(json.ends_with(
+            r#""outcome":"skip","message":"quoted malformed modules are not checked"}]}"#
+        ));
+        // The default mode still stops at the first failing directive.
+        let mut runner = WastRunner::new(test_config(false, ParsingMode::Buffered));
+        let error = runner.process_directives("report.wast", wast).unwrap_err();
+        assert!(format!("{error:#}").starts_with("failed directive on report.wast:6:13"));
+    }
+}
+
+mod on_unsupported {
+    use super::*;
+
+    const WAST: &str = r#"
+        (module
+            (func (export "answer") (result i32) (i32.const 42))
+        )
+        (assert_return (invoke "answer") (i32.const 42))
+        (assert_return (invoke "answer") (i32.const 42))
+        (thread $t (module))
+        (wait $t)
+        (assert_malformed (module quote "(func") "unexpected end")
+        (assert_exception (invoke "answer"))
+    "#;
+
+    #[test]
+    fn unsupported_directives_are_skipped() {
+        let mut runner = WastRunner::new(RunnerConfig {
+            on_unsupported: OnUnsupported::Skip,
+            ..test_config(false, ParsingMode::Buffered)
+        });
+        let summary = runner.process_directives("mixed.wast", WAST).unwrap();
+        assert_eq!(
+            (summary.passed(), summary.failed(), summary.skipped()),
+            (3, 0, 4)
+        );
+        assert_eq!(summary.get("assert_return").passed, 2);
+        assert_eq!(summary.get("module").passed, 1);
+        assert_eq!(summary.get("thread").skipped, 1);
+        assert_eq!(summary.get("wait").skipped, 1);
+        assert_eq!(summary.get("assert_malformed").skipped, 1);
+        assert_eq!(summary.get("assert_exception").skipped, 1);
+        assert_eq!(summary.get("invoke"), Default::default());
+        let report = runner
+            .process_directives_report("mixed.wast", WAST)
+            .unwrap();
+        assert_eq!(report.summary(), summary);
+    }
+
+    #[test]
+    fn unsupported_directives_fail() {
+        let mut runner = WastRunner::new(test_config(false, ParsingMode::Buffered));
+        let error = runner.process_directives("mixed.wast", WAST).unwrap_err();
+        assert!(format!("{error:#}").contains("requires threads feature"));
+        let report = runner
+            .process_directives_report("mixed.wast", WAST)
+            .unwrap();
+        let summary = report.summary();
+        assert_eq!(
+            (summary.passed(), summary.failed(), summary.skipped()),
+            (3, 3, 1)
+        );
+        assert_eq!(summary.get("thread").failed, 1);
+        assert_eq!(summary.get("wait").failed, 1);
+        assert_eq!(summary.get("assert_exception").failed, 1);
+    }
+}
+
+mod set_config {
+    use super::*;
+
+    #[test]
+    fn set_config_resets_environment() {
+        let mut config = Config::default();
+        config.wasm_tail_call(false);
+        let mut runner = WastRunner::new(RunnerConfig {
+            config,
+            parsing_mode: ParsingMode::Buffered,
+            on_unsupported: OnUnsupported::Fail,
+        });
+        runner.register_spectest().unwrap();
+        let tail_call = r#"
+            (module
+                (func $f (result i32) (i32.const 42))
+                (func (export "answer") (result i32) (return_call $f))
+            )
+            (assert_return (invoke "answer") (i32.const 42))
+        "#;
+        assert!(runner
+            .process_directives("tail_call.wast", tail_call)
+            .is_err());
+        config.wasm_tail_call(true);
+        runner.set_config(&config).unwrap();
+        runner
+            .process_directives("tail_call.wast", tail_call)
+            .unwrap();
+        // Switching the config discards all instances of prior directives.
+        runner.set_config(&Config::default()).unwrap();
+        let invoke = r#"(assert_return (invoke "answer") (i32.const 42))"#;
+        assert!(runner.process_directives("invoke.wast", invoke).is_err());
+        // The Wasm spec testsuite module is registered anew.
+        let spectest = r#"
+            (module
+                (import "spectest" "global_i32" (global $g i32))
+                (func (export "get") (result i32) (global.get $g))
+            )
+            (assert_return (invoke "get") (i32.const 666))
+        "#;
+        runner
+            .process_directives("spectest.wast", spectest)
+            .unwrap();
+    }
+}
+
+mod funcref_results {
+    use super::*;
+
+    #[test]
+    fn assert_return_compares_funcref_to_indexed_func() {
+        let wast = r#"
+            (module
+                (import "spectest" "print" (func))
+                (func $a)
+                (func $b)
+                (elem declare func $a $b)
+                (func (export "get-a") (result funcref) (ref.func $a))
+                (func (export "get-null") (result funcref) (ref.null func))
+            )
+            (assert_return (invoke "get-a") (ref.func))
+            (assert_return (invoke "get-a") (ref.func 1))
+        "#;
+        let mut runner = WastRunner::new(test_config(false, ParsingMode::Buffered));
+        runner.register_spectest().unwrap();
+        runner.process_directives("funcref.wast", wast).unwrap();
+        for mismatch in [
+            r#"(assert_return (invoke "get-a") (ref.func 2))"#,
+            r#"(assert_return (invoke "get-null") (ref.func 1))"#,
+            r#"(assert_return (invoke "get-a") (ref.func 10))"#,
+        ] {
+            assert!(runner
+                .process_directives("mismatch.wast", mismatch)
+                .is_err());
+        }
+    }
+}
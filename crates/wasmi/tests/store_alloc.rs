@@ -0,0 +1,101 @@
+//! Tests asserting the heap allocations of Wasmi.
+//!
+//! These live in their own test binary since they install a counting global allocator.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+use wasmi::{core::ValType, Engine, Linker, Memory, MemoryType, Module, Store};
+
+/// A global allocator counting the bytes allocated via [`System`].
+struct CountingAllocator;
+
+thread_local! {
+    /// The total number of bytes allocated via the [`CountingAllocator`] by the current thread.
+    ///
+    /// This is thread local since tests are run concurrently.
+    static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Adds `size` to the bytes allocated by the current thread.
+fn record_allocation(size: usize) {
+    // Note: accessing the thread local fails while the thread is torn down.
+    let _ = ALLOCATED.try_with(|allocated| allocated.set(allocated.get() + size));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the number of bytes allocated while running `f` and its result.
+fn count_allocated<R>(f: impl FnOnce() -> R) -> (usize, R) {
+    let before = ALLOCATED.with(Cell::get);
+    let result = f();
+    let after = ALLOCATED.with(Cell::get);
+    (after - before, result)
+}
+
+#[test]
+fn store_new_does_not_allocate() {
+    let engine = Engine::default();
+    let (allocated, mut store) = count_allocated(|| Store::new(&engine, ()));
+    assert_eq!(allocated, 0);
+    assert_eq!(store.allocated_bytes(), 0);
+    let ty = MemoryType::new(1, None).unwrap();
+    let memory = Memory::new(&mut store, ty).unwrap();
+    assert!(store.allocated_bytes() >= memory.data_size(&store));
+}
+
+#[test]
+fn func_signature_accessors_do_not_allocate() {
+    let wasm = r#"
+        (module
+            (func (export "f") (param i32 i64 f32 f64) (result i64 i32)
+                (local.get 1)
+                (local.get 0)
+            )
+        )
+    "#;
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = Linker::new(&engine)
+        .instantiate(&mut store, &module)
+        .unwrap()
+        .start(&mut store)
+        .unwrap();
+    let func = instance.get_func(&store, "f").unwrap();
+    let params = [ValType::I32, ValType::I64, ValType::F32, ValType::F64];
+    let results = [ValType::I64, ValType::I32];
+    let (allocated, (len_params, len_results, matches, mismatches)) = count_allocated(|| {
+        (
+            func.param_len(&store),
+            func.result_len(&store),
+            func.matches_signature(&store, &params, &results),
+            func.matches_signature(&store, &results, &params),
+        )
+    });
+    assert_eq!(allocated, 0);
+    assert_eq!(len_params, params.len());
+    assert_eq!(len_results, results.len());
+    assert!(matches);
+    assert!(!mismatches);
+    let ty = func.ty(&store);
+    assert_eq!((ty.params(), ty.results()), (&params[..], &results[..]));
+}
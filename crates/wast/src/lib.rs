@@ -1,3 +1,13 @@
+mod report;
+
+pub use self::report::{
+    DirectiveCounts,
+    DirectiveOutcome,
+    DirectiveReport,
+    DisplayJsonStr,
+    WastReport,
+    WastSummary,
+};
 use anyhow::{bail, Context as _, Result};
 use std::collections::HashMap;
 use wasmi::{
@@ -5,6 +15,7 @@ use wasmi::{
     Config,
     Engine,
     Extern,
+    Func,
     Global,
     Instance,
     Linker,
@@ -21,7 +32,7 @@ use wast::{
     core::{AbstractHeapType, HeapType, NanPattern, WastArgCore, WastRetCore},
     lexer::Lexer,
     parser::ParseBuffer,
-    token::Id,
+    token::{Id, Index},
     QuoteWat,
     WastArg,
     WastDirective,
@@ -37,6 +48,18 @@ pub struct RunnerConfig {
     pub config: Config,
     /// The parsing mode that is used.
     pub parsing_mode: ParsingMode,
+    /// How directives unsupported by the runner are handled.
+    pub on_unsupported: OnUnsupported,
+}
+
+/// How the test runner handles `.wast` directives that it does not support.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum OnUnsupported {
+    /// Unsupported directives fail with an error.
+    #[default]
+    Fail,
+    /// Unsupported directives are skipped and counted as such.
+    Skip,
 }
 
 /// The mode in which Wasm is parsed.
@@ -86,6 +109,25 @@ impl WastRunner {
         }
     }
 
+    /// Reconfigures `self` to use the Wasmi `config` for all subsequently processed directives.
+    ///
+    /// # Note
+    ///
+    /// This creates a new [`Engine`] from `config` and resets the execution environment,
+    /// i.e. all previously defined modules and instances are discarded and the Wasm spec
+    /// testsuite module is registered anew.
+    ///
+    /// # Errors
+    ///
+    /// If the Wasm spec testsuite module cannot be registered.
+    pub fn set_config(&mut self, config: &Config) -> Result<(), wasmi::Error> {
+        *self = Self::new(RunnerConfig {
+            config: *config,
+            ..self.config
+        });
+        self.register_spectest()
+    }
+
     /// Sets up the Wasm spec testsuite module for `self`.
     pub fn register_spectest(&mut self) -> Result<(), wasmi::Error> {
         let Self { store, .. } = self;
@@ -170,35 +212,76 @@ impl WastRunner {
     }
 
     /// Processes the directives of the given `wast` source by `self`.
-    pub fn process_directives(&mut self, filename: &str, wast: &str) -> Result<()> {
-        let enhance_error = |mut err: wast::Error| {
-            err.set_path(filename.as_ref());
-            err.set_text(wast);
-            err
-        };
-        let mut lexer = Lexer::new(wast);
-        lexer.allow_confusing_unicode(true);
-        let buffer = ParseBuffer::new_with_lexer(lexer).map_err(enhance_error)?;
-        let directives = wast::parser::parse::<wast::Wast>(&buffer)
-            .map_err(enhance_error)?
-            .directives;
-        for directive in directives {
-            let span = directive.span();
-            self.process_directive(directive)
-                .map_err(|err| match err.downcast::<wast::Error>() {
-                    Ok(err) => enhance_error(err).into(),
-                    Err(err) => err,
-                })
-                .with_context(|| {
-                    let (line, col) = span.linecol_in(wast);
-                    format!("failed directive on {}:{}:{}", filename, line + 1, col)
-                })?;
+    ///
+    /// Returns the [`WastSummary`] of all passed and skipped directives.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first failing directive and returns its error.
+    pub fn process_directives(&mut self, filename: &str, wast: &str) -> Result<WastSummary> {
+        let mut summary = WastSummary::default();
+        for directive in lex_wast(filename, wast)?.parse()?.directives {
+            let kind = directive_kind(&directive);
+            let outcome = match self.process_located_directive(filename, wast, directive)? {
+                Processed::Executed => DirectiveOutcome::Pass,
+                Processed::Skipped(reason) => DirectiveOutcome::Skip { reason },
+            };
+            summary.record(kind, &outcome);
         }
-        Ok(())
+        Ok(summary)
+    }
+
+    /// Processes all directives of the given `wast` source by `self` and reports their outcomes.
+    ///
+    /// Unlike [`WastRunner::process_directives`] this does not stop at the first
+    /// failing directive but instead records the outcome of every directive.
+    ///
+    /// # Errors
+    ///
+    /// If the `wast` source cannot be parsed.
+    pub fn process_directives_report(&mut self, filename: &str, wast: &str) -> Result<WastReport> {
+        let mut report = WastReport::new(filename);
+        for directive in lex_wast(filename, wast)?.parse()?.directives {
+            let kind = directive_kind(&directive);
+            let (line, col) = directive.span().linecol_in(wast);
+            let outcome = match self.process_located_directive(filename, wast, directive) {
+                Ok(Processed::Executed) => DirectiveOutcome::Pass,
+                Ok(Processed::Skipped(reason)) => DirectiveOutcome::Skip { reason },
+                Err(error) => DirectiveOutcome::Fail {
+                    message: format!("{error:#}"),
+                },
+            };
+            report.directives.push(DirectiveReport {
+                kind,
+                line: line + 1,
+                col,
+                outcome,
+            });
+        }
+        Ok(report)
+    }
+
+    /// Processes `directive` and enhances its error with its location within `wast` if any.
+    fn process_located_directive(
+        &mut self,
+        filename: &str,
+        wast: &str,
+        directive: WastDirective,
+    ) -> Result<Processed> {
+        let span = directive.span();
+        self.process_directive(directive)
+            .map_err(|err| match err.downcast::<wast::Error>() {
+                Ok(err) => enhance_error(filename, wast, err).into(),
+                Err(err) => err,
+            })
+            .with_context(|| {
+                let (line, col) = span.linecol_in(wast);
+                format!("failed directive on {}:{}:{}", filename, line + 1, col)
+            })
     }
 
     /// Processes the given `.wast` directive by `self`.
-    fn process_directive(&mut self, directive: WastDirective) -> Result<()> {
+    fn process_directive(&mut self, directive: WastDirective) -> Result<Processed> {
         match directive {
             #[rustfmt::skip]
             WastDirective::Module(
@@ -258,7 +341,11 @@ impl WastRunner {
             WastDirective::AssertMalformed {
                 module: QuoteWat::QuoteModule { .. },
                 ..
-            } => {}
+            } => {
+                return Ok(Processed::Skipped(
+                    "quoted malformed modules are not checked",
+                ))
+            }
             WastDirective::AssertUnlinkable {
                 module: module @ Wat::Module(_),
                 message,
@@ -269,6 +356,10 @@ impl WastRunner {
                     bail!("module succeeded to link but should have failed with: {message}")
                 }
             }
+            WastDirective::AssertUnlinkable {
+                module: Wat::Component(_),
+                ..
+            } => return self.unsupported("requires component model feature"),
             WastDirective::AssertTrap { exec, message, .. } => {
                 match self.execute_wast_execute(exec) {
                     Ok(_) => {
@@ -301,9 +392,32 @@ impl WastRunner {
                     self.assert_trap(error, message)?;
                 }
             },
-            unsupported => bail!("encountered unsupported Wast directive: {unsupported:?}"),
+            WastDirective::Thread(_) | WastDirective::Wait { .. } => {
+                return self.unsupported("requires threads feature")
+            }
+            WastDirective::AssertSuspension { .. } => {
+                return self.unsupported("requires stack switching feature")
+            }
+            unsupported => match self.config.on_unsupported {
+                OnUnsupported::Fail => {
+                    bail!("encountered unsupported Wast directive: {unsupported:?}")
+                }
+                OnUnsupported::Skip => return Ok(Processed::Skipped("unsupported directive")),
+            },
         };
-        Ok(())
+        Ok(Processed::Executed)
+    }
+
+    /// Handles a directive that is unsupported for the given `reason`.
+    ///
+    /// # Errors
+    ///
+    /// If unsupported directives shall fail as configured by [`OnUnsupported`].
+    fn unsupported(&self, reason: &'static str) -> Result<Processed> {
+        match self.config.on_unsupported {
+            OnUnsupported::Fail => bail!("encountered unsupported Wast directive: {reason}"),
+            OnUnsupported::Skip => Ok(Processed::Skipped(reason)),
+        }
     }
 
     /// Instantiates `module` and makes its exports available under `name` if any.
@@ -396,6 +510,13 @@ impl WastRunner {
                     ..
                 })),
             ) => funcref.is_null(),
+            (Val::FuncRef(funcref), WastRetCore::RefFunc(None)) => !funcref.is_null(),
+            (Val::FuncRef(funcref), WastRetCore::RefFunc(Some(index))) => {
+                let expected = self.get_func_by_index(*index)?;
+                funcref
+                    .func()
+                    .is_some_and(|result| Func::same(result, &expected))
+            }
             (
                 Val::ExternRef(externref),
                 WastRetCore::RefNull(Some(HeapType::Abstract {
@@ -482,6 +603,25 @@ impl WastRunner {
         Ok(value)
     }
 
+    /// Returns the [`Func`] at `index` of the current instance.
+    ///
+    /// # Errors
+    ///
+    /// - If there is no current instance.
+    /// - If `index` is symbolic or has no function.
+    fn get_func_by_index(&self, index: Index) -> Result<Func> {
+        let Some(current) = self.current else {
+            bail!("missing previous instance to get function at: {index:?}")
+        };
+        let Index::Num(index, _) = index else {
+            bail!("cannot resolve symbolic function index: {index:?}")
+        };
+        match current.get_func_by_index(&self.store, index) {
+            Some(func) => Ok(func),
+            None => bail!("missing function at index {index}"),
+        }
+    }
+
     /// Asserts that the `error` is a trap with the expected `message`.
     ///
     /// # Panics
@@ -527,7 +667,7 @@ impl WastRunner {
             bail!("missing function at {:?}::{}", invoke.module, invoke.name)
         };
         self.fill_params(&invoke.args)?;
-        let len_results = func.ty(&self.store).results().len();
+        let len_results = func.result_len(&self.store);
         self.results.clear();
         self.results.resize(len_results, Val::I32(0));
         func.call(&mut self.store, &self.params, &mut self.results[..])?;
@@ -553,3 +693,68 @@ impl WastRunner {
         Ok(())
     }
 }
+
+/// The result of successfully processing a single `.wast` directive.
+enum Processed {
+    /// The directive was executed.
+    Executed,
+    /// The directive was skipped for the given reason.
+    Skipped(&'static str),
+}
+
+/// The lexed `.wast` source of a file.
+struct LexedWast<'a> {
+    filename: &'a str,
+    wast: &'a str,
+    buffer: ParseBuffer<'a>,
+}
+
+impl LexedWast<'_> {
+    /// Parses the `.wast` directives of `self`.
+    fn parse(&self) -> Result<wast::Wast<'_>> {
+        let wast = wast::parser::parse::<wast::Wast>(&self.buffer)
+            .map_err(|err| enhance_error(self.filename, self.wast, err))?;
+        Ok(wast)
+    }
+}
+
+/// Lexes the `wast` source of `filename` for parsing its directives.
+fn lex_wast<'a>(filename: &'a str, wast: &'a str) -> Result<LexedWast<'a>> {
+    let mut lexer = Lexer::new(wast);
+    lexer.allow_confusing_unicode(true);
+    let buffer =
+        ParseBuffer::new_with_lexer(lexer).map_err(|err| enhance_error(filename, wast, err))?;
+    Ok(LexedWast {
+        filename,
+        wast,
+        buffer,
+    })
+}
+
+/// Enhances `err` with the `filename` and `wast` source for better error messages.
+fn enhance_error(filename: &str, wast: &str, mut err: wast::Error) -> wast::Error {
+    err.set_path(filename.as_ref());
+    err.set_text(wast);
+    err
+}
+
+/// Returns the name of the `.wast` directive as written in `.wast` files.
+fn directive_kind(directive: &WastDirective) -> &'static str {
+    match directive {
+        WastDirective::Module(_) => "module",
+        WastDirective::ModuleDefinition(_) => "module_definition",
+        WastDirective::ModuleInstance { .. } => "module_instance",
+        WastDirective::AssertMalformed { .. } => "assert_malformed",
+        WastDirective::AssertInvalid { .. } => "assert_invalid",
+        WastDirective::Register { .. } => "register",
+        WastDirective::Invoke(_) => "invoke",
+        WastDirective::AssertTrap { .. } => "assert_trap",
+        WastDirective::AssertReturn { .. } => "assert_return",
+        WastDirective::AssertExhaustion { .. } => "assert_exhaustion",
+        WastDirective::AssertUnlinkable { .. } => "assert_unlinkable",
+        WastDirective::AssertException { .. } => "assert_exception",
+        WastDirective::AssertSuspension { .. } => "assert_suspension",
+        WastDirective::Thread(_) => "thread",
+        WastDirective::Wait { .. } => "wait",
+    }
+}
@@ -0,0 +1,251 @@
+use core::fmt;
+use std::collections::BTreeMap;
+
+/// The outcome of processing a single `.wast` directive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DirectiveOutcome {
+    /// The directive was processed successfully.
+    Pass,
+    /// The directive failed with the given error `message`.
+    Fail {
+        /// The formatted error of the failed directive.
+        message: String,
+    },
+    /// The directive was not processed by the [`WastRunner`].
+    ///
+    /// [`WastRunner`]: crate::WastRunner
+    Skip {
+        /// The reason why the directive was skipped.
+        reason: &'static str,
+    },
+}
+
+impl DirectiveOutcome {
+    /// Returns the name of the outcome as used in the JSON report.
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail { .. } => "fail",
+            Self::Skip { .. } => "skip",
+        }
+    }
+}
+
+/// The report of a single processed `.wast` directive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectiveReport {
+    /// The kind of the `.wast` directive, e.g. `"assert_return"`.
+    pub kind: &'static str,
+    /// The 1-based line of the directive within its `.wast` file.
+    pub line: usize,
+    /// The column of the directive within its line.
+    pub col: usize,
+    /// The outcome of processing the directive.
+    pub outcome: DirectiveOutcome,
+}
+
+/// The report of all processed directives of a single `.wast` file.
+///
+/// Created by [`WastRunner::process_directives_report`].
+///
+/// [`WastRunner::process_directives_report`]: crate::WastRunner::process_directives_report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WastReport {
+    /// The name of the processed `.wast` file.
+    pub filename: String,
+    /// The reports of all directives in the order of their appearance.
+    pub directives: Vec<DirectiveReport>,
+}
+
+impl WastReport {
+    /// Creates a new empty [`WastReport`] for `filename`.
+    pub(crate) fn new(filename: &str) -> Self {
+        Self {
+            filename: filename.into(),
+            directives: Vec::new(),
+        }
+    }
+
+    /// Returns the number of directives with the given `outcome` kind.
+    fn count(&self, f: impl Fn(&DirectiveOutcome) -> bool) -> usize {
+        self.directives.iter().filter(|d| f(&d.outcome)).count()
+    }
+
+    /// Returns the number of passed directives.
+    pub fn passed(&self) -> usize {
+        self.count(|outcome| matches!(outcome, DirectiveOutcome::Pass))
+    }
+
+    /// Returns the number of failed directives.
+    pub fn failed(&self) -> usize {
+        self.count(|outcome| matches!(outcome, DirectiveOutcome::Fail { .. }))
+    }
+
+    /// Returns the number of skipped directives.
+    pub fn skipped(&self) -> usize {
+        self.count(|outcome| matches!(outcome, DirectiveOutcome::Skip { .. }))
+    }
+
+    /// Returns `true` if no directive failed.
+    pub fn is_success(&self) -> bool {
+        self.failed() == 0
+    }
+
+    /// Returns the [`WastSummary`] of all directives of the [`WastReport`].
+    pub fn summary(&self) -> WastSummary {
+        let mut summary = WastSummary::default();
+        for directive in &self.directives {
+            summary.record(directive.kind, &directive.outcome);
+        }
+        summary
+    }
+
+    /// Serializes the [`WastReport`] as JSON.
+    ///
+    /// # Note
+    ///
+    /// The JSON object has the following shape:
+    ///
+    /// ```json
+    /// {
+    ///   "file": "testsuite/i32.wast",
+    ///   "passed": 1, "failed": 1, "skipped": 0,
+    ///   "directives": [
+    ///     { "kind": "module", "line": 1, "col": 0, "outcome": "pass" },
+    ///     { "kind": "assert_return", "line": 5, "col": 0, "outcome": "fail", "message": "..." }
+    ///   ]
+    /// }
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json)
+            .unwrap_or_else(|_| unreachable!("writing to a `String` cannot fail"));
+        json
+    }
+
+    /// Writes the JSON representation of `self` into `w`.
+    fn write_json(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        write!(
+            w,
+            "{{\"file\":{},\"passed\":{},\"failed\":{},\"skipped\":{},\"directives\":[",
+            DisplayJsonStr::from(&self.filename[..]),
+            self.passed(),
+            self.failed(),
+            self.skipped(),
+        )?;
+        for (n, directive) in self.directives.iter().enumerate() {
+            if n != 0 {
+                w.write_char(',')?;
+            }
+            let DirectiveReport {
+                kind,
+                line,
+                col,
+                outcome,
+            } = directive;
+            write!(
+                w,
+                "{{\"kind\":\"{kind}\",\"line\":{line},\"col\":{col},\"outcome\":\"{}\"",
+                outcome.as_str()
+            )?;
+            match outcome {
+                DirectiveOutcome::Pass => {}
+                DirectiveOutcome::Fail { message } => {
+                    write!(w, ",\"message\":{}", DisplayJsonStr::from(&message[..]))?;
+                }
+                DirectiveOutcome::Skip { reason } => {
+                    write!(w, ",\"message\":{}", DisplayJsonStr::from(*reason))?;
+                }
+            }
+            w.write_char('}')?;
+        }
+        w.write_str("]}")
+    }
+}
+
+/// The number of passed, failed and skipped directives of a single kind.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct DirectiveCounts {
+    /// The number of passed directives.
+    pub passed: usize,
+    /// The number of failed directives.
+    pub failed: usize,
+    /// The number of skipped directives.
+    pub skipped: usize,
+}
+
+/// The summary of processed `.wast` directives grouped by their kind.
+///
+/// Returned by [`WastRunner::process_directives`] and [`WastReport::summary`].
+///
+/// [`WastRunner::process_directives`]: crate::WastRunner::process_directives
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WastSummary {
+    /// The counts of processed directives per directive kind, e.g. `"assert_return"`.
+    pub kinds: BTreeMap<&'static str, DirectiveCounts>,
+}
+
+impl WastSummary {
+    /// Records the `outcome` of a directive of the given `kind`.
+    pub(crate) fn record(&mut self, kind: &'static str, outcome: &DirectiveOutcome) {
+        let counts = self.kinds.entry(kind).or_default();
+        match outcome {
+            DirectiveOutcome::Pass => counts.passed += 1,
+            DirectiveOutcome::Fail { .. } => counts.failed += 1,
+            DirectiveOutcome::Skip { .. } => counts.skipped += 1,
+        }
+    }
+
+    /// Returns the [`DirectiveCounts`] of all directives of the given `kind`.
+    pub fn get(&self, kind: &str) -> DirectiveCounts {
+        self.kinds.get(kind).copied().unwrap_or_default()
+    }
+
+    /// Returns the sum of `f` applied to the counts of all directive kinds.
+    fn total(&self, f: impl Fn(&DirectiveCounts) -> usize) -> usize {
+        self.kinds.values().map(f).sum()
+    }
+
+    /// Returns the total number of passed directives.
+    pub fn passed(&self) -> usize {
+        self.total(|counts| counts.passed)
+    }
+
+    /// Returns the total number of failed directives.
+    pub fn failed(&self) -> usize {
+        self.total(|counts| counts.failed)
+    }
+
+    /// Returns the total number of skipped directives.
+    pub fn skipped(&self) -> usize {
+        self.total(|counts| counts.skipped)
+    }
+}
+
+/// [`Display`](fmt::Display)-wrapper type for a `str` as escaped JSON string literal.
+pub struct DisplayJsonStr<'a>(&'a str);
+
+impl<'a> From<&'a str> for DisplayJsonStr<'a> {
+    fn from(value: &'a str) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for DisplayJsonStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write as _;
+        f.write_char('"')?;
+        for c in self.0.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                c => f.write_char(c)?,
+            }
+        }
+        f.write_char('"')
+    }
+}
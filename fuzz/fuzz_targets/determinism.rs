@@ -0,0 +1,187 @@
+#![no_main]
+
+use arbitrary::{Arbitrary, Unstructured};
+use libfuzzer_sys::fuzz_target;
+use wasmi::{
+    core::ValType,
+    Config,
+    Engine,
+    Export,
+    Instance,
+    Linker,
+    Module,
+    Store,
+    StoreLimits,
+    StoreLimitsBuilder,
+    Val,
+};
+use wasmi_fuzz::{FuzzError, FuzzModule, FuzzSmithConfig, FuzzVal, FuzzValType, FuzzWasmiConfig};
+
+/// Fuzzing input for determinism fuzzing.
+#[derive(Debug)]
+pub struct FuzzInput<'a> {
+    /// The configuration of the Wasmi engine.
+    config: FuzzWasmiConfig,
+    /// The fuzzed Wasm module and its configuration.
+    module: FuzzModule,
+    /// Additional unstructured input data used to initialize call parameter etc.
+    u: Unstructured<'a>,
+}
+
+impl<'a> Arbitrary<'a> for FuzzInput<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let config = FuzzWasmiConfig::arbitrary(u)?;
+        let mut fuzz_config = FuzzSmithConfig::arbitrary(u)?;
+        fuzz_config.enable_determinism();
+        fuzz_config.export_everything();
+        let module = FuzzModule::new(fuzz_config, u)?;
+        Ok(Self {
+            config,
+            module,
+            u: Unstructured::new(&[]),
+        })
+    }
+
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> arbitrary::Result<Self> {
+        Self::arbitrary(&mut u).map(|mut input| {
+            input.u = u;
+            input
+        })
+    }
+}
+
+fuzz_target!(|input: FuzzInput| {
+    let FuzzInput {
+        config,
+        module,
+        mut u,
+    } = input;
+    let wasm = module.wasm().into_bytes();
+    let engine_config = {
+        let mut config = Config::from(config);
+        // We use Wasmi's built-in fuel metering to guarantee termination
+        // which is also deterministic and thus part of what gets compared.
+        config.consume_fuel(true);
+        config
+    };
+    let engine = Engine::new(&engine_config);
+    // Note: validation is always checked since executing invalid Wasm
+    //       is undefined behavior and thus inherently non-deterministic.
+    let Ok(module) = Module::new(&engine, &wasm[..]) else {
+        return;
+    };
+    let Some(mut lhs) = FuzzInstance::setup(&module) else {
+        return;
+    };
+    let Some(mut rhs) = FuzzInstance::setup(&module) else {
+        panic!(
+            "second instantiation failed while the first succeeded\n\
+                \tcrash-report: 0x{}\n",
+            generate_crash_inputs(&wasm),
+        )
+    };
+    let mut params = Vec::new();
+    for (name, func) in lhs.funcs() {
+        let func_ty = func.ty(&lhs.store);
+        fill_values(&mut params, func_ty.params(), &mut u);
+        let result_lhs = lhs.call(&name, &params);
+        let result_rhs = rhs.call(&name, &params);
+        let fuel_lhs = lhs.store.get_fuel().ok();
+        let fuel_rhs = rhs.store.get_fuel().ok();
+        if result_lhs == result_rhs && fuel_lhs == fuel_rhs {
+            continue;
+        }
+        let crash_input = generate_crash_inputs(&wasm);
+        panic!(
+            "\
+            function call behaved non-deterministically:\n\
+                \tfunc: {name}\n\
+                \tparams: {params:?}\n\
+                \t1st: {result_lhs:?} (remaining fuel: {fuel_lhs:?})\n\
+                \t2nd: {result_rhs:?} (remaining fuel: {fuel_rhs:?})\n\
+                \tcrash-report: 0x{crash_input}\n\
+            "
+        )
+    }
+});
+
+/// A Wasmi instance living in its own freshly created [`Store`].
+struct FuzzInstance {
+    store: Store<StoreLimits>,
+    instance: Instance,
+    params: Vec<Val>,
+    results: Vec<Val>,
+}
+
+impl FuzzInstance {
+    /// Instantiates `module` in a fresh [`Store`] if possible.
+    fn setup(module: &Module) -> Option<Self> {
+        let engine = module.engine();
+        let linker = Linker::new(engine);
+        let limiter = StoreLimitsBuilder::new()
+            .memory_size(1000 * 0x10000)
+            .build();
+        let mut store = Store::new(engine, limiter);
+        store.limiter(|lim| lim);
+        store.set_fuel(1000).ok()?;
+        let instance = linker
+            .instantiate(&mut store, module)
+            .ok()?
+            .ensure_no_start(&mut store)
+            .ok()?;
+        Some(Self {
+            store,
+            instance,
+            params: Vec::new(),
+            results: Vec::new(),
+        })
+    }
+
+    /// Returns the names and [`Func`]s of all exported functions.
+    ///
+    /// [`Func`]: wasmi::Func
+    fn funcs(&self) -> Vec<(String, wasmi::Func)> {
+        self.instance
+            .exports(&self.store)
+            .filter_map(|export| {
+                let name = export.name().to_string();
+                Export::into_func(export).map(|func| (name, func))
+            })
+            .collect()
+    }
+
+    /// Calls the exported function `name` with `params` and returns its results.
+    fn call(&mut self, name: &str, params: &[Val]) -> Result<Box<[FuzzVal]>, FuzzError> {
+        let Some(func) = self.instance.get_func(&self.store, name) else {
+            panic!("{name:?} is not an exported function")
+        };
+        let func_ty = func.ty(&self.store);
+        self.params.clear();
+        self.params.extend_from_slice(params);
+        self.results.clear();
+        self.results
+            .extend(func_ty.results().iter().copied().map(Val::default));
+        func.call(&mut self.store, &self.params[..], &mut self.results[..])
+            .map_err(FuzzError::from)?;
+        Ok(self.results.iter().cloned().map(FuzzVal::from).collect())
+    }
+}
+
+/// Fill [`Val`]s of type `src` into `dst` using `u` for initialization.
+///
+/// Clears `dst` before the operation.
+fn fill_values(dst: &mut Vec<Val>, src: &[ValType], u: &mut Unstructured) {
+    dst.clear();
+    dst.extend(
+        src.iter()
+            .copied()
+            .map(FuzzValType::from)
+            .map(|ty| FuzzVal::with_type(ty, u))
+            .map(Val::from),
+    );
+}
+
+/// Writes the crash inputs for `wasm` and returns their hash.
+fn generate_crash_inputs(wasm: &[u8]) -> String {
+    wasmi_fuzz::generate_crash_inputs("determinism", wasm).unwrap()
+}
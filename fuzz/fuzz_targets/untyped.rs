@@ -0,0 +1,45 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use wasmi::{
+    core::{UntypedVal, ValType, F32, F64},
+    Val,
+};
+
+fuzz_target!(|input: (u64, i32, i64, u32, u64)| {
+    let (bits, i32, i64, f32, f64) = input;
+    let (f32, f64) = (F32::from_bits(f32), F64::from_bits(f64));
+    // Raw bits and all integer conversions round-trip.
+    assert_eq!(UntypedVal::from_bits(bits).to_bits(), bits);
+    macro_rules! assert_round_trip {
+        ( $( $ty:ty ),* $(,)? ) => {
+            $(
+                let value = bits as $ty;
+                assert_eq!(<$ty>::from(UntypedVal::from(value)), value);
+            )*
+        };
+    }
+    assert_round_trip!(i8, i16, i32, i64, u8, u16, u32, u64);
+    // Float conversions preserve all bits including NaN payloads.
+    assert_eq!(F32::from(UntypedVal::from(f32)).to_bits(), f32.to_bits());
+    assert_eq!(F64::from(UntypedVal::from(f64)).to_bits(), f64.to_bits());
+    // `Val` round-trips through `UntypedVal` given its `ValType`.
+    for (value, ty) in [
+        (Val::I32(i32), ValType::I32),
+        (Val::I64(i64), ValType::I64),
+        (Val::F32(f32), ValType::F32),
+        (Val::F64(f64), ValType::F64),
+    ] {
+        let untyped = value.to_untyped();
+        assert_eq!(Val::from_untyped(untyped, ty).to_untyped(), untyped);
+    }
+    // Slices of `UntypedVal` round-trip tuples of values.
+    let mut slice = [UntypedVal::default(); 4];
+    UntypedVal::encode_slice(&mut slice, (i32, i64, f32, f64)).unwrap();
+    let (out_i32, out_i64, out_f32, out_f64) =
+        UntypedVal::decode_slice::<(i32, i64, F32, F64)>(&slice).unwrap();
+    assert_eq!(out_i32, i32);
+    assert_eq!(out_i64, i64);
+    assert_eq!(out_f32.to_bits(), f32.to_bits());
+    assert_eq!(out_f64.to_bits(), f64.to_bits());
+});